@@ -0,0 +1,53 @@
+use algebra::modulus::BarrettModulus;
+use algebra::ntt::{FieldTableWithShoupRoot, NttRadix, NumberTheoryTransform};
+use algebra::{Field, NttField, U16FieldEval};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{distributions::Uniform, prelude::*};
+
+type F16 = U16FieldEval<12289>;
+
+/// Compares the radix-2 and radix-4 transforms of the same table.
+fn bench_radix<F>(c: &mut Criterion, name: &str, log_ns: &[u32])
+where
+    F: NttField<Table = FieldTableWithShoupRoot<F>>,
+    F: Field<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let distr = Uniform::new_inclusive(F::ZERO, F::MINUS_ONE);
+
+    let mut group = c.benchmark_group(name);
+    for &log_n in log_ns {
+        let n = 1usize << log_n;
+        let mut table = F::generate_ntt_table(log_n).unwrap();
+        let mut poly: Vec<<F as Field>::ValueT> = (&distr).sample_iter(&mut rng).take(n).collect();
+
+        for radix in [NttRadix::Radix2, NttRadix::Radix4] {
+            table.set_radix(radix);
+
+            group.bench_function(BenchmarkId::new(format!("{radix:?} ntt"), n), |b| {
+                b.iter(|| table.transform_slice(poly.as_mut_slice()))
+            });
+
+            group.bench_function(BenchmarkId::new(format!("{radix:?} intt"), n), |b| {
+                b.iter(|| table.inverse_transform_slice(poly.as_mut_slice()))
+            });
+        }
+    }
+    group.finish();
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    bench_radix::<F16>(c, "field 16", &[10, 11]);
+
+    // With `concrete-ntt`, the 32 and 64 bits fields use its tables instead.
+    #[cfg(not(feature = "concrete-ntt"))]
+    {
+        use algebra::{U32FieldEval, U64FieldEval};
+
+        bench_radix::<U32FieldEval<132120577>>(c, "field 32", &[10, 11, 12]);
+        bench_radix::<U64FieldEval<1125899906826241>>(c, "field 64", &[10, 11, 12]);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -3,7 +3,11 @@
 mod gcd;
 mod minus_one;
 mod prim_root;
+mod prime;
+mod round;
 
 pub use gcd::Xgcd;
 pub use minus_one::{ConstMinusOne, MinusOne};
 pub use prim_root::PrimitiveRoot;
+pub use prime::{is_prime, NttPrimes};
+pub use round::{div_round, mul_div_round, MulDivRound};
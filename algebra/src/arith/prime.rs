@@ -0,0 +1,227 @@
+use crate::arith::PrimitiveRoot;
+use crate::integer::{AsFrom, AsInto};
+use crate::modulus::BarrettModulus;
+use crate::numeric::Numeric;
+use crate::reduce::{ReduceExp, ReduceSquare};
+use crate::AlgebraError;
+
+/// The bases which make Miller-Rabin deterministic for all integers below `3.3·10²⁴`.
+const WITNESSES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Checks whether `n` is a prime number.
+///
+/// This is the Miller-Rabin test with a fixed set of bases,
+/// which is deterministic for all types up to `u64`.
+pub fn is_prime<T: Numeric>(n: T) -> bool {
+    if n < T::TWO {
+        return false;
+    }
+    for p in WITNESSES {
+        let p = T::as_from(p);
+        if n == p {
+            return true;
+        }
+        if (n % p).is_zero() {
+            return false;
+        }
+    }
+
+    if T::BITS - n.leading_zeros() < T::BITS - 1 {
+        let modulus = BarrettModulus::new_generic(n);
+        miller_rabin(
+            n,
+            |a, d| modulus.reduce_exp(a, d),
+            |x| modulus.reduce_square(x),
+        )
+    } else {
+        // too wide for `BarrettModulus`, reduce the products in `T::WideT`
+        miller_rabin(n, |a, d| pow_mod(a, d, n), |x| mul_mod(x, x, n))
+    }
+}
+
+/// Runs the Miller-Rabin rounds of every witness on an odd `n > 37`,
+/// with `exp` and `square` computing modulo `n`.
+fn miller_rabin<T: Numeric>(n: T, exp: impl Fn(T, T) -> T, square: impl Fn(T) -> T) -> bool {
+    let n_minus_one = n - T::ONE;
+    let s = n_minus_one.trailing_zeros();
+    let d = n_minus_one >> s;
+
+    WITNESSES.iter().all(|&a| {
+        let mut x = exp(T::as_from(a), d);
+        if x == T::ONE || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = square(x);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[inline]
+fn mul_mod<T: Numeric>(a: T, b: T, n: T) -> T {
+    (T::WideT::as_from(a) * T::WideT::as_from(b) % T::WideT::as_from(n)).as_into()
+}
+
+fn pow_mod<T: Numeric>(mut base: T, mut exp: T, n: T) -> T {
+    let mut result = T::ONE;
+    while !exp.is_zero() {
+        if !(exp & T::ONE).is_zero() {
+            result = mul_mod(result, base, n);
+        }
+        base = mul_mod(base, base, n);
+        exp >>= 1u32;
+    }
+    result
+}
+
+/// An iterator over NTT-friendly primes.
+///
+/// For a ring dimension `N`, it yields primes `p ≡ 1 (mod 2N)` with exactly `bits` bits
+/// from the largest to the smallest, together with the minimal primitive `2N`-th root
+/// of unity modulo `p`. Such primes support the negacyclic NTT of size `N`.
+#[derive(Debug, Clone)]
+pub struct NttPrimes<T: Numeric> {
+    /// The next candidate, it's always `1 (mod 2N)`.
+    candidate: Option<T>,
+    /// `2N`.
+    step: T,
+    /// `2^(bits-1)`.
+    lower_bound: T,
+    /// `log(2N)`.
+    log_degree: u32,
+}
+
+impl<T: Numeric> NttPrimes<T> {
+    /// Creates a new [`NttPrimes<T>`] for primes with `bits` bits and the ring dimension `ring_dimension`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::BitCountErr`] if `bits` is larger than `T::BITS - 2`,
+    /// which is the limit of [`BarrettModulus`], or too small to hold `2N + 1`, the smallest candidate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ring_dimension` is not a power of two.
+    pub fn new(bits: u32, ring_dimension: usize) -> Result<Self, AlgebraError> {
+        assert!(ring_dimension.is_power_of_two());
+        let log_degree = ring_dimension.trailing_zeros() + 1;
+
+        if bits > T::BITS - 2 || bits < log_degree + 1 {
+            return Err(AlgebraError::BitCountErr);
+        }
+
+        let step = T::ONE << log_degree;
+        let lower_bound = T::ONE << (bits - 1);
+        // The largest value `≡ 1 (mod 2N)` below `2^bits`.
+        let candidate = (T::ONE << bits) - step + T::ONE;
+
+        Ok(Self {
+            candidate: Some(candidate),
+            step,
+            lower_bound,
+            log_degree,
+        })
+    }
+}
+
+impl<T: Numeric> Iterator for NttPrimes<T> {
+    /// The prime and its minimal primitive `2N`-th root of unity.
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(candidate) = self.candidate {
+            self.candidate = candidate
+                .checked_sub(self.step)
+                .filter(|&next| next >= self.lower_bound);
+
+            if is_prime(candidate) {
+                let root = BarrettModulus::new_generic(candidate)
+                    .try_minimal_primitive_root(self.log_degree)
+                    .expect("a prime `≡ 1 (mod 2N)` has primitive 2N-th roots");
+                return Some((candidate, root));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reduce::ReduceExpPowOf2;
+
+    use super::*;
+
+    #[test]
+    fn test_is_prime() {
+        let naive = |n: u32| {
+            n >= 2
+                && (2..n)
+                    .take_while(|d| d * d <= n)
+                    .all(|d| !n.is_multiple_of(d))
+        };
+        for n in 0..5000u32 {
+            assert_eq!(is_prime(n), naive(n), "{n}");
+        }
+
+        assert!(is_prime(132120577u32));
+        assert!(is_prime(0x1fffffffffe00001u64));
+        // Strong pseudoprime to the bases 2, 3, 5, 7.
+        assert!(!is_prime(3215031751u64));
+        assert!(!is_prime(u64::MAX));
+        // The largest primes below `2^30` and `2^62`.
+        assert!(is_prime((1u32 << 30) - 35));
+        assert!(is_prime((1u64 << 62) - 57));
+        assert!(!is_prime((1u64 << 62) - 59));
+    }
+
+    #[test]
+    fn test_is_prime_wide() {
+        // wider than the limit of `BarrettModulus<u64>`
+        assert!(is_prime(u64::MAX - 58));
+        assert!(is_prime((1u64 << 63) + 29));
+        assert!(!is_prime((1u64 << 63) + 31));
+        assert!(is_prime((1u64 << 62) + 135));
+        // `(2^32 - 5)(2^32 - 17)`, both prime
+        assert!(!is_prime(4294967291u64 * 4294967279));
+        assert!(is_prime(u32::MAX - 4));
+    }
+
+    #[test]
+    fn test_ntt_primes() {
+        let primes: Vec<(u64, u64)> = NttPrimes::new(30, 1024).unwrap().take(4).collect();
+        assert_eq!(primes.len(), 4);
+        assert_eq!(primes[0].0, 1073707009);
+
+        for window in primes.windows(2) {
+            assert!(window[0].0 > window[1].0);
+        }
+        for &(p, root) in &primes {
+            assert!(is_prime(p));
+            assert_eq!(p % 2048, 1);
+            assert_eq!(p >> 29, 1);
+
+            let modulus = <BarrettModulus<u64>>::new(p);
+            assert_eq!(modulus.reduce_exp_power_of_2(root, 10), p - 1);
+        }
+
+        assert!(NttPrimes::<u32>::new(31, 1024).is_err());
+        assert!(NttPrimes::<u32>::new(11, 1024).is_err());
+        assert_eq!(NttPrimes::<u32>::new(12, 1024).unwrap().count(), 0);
+        assert_eq!(NttPrimes::<u32>::new(13, 1024).unwrap().count(), 0);
+        assert_eq!(
+            NttPrimes::<u32>::new(14, 1024).unwrap().collect::<Vec<_>>(),
+            [(12289, 7)]
+        );
+
+        // The smallest bit count for `N = 1` is `2`, which only holds the prime `3`.
+        assert!(NttPrimes::<u32>::new(1, 1).is_err());
+        assert_eq!(
+            NttPrimes::<u32>::new(2, 1).unwrap().collect::<Vec<_>>(),
+            [(3, 2)]
+        );
+    }
+}
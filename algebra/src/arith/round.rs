@@ -0,0 +1,169 @@
+/// Computes `round(numerator / denominator)`, rounding halves up.
+///
+/// # Panics
+///
+/// Panics if `denominator` is `0`.
+#[inline]
+pub fn div_round(numerator: u128, denominator: u128) -> u128 {
+    // a `u128` division is much slower than a `u64` one
+    if let (Ok(numerator), Ok(denominator)) = (u64::try_from(numerator), u64::try_from(denominator))
+    {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        return if remainder >= denominator - remainder {
+            quotient as u128 + 1
+        } else {
+            quotient as u128
+        };
+    }
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    // `2 * remainder >= denominator` without overflow
+    if remainder >= denominator - remainder {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Computes `round(x * numerator / denominator)` exactly, rounding halves up.
+///
+/// The product is formed in `u128`, so the result is exact for all 64-bit operands,
+/// including the native modulus `2^64` as `numerator` or `denominator`.
+/// Unlike a computation in `f64`, no precision is lost above `2^53`.
+///
+/// # Panics
+///
+/// Panics if `denominator` is `0` or `x * numerator` overflows `u128`.
+#[inline]
+pub fn mul_div_round(x: u128, numerator: u128, denominator: u128) -> u128 {
+    let product = x
+        .checked_mul(numerator)
+        .expect("product overflows u128 in mul_div_round");
+    div_round(product, denominator)
+}
+
+/// Computes `round(x * numerator / denominator)` like [`mul_div_round`],
+/// for many `x < denominator` with the same `numerator` and `denominator`,
+/// e.g. in modulus switching from `denominator` to `numerator`.
+///
+/// When every such product fits in `u64`, the division is replaced by
+/// a multiplication with the Barrett reciprocal of `denominator`, computed once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulDivRound {
+    numerator: u128,
+    denominator: u128,
+    /// `floor(2^64 / denominator)`, if `denominator` is in `[2, 2^64)`
+    /// and `(denominator - 1) * numerator` fits in `u64`.
+    reciprocal: Option<u64>,
+}
+
+impl MulDivRound {
+    /// Creates a new [`MulDivRound`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is `0`.
+    #[inline]
+    pub fn new(numerator: u128, denominator: u128) -> Self {
+        assert!(denominator != 0, "denominator is 0 in MulDivRound");
+        let fits = (denominator - 1)
+            .checked_mul(numerator)
+            .is_some_and(|max| max <= u64::MAX as u128);
+        let reciprocal = (fits && denominator > 1 && denominator <= u64::MAX as u128)
+            .then(|| ((1u128 << 64) / denominator) as u64);
+        Self {
+            numerator,
+            denominator,
+            reciprocal,
+        }
+    }
+
+    /// Computes `round(x * numerator / denominator)` exactly, rounding halves up.
+    ///
+    /// `x` must be less than `denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x * numerator` overflows `u128`.
+    #[inline]
+    pub fn apply(&self, x: u128) -> u128 {
+        debug_assert!(x < self.denominator);
+        let Some(reciprocal) = self.reciprocal else {
+            return mul_div_round(x, self.numerator, self.denominator);
+        };
+
+        let denominator = self.denominator as u64;
+        let product = x as u64 * self.numerator as u64;
+        // the estimate is the quotient or one less
+        let mut quotient = ((product as u128 * reciprocal as u128) >> 64) as u64;
+        let mut remainder = product - quotient * denominator;
+        if remainder >= denominator {
+            quotient += 1;
+            remainder -= denominator;
+        }
+        (quotient + u64::from(remainder >= denominator - remainder)) as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    #[test]
+    fn test_div_round() {
+        assert_eq!(div_round(0, 3), 0);
+        assert_eq!(div_round(1, 2), 1);
+        assert_eq!(div_round(3, 2), 2);
+        assert_eq!(div_round(4, 3), 1);
+        assert_eq!(div_round(5, 3), 2);
+        assert_eq!(div_round(u128::MAX, u128::MAX), 1);
+        assert_eq!(div_round(u128::MAX, 2), 1 << 127);
+    }
+
+    #[test]
+    fn test_mul_div_round() {
+        // `f64` rounds `2^53 + 1` to `2^53`
+        let x = (1u128 << 53) + 1;
+        assert_eq!(mul_div_round(x, 1, 1), x);
+        assert_eq!(mul_div_round(u64::MAX as u128, 1 << 14, 1 << 64), 1 << 14);
+
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let d: u128 = rng.gen_range(1..1 << 62);
+            let x: u128 = rng.gen_range(0..1 << 62);
+            let n: u128 = rng.gen_range(0..1 << 62);
+            let r = mul_div_round(x, n, d);
+
+            // `|r * d - x * n| <= d / 2`, ties rounded up
+            let (p, q) = (x * n, r * d);
+            if q >= p {
+                assert!(2 * (q - p) <= d);
+            } else {
+                assert!(2 * (p - q) < d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_div_round_reciprocal() {
+        let mut rng = thread_rng();
+        for (n, d) in [
+            (1u128 << 10, 1125899906826241u128),
+            (3, 2),
+            (1, 1),
+            (7, 1 << 64),
+        ] {
+            let scale = MulDivRound::new(n, d);
+            for x in [0, 1, d / 2, d - 1].into_iter().filter(|&x| x < d) {
+                assert_eq!(scale.apply(x), mul_div_round(x, n, d));
+            }
+            for _ in 0..1000 {
+                let x = rng.gen_range(0..d);
+                assert_eq!(scale.apply(x), mul_div_round(x, n, d));
+            }
+        }
+    }
+}
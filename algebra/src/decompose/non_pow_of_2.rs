@@ -1,7 +1,10 @@
 use itertools::izip;
 use num_traits::ConstOne;
 
-use crate::integer::{Bits, UnsignedInteger};
+use crate::{
+    integer::{AsInto, Bits, UnsignedInteger},
+    reduce::ReduceMulAdd,
+};
 
 /// The basis for approximate signed decomposition of **non** power of 2 modulus value.
 #[derive(Debug, Clone, Copy, Eq)]
@@ -124,6 +127,26 @@ impl<T: UnsignedInteger> NonPowOf2ApproxSignedBasis<T> {
         }
     }
 
+    /// Creates a new [`NonPowOf2ApproxSignedBasis<T>`],
+    /// or returns `None` where [`NonPowOf2ApproxSignedBasis::new`] panics.
+    #[inline]
+    pub fn checked_new(modulus: T, log_basis: u32, reverse_length: Option<usize>) -> Option<Self> {
+        let modulus_bits = <T as Bits>::BITS - modulus.leading_zeros();
+        let max_length = (modulus_bits / log_basis.max(1)) as usize;
+        let is_valid = !modulus.is_power_of_two()
+            && log_basis > 0
+            && log_basis < <T as Bits>::BITS
+            && log_basis <= modulus_bits
+            && reverse_length.is_none_or(|len| len > 0 && len <= max_length);
+        is_valid.then(|| Self::new(modulus, log_basis, reverse_length))
+    }
+
+    /// Returns the modulus of this [`NonPowOf2ApproxSignedBasis<T>`].
+    #[inline]
+    pub fn modulus(&self) -> T {
+        self.modulus
+    }
+
     /// Returns the decompose length of this [`NonPowOf2ApproxSignedBasis<T>`].
     #[inline]
     pub fn decompose_length(&self) -> usize {
@@ -208,6 +231,50 @@ impl<T: UnsignedInteger> NonPowOf2ApproxSignedBasis<T> {
         )
     }
 
+    /// Returns an iterator over the digits of `value`, from the least significant one.
+    ///
+    /// `value` must be less than the modulus.
+    #[inline]
+    pub fn digits(&self, value: T) -> Digits<T> {
+        let (value, carry) = self.init_value_carry(value);
+        Digits::new(value, carry, self.decompose_iter())
+    }
+
+    /// Returns an iterator over the digits of `value` as signed integers in `[-B/2, B/2]`.
+    #[inline]
+    pub fn signed_digits(&self, value: T) -> impl Iterator<Item = i64>
+    where
+        T: AsInto<i64>,
+    {
+        self.digits(value).signed()
+    }
+
+    /// Decomposes `value` into `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `destination` is not the decompose length.
+    #[inline]
+    pub fn decompose_into(&self, value: T, destination: &mut [T]) {
+        assert_eq!(destination.len(), self.decompose_length);
+        izip!(destination, self.digits(value)).for_each(|(d, digit)| *d = digit);
+    }
+
+    /// Computes `∑ digits[i]·scalar[i]` according to `modulus`,
+    /// the value `digits` approximately decompose.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `digits` is not the decompose length.
+    #[inline]
+    pub fn recompose<M>(&self, digits: &[T], modulus: M) -> T
+    where
+        M: Copy + ReduceMulAdd<T, Output = T>,
+    {
+        assert_eq!(digits.len(), self.decompose_length);
+        recompose(self.scalar_iter(), digits, modulus)
+    }
+
     /// Init carries and adjusted values for a slice and store the adjusted values back to `values`.
     #[inline]
     pub fn init_value_carry_slice_inplace(&self, values: &mut [T], carries: &mut [bool]) {
@@ -356,6 +423,78 @@ impl<T: UnsignedInteger> SignedOnceDecompose<T> {
     }
 }
 
+/// An iterator over the digits of a value, from the least significant one,
+/// created by [`NonPowOf2ApproxSignedBasis::digits`] or
+/// [`PowOf2ApproxSignedBasis::digits`](super::PowOf2ApproxSignedBasis::digits).
+///
+/// A negative digit `d` is represented by `q + d` for the modulus `q`.
+pub struct Digits<T: UnsignedInteger> {
+    value: T,
+    carry: bool,
+    operators: SignedDecomposeIter<T>,
+}
+
+impl<T: UnsignedInteger> Digits<T> {
+    /// Creates a new [`Digits<T>`] from the adjusted value and its init carry.
+    #[inline]
+    pub(super) fn new(value: T, carry: bool, operators: SignedDecomposeIter<T>) -> Self {
+        Self {
+            value,
+            carry,
+            operators,
+        }
+    }
+
+    /// Converts the digits into signed integers.
+    #[inline]
+    pub fn signed(self) -> impl Iterator<Item = i64>
+    where
+        T: AsInto<i64>,
+    {
+        let modulus_minus_one = self.operators.modulus_minus_basis + self.operators.basis_minus_one;
+        let half = modulus_minus_one >> 1u32;
+        self.map(move |d| {
+            if d > half {
+                let magnitude: i64 = (modulus_minus_one - d).as_into();
+                -magnitude - 1
+            } else {
+                d.as_into()
+            }
+        })
+    }
+}
+
+impl<T: UnsignedInteger> Iterator for Digits<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let operator = self.operators.next()?;
+        let (digit, carry) = operator.decompose(self.value, self.carry);
+        self.carry = carry;
+        Some(digit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.operators.length, Some(self.operators.length))
+    }
+}
+
+impl<T: UnsignedInteger> ExactSizeIterator for Digits<T> {}
+
+/// Computes `∑ digits[i]·scalars[i]` according to `modulus`.
+#[inline]
+pub(super) fn recompose<T, M>(scalars: ScalarIter<T>, digits: &[T], modulus: M) -> T
+where
+    T: UnsignedInteger,
+    M: Copy + ReduceMulAdd<T, Output = T>,
+{
+    izip!(digits, scalars).fold(T::ZERO, |acc, (&d, scalar)| {
+        modulus.reduce_mul_add(d, scalar, acc)
+    })
+}
+
 /// An iterator over scalars.
 pub struct ScalarIter<T: UnsignedInteger> {
     scalar: T,
@@ -609,4 +748,51 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_digits() {
+        let rng = rand::thread_rng();
+        let q: u64 = 132120577;
+        let modulus = BarrettModulus::<u64>::new(q);
+        for (log_basis, reverse_length) in [(1, None), (4, None), (7, Some(2)), (9, Some(1))] {
+            let basis = NonPowOf2ApproxSignedBasis::new(q, log_basis, reverse_length);
+            let half = 1i64 << (log_basis - 1);
+            let bound = (1u64 << basis.drop_bits()) >> 1;
+
+            let mut digits = vec![0; basis.decompose_length()];
+            for value in rng.clone().sample_iter(Uniform::new(0, q)).take(1000) {
+                basis.decompose_into(value, &mut digits);
+                assert_eq!(digits, basis.digits(value).collect::<Vec<_>>());
+
+                let signed: Vec<i64> = basis.signed_digits(value).collect();
+                assert!(signed.iter().all(|&d| -half <= d && d <= half));
+                for (&d, &s) in digits.iter().zip(&signed) {
+                    assert_eq!(d, s.rem_euclid(q as i64) as u64);
+                }
+
+                let recomposed = basis.recompose(&digits, modulus);
+                let distance = (recomposed + q - value) % q;
+                assert!(distance.min(q - distance) <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_new() {
+        let modulus: ValueT = 132120577;
+        assert!(NonPowOf2ApproxSignedBasis::checked_new(modulus, 3, Some(9)).is_some());
+        for (modulus, log_basis, reverse_length) in [
+            (1 << 20, 3, None),
+            (modulus, 0, None),
+            (modulus, 28, None),
+            (modulus, ValueT::BITS, None),
+            (modulus, 3, Some(0)),
+            (modulus, 3, Some(10)),
+        ] {
+            assert!(
+                NonPowOf2ApproxSignedBasis::checked_new(modulus, log_basis, reverse_length)
+                    .is_none()
+            );
+        }
+    }
 }
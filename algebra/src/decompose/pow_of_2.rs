@@ -1,9 +1,12 @@
 use itertools::izip;
 use num_traits::ConstOne;
 
-use crate::integer::UnsignedInteger;
+use crate::{
+    integer::{AsInto, UnsignedInteger},
+    reduce::ReduceMulAdd,
+};
 
-use super::{ScalarIter, SignedDecomposeIter};
+use super::{non_pow_of_2::recompose, Digits, ScalarIter, SignedDecomposeIter};
 
 /// The basis for approximate signed decomposition of power of 2 modulus value.
 #[derive(Debug, Clone, Copy, Eq)]
@@ -76,6 +79,23 @@ impl<T: UnsignedInteger> PowOf2ApproxSignedBasis<T> {
         }
     }
 
+    /// Creates a new [`PowOf2ApproxSignedBasis<T>`],
+    /// or returns `None` where [`PowOf2ApproxSignedBasis::new`] panics.
+    #[inline]
+    pub fn checked_new(
+        log_modulus: u32,
+        log_basis: u32,
+        reverse_length: Option<usize>,
+    ) -> Option<Self> {
+        let is_valid = log_basis > 0
+            && log_basis < T::BITS
+            && log_modulus <= T::BITS
+            && log_modulus >= log_basis
+            && reverse_length
+                .is_none_or(|len| len > 0 && len <= (log_modulus / log_basis) as usize);
+        is_valid.then(|| Self::new(log_modulus, log_basis, reverse_length))
+    }
+
     /// Returns the decompose length of this [`PowOf2ApproxSignedBasis<T>`].
     #[inline]
     pub fn decompose_length(&self) -> usize {
@@ -150,6 +170,49 @@ impl<T: UnsignedInteger> PowOf2ApproxSignedBasis<T> {
         }
     }
 
+    /// Returns an iterator over the digits of `value`, from the least significant one.
+    ///
+    /// `value` must be less than the modulus.
+    #[inline]
+    pub fn digits(&self, value: T) -> Digits<T> {
+        Digits::new(value, self.init_carry(value), self.decompose_iter())
+    }
+
+    /// Returns an iterator over the digits of `value` as signed integers in `[-B/2, B/2]`.
+    #[inline]
+    pub fn signed_digits(&self, value: T) -> impl Iterator<Item = i64>
+    where
+        T: AsInto<i64>,
+    {
+        self.digits(value).signed()
+    }
+
+    /// Decomposes `value` into `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `destination` is not the decompose length.
+    #[inline]
+    pub fn decompose_into(&self, value: T, destination: &mut [T]) {
+        assert_eq!(destination.len(), self.decompose_length);
+        izip!(destination, self.digits(value)).for_each(|(d, digit)| *d = digit);
+    }
+
+    /// Computes `∑ digits[i]·scalar[i]` according to `modulus`,
+    /// the value `digits` approximately decompose.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `digits` is not the decompose length.
+    #[inline]
+    pub fn recompose<M>(&self, digits: &[T], modulus: M) -> T
+    where
+        M: Copy + ReduceMulAdd<T, Output = T>,
+    {
+        assert_eq!(digits.len(), self.decompose_length);
+        recompose(self.scalar_iter(), digits, modulus)
+    }
+
     /// Init carries for a slice.
     #[inline]
     pub fn init_carry_slice(&self, values: &[T], carries: &mut [bool]) {
@@ -254,4 +317,53 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_digits() {
+        let mut rng = thread_rng();
+        let q: u64 = 1 << 20;
+        let modulus = PowOf2Modulus::<u64>::new(q);
+        for (log_basis, reverse_length) in [(2, None), (3, Some(4)), (7, Some(2))] {
+            let basis = PowOf2ApproxSignedBasis::<u64>::new(20, log_basis, reverse_length);
+            let half = 1i64 << (log_basis - 1);
+            let bound = (1u64 << basis.drop_bits()) >> 1;
+
+            let mut digits = vec![0; basis.decompose_length()];
+            for _ in 0..1000 {
+                let value = rng.gen_range(0..q);
+                basis.decompose_into(value, &mut digits);
+                assert_eq!(digits, basis.digits(value).collect::<Vec<_>>());
+
+                let signed: Vec<i64> = basis.signed_digits(value).collect();
+                assert!(signed.iter().all(|&d| -half <= d && d <= half));
+                for (&d, &s) in digits.iter().zip(&signed) {
+                    assert_eq!(d, s.rem_euclid(q as i64) as u64);
+                }
+
+                let recomposed = basis.recompose(&digits, modulus);
+                let distance = (recomposed + q - value) % q;
+                assert!(distance.min(q - distance) <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_new() {
+        assert!(PowOf2ApproxSignedBasis::<ValueT>::checked_new(LOG_MODULUS, 6, Some(2)).is_some());
+        for (log_modulus, log_basis, reverse_length) in [
+            (LOG_MODULUS, 0, None),
+            (LOG_MODULUS, 17, None),
+            (ValueT::BITS + 1, 6, None),
+            (ValueT::BITS, ValueT::BITS, None),
+            (LOG_MODULUS, 6, Some(0)),
+            (LOG_MODULUS, 6, Some(3)),
+        ] {
+            assert!(PowOf2ApproxSignedBasis::<ValueT>::checked_new(
+                log_modulus,
+                log_basis,
+                reverse_length
+            )
+            .is_none());
+        }
+    }
 }
@@ -33,6 +33,15 @@ pub enum AlgebraError {
     /// Error that occurs when fails to generate the distribution.
     #[error("Fail to generate the desired distribution.")]
     DistributionErr,
+    /// Error that occurs when fails to decode bytes into the desired type.
+    #[error("Fail to decode the bytes.")]
+    DecodeErr,
+    /// Error that occurs when a value belongs to another key than the given one.
+    #[error("The value belongs to another key.")]
+    KeyMismatch,
+    /// Error that occurs when authenticated bytes were not sealed with the given key.
+    #[error("Fail to authenticate the bytes.")]
+    AuthenticationErr,
     /// Error that occurs when fails to convert the degree into desired type.
     #[error("out of range integral type conversion attempted: {degree} -> {modulus:?}")]
     DegreeConversionErr {
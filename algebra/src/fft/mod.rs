@@ -0,0 +1,261 @@
+//! Defines the complex fast fourier transform of the canonical embedding.
+
+use std::f64::consts::PI;
+
+use crate::utils::ReverseLsbs;
+
+mod complex;
+
+pub use complex::Complex64;
+
+/// This struct store the pre-computed data for the canonical embedding
+/// of the ring `R[X]/(X^n + 1)`, which is used by the CKKS encoding.
+///
+/// Let `ζ = e^{πi/n}` be a primitive `2n`-th root of unity. A real polynomial
+/// `m(X)` is mapped to its evaluations `z_j = m(ζ^{5^j})` for `j < n/2`.
+/// The other half of the roots `ζ^{-5^j}` give the conjugates `z̄_j`,
+/// so the `n/2` slots determine `m(X)`.
+///
+/// Since `ζ^{5^j·n/2} = i`, `m(ζ^{5^j}) = Σ_{k<n/2} (m_k + i·m_{k+n/2})·ζ^{5^j·k}`,
+/// which is computed by a special FFT of size `n/2` with the twiddles `ζ^{5^j}`.
+#[derive(Debug, Clone)]
+pub struct CanonicalEmbedding {
+    /// `n`
+    n: usize,
+    /// `5^j (mod 2n)` for `j < n/2`.
+    rot_group: Vec<usize>,
+    /// `ζ^k` for `k ≤ 2n`.
+    roots: Vec<Complex64>,
+}
+
+impl CanonicalEmbedding {
+    /// Creates a new [`CanonicalEmbedding`] for the ring dimension `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a power of two, or less than `4`.
+    pub fn new(n: usize) -> Self {
+        assert!(n.is_power_of_two() && n >= 4);
+
+        let m = n << 1;
+        let mut rot_group = Vec::with_capacity(n >> 1);
+        let mut power = 1;
+        for _ in 0..n >> 1 {
+            rot_group.push(power);
+            power = power * 5 % m;
+        }
+
+        let roots = (0..=m)
+            .map(|k| Complex64::cis(2.0 * PI * k as f64 / m as f64))
+            .collect();
+
+        Self {
+            n,
+            rot_group,
+            roots,
+        }
+    }
+
+    /// Returns the ring dimension `n` of this [`CanonicalEmbedding`].
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of slots, which is `n/2`.
+    #[inline]
+    pub fn slots(&self) -> usize {
+        self.n >> 1
+    }
+
+    /// Calculates `Σ_{k<n/2} v_k·ζ^{5^j·k}` for every `j < n/2` in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs `v_k` in normal order, outputs in normal order
+    pub fn fft_special(&self, values: &mut [Complex64]) {
+        let size = values.len();
+        debug_assert_eq!(size, self.slots());
+        let m = self.n << 1;
+
+        bit_reverse(values);
+
+        let mut len = 2;
+        while len <= size {
+            let half = len >> 1;
+            let quarter_m = len << 2;
+            for chunk in values.chunks_exact_mut(len) {
+                let (v0, v1) = chunk.split_at_mut(half);
+                for ((u, v), &rot) in v0.iter_mut().zip(v1).zip(&self.rot_group) {
+                    let index = (rot % quarter_m) * (m / quarter_m);
+                    let t = *v * self.roots[index];
+                    *v = *u - t;
+                    *u += t;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    /// The inverse of [`fft_special`](Self::fft_special) in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in normal order, outputs `v_k` in normal order
+    pub fn fft_special_inv(&self, values: &mut [Complex64]) {
+        let size = values.len();
+        debug_assert_eq!(size, self.slots());
+        let m = self.n << 1;
+
+        let mut len = size;
+        while len >= 2 {
+            let half = len >> 1;
+            let quarter_m = len << 2;
+            for chunk in values.chunks_exact_mut(len) {
+                let (v0, v1) = chunk.split_at_mut(half);
+                for ((u, v), &rot) in v0.iter_mut().zip(v1).zip(&self.rot_group) {
+                    let index = (quarter_m - rot % quarter_m) * (m / quarter_m);
+                    let sum = *u + *v;
+                    *v = (*u - *v) * self.roots[index];
+                    *u = sum;
+                }
+            }
+            len >>= 1;
+        }
+
+        bit_reverse(values);
+
+        let inv_size = 1.0 / size as f64;
+        values.iter_mut().for_each(|v| *v = *v * inv_size);
+    }
+
+    /// Encodes `n/2` slots into the `n` real coefficients of `m(X)`,
+    /// such that `m(ζ^{5^j}) = slots_j`.
+    ///
+    /// Scaling and rounding the coefficients is left to the scheme.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slots` is not `n/2`.
+    pub fn encode(&self, slots: &[Complex64]) -> Vec<f64> {
+        assert_eq!(slots.len(), self.slots());
+
+        let mut values = slots.to_vec();
+        self.fft_special_inv(&mut values);
+
+        let mut coeffs = vec![0.0; self.n];
+        let (re, im) = coeffs.split_at_mut(self.slots());
+        for ((r, i), v) in re.iter_mut().zip(im).zip(values) {
+            *r = v.re;
+            *i = v.im;
+        }
+        coeffs
+    }
+
+    /// Decodes the `n` real coefficients of `m(X)` into the `n/2` slots `m(ζ^{5^j})`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `coeffs` is not `n`.
+    pub fn decode(&self, coeffs: &[f64]) -> Vec<Complex64> {
+        assert_eq!(coeffs.len(), self.n);
+
+        let (re, im) = coeffs.split_at(self.slots());
+        let mut values: Vec<Complex64> = re
+            .iter()
+            .zip(im)
+            .map(|(&r, &i)| Complex64::new(r, i))
+            .collect();
+        self.fft_special(&mut values);
+        values
+    }
+}
+
+/// Permutes `values` into bit-reversed order.
+fn bit_reverse(values: &mut [Complex64]) {
+    let log_size = values.len().trailing_zeros();
+    for i in 0..values.len() {
+        let j = i.reverse_lsbs(log_size);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    fn assert_close(a: &[Complex64], b: &[Complex64]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((*x - *y).abs() < 1e-8, "{x:?} != {y:?}");
+        }
+    }
+
+    #[test]
+    fn test_canonical_embedding() {
+        let mut rng = thread_rng();
+
+        for n in [4, 16, 1024] {
+            let embedding = CanonicalEmbedding::new(n);
+            let m = 2 * n;
+
+            let coeffs: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let slots = embedding.decode(&coeffs);
+
+            // evaluate m(X) at ζ^{5^j} directly
+            let expected: Vec<Complex64> = (0..n / 2)
+                .map(|j| {
+                    let exponent = (0..j).fold(1, |acc, _| acc * 5 % m);
+                    coeffs
+                        .iter()
+                        .enumerate()
+                        .fold(Complex64::ZERO, |acc, (k, &c)| {
+                            let angle = PI * (exponent * k % m) as f64 / n as f64;
+                            acc + Complex64::cis(angle) * c
+                        })
+                })
+                .collect();
+            assert_close(&slots, &expected);
+
+            let decoded = embedding.encode(&slots);
+            for (x, y) in decoded.iter().zip(&coeffs) {
+                assert!((x - y).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_embedding_multiplication() {
+        let mut rng = thread_rng();
+        let n = 64;
+        let embedding = CanonicalEmbedding::new(n);
+
+        let random_slots = |rng: &mut ThreadRng| -> Vec<Complex64> {
+            (0..n / 2)
+                .map(|_| Complex64::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)))
+                .collect()
+        };
+        let x = random_slots(&mut rng);
+        let y = random_slots(&mut rng);
+        let a = embedding.encode(&x);
+        let b = embedding.encode(&y);
+
+        // a·b (mod X^n + 1)
+        let mut c = vec![0.0; n];
+        for i in 0..n {
+            for j in 0..n {
+                if i + j < n {
+                    c[i + j] += a[i] * b[j];
+                } else {
+                    c[i + j - n] -= a[i] * b[j];
+                }
+            }
+        }
+
+        let product: Vec<Complex64> = x.iter().zip(&y).map(|(&u, &v)| u * v).collect();
+        assert_close(&embedding.decode(&c), &product);
+    }
+}
@@ -151,6 +151,57 @@ pub trait Field: Sized + Clone + Copy {
     fn div_assign(a: &mut Self::ValueT, b: Self::ValueT) {
         Self::MODULUS.reduce_div_assign(a, b);
     }
+
+    /// Calculates `a_i += b_i` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    fn add_assign_slice(a: &mut [Self::ValueT], b: &[Self::ValueT]) {
+        Self::MODULUS.add_slice(a, b);
+    }
+
+    /// Calculates `a_i -= b_i` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    fn sub_assign_slice(a: &mut [Self::ValueT], b: &[Self::ValueT]) {
+        Self::MODULUS.sub_slice(a, b);
+    }
+
+    /// Calculates `a_i = -a_i` for every `i`.
+    #[inline]
+    fn neg_assign_slice(a: &mut [Self::ValueT]) {
+        a.iter_mut()
+            .for_each(|x| Self::MODULUS.reduce_neg_assign(x));
+    }
+
+    /// Calculates `a_i *= b_i` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    fn mul_assign_pointwise(a: &mut [Self::ValueT], b: &[Self::ValueT]) {
+        Self::MODULUS.mul_slice(a, b);
+    }
+
+    /// Calculates `c_i += a_i * b_i` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c`, `a` and `b` have different lengths.
+    #[inline]
+    fn add_mul_assign_pointwise(c: &mut [Self::ValueT], a: &[Self::ValueT], b: &[Self::ValueT]) {
+        assert!(c.len() == a.len() && c.len() == b.len());
+        c.iter_mut()
+            .zip(a)
+            .zip(b)
+            .for_each(|((z, &x), &y)| *z = Self::MODULUS.reduce_mul_add(x, y, *z));
+    }
 }
 
 impl_barrett_field!(#[derive(Clone, Copy)] impl pub U8FieldEval<u8>);
@@ -18,9 +18,12 @@ mod field;
 
 pub mod random;
 
+pub mod fft;
 pub mod ntt;
 pub mod polynomial;
 
+pub mod sharing;
+
 pub mod utils;
 
 pub use error::AlgebraError;
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use crate::{
     integer::{AsFrom, AsInto},
     numeric::Numeric,
-    reduce::{Modulus, ModulusValue},
+    reduce::{Modulus, ModulusValue, ReduceRepresentation},
 };
 
 #[macro_use]
@@ -73,6 +73,18 @@ impl<T: Numeric> Modulus<T> for BarrettModulus<T> {
         }
     }
 
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        match value {
+            ModulusValue::Native => None,
+            ModulusValue::PowerOf2(value)
+            | ModulusValue::Prime(value)
+            | ModulusValue::Others(value) => (value > T::ONE
+                && T::BITS - value.leading_zeros() < T::BITS - 1)
+                .then(|| Self::new_generic(value)),
+        }
+    }
+
     #[inline]
     fn modulus_value(&self) -> ModulusValue<T> {
         ModulusValue::Others(self.value)
@@ -84,6 +96,18 @@ impl<T: Numeric> Modulus<T> for BarrettModulus<T> {
     }
 }
 
+impl<T: Numeric> ReduceRepresentation<T> for BarrettModulus<T> {
+    #[inline(always)]
+    fn to_representation(self, value: T) -> T {
+        value
+    }
+
+    #[inline(always)]
+    fn to_canonical(self, value: T) -> T {
+        value
+    }
+}
+
 impl_barrett_modulus!(impl BarrettModulus<u8>; WideType: u16);
 impl_barrett_modulus!(impl BarrettModulus<u16>; WideType: u32);
 impl_barrett_modulus!(impl BarrettModulus<u32>; WideType: u64);
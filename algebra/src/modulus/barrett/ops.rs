@@ -506,6 +506,53 @@ impl<T: Numeric> ReduceDotProduct<T> for BarrettModulus<T> {
     }
 }
 
+impl<T: Numeric> SliceModOps<T> for BarrettModulus<T> {
+    /// The modulus has at most `T::BITS - 2` bits, so `a_i + b_i` does not
+    /// overflow and the sum is corrected with a `min` instead of a branch.
+    #[inline]
+    fn add_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        let m = self.value;
+        a.iter_mut().zip(b).for_each(|(x, &y)| {
+            let sum = *x + y;
+            *x = sum.min(sum.wrapping_sub(m));
+        });
+    }
+
+    /// A wrapped difference `a_i - b_i` is larger than the modulus,
+    /// so it is corrected with a `min` instead of a branch.
+    #[inline]
+    fn sub_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        let m = self.value;
+        a.iter_mut().zip(b).for_each(|(x, &y)| {
+            let difference = x.wrapping_sub(y);
+            *x = difference.min(difference.wrapping_add(m));
+        });
+    }
+
+    #[inline]
+    fn mul_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| self.reduce_mul_assign(x, y));
+    }
+
+    #[inline]
+    fn mul_scalar_slice(self, a: &mut [T], scalar: T) {
+        a.iter_mut().for_each(|x| self.reduce_mul_assign(x, scalar));
+    }
+
+    #[inline]
+    fn fma_slice(self, a: &mut [T], b: &[T], scalar: T) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = self.reduce_mul_add(y, scalar, *x));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use num_traits::{One, Zero};
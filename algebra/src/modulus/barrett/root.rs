@@ -3,7 +3,9 @@ use rand::{distributions::Uniform, prelude::Distribution};
 use crate::arith::PrimitiveRoot;
 use crate::modulus::ShoupFactor;
 use crate::numeric::Numeric;
-use crate::reduce::{Modulus, ReduceExp, ReduceExpPowOf2, ReduceMulAssign, ReduceSquare};
+use crate::reduce::{
+    Modulus, ReduceExpPowOf2, ReduceExpWindow, ReduceMulAssign, ReduceSquare, WINDOW_BITS,
+};
 use crate::AlgebraError;
 
 use super::BarrettModulus;
@@ -50,7 +52,7 @@ impl<T: Numeric> PrimitiveRoot<T> for BarrettModulus<T> {
 
         if (0..100).any(|_| {
             let r = distr.sample(&mut rng);
-            w = self.reduce_exp(r, quotient);
+            w = self.reduce_exp_window(r, quotient, WINDOW_BITS);
             self.check_primitive_root(w, log_degree)
         }) {
             Ok(w)
@@ -0,0 +1,328 @@
+use crate::{
+    numeric::Numeric,
+    reduce::{Reduce, ReduceAdd, ReduceMul, ReduceSub},
+    AlgebraError,
+};
+
+use super::BarrettModulus;
+
+/// Precomputed constants of one level of a [`ModulusChain`].
+///
+/// Level `l` uses the moduli `q_0, q_1, ..., q_l`, whose product is `Q_l`.
+#[derive(Debug, Clone)]
+pub struct ChainLevel<T: Numeric> {
+    /// `q_l⁻¹ (mod q_i)` for every `i < l`.
+    last_inv: Vec<T>,
+    /// `(Q_l / q_i)⁻¹ (mod q_i)` for every `i ≤ l`.
+    punctured_inv: Vec<T>,
+}
+
+impl<T: Numeric> ChainLevel<T> {
+    /// Returns `q_l⁻¹ (mod q_i)` for every `i < l`,
+    /// which is used for dropping the last modulus with rescaling.
+    #[inline]
+    pub fn last_inv(&self) -> &[T] {
+        &self.last_inv
+    }
+
+    /// Returns `(Q_l / q_i)⁻¹ (mod q_i)` for every `i ≤ l`,
+    /// which is used for CRT reconstruction and fast basis conversion.
+    #[inline]
+    pub fn punctured_inv(&self) -> &[T] {
+        &self.punctured_inv
+    }
+}
+
+/// An ordered list of pairwise coprime moduli for leveled schemes.
+///
+/// A value at level `l` is stored as its residues modulo `q_0, q_1, ..., q_l`.
+/// Rescaling drops the last modulus from the residues, extending the chain appends a new one.
+/// Constants for every level are computed once, when the modulus is added.
+///
+/// The chain holds no current level. The level of a value is given by
+/// its number of residues, so one chain serves values at any level.
+#[derive(Debug, Clone)]
+pub struct ModulusChain<T: Numeric> {
+    moduli: Vec<BarrettModulus<T>>,
+    levels: Vec<ChainLevel<T>>,
+}
+
+impl<T: Numeric> ModulusChain<T> {
+    /// Creates a new [`ModulusChain<T>`] with `moduli`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if the moduli are not pairwise coprime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `moduli` is empty, or any modulus is even or not valid for [`BarrettModulus`].
+    pub fn new(moduli: &[T]) -> Result<Self, AlgebraError> {
+        assert!(!moduli.is_empty(), "modulus chain can't be empty.");
+
+        let mut chain = Self {
+            moduli: Vec::with_capacity(moduli.len()),
+            levels: Vec::with_capacity(moduli.len()),
+        };
+        for &modulus in moduli {
+            chain.extend(modulus)?;
+        }
+        Ok(chain)
+    }
+
+    /// Returns the top level of this [`ModulusChain<T>`].
+    #[inline]
+    pub fn top_level(&self) -> usize {
+        self.moduli.len() - 1
+    }
+
+    /// Returns all moduli `q_0, ..., q_top_level` of the chain.
+    #[inline]
+    pub fn moduli(&self) -> &[BarrettModulus<T>] {
+        &self.moduli
+    }
+
+    /// Returns the moduli `q_0, ..., q_level` of `level`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is larger than the top level.
+    #[inline]
+    pub fn level_moduli(&self, level: usize) -> &[BarrettModulus<T>] {
+        &self.moduli[..=level]
+    }
+
+    /// Returns the constants of `level`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is larger than the top level.
+    #[inline]
+    pub fn level_constants(&self, level: usize) -> &ChainLevel<T> {
+        &self.levels[level]
+    }
+
+    /// Appends `modulus` to the top of the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if `modulus` is not coprime
+    /// to the moduli already in the chain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is even or not valid for [`BarrettModulus`].
+    pub fn extend(&mut self, modulus: T) -> Result<(), AlgebraError> {
+        // the rounding of `rescale_round` adds `⌊q_l / 2⌋`, which needs an odd `q_l`.
+        assert!(
+            modulus & T::ONE == T::ONE,
+            "modulus of a chain must be odd."
+        );
+        let last = BarrettModulus::new_generic(modulus);
+
+        let last_inv = self
+            .moduli
+            .iter()
+            .map(|q| q.value().try_reduce_inv(q.reduce(modulus)))
+            .collect::<Result<Vec<T>, _>>()?;
+
+        let mut punctured_inv = Vec::with_capacity(self.moduli.len() + 1);
+        for (i, q) in self.moduli.iter().enumerate() {
+            let inv = self.levels.last().unwrap().punctured_inv[i];
+            punctured_inv.push(q.reduce_mul(inv, last_inv[i]));
+        }
+        let punctured = self.moduli.iter().fold(last.reduce(T::ONE), |acc, q| {
+            last.reduce_mul(acc, last.reduce(q.value()))
+        });
+        punctured_inv.push(modulus.try_reduce_inv(punctured)?);
+
+        self.moduli.push(last);
+        self.levels.push(ChainLevel {
+            last_inv,
+            punctured_inv,
+        });
+        Ok(())
+    }
+
+    /// Returns the level `l` of a value with `len` residues, which must be in `1..=top_level`.
+    fn rescale_level(&self, len: usize) -> usize {
+        assert!(
+            len >= 2 && len <= self.moduli.len(),
+            "no level to drop for {len} residues."
+        );
+        len - 1
+    }
+
+    /// Divides a value by the last active modulus `q_l` with flooring,
+    /// and drops that modulus from its residues.
+    ///
+    /// `residues` holds `x mod q_i` for `i ≤ l`, where `l` is `residues.len() - 1`.
+    /// Afterwards it holds `⌊x / q_l⌋ mod q_i` for `i < l`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `residues` has less than two or more than `top_level + 1` elements.
+    pub fn rescale(&self, residues: &mut Vec<T>) {
+        let level = self.rescale_level(residues.len());
+        let last = residues.pop().unwrap();
+
+        for ((x, q), &inv) in residues
+            .iter_mut()
+            .zip(&self.moduli)
+            .zip(&self.levels[level].last_inv)
+        {
+            *x = q.reduce_mul(q.reduce_sub(*x, q.reduce(last)), inv);
+        }
+    }
+
+    /// Divides a value by the last active modulus `q_l` with rounding,
+    /// and drops that modulus from its residues.
+    ///
+    /// `residues` holds `x mod q_i` for `i ≤ l`, where `l` is `residues.len() - 1`.
+    /// Afterwards it holds `⌊x / q_l⌉ mod q_i` for `i < l`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `residues` has less than two or more than `top_level + 1` elements.
+    pub fn rescale_round(&self, residues: &mut Vec<T>) {
+        let level = self.rescale_level(residues.len());
+        let half = self.moduli[level].value() >> 1u32;
+
+        // `⌊x / q_l⌉ = ⌊(x + ⌊q_l / 2⌋) / q_l⌋` as `q_l` is odd.
+        for (x, q) in residues.iter_mut().zip(&self.moduli) {
+            *x = q.reduce_add(q.reduce(*x), q.reduce(half));
+        }
+        self.rescale(residues);
+    }
+
+    /// Performs [`rescale_round`](ModulusChain::rescale_round) on every coefficient of a polynomial.
+    ///
+    /// `limbs[i]` holds the coefficients of the polynomial modulo `q_i`, for `i ≤ l`,
+    /// where `l` is `limbs.len() - 1`. Afterwards the last limb is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limbs` has less than two or more than `top_level + 1` elements,
+    /// or the limbs have different lengths.
+    pub fn rescale_round_limbs(&self, limbs: &mut Vec<Vec<T>>) {
+        let level = self.rescale_level(limbs.len());
+        let last_modulus = self.moduli[level];
+        let half = last_modulus.value() >> 1u32;
+
+        let last = limbs.pop().unwrap();
+        for ((limb, q), &inv) in limbs
+            .iter_mut()
+            .zip(&self.moduli)
+            .zip(&self.levels[level].last_inv)
+        {
+            assert_eq!(limb.len(), last.len());
+            let half_i = q.reduce(half);
+            for (x, &y) in limb.iter_mut().zip(&last) {
+                let y = q.reduce(last_modulus.reduce_add(y, half));
+                *x = q.reduce_mul(q.reduce_sub(q.reduce_add(*x, half_i), y), inv);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    const PRIMES: [u32; 3] = [132120577, 134215681, 1073479681];
+
+    #[test]
+    fn test_modulus_chain_levels() {
+        let mut chain = ModulusChain::new(&PRIMES).unwrap();
+        assert_eq!(chain.top_level(), 2);
+        assert_eq!(chain.moduli().len(), 3);
+        assert_eq!(chain.level_moduli(1).len(), 2);
+
+        chain.extend(998244353).unwrap();
+        assert_eq!(chain.top_level(), 3);
+        assert_eq!(chain.moduli()[3].value(), 998244353);
+        assert_eq!(chain.level_moduli(3).len(), 4);
+
+        assert!(chain.extend(PRIMES[0]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_modulus_chain_even() {
+        let _ = ModulusChain::new(&[PRIMES[0], 1 << 20]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_modulus_chain_rescale_level_0() {
+        let chain = ModulusChain::new(&PRIMES).unwrap();
+        chain.rescale(&mut vec![1]);
+    }
+
+    #[test]
+    fn test_modulus_chain_constants() {
+        let chain = ModulusChain::new(&PRIMES).unwrap();
+        let q: Vec<u128> = PRIMES.iter().map(|&q| q as u128).collect();
+
+        for level in 0..=chain.top_level() {
+            let constants = chain.level_constants(level);
+            for i in 0..level {
+                assert_eq!(constants.last_inv()[i] as u128 * q[level] % q[i], 1);
+            }
+            for i in 0..=level {
+                let punctured: u128 = (0..=level).filter(|&j| j != i).map(|j| q[j]).product();
+                assert_eq!(
+                    constants.punctured_inv()[i] as u128 * (punctured % q[i]) % q[i],
+                    1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_modulus_chain_rescale() {
+        let chain = ModulusChain::new(&PRIMES).unwrap();
+        let q: Vec<u128> = PRIMES.iter().map(|&q| q as u128).collect();
+        let big_q: u128 = q.iter().product();
+        let mut rng = thread_rng();
+
+        for _ in 0..100 {
+            let x = rng.gen_range(0..big_q);
+            let mut residues: Vec<u32> = q.iter().map(|&qi| (x % qi) as u32).collect();
+
+            chain.rescale(&mut residues);
+            let expected: Vec<u32> = q[..2].iter().map(|&qi| (x / q[2] % qi) as u32).collect();
+            assert_eq!(residues, expected);
+
+            chain.rescale(&mut residues);
+            assert_eq!(residues, vec![(x / q[2] / q[1] % q[0]) as u32]);
+        }
+    }
+
+    #[test]
+    fn test_modulus_chain_rescale_round() {
+        let chain = ModulusChain::new(&PRIMES).unwrap();
+        let q: Vec<u128> = PRIMES.iter().map(|&q| q as u128).collect();
+        let big_q: u128 = q.iter().product();
+        let mut rng = thread_rng();
+
+        let values: Vec<u128> = (0..100).map(|_| rng.gen_range(0..big_q)).collect();
+        let mut limbs: Vec<Vec<u32>> = q
+            .iter()
+            .map(|&qi| values.iter().map(|&x| (x % qi) as u32).collect())
+            .collect();
+        chain.rescale_round_limbs(&mut limbs);
+
+        for (j, &x) in values.iter().enumerate() {
+            let mut residues: Vec<u32> = q.iter().map(|&qi| (x % qi) as u32).collect();
+            chain.rescale_round(&mut residues);
+
+            let rounded = (x + q[2] / 2) / q[2];
+            let expected: Vec<u32> = q[..2].iter().map(|&qi| (rounded % qi) as u32).collect();
+            assert_eq!(residues, expected);
+            assert_eq!(vec![limbs[0][j], limbs[1][j]], expected);
+        }
+    }
+}
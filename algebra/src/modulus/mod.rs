@@ -1,11 +1,17 @@
 //! Defines some moduli.
 
 mod barrett;
+mod chain;
+mod montgomery;
 mod native;
 mod powof2;
+mod rns;
 mod shoup;
 
 pub use barrett::BarrettModulus;
+pub use chain::{ChainLevel, ModulusChain};
+pub use montgomery::MontgomeryModulus;
 pub use native::NativeModulus;
 pub use powof2::PowOf2Modulus;
+pub use rns::{BaseConverter, ScaleAndRound};
 pub use shoup::ShoupFactor;
@@ -0,0 +1,332 @@
+use std::fmt::Display;
+
+use crate::{
+    arith::is_prime,
+    integer::{AsFrom, AsInto},
+    numeric::Numeric,
+    reduce::{Modulus, ModulusValue, ReduceRepresentation},
+};
+
+mod ops;
+
+/// A modulus, using montgomery reduction algorithm.
+///
+/// Values are kept in the montgomery domain, that is `a` is stored as `a·R (mod modulus)`
+/// where `R` = 2^T::BITS. Multiplication in this domain only needs multiplications and
+/// shifts, while addition, subtraction and negation are unchanged.
+///
+/// Use [`to_montgomery`](MontgomeryModulus::to_montgomery) and
+/// [`to_canonical`](MontgomeryModulus::to_canonical), or the generic
+/// [`ReduceRepresentation`] trait, to move values in and out of the domain.
+#[derive(Debug, Clone, Copy)]
+pub struct MontgomeryModulus<T: Numeric> {
+    /// the value to indicate the modulus
+    value: T,
+    /// `-value⁻¹ (mod R)`
+    neg_inv: T,
+    /// `R (mod value)`, which is `1` in the montgomery domain
+    r: T,
+    /// `R² (mod value)`, used to convert into the montgomery domain
+    r2: T,
+    /// whether `value` is known to be prime, which allows inversion by Fermat's little theorem
+    prime: bool,
+}
+
+impl<T: Numeric> Display for MontgomeryModulus<T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<T: Numeric> MontgomeryModulus<T> {
+    /// Creates a new [`MontgomeryModulus<T>`] with the given value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is even, `1`, or not less than `2^(T::BITS - 1)`.
+    pub fn new(value: T) -> Self {
+        assert!(value > T::ONE, "modulus can't be 0 or 1.");
+        assert!(value & T::ONE == T::ONE, "montgomery modulus must be odd.");
+        assert!(T::BITS - value.leading_zeros() < T::BITS);
+
+        // `value * value ≡ 1 (mod 8)` for odd value, every newton step doubles the correct bits.
+        let mut inv = value;
+        let mut bits = 3;
+        while bits < T::BITS {
+            inv = inv.wrapping_mul(T::TWO.wrapping_sub(value.wrapping_mul(inv)));
+            bits <<= 1;
+        }
+
+        let r = T::ZERO.wrapping_sub(value) % value;
+        let wide_r = T::WideT::as_from(r);
+        let r2 = (wide_r * wide_r % T::WideT::as_from(value)).as_into();
+
+        Self {
+            value,
+            neg_inv: T::ZERO.wrapping_sub(inv),
+            r,
+            r2,
+            prime: false,
+        }
+    }
+
+    /// Creates a new [`MontgomeryModulus<T>`] with the prime `value`.
+    ///
+    /// Inversions modulo a prime are computed as `value^(p-2)`, without
+    /// leaving the montgomery domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not prime, or not less than `2^(T::BITS - 1)`.
+    pub fn new_prime(value: T) -> Self {
+        assert!(is_prime(value), "{value} is not prime.");
+        Self {
+            prime: true,
+            ..Self::new(value)
+        }
+    }
+
+    /// Returns `true` if the modulus is known to be prime.
+    #[inline]
+    pub const fn is_prime(&self) -> bool {
+        self.prime
+    }
+
+    /// Returns the value of this [`MontgomeryModulus<T>`].
+    #[inline]
+    pub const fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns `-modulus⁻¹ (mod R)` of this [`MontgomeryModulus<T>`].
+    #[inline]
+    pub const fn neg_inv(&self) -> T {
+        self.neg_inv
+    }
+
+    /// Returns `1` in the montgomery domain, i.e. `R (mod modulus)`.
+    #[inline]
+    pub const fn one(&self) -> T {
+        self.r
+    }
+
+    /// Calculates `value·R⁻¹ (mod modulus)`, where `value = (lo, hi)`.
+    ///
+    /// # Correctness
+    ///
+    /// - `value < modulus·R`
+    #[inline]
+    pub fn montgomery_reduce(&self, (lo, hi): (T, T)) -> T {
+        let m = lo.wrapping_mul(self.neg_inv);
+        let (mlo, mhi) = m.widening_mul(self.value);
+        let carry: T = lo.overflowing_add(mlo).1.as_into();
+        self.value.reduce_once(hi + mhi + carry)
+    }
+
+    /// Calculates `a·b·R⁻¹ (mod modulus)`.
+    ///
+    /// For `a` and `b` in the montgomery domain, this is their product in the montgomery domain.
+    ///
+    /// # Correctness
+    ///
+    /// - `a < modulus`
+    /// - `b < modulus`
+    #[inline]
+    pub fn montgomery_mul(&self, a: T, b: T) -> T {
+        self.montgomery_reduce(a.widening_mul(b))
+    }
+
+    /// Converts a canonical `value < modulus` into the montgomery domain.
+    #[inline]
+    pub fn to_montgomery(&self, value: T) -> T {
+        self.montgomery_mul(value, self.r2)
+    }
+
+    /// Converts `value` from the montgomery domain back into a canonical value.
+    #[inline]
+    pub fn to_canonical(&self, value: T) -> T {
+        self.montgomery_reduce((value, T::ZERO))
+    }
+}
+
+impl<T: Numeric> Modulus<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn from_value(value: ModulusValue<T>) -> Self {
+        match value {
+            ModulusValue::Native => panic!("Not match for native"),
+            ModulusValue::PowerOf2(_) => panic!("Not match for power of 2"),
+            ModulusValue::Prime(value) => Self::new_prime(value),
+            ModulusValue::Others(value) => Self::new(value),
+        }
+    }
+
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        let is_valid =
+            |value: T| value > T::ONE && value & T::ONE == T::ONE && value.leading_zeros() > 0;
+        match value {
+            ModulusValue::Native | ModulusValue::PowerOf2(_) => None,
+            ModulusValue::Prime(value) => {
+                (is_valid(value) && is_prime(value)).then(|| Self::new_prime(value))
+            }
+            ModulusValue::Others(value) => is_valid(value).then(|| Self::new(value)),
+        }
+    }
+
+    #[inline]
+    fn modulus_value(&self) -> ModulusValue<T> {
+        if self.prime {
+            ModulusValue::Prime(self.value)
+        } else {
+            ModulusValue::Others(self.value)
+        }
+    }
+
+    #[inline]
+    fn modulus_minus_one(&self) -> T {
+        self.value - T::ONE
+    }
+}
+
+impl<T: Numeric> ReduceRepresentation<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn to_representation(self, value: T) -> T {
+        MontgomeryModulus::to_montgomery(&self, value)
+    }
+
+    #[inline]
+    fn to_canonical(self, value: T) -> T {
+        MontgomeryModulus::to_canonical(&self, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::modulus::BarrettModulus;
+    use crate::reduce::*;
+
+    use super::*;
+
+    /// Computes `a·b + c` with any modulus, on canonical inputs and output.
+    fn mul_add_canonical<M: RingReduce<u64> + ReduceRepresentation<u64>>(
+        modulus: M,
+        a: u64,
+        b: u64,
+        c: u64,
+    ) -> u64 {
+        let [a, b, c] = [a, b, c].map(|v| modulus.to_representation(v));
+        modulus.to_canonical(modulus.reduce_mul_add(a, b, c))
+    }
+
+    #[test]
+    fn test_montgomery_u32() {
+        const P: u32 = 1000000513;
+        let mont = MontgomeryModulus::<u32>::new(P);
+        let barrett = BarrettModulus::<u32>::new(P);
+        let mut rng = thread_rng();
+
+        assert_eq!(mont.neg_inv().wrapping_mul(P), u32::MAX);
+        assert_eq!(mont.to_canonical(mont.one()), 1);
+
+        for _ in 0..100 {
+            let a = rng.gen_range(0..P);
+            let b = rng.gen_range(0..P);
+            let (am, bm) = (mont.to_montgomery(a), mont.to_montgomery(b));
+
+            assert_eq!(mont.to_canonical(am), a);
+            assert_eq!(
+                mont.to_canonical(mont.reduce_mul(am, bm)),
+                barrett.reduce_mul(a, b)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_add(am, bm)),
+                barrett.reduce_add(a, b)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_sub(am, bm)),
+                barrett.reduce_sub(a, b)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_neg(am)),
+                barrett.reduce_neg(a)
+            );
+        }
+    }
+
+    #[test]
+    fn test_montgomery_composite_inv() {
+        let mont = MontgomeryModulus::<u32>::from_value(ModulusValue::Others(15));
+        assert!(!mont.is_prime());
+        for a in [1u32, 2, 4, 7, 8, 11, 13, 14] {
+            let am = mont.to_montgomery(a);
+            assert_eq!(mont.reduce_mul(mont.reduce_inv(am), am), mont.one());
+        }
+
+        let mont = MontgomeryModulus::<u32>::from_value(ModulusValue::Prime(132120577));
+        assert!(mont.is_prime());
+        assert!(matches!(
+            mont.modulus_value(),
+            ModulusValue::Prime(132120577)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_montgomery_new_prime_composite() {
+        MontgomeryModulus::<u32>::new_prime(15);
+    }
+
+    #[test]
+    fn test_montgomery_u64() {
+        const P: u64 = 0x1fffffffffe00001;
+        let mont = MontgomeryModulus::<u64>::new(P);
+        let mont_prime = MontgomeryModulus::<u64>::new_prime(P);
+        let barrett = BarrettModulus::<u64>::new(P);
+        let mut rng = thread_rng();
+
+        for _ in 0..100 {
+            let a = rng.gen_range(0..P);
+            let b = rng.gen_range(0..P);
+            let c = rng.gen_range(0..P);
+            let e: u32 = rng.gen();
+            let (am, bm) = (mont.to_montgomery(a), mont.to_montgomery(b));
+
+            assert_eq!(
+                mul_add_canonical(mont, a, b, c),
+                mul_add_canonical(barrett, a, b, c)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_square(am)),
+                barrett.reduce_square(a)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_exp(am, e)),
+                barrett.reduce_exp(a, e)
+            );
+            assert_eq!(
+                mont.to_canonical(mont.reduce_exp_power_of_2(am, 5)),
+                barrett.reduce_exp_power_of_2(a, 5)
+            );
+            if a != 0 {
+                assert_eq!(mont.reduce_mul(mont.reduce_inv(am), am), mont.one());
+                assert_eq!(mont_prime.reduce_inv(am), mont.reduce_inv(am));
+                assert_eq!(
+                    mont.to_canonical(mont.reduce_div(bm, am)),
+                    barrett.reduce_div(b, a)
+                );
+            }
+        }
+
+        let a: Vec<u64> = (0..37).map(|_| rng.gen_range(0..P)).collect();
+        let b: Vec<u64> = (0..37).map(|_| rng.gen_range(0..P)).collect();
+        let am: Vec<u64> = a.iter().map(|&v| mont.to_montgomery(v)).collect();
+        let bm: Vec<u64> = b.iter().map(|&v| mont.to_montgomery(v)).collect();
+        assert_eq!(
+            mont.to_canonical(mont.reduce_dot_product(&am, &bm)),
+            barrett.reduce_dot_product(&a, &b)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Operations of [`MontgomeryModulus`].
+//!
+//! Except [`Reduce`], every operation takes and returns values in the montgomery domain.
+
+use crate::integer::UnsignedInteger;
+use crate::numeric::Numeric;
+use crate::reduce::*;
+
+use super::MontgomeryModulus;
+
+impl<T: Numeric> Reduce<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates `value (mod modulus)`.
+    ///
+    /// This only reduces the representative, it does not change the domain of `value`.
+    #[inline]
+    fn reduce(self, value: T) -> Self::Output {
+        value % self.value
+    }
+}
+
+impl<T: Numeric> ReduceAssign<T> for MontgomeryModulus<T> {
+    /// Calculates `value (mod modulus)`.
+    ///
+    /// This only reduces the representative, it does not change the domain of `value`.
+    #[inline]
+    fn reduce_assign(self, value: &mut T) {
+        *value = self.reduce(*value);
+    }
+}
+
+impl<T: Numeric> ReduceOnce<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn reduce_once(self, value: T) -> Self::Output {
+        self.value.reduce_once(value)
+    }
+}
+
+impl<T: Numeric> ReduceOnceAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_once_assign(self, value: &mut T) {
+        self.value.reduce_once_assign(value);
+    }
+}
+
+impl<T: Numeric> ReduceAdd<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn reduce_add(self, a: T, b: T) -> Self::Output {
+        self.value.reduce_add(a, b)
+    }
+}
+
+impl<T: Numeric> ReduceAddAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_add_assign(self, a: &mut T, b: T) {
+        self.value.reduce_add_assign(a, b);
+    }
+}
+
+impl<T: Numeric> ReduceDouble<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn reduce_double(self, value: T) -> Self::Output {
+        self.value.reduce_double(value)
+    }
+}
+
+impl<T: Numeric> ReduceDoubleAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_double_assign(self, value: &mut T) {
+        self.value.reduce_double_assign(value);
+    }
+}
+
+impl<T: Numeric> ReduceSub<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn reduce_sub(self, a: T, b: T) -> Self::Output {
+        self.value.reduce_sub(a, b)
+    }
+}
+
+impl<T: Numeric> ReduceSubAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_sub_assign(self, a: &mut T, b: T) {
+        self.value.reduce_sub_assign(a, b);
+    }
+}
+
+impl<T: Numeric> ReduceNeg<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn reduce_neg(self, value: T) -> Self::Output {
+        self.value.reduce_neg(value)
+    }
+}
+
+impl<T: Numeric> ReduceNegAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_neg_assign(self, value: &mut T) {
+        self.value.reduce_neg_assign(value);
+    }
+}
+
+impl<T: Numeric> ReduceMul<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_mul(self, a: T, b: T) -> Self::Output {
+        self.montgomery_mul(a, b)
+    }
+}
+
+impl<T: Numeric> ReduceMulAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_mul_assign(self, a: &mut T, b: T) {
+        *a = self.montgomery_mul(*a, b);
+    }
+}
+
+impl<T: Numeric> ReduceSquare<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_square(self, value: T) -> Self::Output {
+        self.montgomery_mul(value, value)
+    }
+}
+
+impl<T: Numeric> ReduceSquareAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_square_assign(self, value: &mut T) {
+        *value = self.montgomery_mul(*value, *value);
+    }
+}
+
+impl<T: Numeric> ReduceMulAdd<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_mul_add(self, a: T, b: T, c: T) -> Self::Output {
+        self.value.reduce_add(self.montgomery_mul(a, b), c)
+    }
+}
+
+impl<T: Numeric> ReduceMulAddAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_mul_add_assign(self, a: &mut T, b: T, c: T) {
+        *a = self.reduce_mul_add(*a, b, c);
+    }
+}
+
+impl<T: Numeric> ReduceInv<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates `value^(p-2)` by Fermat's little theorem for a modulus known to be
+    /// a prime `p`, see [`MontgomeryModulus::new_prime`]. Other moduli use the extended gcd.
+    #[inline]
+    fn reduce_inv(self, value: T) -> Self::Output {
+        if self.prime {
+            debug_assert!(!value.is_zero());
+            self.reduce_exp(value, self.value - T::TWO)
+        } else {
+            self.to_montgomery(self.value.reduce_inv(self.to_canonical(value)))
+        }
+    }
+}
+
+impl<T: Numeric> ReduceInvAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_inv_assign(self, value: &mut T) {
+        *value = self.reduce_inv(*value);
+    }
+}
+
+impl<T: Numeric> ReduceDiv<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_div(self, a: T, b: T) -> Self::Output {
+        self.montgomery_mul(a, self.reduce_inv(b))
+    }
+}
+
+impl<T: Numeric> ReduceDivAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_div_assign(self, a: &mut T, b: T) {
+        *a = self.reduce_div(*a, b);
+    }
+}
+
+impl<T: Numeric> ReduceExp<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_exp<E: UnsignedInteger>(self, base: T, mut exp: E) -> T {
+        if exp.is_zero() {
+            return self.r;
+        }
+
+        if base.is_zero() {
+            return T::ZERO;
+        }
+
+        debug_assert!(base < self.value);
+
+        let mut power = base;
+        let mut intermediate = self.r;
+        loop {
+            if !(exp & E::ONE).is_zero() {
+                self.reduce_mul_assign(&mut intermediate, power);
+            }
+            exp >>= 1;
+            if exp.is_zero() {
+                break;
+            }
+            self.reduce_square_assign(&mut power);
+        }
+        intermediate
+    }
+}
+
+impl<T: Numeric> ReduceExpPowOf2<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_exp_power_of_2(self, base: T, exp_log: u32) -> T {
+        let mut power = base;
+
+        for _ in 0..exp_log {
+            self.reduce_square_assign(&mut power);
+        }
+
+        power
+    }
+}
+
+impl<T: Numeric> ReduceDotProduct<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_dot_product(self, a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> Self::Output {
+        let a = a.as_ref();
+        let b = b.as_ref();
+
+        debug_assert_eq!(a.len(), b.len());
+
+        a.iter().zip(b).fold(T::ZERO, |acc, (&a, &b)| {
+            self.value.reduce_add(acc, self.montgomery_mul(a, b))
+        })
+    }
+}
+
+impl<T: Numeric> SliceModOps<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn add_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| self.value.reduce_add_assign(x, y));
+    }
+
+    #[inline]
+    fn sub_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| self.value.reduce_sub_assign(x, y));
+    }
+
+    #[inline]
+    fn mul_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = self.montgomery_mul(*x, y));
+    }
+
+    #[inline]
+    fn mul_scalar_slice(self, a: &mut [T], scalar: T) {
+        a.iter_mut()
+            .for_each(|x| *x = self.montgomery_mul(*x, scalar));
+    }
+
+    #[inline]
+    fn fma_slice(self, a: &mut [T], b: &[T], scalar: T) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = self.reduce_mul_add(y, scalar, *x));
+    }
+}
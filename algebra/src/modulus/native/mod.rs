@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{
     integer::UnsignedInteger,
-    reduce::{Modulus, ModulusValue},
+    reduce::{Modulus, ModulusValue, ReduceRepresentation},
 };
 
 mod ops;
@@ -55,4 +55,21 @@ impl<T: UnsignedInteger> Modulus<T> for NativeModulus<T> {
             _ => panic!("The value is not a native modulus."),
         }
     }
+
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        value.is_native().then(Self::new)
+    }
+}
+
+impl<T: UnsignedInteger> ReduceRepresentation<T> for NativeModulus<T> {
+    #[inline(always)]
+    fn to_representation(self, value: T) -> T {
+        value
+    }
+
+    #[inline(always)]
+    fn to_canonical(self, value: T) -> T {
+        value
+    }
 }
@@ -214,3 +214,42 @@ impl<T: UnsignedInteger> ReduceDotProduct<T> for NativeModulus<T> {
             .fold(T::ZERO, |acc, (&x, &y)| x.wrapping_mul(y).wrapping_add(acc))
     }
 }
+
+impl<T: UnsignedInteger> SliceModOps<T> for NativeModulus<T> {
+    #[inline]
+    fn add_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_add(y));
+    }
+
+    #[inline]
+    fn sub_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_sub(y));
+    }
+
+    #[inline]
+    fn mul_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_mul(y));
+    }
+
+    #[inline]
+    fn mul_scalar_slice(self, a: &mut [T], scalar: T) {
+        a.iter_mut().for_each(|x| *x = x.wrapping_mul(scalar));
+    }
+
+    #[inline]
+    fn fma_slice(self, a: &mut [T], b: &[T], scalar: T) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = y.wrapping_mul(scalar).wrapping_add(*x));
+    }
+}
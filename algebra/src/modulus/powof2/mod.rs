@@ -1,6 +1,6 @@
 use crate::{
     integer::UnsignedInteger,
-    reduce::{Modulus, ModulusValue},
+    reduce::{Modulus, ModulusValue, ReduceRepresentation},
 };
 
 #[macro_use]
@@ -43,6 +43,16 @@ impl<T: UnsignedInteger> Modulus<T> for PowOf2Modulus<T> {
         }
     }
 
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        match value {
+            ModulusValue::PowerOf2(value) if value.is_power_of_two() => Some(Self {
+                mask: value - T::ONE,
+            }),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn modulus_value(&self) -> ModulusValue<T> {
         ModulusValue::PowerOf2(self.value())
@@ -54,6 +64,18 @@ impl<T: UnsignedInteger> Modulus<T> for PowOf2Modulus<T> {
     }
 }
 
+impl<T: UnsignedInteger> ReduceRepresentation<T> for PowOf2Modulus<T> {
+    #[inline(always)]
+    fn to_representation(self, value: T) -> T {
+        value
+    }
+
+    #[inline(always)]
+    fn to_canonical(self, value: T) -> T {
+        value
+    }
+}
+
 impl_powof2_modulus!(impl PowOf2Modulus<u8>);
 impl_powof2_modulus!(impl PowOf2Modulus<u16>);
 impl_powof2_modulus!(impl PowOf2Modulus<u32>);
@@ -222,3 +222,48 @@ impl<T: UnsignedInteger> ReduceDotProduct<T> for PowOf2Modulus<T> {
             & self.mask
     }
 }
+
+impl<T: UnsignedInteger> SliceModOps<T> for PowOf2Modulus<T> {
+    #[inline]
+    fn add_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        let mask = self.mask;
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_add(y) & mask);
+    }
+
+    #[inline]
+    fn sub_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        let mask = self.mask;
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_sub(y) & mask);
+    }
+
+    #[inline]
+    fn mul_slice(self, a: &mut [T], b: &[T]) {
+        assert_eq!(a.len(), b.len());
+        let mask = self.mask;
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = x.wrapping_mul(y) & mask);
+    }
+
+    #[inline]
+    fn mul_scalar_slice(self, a: &mut [T], scalar: T) {
+        let mask = self.mask;
+        a.iter_mut()
+            .for_each(|x| *x = x.wrapping_mul(scalar) & mask);
+    }
+
+    #[inline]
+    fn fma_slice(self, a: &mut [T], b: &[T], scalar: T) {
+        assert_eq!(a.len(), b.len());
+        let mask = self.mask;
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| *x = y.wrapping_mul(scalar).wrapping_add(*x) & mask);
+    }
+}
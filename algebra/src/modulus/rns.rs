@@ -0,0 +1,487 @@
+use crate::{
+    integer::{AsFrom, AsInto},
+    numeric::Numeric,
+    reduce::{Reduce, ReduceAdd, ReduceMul, ReduceSub},
+    AlgebraError,
+};
+
+use super::BarrettModulus;
+
+/// The Shenoy-Kumaresan correction of a [`BaseConverter`].
+#[derive(Debug, Clone)]
+struct Redundant<T: Numeric> {
+    modulus: BarrettModulus<T>,
+    /// `Q / q_i (mod m)` for every `i`.
+    punctured: Vec<T>,
+    /// `Q⁻¹ (mod m)`.
+    product_inv: T,
+}
+
+/// Fast conversion of RNS residues from a base `q_0, ..., q_{k-1}` to a base `p_0, ..., p_{n-1}`.
+///
+/// With `Q = ∏ q_i`, `Q_i = Q / q_i` and `y_i = x_i·Q_i⁻¹ (mod q_i)`, the value
+/// `x ∈ [0, Q)` equals `Σ y_i·Q_i - α·Q` for some `α ∈ [0, k)`. The conversions differ
+/// in how they handle `α`:
+///
+/// - [`fast_convert`](BaseConverter::fast_convert) ignores it, and outputs `x + α·Q`.
+/// - [`convert_centered`](BaseConverter::convert_centered) estimates it with floating point,
+///   as HPS does, and outputs the centered lift of `x`.
+/// - [`convert_exact`](BaseConverter::convert_exact) recovers it from `x` modulo an
+///   extra modulus `m`, as BEHZ does with the Shenoy-Kumaresan correction, and outputs `x`.
+#[derive(Debug, Clone)]
+pub struct BaseConverter<T: Numeric> {
+    from: Vec<BarrettModulus<T>>,
+    to: Vec<BarrettModulus<T>>,
+    /// `Q_i⁻¹ (mod q_i)` for every `i`.
+    punctured_inv: Vec<T>,
+    /// `Q_i (mod p_j)`, indexed by `j` then `i`.
+    punctured: Vec<Vec<T>>,
+    /// `Q (mod p_j)` for every `j`.
+    product: Vec<T>,
+    /// `1 / q_i` for every `i`.
+    reciprocals: Vec<f64>,
+    redundant: Option<Redundant<T>>,
+}
+
+impl<T: Numeric> BaseConverter<T> {
+    /// Creates a new [`BaseConverter<T>`] from the base `from` to the base `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if the moduli of `from` are not pairwise coprime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is empty, or any modulus is not valid for [`BarrettModulus`].
+    pub fn new(from: &[T], to: &[T]) -> Result<Self, AlgebraError> {
+        assert!(!from.is_empty() && !to.is_empty(), "base can't be empty.");
+
+        let from_moduli: Vec<BarrettModulus<T>> = from
+            .iter()
+            .map(|&q| BarrettModulus::new_generic(q))
+            .collect();
+        let to_moduli: Vec<BarrettModulus<T>> =
+            to.iter().map(|&p| BarrettModulus::new_generic(p)).collect();
+
+        let punctured_inv = from_moduli
+            .iter()
+            .enumerate()
+            .map(|(i, q)| q.value().try_reduce_inv(punctured_product(from, i, *q)))
+            .collect::<Result<Vec<T>, _>>()?;
+
+        let punctured = to_moduli
+            .iter()
+            .map(|&p| {
+                (0..from.len())
+                    .map(|i| punctured_product(from, i, p))
+                    .collect()
+            })
+            .collect();
+        let product = to_moduli
+            .iter()
+            .map(|&p| punctured_product(from, from.len(), p))
+            .collect();
+        let reciprocals = from
+            .iter()
+            .map(|&q| 1.0 / AsInto::<f64>::as_into(q))
+            .collect();
+
+        Ok(Self {
+            from: from_moduli,
+            to: to_moduli,
+            punctured_inv,
+            punctured,
+            product,
+            reciprocals,
+            redundant: None,
+        })
+    }
+
+    /// Adds the redundant modulus `m` used by [`convert_exact`](BaseConverter::convert_exact).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if `m` is not coprime to the moduli of the source base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is not larger than the number of moduli in the source base,
+    /// or is not valid for [`BarrettModulus`].
+    pub fn with_redundant(mut self, m: T) -> Result<Self, AlgebraError> {
+        let count: T = self.from.len().as_into();
+        assert!(m > count, "redundant modulus is too small.");
+
+        let modulus = BarrettModulus::new_generic(m);
+        let moduli: Vec<T> = self.from.iter().map(|q| q.value()).collect();
+
+        let punctured = (0..moduli.len())
+            .map(|i| punctured_product(&moduli, i, modulus))
+            .collect();
+        let product_inv = m.try_reduce_inv(punctured_product(&moduli, moduli.len(), modulus))?;
+
+        self.redundant = Some(Redundant {
+            modulus,
+            punctured,
+            product_inv,
+        });
+        Ok(self)
+    }
+
+    /// Returns the moduli of the source base.
+    #[inline]
+    pub fn from_moduli(&self) -> &[BarrettModulus<T>] {
+        &self.from
+    }
+
+    /// Returns the moduli of the target base.
+    #[inline]
+    pub fn to_moduli(&self) -> &[BarrettModulus<T>] {
+        &self.to
+    }
+
+    /// Returns the redundant modulus, if it's set.
+    #[inline]
+    pub fn redundant_modulus(&self) -> Option<BarrettModulus<T>> {
+        self.redundant.as_ref().map(|r| r.modulus)
+    }
+
+    /// Converts `residues` of `x ∈ [0, Q)` into `output`, which then holds
+    /// `x + α·Q (mod p_j)` for an unknown `α ∈ [0, k)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `residues` and `output` don't match the bases.
+    pub fn fast_convert(&self, residues: &[T], output: &mut [T]) {
+        let y = self.scaled(residues);
+        assert_eq!(output.len(), self.to.len());
+
+        for ((out, &p), punctured) in output.iter_mut().zip(&self.to).zip(&self.punctured) {
+            *out = dot(p, &y, punctured);
+        }
+    }
+
+    /// Converts `residues` of `x ∈ [0, Q)` into `output`, which then holds
+    /// the centered lift of `x`, that is `x` if `x < Q/2` and `x - Q` otherwise, modulo `p_j`.
+    ///
+    /// `α` is estimated as `⌊Σ y_i / q_i⌉` in `f64`. The result is only wrong when
+    /// `x` is within about `k·2⁻⁵²·Q` of `Q/2`, which is negligible for random values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `residues` and `output` don't match the bases.
+    pub fn convert_centered(&self, residues: &[T], output: &mut [T]) {
+        let y = self.scaled(residues);
+        assert_eq!(output.len(), self.to.len());
+
+        let alpha: f64 = y
+            .iter()
+            .zip(&self.reciprocals)
+            .map(|(&y, &r)| AsInto::<f64>::as_into(y) * r)
+            .sum();
+        let alpha: T = (alpha.round() as u64).as_into();
+
+        for (((out, &p), punctured), &product) in output
+            .iter_mut()
+            .zip(&self.to)
+            .zip(&self.punctured)
+            .zip(&self.product)
+        {
+            let sum = dot(p, &y, punctured);
+            *out = p.reduce_sub(sum, p.reduce_mul(p.reduce(alpha), product));
+        }
+    }
+
+    /// Converts `residues` of `x ∈ [0, Q)` into `output`, which then holds `x (mod p_j)` exactly.
+    ///
+    /// `redundant` is `x (mod m)`, where `m` is the redundant modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the redundant modulus is not set,
+    /// or the lengths of `residues` and `output` don't match the bases.
+    pub fn convert_exact(&self, residues: &[T], redundant: T, output: &mut [T]) {
+        let sk = self
+            .redundant
+            .as_ref()
+            .expect("redundant modulus is not set.");
+        let y = self.scaled(residues);
+        assert_eq!(output.len(), self.to.len());
+
+        let m = sk.modulus;
+        let approx = dot(m, &y, &sk.punctured);
+        let alpha = m.reduce_mul(m.reduce_sub(approx, m.reduce(redundant)), sk.product_inv);
+
+        for (((out, &p), punctured), &product) in output
+            .iter_mut()
+            .zip(&self.to)
+            .zip(&self.punctured)
+            .zip(&self.product)
+        {
+            let sum = dot(p, &y, punctured);
+            *out = p.reduce_sub(sum, p.reduce_mul(p.reduce(alpha), product));
+        }
+    }
+
+    /// Returns `y_i = x_i·Q_i⁻¹ (mod q_i)`.
+    fn scaled(&self, residues: &[T]) -> Vec<T> {
+        assert_eq!(residues.len(), self.from.len());
+        residues
+            .iter()
+            .zip(&self.from)
+            .zip(&self.punctured_inv)
+            .map(|((&x, q), &inv)| q.reduce_mul(q.reduce(x), inv))
+            .collect()
+    }
+}
+
+/// Scaling of RNS residues by `t / Q` with rounding, as in BFV decryption.
+///
+/// With `Q_i = Q / q_i` and `Q̃_i = Q_i⁻¹ (mod q_i)`, `t·x / Q ≡ Σ x_i·t·Q̃_i / q_i (mod t)`.
+/// Each `t·Q̃_i / q_i` is precomputed as an integer part `ω_i (mod t)` and a remainder `r_i`,
+/// then `x_i·r_i / q_i` is split again with a double width division. Only the sum of
+/// the fractional parts is approximated, in `64`-bits fixed point with `128`-bits integers,
+/// so the result is exact unless `t·x / Q` is within `k·2⁻⁶⁴` of a half.
+#[derive(Debug, Clone)]
+pub struct ScaleAndRound<T: Numeric> {
+    moduli: Vec<BarrettModulus<T>>,
+    plain: BarrettModulus<T>,
+    /// `⌊t·Q̃_i / q_i⌋ (mod t)` for every `i`.
+    omega: Vec<T>,
+    /// `t·Q̃_i (mod q_i)` for every `i`.
+    remainder: Vec<T>,
+}
+
+impl<T: Numeric> ScaleAndRound<T> {
+    /// Creates a new [`ScaleAndRound<T>`] for the base `moduli` and the plain modulus `t`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if `moduli` are not pairwise coprime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `moduli` is empty, or any modulus or `t` is not valid for [`BarrettModulus`].
+    pub fn new(moduli: &[T], t: T) -> Result<Self, AlgebraError> {
+        assert!(!moduli.is_empty(), "base can't be empty.");
+
+        let plain = BarrettModulus::new_generic(t);
+        let barrett: Vec<BarrettModulus<T>> = moduli
+            .iter()
+            .map(|&q| BarrettModulus::new_generic(q))
+            .collect();
+
+        let mut omega = Vec::with_capacity(moduli.len());
+        let mut remainder = Vec::with_capacity(moduli.len());
+        for (i, q) in barrett.iter().enumerate() {
+            let inv = q.value().try_reduce_inv(punctured_product(moduli, i, *q))?;
+            let product = T::WideT::as_from(t) * T::WideT::as_from(inv);
+            let value = T::WideT::as_from(q.value());
+
+            let quotient: T = (product / value).as_into();
+            omega.push(plain.reduce(quotient));
+            remainder.push((product % value).as_into());
+        }
+
+        Ok(Self {
+            moduli: barrett,
+            plain,
+            omega,
+            remainder,
+        })
+    }
+
+    /// Returns the plain modulus `t`.
+    #[inline]
+    pub fn plain_modulus(&self) -> T {
+        self.plain.value()
+    }
+
+    /// Returns `⌊t·x / Q⌉ (mod t)` for `x ∈ [0, Q)` given by its `residues`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `residues` doesn't match the base.
+    pub fn scale_round(&self, residues: &[T]) -> T {
+        assert_eq!(residues.len(), self.moduli.len());
+        let t = self.plain;
+
+        let mut integer = T::ZERO;
+        let mut fraction = 0u128;
+        for (((&x, q), &omega), &r) in residues
+            .iter()
+            .zip(&self.moduli)
+            .zip(&self.omega)
+            .zip(&self.remainder)
+        {
+            let x = q.reduce(x);
+            let product = T::WideT::as_from(x) * T::WideT::as_from(r);
+            let value = T::WideT::as_from(q.value());
+
+            let quotient: T = (product / value).as_into();
+            let rest: u128 = (product % value).as_into();
+            let rest = (rest << 64) / AsInto::<u128>::as_into(q.value());
+
+            integer = t.reduce_add(integer, t.reduce_mul(t.reduce(x), omega));
+            integer = t.reduce_add(integer, t.reduce(quotient));
+            fraction += rest;
+        }
+
+        let carry: T = ((fraction + (1 << 63)) >> 64).as_into();
+        t.reduce_add(integer, t.reduce(carry))
+    }
+
+    /// Performs [`scale_round`](ScaleAndRound::scale_round) on every coefficient of a polynomial.
+    ///
+    /// `limbs[i]` holds the coefficients of the polynomial modulo `q_i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of limbs doesn't match the base,
+    /// or any limb has a different length from `output`.
+    pub fn scale_round_limbs(&self, limbs: &[Vec<T>], output: &mut [T]) {
+        assert_eq!(limbs.len(), self.moduli.len());
+        assert!(limbs.iter().all(|limb| limb.len() == output.len()));
+
+        let mut residues = vec![T::ZERO; limbs.len()];
+        for (j, out) in output.iter_mut().enumerate() {
+            for (residue, limb) in residues.iter_mut().zip(limbs) {
+                *residue = limb[j];
+            }
+            *out = self.scale_round(&residues);
+        }
+    }
+}
+
+/// Returns `∏_{l ≠ skip} moduli[l] (mod modulus)`.
+fn punctured_product<T: Numeric>(moduli: &[T], skip: usize, modulus: BarrettModulus<T>) -> T {
+    moduli
+        .iter()
+        .enumerate()
+        .filter(|&(l, _)| l != skip)
+        .fold(modulus.reduce(T::ONE), |acc, (_, &q)| {
+            modulus.reduce_mul(acc, modulus.reduce(q))
+        })
+}
+
+/// Returns `Σ y_i·c_i (mod p)`, where `c_i < p`.
+#[inline]
+fn dot<T: Numeric>(p: BarrettModulus<T>, y: &[T], c: &[T]) -> T {
+    y.iter().zip(c).fold(T::ZERO, |acc, (&y, &c)| {
+        p.reduce_add(acc, p.reduce_mul(p.reduce(y), c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    const FROM: [u32; 3] = [132120577, 134215681, 1073479681];
+    const TO: [u32; 2] = [998244353, 1004535809];
+
+    fn residues(x: u128, moduli: &[u32]) -> Vec<u32> {
+        moduli.iter().map(|&q| (x % q as u128) as u32).collect()
+    }
+
+    #[test]
+    fn test_fast_convert() {
+        let converter = BaseConverter::new(&FROM, &TO).unwrap();
+        let big_q: u128 = FROM.iter().map(|&q| q as u128).product();
+        let mut rng = thread_rng();
+        let mut output = [0u32; 2];
+
+        for _ in 0..100 {
+            let x = rng.gen_range(0..big_q);
+            converter.fast_convert(&residues(x, &FROM), &mut output);
+
+            let alpha = (0..FROM.len() as u128)
+                .find(|&alpha| output.to_vec() == residues(x + alpha * big_q, &TO));
+            assert!(alpha.is_some());
+        }
+    }
+
+    #[test]
+    fn test_convert_centered() {
+        let converter = BaseConverter::new(&FROM, &TO).unwrap();
+        let big_q: u128 = FROM.iter().map(|&q| q as u128).product();
+        let mut rng = thread_rng();
+        let mut output = [0u32; 2];
+
+        // Values within about `2⁻⁵⁰·Q` of `Q/2` may be lifted to the wrong side.
+        let margin = 1 << 40;
+        for x in [0, 1, big_q / 2 - margin, big_q / 2 + margin, big_q - 1]
+            .into_iter()
+            .chain((0..100).map(|_| rng.gen_range(0..big_q)))
+        {
+            converter.convert_centered(&residues(x, &FROM), &mut output);
+
+            let expected: Vec<u32> = TO
+                .iter()
+                .map(|&p| {
+                    let p = p as u128;
+                    let lift = if x < big_q / 2 { x } else { x + p - big_q % p };
+                    (lift % p) as u32
+                })
+                .collect();
+            assert_eq!(output.to_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_convert_exact() {
+        const M: u32 = 65537;
+        let converter = BaseConverter::new(&FROM, &TO)
+            .unwrap()
+            .with_redundant(M)
+            .unwrap();
+        assert_eq!(converter.redundant_modulus().unwrap().value(), M);
+
+        let big_q: u128 = FROM.iter().map(|&q| q as u128).product();
+        let mut rng = thread_rng();
+        let mut output = [0u32; 2];
+
+        for x in [0, 1, big_q - 1]
+            .into_iter()
+            .chain((0..100).map(|_| rng.gen_range(0..big_q)))
+        {
+            converter.convert_exact(&residues(x, &FROM), (x % M as u128) as u32, &mut output);
+            assert_eq!(output.to_vec(), residues(x, &TO));
+        }
+
+        assert!(BaseConverter::new(&FROM, &TO)
+            .unwrap()
+            .with_redundant(FROM[0])
+            .is_err());
+        assert!(BaseConverter::new(&[FROM[0], FROM[0]], &TO).is_err());
+    }
+
+    #[test]
+    fn test_scale_and_round() {
+        const T: u32 = 65537;
+        let scaler = ScaleAndRound::new(&FROM, T).unwrap();
+        let big_q: u128 = FROM.iter().map(|&q| q as u128).product();
+        let mut rng = thread_rng();
+
+        let values: Vec<u128> = [0, 1, big_q / 2, big_q - 1]
+            .into_iter()
+            .chain((0..100).map(|_| rng.gen_range(0..big_q)))
+            .collect();
+        let expected: Vec<u32> = values
+            .iter()
+            .map(|&x| ((T as u128 * x + big_q / 2) / big_q % T as u128) as u32)
+            .collect();
+
+        for (&x, &expected) in values.iter().zip(&expected) {
+            assert_eq!(scaler.scale_round(&residues(x, &FROM)), expected);
+        }
+
+        let limbs: Vec<Vec<u32>> = FROM
+            .iter()
+            .map(|&q| values.iter().map(|&x| (x % q as u128) as u32).collect())
+            .collect();
+        let mut output = vec![0; values.len()];
+        scaler.scale_round_limbs(&limbs, &mut output);
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,308 @@
+use num_traits::Zero;
+
+use crate::{
+    arith::is_prime,
+    integer::{AsFrom, AsInto},
+    ntt::{NttTable, NumberTheoryTransform},
+    AlgebraError, Field, NttField,
+};
+
+/// This struct store the pre-computed data for number theory transform of
+/// any length `m` with Bluestein's algorithm.
+///
+/// The transform of `a_0, ..., a_{m-1}` is `A_k = Σ a_j·ω^{jk}` for a primitive
+/// `m`-th root of unity `ω`, i.e. the evaluations of `a(X) (mod X^m - 1)` at the
+/// powers of `ω`, in normal order.
+///
+/// With `ω = ψ²` and `jk = (j² + k² - (k-j)²) / 2`, the transform becomes a
+/// convolution with the chirp `ψ^{-t²}`, which is computed by the power of 2
+/// negacyclic transform of the field with the length `L ≥ 2m - 1`.
+/// So the modulus `p` must satisfy `2m | p - 1` and `2L | p - 1`.
+pub struct BluesteinTable<F: NttField> {
+    m: usize,
+    root: <F as Field>::ValueT,
+    inv_m: <F as Field>::ValueT,
+    /// `ψ^{k²}` for `k < m`.
+    chirp: Vec<<F as Field>::ValueT>,
+    /// `ψ^{-k²}` for `k < m`.
+    inv_chirp: Vec<<F as Field>::ValueT>,
+    /// The transform of `ψ^{-t²}` for `t = 1-m, ..., m-1`.
+    kernel: Vec<<F as Field>::ValueT>,
+    /// The transform of `ψ^{t²}` for `t = 1-m, ..., m-1`.
+    inv_kernel: Vec<<F as Field>::ValueT>,
+    table: <F as NttField>::Table,
+}
+
+impl<F: NttField> BluesteinTable<F> {
+    /// Creates a new [`BluesteinTable<F>`] for the length `m`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoPrimitiveRoot`] if `2m` doesn't divide `p - 1`,
+    /// or the error of [`NttField::generate_ntt_table`] for the convolution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is less than `2`.
+    pub fn new(m: usize) -> Result<Self, AlgebraError> {
+        assert!(m >= 2, "transform length should be at least 2.");
+
+        let p = <F as Field>::MODULUS_VALUE;
+        let order = m << 1;
+        let no_root = || AlgebraError::NoPrimitiveRoot {
+            degree: Box::new(order),
+            modulus: Box::new(p),
+        };
+
+        let order_t = <F as Field>::ValueT::try_from(order).map_err(|_| no_root())?;
+        let p_minus_one = p - <F as Field>::ONE;
+        if !(p_minus_one % order_t).is_zero() {
+            return Err(no_root());
+        }
+        let cofactor = p_minus_one / order_t;
+
+        let factors = prime_factors(order);
+        let psi = (2..p.as_into())
+            .map(|g: u64| F::exp(<F as Field>::ValueT::as_from(g), cofactor))
+            .find(|&psi| factors.iter().all(|&r| F::exp(psi, order / r) != F::ONE))
+            .ok_or_else(no_root)?;
+        let inv_psi = F::inv(psi);
+
+        let chirp: Vec<_> = (0..m).map(|k| F::exp(psi, k * k % order)).collect();
+        let inv_chirp: Vec<_> = (0..m).map(|k| F::exp(inv_psi, k * k % order)).collect();
+
+        let log_l = ((m << 1) - 1).next_power_of_two().trailing_zeros();
+        let table = F::generate_ntt_table(log_l)?;
+
+        let kernel_of = |powers: &[<F as Field>::ValueT]| {
+            let mut kernel = vec![<F as Field>::ZERO; table.dimension()];
+            for (t, k) in kernel[..(m << 1) - 1].iter_mut().enumerate() {
+                *k = powers[t.abs_diff(m - 1)];
+            }
+            table.transform_slice(&mut kernel);
+            kernel
+        };
+        let kernel = kernel_of(&inv_chirp);
+        let inv_kernel = kernel_of(&chirp);
+
+        let inv_m = F::inv(<F as Field>::ValueT::as_from(m));
+
+        Ok(Self {
+            m,
+            root: F::mul(psi, psi),
+            inv_m,
+            chirp,
+            inv_chirp,
+            kernel,
+            inv_kernel,
+            table,
+        })
+    }
+
+    /// Returns the transform length of this [`BluesteinTable<F>`].
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the primitive `m`-th root of unity `ω` of this [`BluesteinTable<F>`].
+    #[inline]
+    pub fn root(&self) -> <F as Field>::ValueT {
+        self.root
+    }
+
+    /// Perform a number theory transform of length `m` in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs `a_j` in normal order, outputs `A_k = Σ a_j·ω^{jk}` in normal order
+    pub fn transform_slice(&self, values: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(values.len(), self.m);
+        self.convolve(values, &self.chirp, &self.kernel);
+    }
+
+    /// Perform an inverse number theory transform of length `m` in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs `A_k` in normal order, outputs `a_j = m⁻¹·Σ A_k·ω^{-jk}` in normal order
+    pub fn inverse_transform_slice(&self, values: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(values.len(), self.m);
+        self.convolve(values, &self.inv_chirp, &self.inv_kernel);
+        values.iter_mut().for_each(|v| F::mul_assign(v, self.inv_m));
+    }
+
+    /// Transforms `a(X)` of the ring `Z_p[X]/(Φ_m(X))` for a prime `m`.
+    ///
+    /// `Φ_m(X) = 1 + X + ... + X^{m-1}` has the roots `ω, ω², ..., ω^{m-1}`,
+    /// so this returns the `m - 1` evaluations of `a(X)` at them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is not prime, or `poly` doesn't have `m - 1` coefficients.
+    pub fn transform_cyclotomic(&self, poly: &[<F as Field>::ValueT]) -> Vec<<F as Field>::ValueT> {
+        assert!(is_prime(self.m as u64));
+        assert_eq!(poly.len(), self.m - 1);
+
+        let mut values = poly.to_vec();
+        values.push(<F as Field>::ZERO);
+        self.transform_slice(&mut values);
+        values.remove(0);
+        values
+    }
+
+    /// Inverse of [`transform_cyclotomic`](Self::transform_cyclotomic).
+    ///
+    /// The evaluation at `ω^0` is chosen so that the coefficient of `X^{m-1}`
+    /// is zero, which is `-Σ_{k≥1} A_k·ω^k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is not prime, or `values` doesn't have `m - 1` elements.
+    pub fn inverse_transform_cyclotomic(
+        &self,
+        values: &[<F as Field>::ValueT],
+    ) -> Vec<<F as Field>::ValueT> {
+        assert!(is_prime(self.m as u64));
+        assert_eq!(values.len(), self.m - 1);
+
+        let mut power = <F as Field>::ONE;
+        let mut first = <F as Field>::ZERO;
+        for &v in values {
+            F::mul_assign(&mut power, self.root);
+            first = F::mul_add(v, power, first);
+        }
+
+        let mut poly = Vec::with_capacity(self.m);
+        poly.push(F::neg(first));
+        poly.extend_from_slice(values);
+        self.inverse_transform_slice(&mut poly);
+
+        debug_assert!(poly[self.m - 1].is_zero());
+        poly.pop();
+        poly
+    }
+
+    /// Computes `chirp_k·Σ_j (values_j·chirp_j)·kernel_{k-j}` in place.
+    fn convolve(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        chirp: &[<F as Field>::ValueT],
+        kernel: &[<F as Field>::ValueT],
+    ) {
+        let mut buf = vec![<F as Field>::ZERO; self.table.dimension()];
+        for ((b, &v), &c) in buf.iter_mut().zip(values.iter()).zip(chirp) {
+            *b = F::mul(v, c);
+        }
+
+        self.table.transform_slice(&mut buf);
+        for (b, &k) in buf.iter_mut().zip(kernel) {
+            F::mul_assign(b, k);
+        }
+        self.table.inverse_transform_slice(&mut buf);
+
+        // The wrapped part of the negacyclic product starts after `L ≥ 2m - 1`,
+        // so the middle `m` terms of the linear convolution are intact.
+        for ((v, &b), &c) in values.iter_mut().zip(&buf[self.m - 1..]).zip(chirp) {
+            *v = F::mul(b, c);
+        }
+    }
+}
+
+/// Returns the distinct prime factors of `n`.
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::U64FieldEval;
+
+    use super::*;
+
+    type Fp = U64FieldEval<7710220801>;
+
+    fn naive_dft(values: &[u64], root: u64) -> Vec<u64> {
+        let m = values.len();
+        (0..m)
+            .map(|k| {
+                values.iter().enumerate().fold(0, |acc, (j, &a)| {
+                    Fp::mul_add(a, Fp::exp(root, j * k % m), acc)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bluestein_transform() {
+        let mut rng = thread_rng();
+
+        for m in [9, 17, 31, 40, 127] {
+            let table = BluesteinTable::<Fp>::new(m).unwrap();
+            let root = table.root();
+            assert_eq!(Fp::exp(root, m), 1);
+            assert!(prime_factors(m).iter().all(|&r| Fp::exp(root, m / r) != 1));
+
+            let poly: Vec<u64> = (0..m).map(|_| rng.gen_range(0..7710220801)).collect();
+            let mut values = poly.clone();
+            table.transform_slice(&mut values);
+            assert_eq!(values, naive_dft(&poly, root), "m = {m}");
+
+            table.inverse_transform_slice(&mut values);
+            assert_eq!(values, poly, "m = {m}");
+        }
+
+        assert!(BluesteinTable::<Fp>::new(11).is_err());
+    }
+
+    #[test]
+    fn test_bluestein_cyclotomic() {
+        let mut rng = thread_rng();
+
+        for m in [17, 31] {
+            let table = BluesteinTable::<Fp>::new(m).unwrap();
+            let distr = rand::distributions::Uniform::new(0, 7710220801);
+            let a: Vec<u64> = distr.sample_iter(&mut rng).take(m - 1).collect();
+            let b: Vec<u64> = distr.sample_iter(&mut rng).take(m - 1).collect();
+
+            // a·b (mod Φ_m), with X^{m-1} = -(1 + X + ... + X^{m-2})
+            let mut product = vec![0u64; 2 * m - 3];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    product[i + j] = Fp::mul_add(x, y, product[i + j]);
+                }
+            }
+            for d in (m - 1..product.len()).rev() {
+                let top = product.pop().unwrap();
+                // X^d = X^{d-m}·X^m = X^{d-m} (mod X^m - 1), and X^{m-1} is reduced below
+                if d >= m {
+                    Fp::add_assign(&mut product[d - m], top);
+                } else {
+                    product.iter_mut().for_each(|c| Fp::sub_assign(c, top));
+                }
+            }
+
+            let fa = table.transform_cyclotomic(&a);
+            let fb = table.transform_cyclotomic(&b);
+            let fc: Vec<u64> = fa.iter().zip(&fb).map(|(&x, &y)| Fp::mul(x, y)).collect();
+            assert_eq!(table.inverse_transform_cyclotomic(&fc), product, "m = {m}");
+            assert_eq!(table.inverse_transform_cyclotomic(&fa), a, "m = {m}");
+        }
+    }
+}
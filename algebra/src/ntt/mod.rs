@@ -2,8 +2,10 @@
 
 use crate::{arith::PrimitiveRoot, AlgebraError};
 
+mod bluestein;
 mod table;
 
+pub use bluestein::BluesteinTable;
 pub use table::*;
 
 /// An abstract for ntt table generation.
@@ -13,6 +13,18 @@ use crate::{
     AlgebraError, Field, NttField,
 };
 
+/// The butterfly layout used by the transforms of a [`FieldTableWithShoupRoot`].
+///
+/// Both give the same results, see [`FieldTableWithShoupRoot::set_radix`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NttRadix {
+    /// One layer per pass over the values.
+    #[default]
+    Radix2,
+    /// Two layers per pass over the values, with a single radix-2 layer when needed.
+    Radix4,
+}
+
 /// This struct store the pre-computed data for number theory transform and
 /// inverse number theory transform.
 ///
@@ -50,6 +62,7 @@ where
     ordinal_root_powers: Vec<ShoupFactor<<F as Field>::ValueT>>,
     reverse_lsbs: Vec<usize>,
     pool: Pool<Vec<<F as Field>::ValueT>>,
+    radix: NttRadix,
 }
 
 impl<F> FieldTableWithShoupRoot<F>
@@ -115,6 +128,217 @@ where
     pub fn reverse_lsbs(&self) -> &[usize] {
         &self.reverse_lsbs
     }
+
+    /// Returns the radix of this [`FieldTableWithShoupRoot<F>`].
+    #[inline]
+    pub fn radix(&self) -> NttRadix {
+        self.radix
+    }
+
+    /// Sets the radix used by the [`NumberTheoryTransform`] methods of this table.
+    ///
+    /// With [`NttRadix::Radix4`], the forward and inverse transforms run
+    /// [`lazy_transform_slice_radix4`](Self::lazy_transform_slice_radix4) and
+    /// [`lazy_inverse_transform_slice_radix4`](Self::lazy_inverse_transform_slice_radix4).
+    /// Everything using this table, such as the blind rotation, follows.
+    /// The results are the same for both radixes.
+    #[inline]
+    pub fn set_radix(&mut self, radix: NttRadix) {
+        self.radix = radix;
+    }
+
+    /// Perform a fast number theory transform in place with radix-4 butterflies.
+    ///
+    /// Every pass merges two radix-2 layers, which halves the passes over `poly`.
+    /// If `log_n` is odd, a single radix-2 layer goes first.
+    /// The root powers are the same as the radix-2 transform,
+    /// so is the result of [`NumberTheoryTransform::lazy_transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - inputs in normal order with coefficient in `[0, 4*modulus)`,
+    ///   outputs in bit-reversed order with coefficient in `[0, 4*modulus)`
+    pub fn lazy_transform_slice_radix4(&self, poly: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(poly.len(), self.n);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let butterfly = |i: &mut <F as Field>::ValueT,
+                         j: &mut <F as Field>::ValueT,
+                         root: ShoupFactor<<F as Field>::ValueT>| {
+            let u = twice_modulus_value.reduce_once(*i);
+            let v = modulus_value.lazy_reduce_mul(root, *j);
+            *i = u + v;
+            *j = u + twice_modulus_value - v;
+        };
+
+        let roots = self.root_powers();
+
+        // `m` is the number of blocks of the current layer, each one has `2*gap` values.
+        let mut m = 1;
+        let mut gap = self.n >> 1;
+
+        if self.log_n & 1 == 1 {
+            let (v0, v1) = poly.split_at_mut(gap);
+            for (i, j) in core::iter::zip(v0, v1) {
+                butterfly(i, j, roots[1]);
+            }
+            m <<= 1;
+            gap >>= 1;
+        }
+
+        while gap > 1 {
+            let quarter = gap >> 1;
+            for (k, vc) in poly.chunks_exact_mut(gap << 1).enumerate() {
+                let root = roots[m + k];
+                let root0 = roots[2 * (m + k)];
+                let root1 = roots[2 * (m + k) + 1];
+
+                let (a, b) = vc.split_at_mut(gap);
+                let (x0, x1) = a.split_at_mut(quarter);
+                let (x2, x3) = b.split_at_mut(quarter);
+                for (((x0, x1), x2), x3) in x0.iter_mut().zip(x1).zip(x2).zip(x3) {
+                    butterfly(x0, x2, root);
+                    butterfly(x1, x3, root);
+                    butterfly(x0, x1, root0);
+                    butterfly(x2, x3, root1);
+                }
+            }
+            m <<= 2;
+            gap >>= 2;
+        }
+    }
+
+    /// Perform a fast number theory transform in place with radix-4 butterflies.
+    ///
+    /// It's the same as [`NumberTheoryTransform::transform_slice`],
+    /// see [`lazy_transform_slice_radix4`](Self::lazy_transform_slice_radix4).
+    pub fn transform_slice_radix4(&self, poly: &mut [<F as Field>::ValueT]) {
+        self.lazy_transform_slice_radix4(poly);
+        Self::normalize_forward(poly);
+    }
+
+    /// Perform a fast inverse number theory transform in place with radix-4 butterflies.
+    ///
+    /// Every pass merges two radix-2 layers, which halves the passes over `values`.
+    /// If `log_n` is even, a single radix-2 layer goes first. The last layer, which
+    /// is also scaled by `n⁻¹`, is still a radix-2 layer.
+    /// The result is the same as [`NumberTheoryTransform::lazy_inverse_transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in bit-reversed order with coefficient in `[0, 2*modulus)`,
+    ///   outputs in normal order with coefficient in `[0, 2*modulus)`
+    pub fn lazy_inverse_transform_slice_radix4(&self, values: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(values.len(), self.n);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let butterfly = |i: &mut <F as Field>::ValueT,
+                         j: &mut <F as Field>::ValueT,
+                         root: ShoupFactor<<F as Field>::ValueT>| {
+            let u = *i;
+            let v = *j;
+            *i = twice_modulus_value.reduce_add(u, v);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+        };
+
+        let n = self.n;
+        let roots = self.inv_root_powers();
+
+        // The roots of every layer follow the ones of the previous layer.
+        let mut offset = 1;
+        let mut gap = 1;
+        let mut layers = self.log_n - 1;
+
+        if layers & 1 == 1 {
+            for (vc, &root) in values.chunks_exact_mut(2).zip(&roots[offset..]) {
+                let (v0, v1) = vc.split_at_mut(1);
+                butterfly(&mut v0[0], &mut v1[0], root);
+            }
+            offset += n >> 1;
+            gap <<= 1;
+            layers -= 1;
+        }
+
+        while layers > 0 {
+            let (roots0, roots1) = roots[offset..].split_at(n / (gap << 1));
+            for (k, vc) in values.chunks_exact_mut(gap << 2).enumerate() {
+                let root0 = roots0[2 * k];
+                let root1 = roots0[2 * k + 1];
+                let root = roots1[k];
+
+                let (a, b) = vc.split_at_mut(gap << 1);
+                let (x0, x1) = a.split_at_mut(gap);
+                let (x2, x3) = b.split_at_mut(gap);
+                for (((x0, x1), x2), x3) in x0.iter_mut().zip(x1).zip(x2).zip(x3) {
+                    butterfly(x0, x1, root0);
+                    butterfly(x2, x3, root1);
+                    butterfly(x0, x2, root);
+                    butterfly(x1, x3, root);
+                }
+            }
+            offset += n / (gap << 1) + n / (gap << 2);
+            gap <<= 2;
+            layers -= 2;
+        }
+
+        self.inverse_last_layer(values, roots[offset]);
+    }
+
+    /// Perform a fast inverse number theory transform in place with radix-4 butterflies.
+    ///
+    /// It's the same as [`NumberTheoryTransform::inverse_transform_slice`],
+    /// see [`lazy_inverse_transform_slice_radix4`](Self::lazy_inverse_transform_slice_radix4).
+    pub fn inverse_transform_slice_radix4(&self, values: &mut [<F as Field>::ValueT]) {
+        self.lazy_inverse_transform_slice_radix4(values);
+        Self::normalize_inverse(values);
+    }
+
+    /// The last layer of the inverse transform, merged with the scaling by `n⁻¹`.
+    #[inline]
+    fn inverse_last_layer(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        root: ShoupFactor<<F as Field>::ValueT>,
+    ) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+
+        let gap = 1 << (self.log_n - 1);
+
+        let scalar = self.inv_n();
+        let scaled_r = modulus_value.reduce_mul(scalar.value(), root);
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        let (v0, v1) = values.split_at_mut(gap);
+        for (i, j) in core::iter::zip(v0, v1) {
+            let u = *i;
+            let v = *j;
+            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
+        }
+    }
+
+    /// Reduces the output of the lazy transform from `[0, 4*modulus)` to `[0, modulus)`.
+    #[inline]
+    fn normalize_forward(poly: &mut [<F as Field>::ValueT]) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        poly.iter_mut().for_each(|v| {
+            let r = twice_modulus_value.reduce_once(*v);
+            *v = modulus_value.reduce_once(r);
+        });
+    }
+
+    /// Reduces the output of the lazy inverse transform from `[0, 2*modulus)` to `[0, modulus)`.
+    #[inline]
+    fn normalize_inverse(values: &mut [<F as Field>::ValueT]) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        values.iter_mut().for_each(|v| {
+            modulus_value.reduce_once_assign(v);
+        });
+    }
 }
 
 impl<F> NttTable for FieldTableWithShoupRoot<F>
@@ -202,6 +426,7 @@ where
             ordinal_root_powers,
             reverse_lsbs,
             pool,
+            radix: NttRadix::Radix2,
         })
     }
 
@@ -233,6 +458,10 @@ where
 
     #[inline]
     fn lazy_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+        if self.radix == NttRadix::Radix4 {
+            return self.lazy_transform_slice_radix4(poly);
+        }
+
         debug_assert_eq!(poly.len(), self.n);
 
         let modulus_value = <F as Field>::MODULUS_VALUE;
@@ -258,17 +487,15 @@ where
     #[inline]
     fn transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
         self.lazy_transform_slice(poly);
-
-        let modulus_value = <F as Field>::MODULUS_VALUE;
-        let twice_modulus_value = modulus_value << 1u32;
-        poly.iter_mut().for_each(|v| {
-            let r = twice_modulus_value.reduce_once(*v);
-            *v = modulus_value.reduce_once(r);
-        });
+        Self::normalize_forward(poly);
     }
 
     #[inline]
     fn lazy_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        if self.radix == NttRadix::Radix4 {
+            return self.lazy_inverse_transform_slice_radix4(values);
+        }
+
         debug_assert_eq!(values.len(), self.n);
 
         let log_n = self.log_n;
@@ -292,29 +519,13 @@ where
             }
         }
 
-        let gap = 1 << (log_n - 1);
-
-        let scalar = self.inv_n();
-        let scaled_r = modulus_value.reduce_mul(scalar.value(), root_iter.next().unwrap());
-        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
-
-        let (v0, v1) = values.split_at_mut(gap);
-        for (i, j) in core::iter::zip(v0, v1) {
-            let u = *i;
-            let v = *j;
-            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
-            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
-        }
+        self.inverse_last_layer(values, root_iter.next().unwrap());
     }
 
     #[inline]
     fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
         self.lazy_inverse_transform_slice(values);
-
-        let modulus_value = <F as Field>::MODULUS_VALUE;
-        values.iter_mut().for_each(|v| {
-            modulus_value.reduce_once_assign(v);
-        });
+        Self::normalize_inverse(values);
     }
 
     #[inline]
@@ -469,3 +680,70 @@ where
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::U16FieldEval;
+
+    use super::*;
+
+    type Fp = U16FieldEval<12289>;
+
+    #[test]
+    fn test_radix4_transform() {
+        let mut rng = thread_rng();
+
+        for log_n in 1..=11 {
+            let table = Fp::generate_ntt_table(log_n).unwrap();
+            let poly: Vec<u16> = (0..1 << log_n)
+                .map(|_| rng.gen_range(0..4 * Fp::MODULUS_VALUE))
+                .collect();
+
+            let mut radix2 = poly.clone();
+            let mut radix4 = poly.clone();
+            table.lazy_transform_slice(&mut radix2);
+            table.lazy_transform_slice_radix4(&mut radix4);
+            assert_eq!(radix2, radix4, "log_n = {log_n}");
+
+            let mut radix2 = poly.clone();
+            let mut values = poly.clone();
+            table.transform_slice(&mut radix2);
+            table.transform_slice_radix4(&mut values);
+            assert_eq!(radix2, values, "log_n = {log_n}");
+
+            let mut radix4 = values.clone();
+            table.lazy_inverse_transform_slice(&mut radix2);
+            table.lazy_inverse_transform_slice_radix4(&mut radix4);
+            assert_eq!(radix2, radix4, "log_n = {log_n}");
+
+            table.inverse_transform_slice_radix4(&mut values);
+            let expected: Vec<u16> = poly.iter().map(|v| v % Fp::MODULUS_VALUE).collect();
+            assert_eq!(values, expected, "log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    fn test_set_radix() {
+        let mut rng = thread_rng();
+
+        let mut table = Fp::generate_ntt_table(10).unwrap();
+        assert_eq!(table.radix(), NttRadix::Radix2);
+        table.set_radix(NttRadix::Radix4);
+        assert_eq!(table.radix(), NttRadix::Radix4);
+
+        let poly: Vec<u16> = (0..1 << 10)
+            .map(|_| rng.gen_range(0..Fp::MODULUS_VALUE))
+            .collect();
+
+        let mut values = poly.clone();
+        let mut expected = poly.clone();
+        table.transform_slice(&mut values);
+        table.transform_slice_radix4(&mut expected);
+        assert_eq!(values, expected);
+
+        table.inverse_transform_slice(&mut values);
+        assert_eq!(values, poly);
+    }
+}
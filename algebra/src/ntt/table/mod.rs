@@ -7,5 +7,5 @@ mod numeric_ntt_table;
 pub use concrete::prime32::Concrete32Table;
 #[cfg(feature = "concrete-ntt")]
 pub use concrete::prime64::Concrete64Table;
-pub use field_ntt_table::FieldTableWithShoupRoot;
+pub use field_ntt_table::{FieldTableWithShoupRoot, NttRadix};
 pub use numeric_ntt_table::TableWithShoupRoot;
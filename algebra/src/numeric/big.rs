@@ -0,0 +1,779 @@
+//! Fixed-size multi-limb unsigned integers.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub},
+};
+
+use crate::{
+    reduce::{ReduceInv, ReduceInvAssign, TryReduceInv},
+    AlgebraError,
+};
+
+use super::{BorrowingSub, CarryingAdd, CarryingMul, WideningMul};
+
+macro_rules! impl_big_uint {
+    ($(#[$meta:meta])* $Name:ident, $LIMBS:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $Name {
+            limbs: [u64; $LIMBS],
+        }
+
+        impl $Name {
+            /// The count of 64-bit limbs.
+            pub const LIMBS: usize = $LIMBS;
+            /// The size of this integer type in bits.
+            pub const BITS: u32 = 64 * $LIMBS;
+            /// The value `0`.
+            pub const ZERO: Self = Self { limbs: [0; $LIMBS] };
+            /// The value `1`.
+            pub const ONE: Self = Self::from_u64(1);
+            /// The largest value.
+            pub const MAX: Self = Self { limbs: [u64::MAX; $LIMBS] };
+
+            /// Creates a new value from little-endian limbs.
+            #[inline]
+            pub const fn from_limbs(limbs: [u64; $LIMBS]) -> Self {
+                Self { limbs }
+            }
+
+            /// Returns the little-endian limbs.
+            #[inline]
+            pub const fn limbs(&self) -> &[u64; $LIMBS] {
+                &self.limbs
+            }
+
+            /// Creates a new value from a [`u64`].
+            #[inline]
+            pub const fn from_u64(value: u64) -> Self {
+                let mut limbs = [0; $LIMBS];
+                limbs[0] = value;
+                Self { limbs }
+            }
+
+            /// Creates a new value from a [`u128`].
+            #[inline]
+            pub const fn from_u128(value: u128) -> Self {
+                let mut limbs = [0; $LIMBS];
+                limbs[0] = value as u64;
+                limbs[1] = (value >> 64) as u64;
+                Self { limbs }
+            }
+
+            /// Returns the lowest 64 bits.
+            #[inline]
+            pub const fn low_u64(&self) -> u64 {
+                self.limbs[0]
+            }
+
+            /// Returns the lowest 128 bits.
+            #[inline]
+            pub const fn low_u128(&self) -> u128 {
+                self.limbs[0] as u128 | ((self.limbs[1] as u128) << 64)
+            }
+
+            /// Returns `true` if `self` is `0`.
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                self.limbs.iter().all(|&l| l == 0)
+            }
+
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            #[inline]
+            pub fn leading_zeros(&self) -> u32 {
+                let mut zeros = 0;
+                for &l in self.limbs.iter().rev() {
+                    zeros += l.leading_zeros();
+                    if l != 0 {
+                        break;
+                    }
+                }
+                zeros
+            }
+
+            /// Returns the minimum number of bits required to represent `self`.
+            #[inline]
+            pub fn bits(&self) -> u32 {
+                Self::BITS - self.leading_zeros()
+            }
+
+            /// Returns `true` if the `index`-th bit is set.
+            #[inline]
+            pub fn bit(&self, index: u32) -> bool {
+                debug_assert!(index < Self::BITS);
+                (self.limbs[(index / 64) as usize] >> (index % 64)) & 1 == 1
+            }
+
+            /// Calculates `self + rhs`, returns the wrapped sum and whether an overflow occurred.
+            #[inline]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let mut carry = false;
+                let mut limbs = [0; $LIMBS];
+                for (r, (&a, &b)) in limbs.iter_mut().zip(self.limbs.iter().zip(&rhs.limbs)) {
+                    (*r, carry) = CarryingAdd::carrying_add(a, b, carry);
+                }
+                (Self { limbs }, carry)
+            }
+
+            /// Calculates `self - rhs`, returns the wrapped difference and whether an overflow occurred.
+            #[inline]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let mut borrow = false;
+                let mut limbs = [0; $LIMBS];
+                for (r, (&a, &b)) in limbs.iter_mut().zip(self.limbs.iter().zip(&rhs.limbs)) {
+                    (*r, borrow) = BorrowingSub::borrowing_sub(a, b, borrow);
+                }
+                (Self { limbs }, borrow)
+            }
+
+            /// Calculates `self + rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Calculates `self - rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Calculates `self * rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                self.widening_mul(rhs).0
+            }
+
+            /// Checked addition. Returns `None` if overflow occurred.
+            #[inline]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (r, false) => Some(r),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked subtraction. Returns `None` if overflow occurred.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (r, false) => Some(r),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked multiplication. Returns `None` if overflow occurred.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.widening_mul(rhs) {
+                    (lo, hi) if hi.is_zero() => Some(lo),
+                    _ => None,
+                }
+            }
+
+            /// Calculates the quotient and the remainder of `self / rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is `0`.
+            pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+                assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+                if self < rhs {
+                    return (Self::ZERO, self);
+                }
+
+                let mut quotient = Self::ZERO;
+                let mut remainder = Self::ZERO;
+                for i in (0..self.bits()).rev() {
+                    let (shifted, carry) = remainder.overflowing_shl1(self.bit(i));
+                    remainder = shifted;
+                    if carry || remainder >= rhs {
+                        remainder = remainder.wrapping_sub(rhs);
+                        quotient.limbs[(i / 64) as usize] |= 1 << (i % 64);
+                    }
+                }
+                (quotient, remainder)
+            }
+
+            /// Calculates `(self << 1) | bit`, returns the result and the shifted out bit.
+            #[inline]
+            fn overflowing_shl1(self, bit: bool) -> (Self, bool) {
+                let mut carry = bit;
+                let mut limbs = [0; $LIMBS];
+                for (r, &l) in limbs.iter_mut().zip(self.limbs.iter()) {
+                    *r = (l << 1) | carry as u64;
+                    carry = l >> 63 == 1;
+                }
+                (Self { limbs }, carry)
+            }
+
+            /// Calculates `self mod modulus`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is `0`.
+            #[inline]
+            pub fn reduce(self, modulus: Self) -> Self {
+                self.div_rem(modulus).1
+            }
+
+            /// Calculates `(lo + hi * 2^BITS) mod modulus`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is `0`.
+            pub fn reduce_wide(lo: Self, hi: Self, modulus: Self) -> Self {
+                assert!(!modulus.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+
+                let mut remainder = hi.reduce(modulus);
+                for i in (0..Self::BITS).rev() {
+                    let (shifted, carry) = remainder.overflowing_shl1(lo.bit(i));
+                    remainder = shifted;
+                    if carry || remainder >= modulus {
+                        remainder = remainder.wrapping_sub(modulus);
+                    }
+                }
+                remainder
+            }
+
+            /// Calculates `(self + rhs) mod modulus`, where `self` and `rhs` are less than `modulus`.
+            #[inline]
+            pub fn add_mod(self, rhs: Self, modulus: Self) -> Self {
+                debug_assert!(self < modulus && rhs < modulus);
+                let (sum, carry) = self.overflowing_add(rhs);
+                if carry || sum >= modulus {
+                    sum.wrapping_sub(modulus)
+                } else {
+                    sum
+                }
+            }
+
+            /// Calculates `(self - rhs) mod modulus`, where `self` and `rhs` are less than `modulus`.
+            #[inline]
+            pub fn sub_mod(self, rhs: Self, modulus: Self) -> Self {
+                debug_assert!(self < modulus && rhs < modulus);
+                let (difference, borrow) = self.overflowing_sub(rhs);
+                if borrow {
+                    difference.wrapping_add(modulus)
+                } else {
+                    difference
+                }
+            }
+
+            /// Calculates `(self * rhs) mod modulus`.
+            #[inline]
+            pub fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                let (lo, hi) = self.widening_mul(rhs);
+                Self::reduce_wide(lo, hi, modulus)
+            }
+
+            /// Calculates `self^exp mod modulus`.
+            pub fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+                let mut base = self.reduce(modulus);
+                let mut result = Self::ONE.reduce(modulus);
+                for i in 0..exp.bits() {
+                    if exp.bit(i) {
+                        result = result.mul_mod(base, modulus);
+                    }
+                    base = base.mul_mod(base, modulus);
+                }
+                result
+            }
+
+            /// Calculates the greatest common divisor of `self` and `other`.
+            pub fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b) = (self, other);
+                while !b.is_zero() {
+                    (a, b) = (b, a.reduce(b));
+                }
+                a
+            }
+
+            /// Returns the greatest common divisor `g` of `self` and `modulus`,
+            /// and `a` such that `0 ≤ a < modulus` and `a·self = g (mod modulus)`.
+            ///
+            /// This is the extended Euclidean algorithm, computing only the cofactor of `self`.
+            /// When `modulus = 1`, `g` is set to `1` and `a` is set to `0`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is `0`.
+            pub fn gcdinv(self, modulus: Self) -> (Self, Self) {
+                let (mut r0, mut r1) = (modulus, self.reduce(modulus));
+                let (mut t0, mut t1) = (Self::ZERO, Self::ONE.reduce(modulus));
+                while !r1.is_zero() {
+                    let (q, r) = r0.div_rem(r1);
+                    (r0, r1) = (r1, r);
+                    (t0, t1) = (t1, t0.sub_mod(q.mul_mod(t1, modulus), modulus));
+                }
+                (r0, t0)
+            }
+
+            /// Calculates `self⁻¹ mod modulus`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is `0`, or `self` has no inverse modulo `modulus`.
+            #[inline]
+            pub fn inv_mod(self, modulus: Self) -> Self {
+                let (gcd, inv) = self.gcdinv(modulus);
+                assert!(gcd == Self::ONE, "No {self}^(-1) mod {modulus}");
+                inv
+            }
+
+            /// Tries to calculate `self⁻¹ mod modulus`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`AlgebraError::NoInverse`] if `self` has no inverse modulo `modulus`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is `0`.
+            #[inline]
+            pub fn try_inv_mod(self, modulus: Self) -> Result<Self, AlgebraError> {
+                let (gcd, inv) = self.gcdinv(modulus);
+                if gcd == Self::ONE {
+                    Ok(inv)
+                } else {
+                    Err(AlgebraError::NoInverse {
+                        value: Box::new(self),
+                        modulus: Box::new(modulus),
+                    })
+                }
+            }
+        }
+
+        impl ReduceInv<Self> for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn reduce_inv(self, value: Self) -> Self::Output {
+                value.inv_mod(self)
+            }
+        }
+
+        impl ReduceInvAssign<Self> for $Name {
+            #[inline]
+            fn reduce_inv_assign(self, value: &mut Self) {
+                *value = value.inv_mod(self);
+            }
+        }
+
+        impl TryReduceInv<Self> for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn try_reduce_inv(self, value: Self) -> Result<Self::Output, AlgebraError> {
+                value.try_inv_mod(self)
+            }
+        }
+
+        impl CarryingAdd for $Name {
+            type CarryT = bool;
+
+            #[inline]
+            fn carrying_add(self, rhs: Self, carry: Self::CarryT) -> (Self, Self::CarryT) {
+                let (a, b) = self.overflowing_add(rhs);
+                let (c, d) = a.overflowing_add(Self::from_u64(carry as u64));
+                (c, b || d)
+            }
+        }
+
+        impl BorrowingSub for $Name {
+            type BorrowT = bool;
+
+            #[inline]
+            fn borrowing_sub(self, rhs: Self, borrow: Self::BorrowT) -> (Self, Self::BorrowT) {
+                let (a, b) = self.overflowing_sub(rhs);
+                let (c, d) = a.overflowing_sub(Self::from_u64(borrow as u64));
+                (c, b || d)
+            }
+        }
+
+        impl WideningMul for $Name {
+            fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let mut product = [0u64; 2 * $LIMBS];
+                for (i, &a) in self.limbs.iter().enumerate() {
+                    let mut carry = 0u64;
+                    for (j, &b) in rhs.limbs.iter().enumerate() {
+                        let (lo, hi) = CarryingMul::carrying_mul(a, b, carry);
+                        let (sum, c) = CarryingAdd::carrying_add(product[i + j], lo, false);
+                        product[i + j] = sum;
+                        // `hi <= 2^64 - 2`, so this never overflows
+                        carry = hi + c as u64;
+                    }
+                    product[i + $LIMBS] = carry;
+                }
+
+                let mut lo = Self::ZERO;
+                let mut hi = Self::ZERO;
+                lo.limbs.copy_from_slice(&product[..$LIMBS]);
+                hi.limbs.copy_from_slice(&product[$LIMBS..]);
+                (lo, hi)
+            }
+
+            #[inline]
+            fn widening_mul_hw(self, rhs: Self) -> Self {
+                self.widening_mul(rhs).1
+            }
+        }
+
+        impl Ord for $Name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+            }
+        }
+
+        impl PartialOrd for $Name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl From<u64> for $Name {
+            #[inline]
+            fn from(value: u64) -> Self {
+                Self::from_u64(value)
+            }
+        }
+
+        impl From<u128> for $Name {
+            #[inline]
+            fn from(value: u128) -> Self {
+                Self::from_u128(value)
+            }
+        }
+
+        impl Add for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self.checked_add(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl Sub for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self.checked_sub(rhs).expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl Mul for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.checked_mul(rhs).expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl Div for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                self.div_rem(rhs).0
+            }
+        }
+
+        impl Rem for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                self.div_rem(rhs).1
+            }
+        }
+
+        impl Shl<u32> for $Name {
+            type Output = Self;
+
+            /// Bits shifted out are discarded, shifting by `BITS` or more gives `0`.
+            fn shl(self, rhs: u32) -> Self {
+                let limb_shift = (rhs / 64) as usize;
+                let bit_shift = rhs % 64;
+                let mut limbs = [0; $LIMBS];
+                for i in limb_shift..$LIMBS {
+                    let j = i - limb_shift;
+                    limbs[i] = self.limbs[j] << bit_shift;
+                    if bit_shift > 0 && j > 0 {
+                        limbs[i] |= self.limbs[j - 1] >> (64 - bit_shift);
+                    }
+                }
+                Self { limbs }
+            }
+        }
+
+        impl Shr<u32> for $Name {
+            type Output = Self;
+
+            /// Shifting by `BITS` or more gives `0`.
+            fn shr(self, rhs: u32) -> Self {
+                let limb_shift = (rhs / 64) as usize;
+                let bit_shift = rhs % 64;
+                let mut limbs = [0; $LIMBS];
+                for i in 0..($LIMBS as usize).saturating_sub(limb_shift) {
+                    let j = i + limb_shift;
+                    limbs[i] = self.limbs[j] >> bit_shift;
+                    if bit_shift > 0 && j + 1 < $LIMBS {
+                        limbs[i] |= self.limbs[j + 1] << (64 - bit_shift);
+                    }
+                }
+                Self { limbs }
+            }
+        }
+
+        impl BitAnd for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(mut self, rhs: Self) -> Self {
+                self.limbs.iter_mut().zip(rhs.limbs).for_each(|(a, b)| *a &= b);
+                self
+            }
+        }
+
+        impl BitOr for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(mut self, rhs: Self) -> Self {
+                self.limbs.iter_mut().zip(rhs.limbs).for_each(|(a, b)| *a |= b);
+                self
+            }
+        }
+
+        impl BitXor for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(mut self, rhs: Self) -> Self {
+                self.limbs.iter_mut().zip(rhs.limbs).for_each(|(a, b)| *a ^= b);
+                self
+            }
+        }
+
+        impl Not for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn not(mut self) -> Self {
+                self.limbs.iter_mut().for_each(|a| *a = !*a);
+                self
+            }
+        }
+
+        impl fmt::LowerHex for $Name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut iter = self.limbs.iter().rev().skip_while(|&&l| l == 0);
+                match iter.next() {
+                    Some(first) => {
+                        let mut s = format!("{first:x}");
+                        iter.for_each(|l| s.push_str(&format!("{l:016x}")));
+                        f.pad_integral(true, "0x", &s)
+                    }
+                    None => f.pad_integral(true, "0x", "0"),
+                }
+            }
+        }
+
+        impl fmt::Display for $Name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+                let mut chunks = Vec::new();
+                let mut rest = *self;
+                loop {
+                    let (q, r) = rest.div_rem(Self::from_u64(CHUNK));
+                    chunks.push(r.low_u64());
+                    if q.is_zero() {
+                        break;
+                    }
+                    rest = q;
+                }
+
+                let mut iter = chunks.iter().rev();
+                let mut s = iter.next().unwrap().to_string();
+                iter.for_each(|c| s.push_str(&format!("{c:019}")));
+                f.pad_integral(true, "", &s)
+            }
+        }
+
+        impl fmt::Debug for $Name {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    };
+}
+
+impl_big_uint!(
+    /// A 256-bit unsigned integer composed of four 64-bit limbs.
+    U256,
+    4
+);
+
+impl_big_uint!(
+    /// A 512-bit unsigned integer composed of eight 64-bit limbs.
+    U512,
+    8
+);
+
+impl U256 {
+    /// Calculates the complete product `self * rhs` as a [`U512`].
+    #[inline]
+    pub fn widening_mul_full(self, rhs: Self) -> U512 {
+        let (lo, hi) = self.widening_mul(rhs);
+        let mut limbs = [0; 8];
+        limbs[..4].copy_from_slice(&lo.limbs);
+        limbs[4..].copy_from_slice(&hi.limbs);
+        U512::from_limbs(limbs)
+    }
+}
+
+impl From<U256> for U512 {
+    #[inline]
+    fn from(value: U256) -> Self {
+        let mut limbs = [0; 8];
+        limbs[..4].copy_from_slice(&value.limbs);
+        U512::from_limbs(limbs)
+    }
+}
+
+impl TryFrom<U512> for U256 {
+    type Error = U512;
+
+    /// Fails and returns the value back if it doesn't fit in 256 bits.
+    #[inline]
+    fn try_from(value: U512) -> Result<Self, Self::Error> {
+        if value.limbs[4..].iter().all(|&l| l == 0) {
+            let mut limbs = [0; 4];
+            limbs.copy_from_slice(&value.limbs[..4]);
+            Ok(U256::from_limbs(limbs))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    fn random_u256(rng: &mut impl Rng) -> U256 {
+        U256::from_limbs(rng.gen())
+    }
+
+    #[test]
+    fn test_u256_matches_u128() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen::<u64>() as u128;
+            let b: u128 = rng.gen::<u64>() as u128 | 1;
+            let (x, y) = (U256::from(a), U256::from(b));
+
+            assert_eq!((x + y).low_u128(), a + b);
+            assert_eq!((x * y).low_u128(), a * b);
+            assert_eq!((x / y).low_u128(), a / b);
+            assert_eq!((x % y).low_u128(), a % b);
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!((x << 37).low_u128(), a << 37);
+            assert_eq!((x >> 5).low_u128(), a >> 5);
+            assert_eq!(x.mul_mod(x, y).low_u128(), a * a % b);
+        }
+    }
+
+    #[test]
+    fn test_u256_div_rem() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let n = random_u256(&mut rng);
+            let d = random_u256(&mut rng) >> rng.gen_range(0..256);
+            if d.is_zero() {
+                continue;
+            }
+
+            let (q, r) = n.div_rem(d);
+            assert!(r < d);
+            assert_eq!(q.checked_mul(d).and_then(|p| p.checked_add(r)), Some(n));
+        }
+    }
+
+    #[test]
+    fn test_u256_wide_mul_and_reduce() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = random_u256(&mut rng);
+            let b = random_u256(&mut rng);
+            let m = random_u256(&mut rng) | U256::ONE;
+
+            let full = a.widening_mul_full(b);
+            let expected = U256::try_from(full % U512::from(m)).unwrap();
+            assert_eq!(a.mul_mod(b, m), expected);
+
+            let (a, b) = (a.reduce(m), b.reduce(m));
+            assert_eq!(a.add_mod(b, m).sub_mod(b, m), a);
+        }
+    }
+
+    #[test]
+    fn test_u256_pow_mod_and_format() {
+        // Fermat's little theorem with the prime 2^255 - 19.
+        let p = (U256::ONE << 255) - U256::from_u64(19);
+        let a = U256::from_u64(123456789);
+        assert_eq!(a.pow_mod(p - U256::ONE, p), U256::ONE);
+
+        assert_eq!(
+            p.to_string(),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949"
+        );
+        assert_eq!(
+            format!("{p:x}"),
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+        );
+        assert_eq!(U512::ZERO.to_string(), "0");
+        assert_eq!(U512::MAX.bits(), 512);
+    }
+
+    #[test]
+    fn test_u256_inv_mod() {
+        let mut rng = thread_rng();
+        let p = (U256::ONE << 255) - U256::from_u64(19);
+
+        for _ in 0..20 {
+            let a = random_u256(&mut rng).reduce(p);
+            if a.is_zero() {
+                continue;
+            }
+            assert_eq!(a.inv_mod(p).mul_mod(a, p), U256::ONE);
+            assert_eq!(p.reduce_inv(a), a.inv_mod(p));
+
+            let b = random_u256(&mut rng) >> rng.gen_range(0..256);
+            let g = a.gcd(b);
+            let (gcd, inv) = a.gcdinv(b | U256::ONE);
+            assert_eq!(gcd, a.gcd(b | U256::ONE));
+            assert_eq!(inv.mul_mod(a, b | U256::ONE), gcd.reduce(b | U256::ONE));
+            if !g.is_zero() {
+                assert!(a.reduce(g).is_zero() && b.reduce(g).is_zero());
+            }
+        }
+
+        let m = U256::from_u64(6) << 100;
+        assert!(U256::from_u64(3).try_inv_mod(m).is_err());
+        assert!(m.try_reduce_inv(U256::from_u64(5)).is_ok());
+        assert_eq!(U256::ZERO.gcdinv(U256::ONE), (U256::ONE, U256::ZERO));
+    }
+}
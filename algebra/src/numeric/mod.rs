@@ -2,8 +2,10 @@
 
 use crate::integer::{AsFrom, UnsignedInteger};
 
+mod big;
 mod widening;
 
+pub use big::{U256, U512};
 pub use widening::*;
 
 /// [UnsignedInteger] extended trait, insure some arithmetic operation.
@@ -0,0 +1,241 @@
+use crate::{
+    ntt::{NttTable, NumberTheoryTransform},
+    reduce::{ReduceAddAssign, ReduceNeg, ReduceSubAssign},
+    Field, NttField,
+};
+
+use super::{FieldNttPolynomial, FieldPolynomial};
+
+/// The automorphism `σ: X -> X^degree` of `F[X]/(X^N + 1)` for an odd `degree`,
+/// with precomputed tables for both the coefficient and the NTT domain.
+///
+/// In coefficient domain, `σ` maps the coefficient `i` to the index `i * degree mod N`,
+/// negated when `i * degree mod 2N >= N`. In NTT domain, it's a plain permutation of
+/// the evaluation points, since `σ(p)(ζ) = p(ζ^degree)`.
+#[derive(Debug, Clone)]
+pub struct Automorphism<F: NttField> {
+    degree: usize,
+    /// The target index of every coefficient.
+    indices: Vec<usize>,
+    /// Whether every coefficient is negated.
+    signs: Vec<bool>,
+    /// `π` with `NTT(σ(p))[j] = NTT(p)[π[j]]`.
+    ntt_permutation: Vec<usize>,
+    _marker: std::marker::PhantomData<F>,
+}
+
+impl<F: NttField> Automorphism<F> {
+    /// Creates a new [`Automorphism<F>`] of `degree` for the ring dimension of `ntt_table`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is even or not less than `2N`.
+    pub fn new(degree: usize, ntt_table: &<F as NttField>::Table) -> Self {
+        let dimension = ntt_table.dimension();
+        let twice_dimension = dimension << 1;
+        assert!(degree % 2 == 1 && degree < twice_dimension);
+
+        let (indices, signs) = (0..dimension)
+            .map(|i| {
+                let j = i * degree % twice_dimension;
+                if j < dimension {
+                    (j, false)
+                } else {
+                    (j - dimension, true)
+                }
+            })
+            .unzip();
+
+        Self {
+            degree,
+            indices,
+            signs,
+            ntt_permutation: ntt_permutation::<F>(degree, ntt_table),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the degree of this [`Automorphism<F>`].
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the ring dimension of this [`Automorphism<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the target index of every coefficient.
+    #[inline]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns whether every coefficient is negated.
+    #[inline]
+    pub fn signs(&self) -> &[bool] {
+        &self.signs
+    }
+
+    /// Returns the permutation `π` with `NTT(σ(p))[j] = NTT(p)[π[j]]`.
+    #[inline]
+    pub fn ntt_permutation(&self) -> &[usize] {
+        &self.ntt_permutation
+    }
+
+    /// Computes `destination = σ(poly)` in coefficient domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly` or `destination` has a different dimension from `self`.
+    pub fn apply(&self, poly: &FieldPolynomial<F>, destination: &mut FieldPolynomial<F>) {
+        assert_eq!(poly.coeff_count(), self.dimension());
+        assert_eq!(destination.coeff_count(), self.dimension());
+        for ((&c, &j), &negate) in poly.iter().zip(&self.indices).zip(&self.signs) {
+            destination[j] = if negate { F::MODULUS.reduce_neg(c) } else { c };
+        }
+    }
+
+    /// Computes `destination += σ(poly)` in coefficient domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly` or `destination` has a different dimension from `self`.
+    pub fn apply_add_assign(
+        &self,
+        poly: &FieldPolynomial<F>,
+        destination: &mut FieldPolynomial<F>,
+    ) {
+        assert_eq!(poly.coeff_count(), self.dimension());
+        assert_eq!(destination.coeff_count(), self.dimension());
+        for ((&c, &j), &negate) in poly.iter().zip(&self.indices).zip(&self.signs) {
+            if negate {
+                F::MODULUS.reduce_sub_assign(&mut destination[j], c);
+            } else {
+                F::MODULUS.reduce_add_assign(&mut destination[j], c);
+            }
+        }
+    }
+
+    /// Computes `destination = σ(poly)` in NTT domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly` or `destination` has a different dimension from `self`.
+    pub fn apply_ntt(&self, poly: &FieldNttPolynomial<F>, destination: &mut FieldNttPolynomial<F>) {
+        assert_eq!(poly.coeff_count(), self.dimension());
+        assert_eq!(destination.coeff_count(), self.dimension());
+        destination
+            .iter_mut()
+            .zip(&self.ntt_permutation)
+            .for_each(|(d, &j)| *d = poly[j]);
+    }
+}
+
+impl<F: NttField> FieldPolynomial<F> {
+    /// Returns `σ(self)` for the automorphism `σ: X -> X^degree`.
+    #[inline]
+    pub fn apply_automorphism(&self, automorphism: &Automorphism<F>) -> Self {
+        let mut result = Self::zero(self.coeff_count());
+        automorphism.apply(self, &mut result);
+        result
+    }
+}
+
+impl<F: NttField> FieldNttPolynomial<F> {
+    /// Returns `σ(self)` for the automorphism `σ: X -> X^degree`.
+    #[inline]
+    pub fn apply_automorphism(&self, automorphism: &Automorphism<F>) -> Self {
+        let mut result = Self::zero(self.coeff_count());
+        automorphism.apply_ntt(self, &mut result);
+        result
+    }
+}
+
+/// Computes the permutation `π` with `NTT(σ(p))[j] = NTT(p)[π[j]]`.
+///
+/// `NTT(X)` lists the evaluation points `ζ_j` in the table's order,
+/// and `σ(p)(ζ_j) = p(ζ_j^degree)`, so `π[j]` is the index of `ζ_j^degree`.
+fn ntt_permutation<F: NttField>(degree: usize, ntt_table: &<F as NttField>::Table) -> Vec<usize> {
+    let dimension = ntt_table.dimension();
+    let mut x = FieldPolynomial::<F>::zero(dimension);
+    x[1] = F::ONE;
+    let points = ntt_table.transform_inplace(x);
+
+    let mut sorted: Vec<(<F as Field>::ValueT, usize)> =
+        points.iter().copied().zip(0..dimension).collect();
+    sorted.sort_unstable();
+
+    points
+        .iter()
+        .map(|&point| {
+            let target = F::exp(point, degree);
+            let k = sorted
+                .binary_search_by(|&(v, _)| v.cmp(&target))
+                .expect("an odd power of a primitive 2N-th root is a primitive 2N-th root");
+            sorted[k].1
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::U32FieldEval;
+
+    use super::*;
+
+    type Fp = U32FieldEval<132120577>;
+
+    const LOG_N: u32 = 8;
+    const N: usize = 1 << LOG_N;
+
+    #[test]
+    fn test_automorphism_signs() {
+        let mut rng = rand::thread_rng();
+        let table = Fp::generate_ntt_table(LOG_N).unwrap();
+        let automorphism = Automorphism::<Fp>::new(N + 1, &table);
+
+        let poly = FieldPolynomial::<Fp>::random(N, &mut rng);
+        let result = poly.apply_automorphism(&automorphism);
+
+        for (i, (&r, &p)) in result.iter().zip(poly.iter()).enumerate() {
+            if i % 2 == 1 {
+                assert_eq!(r, Fp::MODULUS.reduce_neg(p));
+            } else {
+                assert_eq!(r, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_automorphism_ntt() {
+        let mut rng = rand::thread_rng();
+        let table = Fp::generate_ntt_table(LOG_N).unwrap();
+        let poly = FieldPolynomial::<Fp>::random(N, &mut rng);
+        let ntt_poly = table.transform(&poly);
+
+        for degree in [1, 3, 5, N - 1, N + 1, 2 * N - 1] {
+            let automorphism = Automorphism::<Fp>::new(degree, &table);
+            assert_eq!(automorphism.degree(), degree);
+
+            let coeff = poly.apply_automorphism(&automorphism);
+            assert_eq!(
+                table.transform(&coeff),
+                ntt_poly.apply_automorphism(&automorphism)
+            );
+
+            let mut sum = poly.clone();
+            automorphism.apply_add_assign(&poly, &mut sum);
+            assert_eq!(sum, &poly + coeff);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_automorphism_even_degree() {
+        let table = Fp::generate_ntt_table(LOG_N).unwrap();
+        Automorphism::<Fp>::new(2, &table);
+    }
+}
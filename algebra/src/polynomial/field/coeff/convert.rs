@@ -9,4 +9,19 @@ impl<F: NttField> FieldPolynomial<F> {
         ntt_table.transform_slice(self.as_mut_slice());
         FieldNttPolynomial::new(self.data)
     }
+
+    /// Transforms `self` into `destination`, without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `destination` have different lengths.
+    #[inline]
+    pub fn transform_inplace(
+        &self,
+        ntt_table: &<F as NttField>::Table,
+        destination: &mut FieldNttPolynomial<F>,
+    ) {
+        destination.copy_from(self);
+        ntt_table.transform_slice(destination.as_mut_slice());
+    }
 }
@@ -0,0 +1,112 @@
+use num_traits::ConstZero;
+
+use crate::Field;
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Evaluates `p(x)` at every point of `points`.
+    ///
+    /// The points share one pass of Horner's rule over the coefficients.
+    pub fn evaluate_many(&self, points: &[<F as Field>::ValueT]) -> Vec<<F as Field>::ValueT> {
+        let mut values = vec![<F as Field>::ValueT::ZERO; points.len()];
+        for &a in self.data.iter().rev() {
+            values
+                .iter_mut()
+                .zip(points)
+                .for_each(|(v, &x)| *v = F::mul_add(*v, x, a));
+        }
+        values
+    }
+
+    /// Computes the unique polynomial of degree less than `points.len()`
+    /// with `p(points[i]) = values[i]`, by Lagrange interpolation.
+    ///
+    /// The result has `points.len()` coefficients. It costs `O(n^2)` field
+    /// operations and `n` inversions for `n` points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `values` have different lengths, or two points are equal.
+    pub fn interpolate(points: &[<F as Field>::ValueT], values: &[<F as Field>::ValueT]) -> Self {
+        assert_eq!(points.len(), values.len());
+        let n = points.len();
+
+        // `m(X) = ∏ (X - x_i)`, it has `n + 1` coefficients.
+        let mut m = vec![<F as Field>::ValueT::ZERO; n + 1];
+        m[0] = F::ONE;
+        for (len, &x) in (1..=n).zip(points) {
+            for k in (1..=len).rev() {
+                m[k] = F::sub(m[k - 1], F::mul(m[k], x));
+            }
+            m[0] = F::neg(F::mul(m[0], x));
+        }
+
+        let mut result = vec![<F as Field>::ValueT::ZERO; n];
+        let mut quotient = vec![<F as Field>::ValueT::ZERO; n];
+        for (i, (&x, &y)) in points.iter().zip(values).enumerate() {
+            // `m(X) / (X - x_i)` by synthetic division, the remainder is zero.
+            let mut carry = <F as Field>::ValueT::ZERO;
+            for k in (0..n).rev() {
+                carry = F::mul_add(carry, x, m[k + 1]);
+                quotient[k] = carry;
+            }
+
+            // the quotient at `x_i` is `∏_{j != i} (x_i - x_j)`
+            let denominator = quotient
+                .iter()
+                .rev()
+                .fold(<F as Field>::ValueT::ZERO, |acc, &c| F::mul_add(acc, x, c));
+            assert!(
+                denominator != <F as Field>::ValueT::ZERO,
+                "duplicate interpolation point at {i}"
+            );
+            let weight = F::div(y, denominator);
+
+            result
+                .iter_mut()
+                .zip(&quotient)
+                .for_each(|(r, &q)| *r = F::mul_add(q, weight, *r));
+        }
+
+        Self::new(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, prelude::Distribution};
+
+    use crate::U64FieldEval;
+
+    use super::*;
+
+    type Fp = U64FieldEval<0x1fffffffffe00001>;
+
+    #[test]
+    fn test_evaluate_interpolate() {
+        let mut rng = rand::thread_rng();
+        let distr = Uniform::new(0, <Fp as Field>::MODULUS_VALUE);
+
+        for n in [1, 2, 17, 64] {
+            let poly = FieldPolynomial::<Fp>::random(n, &mut rng);
+            let points: Vec<u64> = distr.sample_iter(&mut rng).take(n).collect();
+
+            let values = poly.evaluate_many(&points);
+            for (&x, &y) in points.iter().zip(&values) {
+                assert_eq!(poly.evaluate(x), y);
+            }
+
+            assert_eq!(FieldPolynomial::interpolate(&points, &values), poly);
+        }
+
+        let empty = FieldPolynomial::<Fp>::interpolate(&[], &[]);
+        assert_eq!(empty.coeff_count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interpolate_duplicate_points() {
+        FieldPolynomial::<Fp>::interpolate(&[1, 2, 1], &[3, 4, 5]);
+    }
+}
@@ -5,6 +5,7 @@ use crate::{reduce::ReduceMulAdd, Field};
 mod basic;
 mod convert;
 mod decompose;
+mod interpolate;
 mod random;
 
 mod add;
@@ -1,5 +1,9 @@
+mod automorphism;
 mod coeff;
 mod ntt;
+mod sparse;
 
+pub use automorphism::Automorphism;
 pub use coeff::FieldPolynomial;
 pub use ntt::FieldNttPolynomial;
+pub use sparse::SparsePolynomial;
@@ -9,4 +9,19 @@ impl<F: NttField> FieldNttPolynomial<F> {
         ntt_table.inverse_transform_slice(self.as_mut_slice());
         FieldPolynomial::new(self.data)
     }
+
+    /// Inverse transforms `self` into `destination`, without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `destination` have different lengths.
+    #[inline]
+    pub fn inverse_transform_inplace(
+        &self,
+        ntt_table: &<F as NttField>::Table,
+        destination: &mut FieldPolynomial<F>,
+    ) {
+        destination.copy_from(self);
+        ntt_table.inverse_transform_slice(destination.as_mut_slice());
+    }
 }
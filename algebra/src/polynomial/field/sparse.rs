@@ -0,0 +1,233 @@
+use num_traits::{ConstOne, Zero};
+use rand::{CryptoRng, Rng};
+
+use crate::Field;
+
+use super::FieldPolynomial;
+
+/// A polynomial of `F[X]/(X^N + 1)` stored by its nonzero coefficients.
+///
+/// Secret keys and test vectors often have only a few nonzero coefficients.
+/// Multiplying such a polynomial by a dense one costs `O(h * N)` for the
+/// Hamming weight `h`, without any NTT, and is exact for every modulus.
+pub struct SparsePolynomial<F: Field> {
+    dimension: usize,
+    /// Strictly increasing indices of the nonzero coefficients.
+    indices: Vec<usize>,
+    values: Vec<<F as Field>::ValueT>,
+}
+
+impl<F: Field> Clone for SparsePolynomial<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            dimension: self.dimension,
+            indices: self.indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<F: Field> core::fmt::Debug for SparsePolynomial<F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SparsePolynomial")
+            .field("dimension", &self.dimension)
+            .field("indices", &self.indices)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<F: Field> PartialEq for SparsePolynomial<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+            && self.indices == other.indices
+            && self.values == other.values
+    }
+}
+
+impl<F: Field> Eq for SparsePolynomial<F> {}
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Creates a new [`SparsePolynomial<F>`] with the coefficients
+    /// `values[k]` at `indices[k]`, all other coefficients are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` and `values` have different lengths,
+    /// or `indices` is not strictly increasing and less than `dimension`.
+    pub fn new(dimension: usize, indices: Vec<usize>, values: Vec<<F as Field>::ValueT>) -> Self {
+        assert_eq!(indices.len(), values.len());
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert!(indices.last().is_none_or(|&i| i < dimension));
+        Self {
+            dimension,
+            indices,
+            values,
+        }
+    }
+
+    /// Creates a [`SparsePolynomial<F>`] from the nonzero coefficients of `poly`.
+    pub fn from_dense(poly: &FieldPolynomial<F>) -> Self {
+        let (indices, values) = poly
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_zero())
+            .map(|(i, &v)| (i, v))
+            .unzip();
+        Self {
+            dimension: poly.coeff_count(),
+            indices,
+            values,
+        }
+    }
+
+    /// Converts `self` into a [`FieldPolynomial<F>`].
+    pub fn to_dense(&self) -> FieldPolynomial<F> {
+        let mut poly = FieldPolynomial::zero(self.dimension);
+        for (i, v) in self.iter() {
+            poly[i] = v;
+        }
+        poly
+    }
+
+    /// Generates a random ternary [`SparsePolynomial<F>`]
+    /// with exactly `hamming_weight` nonzero coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hamming_weight` is larger than `dimension`.
+    pub fn random_ternary<R>(dimension: usize, hamming_weight: usize, rng: &mut R) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        let mut indices = rand::seq::index::sample(rng, dimension, hamming_weight).into_vec();
+        indices.sort_unstable();
+        let values = (0..hamming_weight)
+            .map(|_| {
+                if rng.gen() {
+                    <F as Field>::ValueT::ONE
+                } else {
+                    F::MINUS_ONE
+                }
+            })
+            .collect();
+        Self {
+            dimension,
+            indices,
+            values,
+        }
+    }
+
+    /// Returns the dimension of this [`SparsePolynomial<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the number of nonzero coefficients of this [`SparsePolynomial<F>`].
+    #[inline]
+    pub fn hamming_weight(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns an iterator over the indices and values of the stored coefficients.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, <F as Field>::ValueT)> + '_ {
+        self.indices
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+    }
+
+    /// Computes `self * dense` in `F[X]/(X^N + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dense` has a different dimension from `self`.
+    pub fn mul_dense(&self, dense: &FieldPolynomial<F>) -> FieldPolynomial<F> {
+        let mut result = FieldPolynomial::zero(self.dimension);
+        self.mul_dense_add_assign(dense, &mut result);
+        result
+    }
+
+    /// Computes `destination += self * dense` in `F[X]/(X^N + 1)`.
+    ///
+    /// Coefficients `±1` are added or subtracted without multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dense` or `destination` has a different dimension from `self`.
+    pub fn mul_dense_add_assign(
+        &self,
+        dense: &FieldPolynomial<F>,
+        destination: &mut FieldPolynomial<F>,
+    ) {
+        let n = self.dimension;
+        assert_eq!(dense.coeff_count(), n);
+        assert_eq!(destination.coeff_count(), n);
+
+        let dense = dense.as_slice();
+        let destination = destination.as_mut_slice();
+        for (k, v) in self.iter() {
+            // `v * X^k * dense`, the coefficients wrapping around `X^N` are negated
+            let (low, high) = destination.split_at_mut(k);
+            let (head, tail) = dense.split_at(n - k);
+            if v == <F as Field>::ValueT::ONE {
+                F::add_assign_slice(high, head);
+                F::sub_assign_slice(low, tail);
+            } else if v == F::MINUS_ONE {
+                F::sub_assign_slice(high, head);
+                F::add_assign_slice(low, tail);
+            } else {
+                let minus_v = F::neg(v);
+                high.iter_mut()
+                    .zip(head)
+                    .for_each(|(d, &a)| *d = F::mul_add(a, v, *d));
+                low.iter_mut()
+                    .zip(tail)
+                    .for_each(|(d, &a)| *d = F::mul_add(a, minus_v, *d));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ntt::NumberTheoryTransform, NttField, U32FieldEval};
+
+    use super::*;
+
+    type Fp = U32FieldEval<132120577>;
+
+    const LOG_N: u32 = 8;
+    const N: usize = 1 << LOG_N;
+
+    #[test]
+    fn test_sparse_mul_dense() {
+        let mut rng = rand::thread_rng();
+        let table = Fp::generate_ntt_table(LOG_N).unwrap();
+
+        let dense = FieldPolynomial::<Fp>::random(N, &mut rng);
+        let ternary = SparsePolynomial::<Fp>::random_ternary(N, 64, &mut rng);
+        assert_eq!(ternary.hamming_weight(), 64);
+        assert_eq!(SparsePolynomial::from_dense(&ternary.to_dense()), ternary);
+
+        let mut general = FieldPolynomial::<Fp>::random(N, &mut rng);
+        general.iter_mut().step_by(3).for_each(|v| *v = 0);
+        let general = SparsePolynomial::from_dense(&general);
+
+        for sparse in [ternary, general] {
+            let expected = table.inverse_transform_inplace(
+                table.transform(&sparse.to_dense()) * table.transform(&dense),
+            );
+            assert_eq!(sparse.mul_dense(&dense), expected);
+
+            let mut sum = dense.clone();
+            sparse.mul_dense_add_assign(&dense, &mut sum);
+            assert_eq!(sum, expected + &dense);
+        }
+    }
+}
@@ -1,7 +1,11 @@
 //! Defines polynomial.
 
 mod field;
+mod negacyclic;
 mod numeric;
 
-pub use field::{FieldNttPolynomial, FieldPolynomial};
-pub use numeric::{NttPolynomial, Polynomial};
+pub use field::{Automorphism, FieldNttPolynomial, FieldPolynomial, SparsePolynomial};
+pub use negacyclic::{
+    karatsuba_mul, negacyclic_mul, KARATSUBA_THRESHOLD, NEGACYCLIC_NTT_THRESHOLD,
+};
+pub use numeric::{ArrayPolynomial, NttPolynomial, Polynomial};
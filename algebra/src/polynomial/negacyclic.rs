@@ -0,0 +1,188 @@
+use crate::{
+    arith::is_prime,
+    modulus::BarrettModulus,
+    ntt::{NttTable, NumberTheoryTransform, TableWithShoupRoot},
+    numeric::Numeric,
+    reduce::{ReduceAdd, ReduceMulAdd, ReduceMulAssign, ReduceSub},
+};
+
+/// The smallest degree for which [`negacyclic_mul`] tries the number theory transform.
+pub const NEGACYCLIC_NTT_THRESHOLD: usize = 64;
+
+/// The smallest length for which [`karatsuba_mul`] splits the operands,
+/// shorter ones are multiplied with the schoolbook method.
+pub const KARATSUBA_THRESHOLD: usize = 16;
+
+/// Calculates `a·b (mod X^n + 1, modulus)`, where `n` is the length of `a` and `b`.
+///
+/// It uses the number theory transform if `n` is a power of two not less than
+/// [`NEGACYCLIC_NTT_THRESHOLD`] and `modulus` is a prime with `2n | modulus - 1`.
+/// Otherwise, it falls back to [`karatsuba_mul`] followed by the reduction modulo `X^n + 1`,
+/// which works for any `n` and modulus.
+///
+/// The ntt table is built for this product only, callers multiplying many
+/// polynomials of the same degree should keep a [`TableWithShoupRoot`] instead.
+///
+/// # Panics
+///
+/// Panics if the lengths of `a` and `b` are not equal.
+pub fn negacyclic_mul<T: Numeric>(a: &[T], b: &[T], modulus: BarrettModulus<T>) -> Vec<T> {
+    assert_eq!(a.len(), b.len());
+    let n = a.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    if n.is_power_of_two() && n >= NEGACYCLIC_NTT_THRESHOLD && is_prime(modulus.value()) {
+        if let Ok(table) = <TableWithShoupRoot<T>>::new(modulus, n.trailing_zeros()) {
+            let mut a = a.to_vec();
+            let mut b = b.to_vec();
+            table.transform_slice(&mut a);
+            table.transform_slice(&mut b);
+            for (x, &y) in a.iter_mut().zip(&b) {
+                modulus.reduce_mul_assign(x, y);
+            }
+            table.inverse_transform_slice(&mut a);
+            return a;
+        }
+    }
+
+    let mut product = karatsuba_mul(a, b, modulus);
+    let high = product.split_off(n);
+    for (x, y) in product.iter_mut().zip(high) {
+        *x = modulus.reduce_sub(*x, y);
+    }
+    product
+}
+
+/// Calculates the product `a·b (mod modulus)` of two polynomials with the same length `n`,
+/// without any reduction by a polynomial, so the result has `2n - 1` coefficients.
+///
+/// Operands not shorter than [`KARATSUBA_THRESHOLD`] are split in halves recursively,
+/// with three half size products instead of four. It needs neither a prime modulus
+/// nor a power of two length, which makes it the fallback when the
+/// number theory transform is not available.
+///
+/// # Panics
+///
+/// Panics if the lengths of `a` and `b` are not equal.
+pub fn karatsuba_mul<T: Numeric>(a: &[T], b: &[T], modulus: BarrettModulus<T>) -> Vec<T> {
+    assert_eq!(a.len(), b.len());
+    if a.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![T::ZERO; 2 * a.len() - 1];
+    karatsuba_inplace(a, b, modulus, &mut product);
+    product
+}
+
+/// Adds `a·b` to `product`, which has at least `2n - 1` coefficients.
+fn karatsuba_inplace<T: Numeric>(a: &[T], b: &[T], modulus: BarrettModulus<T>, product: &mut [T]) {
+    let n = a.len();
+    if n < KARATSUBA_THRESHOLD {
+        for (i, &x) in a.iter().enumerate() {
+            for (p, &y) in product[i..].iter_mut().zip(b) {
+                *p = modulus.reduce_mul_add(x, y, *p);
+            }
+        }
+        return;
+    }
+
+    // `a = a0 + X^m·a1`, `b = b0 + X^m·b1`, where `a1` and `b1` have `h ≥ m` coefficients.
+    let m = n / 2;
+    let h = n - m;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let mut z0 = vec![T::ZERO; 2 * m - 1];
+    let mut z2 = vec![T::ZERO; 2 * h - 1];
+    karatsuba_inplace(a0, b0, modulus, &mut z0);
+    karatsuba_inplace(a1, b1, modulus, &mut z2);
+
+    // `z1 = (a0 + a1)·(b0 + b1) - z0 - z2`
+    let mut sum_a = a1.to_vec();
+    let mut sum_b = b1.to_vec();
+    for (x, &y) in sum_a.iter_mut().zip(a0) {
+        *x = modulus.reduce_add(*x, y);
+    }
+    for (x, &y) in sum_b.iter_mut().zip(b0) {
+        *x = modulus.reduce_add(*x, y);
+    }
+    let mut z1 = vec![T::ZERO; 2 * h - 1];
+    karatsuba_inplace(&sum_a, &sum_b, modulus, &mut z1);
+    for (x, &y) in z1.iter_mut().zip(&z0) {
+        *x = modulus.reduce_sub(*x, y);
+    }
+    for (x, &y) in z1.iter_mut().zip(&z2) {
+        *x = modulus.reduce_sub(*x, y);
+    }
+
+    for (p, &z) in product.iter_mut().zip(&z0) {
+        *p = modulus.reduce_add(*p, z);
+    }
+    for (p, &z) in product[m..].iter_mut().zip(&z1) {
+        *p = modulus.reduce_add(*p, z);
+    }
+    for (p, &z) in product[2 * m..].iter_mut().zip(&z2) {
+        *p = modulus.reduce_add(*p, z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::polynomial::Polynomial;
+
+    use super::*;
+
+    #[test]
+    fn test_negacyclic_mul() {
+        let mut rng = thread_rng();
+
+        for (p, n) in [
+            (132120577u64, 1024),
+            (132120577, 16),
+            (132120577, 100),
+            (1000000007, 256),
+            (1 << 20, 128),
+            (1 << 20, 77),
+            (65535, 1),
+        ] {
+            let modulus = <BarrettModulus<u64>>::new(p);
+            let a: Vec<u64> = (0..n).map(|_| rng.gen_range(0..p)).collect();
+            let b: Vec<u64> = (0..n).map(|_| rng.gen_range(0..p)).collect();
+
+            let mut expected = Polynomial::zero(n);
+            Polynomial::from_slice(&a).naive_mul_inplace(&b, modulus, &mut expected);
+            assert_eq!(negacyclic_mul(&a, &b, modulus), expected.inner_vec());
+        }
+
+        // schoolbook linear product as reference
+        for n in [1, 15, 16, 17, 33, 100] {
+            let modulus = <BarrettModulus<u32>>::new(1000000007);
+            let a: Vec<u32> = (0..n).map(|_| rng.gen_range(0..1000000007)).collect();
+            let b: Vec<u32> = (0..n).map(|_| rng.gen_range(0..1000000007)).collect();
+
+            let mut expected = vec![0u64; 2 * n - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    expected[i + j] = (expected[i + j] + x as u64 * y as u64) % 1000000007;
+                }
+            }
+            let expected: Vec<u32> = expected.into_iter().map(|v| v as u32).collect();
+            assert_eq!(karatsuba_mul(&a, &b, modulus), expected);
+        }
+
+        // x^(n-1) * x = -1
+        let modulus = <BarrettModulus<u64>>::new(132120577);
+        let mut a = vec![0u64; 64];
+        let mut b = vec![0u64; 64];
+        a[63] = 1;
+        b[1] = 1;
+        let c = negacyclic_mul(&a, &b, modulus);
+        assert_eq!(c[0], 132120576);
+        assert!(c[1..].iter().all(|&v| v == 0));
+    }
+}
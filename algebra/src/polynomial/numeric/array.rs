@@ -0,0 +1,407 @@
+use core::ops::{Index, IndexMut};
+
+use num_traits::{ConstZero, Zero};
+
+use crate::{
+    integer::UnsignedInteger,
+    reduce::{
+        ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign, ReduceNeg, ReduceNegAssign,
+        ReduceSub, ReduceSubAssign,
+    },
+};
+
+use super::Polynomial;
+
+/// Represents a polynomial with a fixed coefficient count `N`,
+/// where coefficients are elements of a specified numeric `T`.
+///
+/// The coefficients live inline instead of on the heap. With `N` a constant,
+/// the loops over the coefficients have a known trip count, so the compiler
+/// can unroll them and remove their bounds checks.
+///
+/// Only coefficient domain operations are provided. There is no NTT for this
+/// type, and the bootstrapping in `fhe_core` keeps using the dynamic
+/// [`Polynomial<T>`] and NTT polynomials.
+///
+/// It is [`Clone`] but not [`Copy`], so copying `N` coefficients is always explicit.
+///
+/// `N` must be positive, constructors fail to compile otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayPolynomial<T, const N: usize> {
+    poly: [T; N],
+}
+
+impl<T, const N: usize> ArrayPolynomial<T, N> {
+    /// Evaluated by every constructor, `naive_mul` underflows for `N == 0`.
+    const NON_EMPTY: () = assert!(N > 0, "ArrayPolynomial needs at least one coefficient");
+
+    /// Creates a new [`ArrayPolynomial<T, N>`].
+    #[inline]
+    pub const fn new(poly: [T; N]) -> Self {
+        let () = Self::NON_EMPTY;
+        Self { poly }
+    }
+
+    /// Drop self, and return the array.
+    #[inline]
+    pub fn inner_array(self) -> [T; N] {
+        self.poly
+    }
+
+    /// Extracts a slice containing the entire array.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.poly
+    }
+
+    /// Extracts a mutable slice of the entire array.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.poly
+    }
+
+    /// Get the coefficient counts of polynomial.
+    #[inline]
+    pub const fn coeff_count(&self) -> usize {
+        N
+    }
+
+    /// Returns an iterator that allows reading each value or coefficient of the polynomial.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.poly.iter()
+    }
+
+    /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.poly.iter_mut()
+    }
+}
+
+impl<T: Copy, const N: usize> ArrayPolynomial<T, N> {
+    /// Constructs a new polynomial from a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `polynomial` is not `N`.
+    #[inline]
+    pub fn from_slice(polynomial: &[T]) -> Self {
+        let () = Self::NON_EMPTY;
+        Self {
+            poly: polynomial.try_into().unwrap(),
+        }
+    }
+
+    /// Copy the coefficients from another slice.
+    #[inline]
+    pub fn copy_from(&mut self, src: impl AsRef<[T]>) {
+        self.poly.copy_from_slice(src.as_ref())
+    }
+
+    /// Converts `self` into a heap allocated [`Polynomial<T>`].
+    #[inline]
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        Polynomial::from_slice(&self.poly)
+    }
+
+    /// Performs `self += rhs` according to `modulus`.
+    #[inline]
+    pub fn add_assign<M>(&mut self, rhs: &Self, modulus: M)
+    where
+        M: Copy + ReduceAddAssign<T>,
+    {
+        self.poly
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(a, &b)| modulus.reduce_add_assign(a, b));
+    }
+
+    /// Performs `self -= rhs` according to `modulus`.
+    #[inline]
+    pub fn sub_assign<M>(&mut self, rhs: &Self, modulus: M)
+    where
+        M: Copy + ReduceSubAssign<T>,
+    {
+        self.poly
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(a, &b)| modulus.reduce_sub_assign(a, b));
+    }
+
+    /// Performs the unary `-` operation.
+    #[inline]
+    pub fn neg_assign<M>(&mut self, modulus: M)
+    where
+        M: Copy + ReduceNegAssign<T>,
+    {
+        self.poly
+            .iter_mut()
+            .for_each(|v| modulus.reduce_neg_assign(v));
+    }
+
+    /// Multiply `self` with a scalar assign.
+    #[inline]
+    pub fn mul_scalar_assign<M>(&mut self, scalar: T, modulus: M)
+    where
+        M: Copy + ReduceMulAssign<T>,
+    {
+        self.poly
+            .iter_mut()
+            .for_each(|v| modulus.reduce_mul_assign(v, scalar))
+    }
+
+    /// Add the multiply result `rhs` with a scalar inplace.
+    #[inline]
+    pub fn add_mul_scalar_assign<M>(&mut self, rhs: &Self, scalar: T, modulus: M)
+    where
+        M: Copy + ReduceMulAdd<T, Output = T>,
+    {
+        self.poly
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(r, &v)| *r = modulus.reduce_mul_add(v, scalar, *r));
+    }
+    /// Performs `destination = self * (X^r - 1)` modulo `X^N + 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r >= 2N`.
+    pub fn mul_monic_monomial_sub_one_inplace<M>(
+        &self,
+        r: usize,
+        modulus: M,
+        destination: &mut Self,
+    ) where
+        M: Copy + ReduceNeg<T, Output = T> + ReduceSub<T, Output = T>,
+    {
+        assert!(r < 2 * N);
+        // `X^r = -X^(r - N)`, the coefficients wrapping around `X^N` are negated.
+        let (r, wrapped_negated) = if r <= N { (r, true) } else { (r - N, false) };
+        let (head, tail) = self.poly.split_at(N - r);
+        let (low, high) = destination.poly.split_at_mut(r);
+
+        for ((d, &v), &p) in low.iter_mut().zip(tail).zip(&self.poly[..r]) {
+            let v = if wrapped_negated {
+                modulus.reduce_neg(v)
+            } else {
+                v
+            };
+            *d = modulus.reduce_sub(v, p);
+        }
+        for ((d, &v), &p) in high.iter_mut().zip(head).zip(&self.poly[r..]) {
+            let v = if wrapped_negated {
+                v
+            } else {
+                modulus.reduce_neg(v)
+            };
+            *d = modulus.reduce_sub(v, p);
+        }
+    }
+
+    /// Performs `self += rhs * X^r` modulo `X^N + 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r >= 2N`.
+    pub fn add_assign_rhs_mul_monic_monomial<M>(&mut self, rhs: &Self, r: usize, modulus: M)
+    where
+        M: Copy + ReduceAddAssign<T> + ReduceSubAssign<T>,
+    {
+        assert!(r < 2 * N);
+        let (r, wrapped_negated) = if r <= N { (r, true) } else { (r - N, false) };
+        let (head, tail) = rhs.poly.split_at(N - r);
+        let (low, high) = self.poly.split_at_mut(r);
+
+        if wrapped_negated {
+            low.iter_mut()
+                .zip(tail)
+                .for_each(|(u, &v)| modulus.reduce_sub_assign(u, v));
+            high.iter_mut()
+                .zip(head)
+                .for_each(|(u, &v)| modulus.reduce_add_assign(u, v));
+        } else {
+            low.iter_mut()
+                .zip(tail)
+                .for_each(|(u, &v)| modulus.reduce_add_assign(u, v));
+            high.iter_mut()
+                .zip(head)
+                .for_each(|(u, &v)| modulus.reduce_sub_assign(u, v));
+        }
+    }
+}
+
+impl<T: Copy + ConstZero, const N: usize> ArrayPolynomial<T, N> {
+    /// Creates a [`ArrayPolynomial<T, N>`] with all coefficients equal to zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new([<T as ConstZero>::ZERO; N])
+    }
+
+    /// Returns `true` if `self` is equal to `0`.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.poly.iter().all(<T as Zero>::is_zero)
+    }
+
+    /// Sets `self` to `0`.
+    #[inline]
+    pub fn set_zero(&mut self) {
+        self.poly.fill(<T as ConstZero>::ZERO);
+    }
+}
+
+impl<T: UnsignedInteger, const N: usize> ArrayPolynomial<T, N> {
+    /// A naive multiplication over polynomial, modulo `x^N + 1`.
+    pub fn naive_mul<M>(&self, rhs: &Self, modulus: M) -> Self
+    where
+        M: Copy + ReduceAddAssign<T> + ReduceSubAssign<T> + ReduceMul<T, Output = T>,
+    {
+        let mut destination = Self::zero();
+
+        for i in 0..N {
+            for j in 0..=i {
+                modulus.reduce_add_assign(
+                    &mut destination.poly[i],
+                    modulus.reduce_mul(self.poly[j], rhs.poly[i - j]),
+                );
+            }
+        }
+
+        // mod (x^n + 1)
+        for i in N..N * 2 - 1 {
+            let k = i - N;
+            for j in i - N + 1..N {
+                modulus.reduce_sub_assign(
+                    &mut destination.poly[k],
+                    modulus.reduce_mul(self.poly[j], rhs.poly[i - j]),
+                );
+            }
+        }
+
+        destination
+    }
+}
+
+impl<T: Copy + ConstZero, const N: usize> Default for ArrayPolynomial<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayPolynomial<T, N> {
+    #[inline]
+    fn from(poly: [T; N]) -> Self {
+        Self::new(poly)
+    }
+}
+
+impl<T, const N: usize> TryFrom<Polynomial<T>> for ArrayPolynomial<T, N> {
+    type Error = Polynomial<T>;
+
+    /// Fails and returns the polynomial back if its coefficient count is not `N`.
+    #[inline]
+    fn try_from(polynomial: Polynomial<T>) -> Result<Self, Self::Error> {
+        <[T; N]>::try_from(polynomial.inner_vec())
+            .map(Self::new)
+            .map_err(Polynomial::new)
+    }
+}
+
+impl<T, I, const N: usize> Index<I> for ArrayPolynomial<T, N>
+where
+    [T]: Index<I>,
+{
+    type Output = <[T] as Index<I>>::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.poly.as_slice(), index)
+    }
+}
+
+impl<T, I, const N: usize> IndexMut<I> for ArrayPolynomial<T, N>
+where
+    [T]: IndexMut<I>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.poly.as_mut_slice(), index)
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayPolynomial<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.poly
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayPolynomial<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.poly
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayPolynomial<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.poly.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayPolynomial<T, N> {
+    type Item = &'a mut T;
+
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.poly.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, thread_rng, Rng};
+
+    use crate::modulus::BarrettModulus;
+
+    use super::*;
+
+    #[test]
+    fn test_array_naive_mul() {
+        const N: usize = 16;
+        const P: u32 = 132120577;
+
+        let mut rng = thread_rng();
+        let dis = Uniform::new(0, P);
+        let modulus = <BarrettModulus<u32>>::new(P);
+
+        let a: [u32; N] = core::array::from_fn(|_| rng.sample(dis));
+        let b: [u32; N] = core::array::from_fn(|_| rng.sample(dis));
+
+        let mut expected = Polynomial::zero(N);
+        Polynomial::new(a.to_vec()).naive_mul_inplace(b, modulus, &mut expected);
+
+        let a = ArrayPolynomial::new(a);
+        let b = ArrayPolynomial::new(b);
+        let result = a.naive_mul(&b, modulus);
+        assert_eq!(result.to_polynomial(), expected);
+        assert_eq!(ArrayPolynomial::try_from(expected), Ok(result));
+
+        let mut c = a.clone();
+        c.add_assign(&b, modulus);
+        c.sub_assign(&b, modulus);
+        assert_eq!(c, a);
+        c.neg_assign(modulus);
+        c.add_assign(&a, modulus);
+        assert!(c.is_zero());
+    }
+}
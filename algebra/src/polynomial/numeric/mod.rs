@@ -1,5 +1,7 @@
+mod array;
 mod coeff;
 mod ntt;
 
+pub use array::ArrayPolynomial;
 pub use coeff::Polynomial;
 pub use ntt::NttPolynomial;
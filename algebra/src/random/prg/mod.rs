@@ -5,7 +5,7 @@ mod block;
 mod sse2neon;
 
 pub use aes::Aes;
-use block::Block;
+pub use block::Block;
 use rand::{CryptoRng, Rng, RngCore, SeedableRng};
 use rand_core::block::{BlockRng, BlockRngCore};
 
@@ -13,12 +13,13 @@ use rand_core::block::{BlockRng, BlockRngCore};
 #[derive(Clone, Copy, Debug)]
 struct PrgCore {
     aes: Aes,
+    seed: Block,
     state: u64,
 }
 
 impl BlockRngCore for PrgCore {
     type Item = u32;
-    type Results = [u32; 32];
+    type Results = [u32; RESULTS_LEN];
 
     // Compute [AES(state)..AES(state+8)]
     #[inline(always)]
@@ -41,13 +42,20 @@ impl SeedableRng for PrgCore {
     #[inline(always)]
     fn from_seed(seed: Self::Seed) -> Self {
         let aes = Aes::new(seed);
-        Self { aes, state: 0u64 }
+        Self {
+            aes,
+            seed,
+            state: 0u64,
+        }
     }
 }
 
 impl CryptoRng for PrgCore {}
 
 /// Struct of PRG
+///
+/// It runs AES in counter mode, and implements [`RngCore`], [`SeedableRng`]
+/// and [`CryptoRng`], so it can be passed to any sampler of the `rand` ecosystem.
 #[derive(Clone, Debug)]
 pub struct Prg(BlockRng<PrgCore>);
 
@@ -89,7 +97,65 @@ impl SeedableRng for Prg {
 
 impl CryptoRng for Prg {}
 
+/// The number of `u32` words in one AES block.
+const WORDS_PER_BLOCK: u64 = 4;
+
+/// The number of `u32` words [`PrgCore`] generates at once.
+const RESULTS_LEN: usize = 32;
+
+/// Domain separation tag used by [`Prg::derive_seed`].
+const DERIVE_TAG: u64 = u64::from_le_bytes(*b"prg-kdf\0");
+
 impl Prg {
+    /// Derives a child seed from `seed` along an index `path`.
+    ///
+    /// Every step computes `AES_seed(x) xor x` with `x = (index, tag)`,
+    /// which is a one-way PRF of the parent seed. Different paths give
+    /// independent seeds, the same path always gives the same seed.
+    #[inline]
+    pub fn derive_seed(seed: Block, path: &[u64]) -> Block {
+        path.iter().fold(seed, |parent, &index| {
+            let x = Block::from([index, DERIVE_TAG]);
+            Aes::new(parent).encrypt_block(x) ^ x
+        })
+    }
+
+    /// New a Prg with the seed derived from `seed` along `path`.
+    ///
+    /// See [`Prg::derive_seed`].
+    #[inline]
+    pub fn derive(seed: Block, path: &[u64]) -> Self {
+        Prg::from_seed(Self::derive_seed(seed, path))
+    }
+
+    /// Returns the seed of this [`Prg`].
+    #[inline]
+    pub fn seed(&self) -> Block {
+        self.0.core.seed
+    }
+
+    /// Returns the number of `u32` words drawn from this [`Prg`] since it was seeded.
+    ///
+    /// A [`Prg`] seeded with [`Prg::seed`] and moved to this position with
+    /// [`Prg::set_word_pos`] continues with the same output.
+    #[inline]
+    pub fn word_pos(&self) -> u64 {
+        let buffered = (RESULTS_LEN - self.0.index()) as u64;
+        self.0.core.state * WORDS_PER_BLOCK - buffered
+    }
+
+    /// Moves this [`Prg`] to the position after `word_pos` drawn `u32` words.
+    #[inline]
+    pub fn set_word_pos(&mut self, word_pos: u64) {
+        let index = (word_pos % RESULTS_LEN as u64) as usize;
+        self.0.core.state = (word_pos - index as u64) / WORDS_PER_BLOCK;
+        if index == 0 {
+            self.0.reset();
+        } else {
+            self.0.generate_and_set(index);
+        }
+    }
+
     /// New a Prg with random seed.
     #[inline(always)]
     pub fn new() -> Self {
@@ -149,3 +215,64 @@ fn prg_test() {
     prg.random_blocks(&mut x);
     assert_ne!(x[0], x[1]);
 }
+
+#[test]
+fn prg_derive_test() {
+    let master = rand::random::<Block>();
+
+    assert_eq!(Prg::derive_seed(master, &[]), master);
+    assert_eq!(
+        Prg::derive_seed(master, &[1, 2]),
+        Prg::derive_seed(Prg::derive_seed(master, &[1]), &[2])
+    );
+    assert_ne!(
+        Prg::derive_seed(master, &[1, 2]),
+        Prg::derive_seed(master, &[2, 1])
+    );
+    assert_ne!(Prg::derive_seed(master, &[0]), master);
+
+    let mut a = Prg::derive(master, &[7, 3]);
+    let mut b = Prg::derive(master, &[7, 3]);
+    assert_eq!(a.random_block(), b.random_block());
+}
+
+#[test]
+fn prg_rand_traits_test() {
+    use rand_distr::{Distribution, Uniform};
+
+    fn sample<R: RngCore + CryptoRng>(rng: &mut R) -> Vec<u64> {
+        let distr = Uniform::new(0, 1000);
+        (0..16).map(|_| distr.sample(rng)).collect()
+    }
+
+    let mut a = Prg::seed_from_u64(42);
+    let mut b = Prg::seed_from_u64(42);
+    assert_eq!(sample(&mut a), sample(&mut b));
+    assert_eq!(a.next_u64(), b.next_u64());
+
+    let mut c = Prg::from_rng(&mut a).unwrap();
+    assert_ne!(sample(&mut a), sample(&mut c));
+}
+
+#[test]
+fn prg_word_pos_test() {
+    let mut a = Prg::seed_from_u64(7);
+    assert_eq!(a.word_pos(), 0);
+
+    for drawn in [1u64, 31, 32, 33, 100] {
+        let mut a = Prg::seed_from_u64(7);
+        (0..drawn).for_each(|_| {
+            a.next_u32();
+        });
+        assert_eq!(a.word_pos(), drawn);
+
+        let mut b = Prg::from_seed(a.seed());
+        b.set_word_pos(drawn);
+        assert_eq!(b.word_pos(), drawn);
+        assert_eq!(a.random_block(), b.random_block());
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+
+    a.random_bytes(&mut [0; 10]);
+    assert_eq!(a.word_pos(), 3);
+}
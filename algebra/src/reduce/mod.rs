@@ -2,6 +2,7 @@
 
 mod lazy_ops;
 mod ops;
+mod window;
 
 mod macros;
 
@@ -10,6 +11,8 @@ use std::fmt::Debug;
 pub use lazy_ops::*;
 use num_traits::ConstOne;
 pub use ops::*;
+pub use window::ReduceExpWindow;
+pub(crate) use window::WINDOW_BITS;
 
 use crate::{arith::PrimitiveRoot, integer::UnsignedInteger, numeric::Numeric};
 
@@ -89,6 +92,18 @@ pub trait Modulus<T> {
     /// Converts a modulus value to a modulus.
     fn from_value(value: ModulusValue<T>) -> Self;
 
+    /// Converts a modulus value to a modulus,
+    /// or returns `None` where [`Modulus::from_value`] panics.
+    ///
+    /// The default implementation catches the panic of [`Modulus::from_value`],
+    /// so it still panics when panics abort. Implementors check `value` instead.
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| Self::from_value(value))).ok()
+    }
+
     /// Returns the modulus value.
     fn modulus_value(&self) -> ModulusValue<T>;
 
@@ -96,6 +111,20 @@ pub trait Modulus<T> {
     fn modulus_minus_one(&self) -> T;
 }
 
+/// Conversion between canonical values and the representation a modulus works on.
+///
+/// Most moduli operate on canonical values directly, while
+/// [`MontgomeryModulus`](crate::modulus::MontgomeryModulus) keeps values
+/// in the Montgomery domain. Generic code converts at its boundaries with
+/// this trait, so the reduction strategy can be chosen per modulus.
+pub trait ReduceRepresentation<T> {
+    /// Converts a canonical `value < modulus` into the representation of `self`.
+    fn to_representation(self, value: T) -> T;
+
+    /// Converts `value` from the representation of `self` back into a canonical value.
+    fn to_canonical(self, value: T) -> T;
+}
+
 /// An trait indicate the modulus can perform operation like a ring.
 pub trait RingReduce<T>:
     Sized
@@ -122,6 +151,7 @@ pub trait RingReduce<T>:
     + ReduceExp<T>
     + ReduceExpPowOf2<T>
     + ReduceDotProduct<T, Output = T>
+    + SliceModOps<T>
 {
 }
 
@@ -150,6 +180,7 @@ impl<T: UnsignedInteger, M> RingReduce<T> for M where
         + ReduceExp<T>
         + ReduceExpPowOf2<T>
         + ReduceDotProduct<T, Output = T>
+        + SliceModOps<T>
 {
 }
 
@@ -223,4 +254,83 @@ mod tests {
             assert_eq!(1, (WideT::from(c) * a_d) % m_d, "reduce_sub");
         }
     }
+
+    /// A modulus which only provides [`Modulus::from_value`].
+    struct OddModulus(u32);
+
+    impl Modulus<u32> for OddModulus {
+        fn from_value(value: ModulusValue<u32>) -> Self {
+            match value {
+                ModulusValue::Prime(v) | ModulusValue::Others(v) if v % 2 == 1 => Self(v),
+                _ => panic!("modulus is not odd"),
+            }
+        }
+
+        fn modulus_value(&self) -> ModulusValue<u32> {
+            ModulusValue::Others(self.0)
+        }
+
+        fn modulus_minus_one(&self) -> u32 {
+            self.0 - 1
+        }
+    }
+
+    #[test]
+    fn test_default_try_from_value() {
+        let modulus = OddModulus::try_from_value(ModulusValue::Others(15)).unwrap();
+        assert_eq!(modulus.modulus_minus_one(), 14);
+        assert!(OddModulus::try_from_value(ModulusValue::PowerOf2(16)).is_none());
+    }
+
+    fn check_slice_mod_ops<M: RingReduce<u64>>(modulus: M, bound: u64) {
+        const N: usize = 67;
+        let mut rng = thread_rng();
+        let a: Vec<u64> = (0..N).map(|_| rng.gen_range(0..=bound)).collect();
+        let b: Vec<u64> = (0..N).map(|_| rng.gen_range(0..=bound)).collect();
+        let scalar = rng.gen_range(0..=bound);
+
+        let zip = |f: &dyn Fn(u64, u64) -> u64| -> Vec<u64> {
+            a.iter().zip(&b).map(|(&x, &y)| f(x, y)).collect()
+        };
+
+        let mut c = a.clone();
+        modulus.add_slice(&mut c, &b);
+        assert_eq!(c, zip(&|x, y| modulus.reduce_add(x, y)), "add_slice");
+
+        let mut c = a.clone();
+        modulus.sub_slice(&mut c, &b);
+        assert_eq!(c, zip(&|x, y| modulus.reduce_sub(x, y)), "sub_slice");
+
+        let mut c = a.clone();
+        modulus.mul_slice(&mut c, &b);
+        assert_eq!(c, zip(&|x, y| modulus.reduce_mul(x, y)), "mul_slice");
+
+        let mut c = a.clone();
+        modulus.mul_scalar_slice(&mut c, scalar);
+        assert_eq!(
+            c,
+            zip(&|x, _| modulus.reduce_mul(x, scalar)),
+            "mul_scalar_slice"
+        );
+
+        let mut c = a.clone();
+        modulus.fma_slice(&mut c, &b, scalar);
+        assert_eq!(
+            c,
+            zip(&|x, y| modulus.reduce_mul_add(y, scalar, x)),
+            "fma_slice"
+        );
+    }
+
+    #[test]
+    fn test_slice_mod_ops() {
+        use crate::modulus::{BarrettModulus, MontgomeryModulus, NativeModulus, PowOf2Modulus};
+
+        let m = (1 << 61) - 1;
+        check_slice_mod_ops(BarrettModulus::<u64>::new(m), m - 1);
+        check_slice_mod_ops(BarrettModulus::<u64>::new(3), 2);
+        check_slice_mod_ops(MontgomeryModulus::<u64>::new(m), m - 1);
+        check_slice_mod_ops(PowOf2Modulus::<u64>::new(1 << 40), (1 << 40) - 1);
+        check_slice_mod_ops(NativeModulus::<u64>::new(), u64::MAX);
+    }
 }
@@ -268,3 +268,29 @@ pub trait ReduceDotProduct<T> {
     /// Calculate `∑a_i×b_i (mod modulus)` where `self` is modulus.
     fn reduce_dot_product(self, a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> Self::Output;
 }
+
+/// Modular arithmetic over slices.
+///
+/// Every element of the slices must be less than the modulus, and the results are too.
+/// The implementations keep the loop bodies free of branches where the modulus
+/// allows it, so the compiler can vectorize them.
+///
+/// # Panics
+///
+/// The methods taking two slices panic if they have different lengths.
+pub trait SliceModOps<T> {
+    /// Calculates `a_i = a_i + b_i (mod modulus)` for every `i`.
+    fn add_slice(self, a: &mut [T], b: &[T]);
+
+    /// Calculates `a_i = a_i - b_i (mod modulus)` for every `i`.
+    fn sub_slice(self, a: &mut [T], b: &[T]);
+
+    /// Calculates `a_i = a_i * b_i (mod modulus)` for every `i`.
+    fn mul_slice(self, a: &mut [T], b: &[T]);
+
+    /// Calculates `a_i = a_i * scalar (mod modulus)` for every `i`.
+    fn mul_scalar_slice(self, a: &mut [T], scalar: T);
+
+    /// Calculates `a_i = a_i + b_i * scalar (mod modulus)` for every `i`.
+    fn fma_slice(self, a: &mut [T], b: &[T], scalar: T);
+}
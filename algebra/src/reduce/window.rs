@@ -0,0 +1,164 @@
+use crate::integer::UnsignedInteger;
+
+use super::{ReduceExp, ReduceMul, ReduceSquare};
+
+/// The window size of the exponentiations inside the crate, such as the primitive root search.
+pub(crate) const WINDOW_BITS: u32 = 4;
+
+/// The modular exponentiation with the fixed-window method.
+///
+/// `2^window_bits` powers of the base are precomputed, then the exponent
+/// is consumed `window_bits` bits at a time, with one multiplication per window.
+///
+/// It's implemented for every modulus with [`ReduceExp`], [`ReduceMul`] and [`ReduceSquare`],
+/// and works on the representation of that modulus.
+pub trait ReduceExpWindow<T> {
+    /// Calculates `base^exp (mod modulus)` where `self` is modulus.
+    ///
+    /// This is variable time, leading zero bits and zero windows of `exp` are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_bits` is not in `1..=8`.
+    fn reduce_exp_window<E: UnsignedInteger>(self, base: T, exp: E, window_bits: u32) -> T;
+
+    /// Calculates `base^exp (mod modulus)` where `self` is modulus.
+    ///
+    /// The sequence of operations and memory accesses only depends on `E::BITS` and
+    /// `window_bits`: every window is squared and multiplied, and the power is
+    /// selected by scanning the whole table with masks. The result is constant time
+    /// as far as the multiplication and square of the modulus are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_bits` is not in `1..=8`.
+    fn reduce_exp_window_ct<E: UnsignedInteger>(self, base: T, exp: E, window_bits: u32) -> T;
+}
+
+impl<T, M> ReduceExpWindow<T> for M
+where
+    T: UnsignedInteger,
+    M: Copy + ReduceExp<T> + ReduceMul<T, Output = T> + ReduceSquare<T, Output = T>,
+{
+    fn reduce_exp_window<E: UnsignedInteger>(self, base: T, exp: E, window_bits: u32) -> T {
+        let table = power_table(self, base, window_bits);
+        let mask: usize = (1 << window_bits) - 1;
+
+        let bits = E::BITS - exp.leading_zeros();
+        let mut shift = bits.div_ceil(window_bits) * window_bits;
+        let mut result: Option<T> = None;
+
+        while shift > 0 {
+            shift -= window_bits;
+            let digit: usize = (exp >> shift).as_into();
+            let digit = digit & mask;
+
+            if let Some(value) = result.as_mut() {
+                for _ in 0..window_bits {
+                    *value = self.reduce_square(*value);
+                }
+                if digit != 0 {
+                    *value = self.reduce_mul(*value, table[digit]);
+                }
+            } else if digit != 0 {
+                result = Some(table[digit]);
+            }
+        }
+
+        result.unwrap_or(table[0])
+    }
+
+    fn reduce_exp_window_ct<E: UnsignedInteger>(self, base: T, exp: E, window_bits: u32) -> T {
+        let table = power_table(self, base, window_bits);
+        let mask: usize = (1 << window_bits) - 1;
+
+        let mut shift = E::BITS.div_ceil(window_bits) * window_bits;
+        let mut result = table[0];
+
+        while shift > 0 {
+            shift -= window_bits;
+            let digit: usize = if shift < E::BITS {
+                (exp >> shift).as_into()
+            } else {
+                0
+            };
+            let digit = digit & mask;
+
+            for _ in 0..window_bits {
+                result = self.reduce_square(result);
+            }
+
+            let mut power = T::ZERO;
+            for (i, &entry) in table.iter().enumerate() {
+                // `1` if `i == digit`, `0` otherwise.
+                let equal = ((i ^ digit) as u64).wrapping_sub(1) >> 63;
+                power |= entry & T::ZERO.wrapping_sub(T::as_from(equal));
+            }
+            result = self.reduce_mul(result, power);
+        }
+
+        result
+    }
+}
+
+/// Returns `[base^0, base^1, ..., base^(2^window_bits - 1)]` in the representation of `modulus`.
+fn power_table<T, M>(modulus: M, base: T, window_bits: u32) -> Vec<T>
+where
+    T: UnsignedInteger,
+    M: Copy + ReduceExp<T> + ReduceMul<T, Output = T>,
+{
+    assert!((1..=8).contains(&window_bits));
+
+    // `base^0` is the one of the modulus representation, which isn't always `1`.
+    let one = modulus.reduce_exp(base, 0u32);
+
+    let size = 1usize << window_bits;
+    let mut table = Vec::with_capacity(size);
+    table.push(one);
+    table.push(base);
+    for i in 2..size {
+        table.push(modulus.reduce_mul(table[i - 1], base));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::modulus::{BarrettModulus, MontgomeryModulus, PowOf2Modulus};
+    use crate::reduce::ReduceRepresentation;
+
+    use super::*;
+
+    #[test]
+    fn test_reduce_exp_window() {
+        const P: u64 = 0x1fffffffffe00001;
+        let barrett = BarrettModulus::<u64>::new(P);
+        let montgomery = MontgomeryModulus::<u64>::new(P);
+        let pow_of_2 = <PowOf2Modulus<u64>>::new(1 << 40);
+        let mut rng = thread_rng();
+
+        for window_bits in 1..=6 {
+            for exp in [0u64, 1, 2, u64::MAX, rng.gen(), rng.gen::<u64>() >> 40] {
+                let base = rng.gen_range(0..P);
+                let expected = barrett.reduce_exp(base, exp);
+                assert_eq!(barrett.reduce_exp_window(base, exp, window_bits), expected);
+                assert_eq!(
+                    barrett.reduce_exp_window_ct(base, exp, window_bits),
+                    expected
+                );
+
+                let base_m = montgomery.to_representation(base);
+                let power = montgomery.reduce_exp_window_ct(base_m, exp, window_bits);
+                assert_eq!(montgomery.to_canonical(power), expected);
+
+                let base = rng.gen_range(0..1 << 40);
+                assert_eq!(
+                    pow_of_2.reduce_exp_window(base, exp, window_bits),
+                    pow_of_2.reduce_exp(base, exp)
+                );
+            }
+        }
+    }
+}
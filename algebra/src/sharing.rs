@@ -0,0 +1,379 @@
+//! Secret sharing over the prime fields.
+//!
+//! - [`Shamir`] is the `t`-out-of-`n` threshold scheme, a secret is the constant term
+//!   of a random polynomial of degree `t - 1` and every party gets one evaluation.
+//! - [`Replicated`] is the `2`-out-of-`3` replicated scheme, a secret is split into
+//!   three additive parts and every party gets two of them.
+//!
+//! Both schemes are linear, shares of the same secrets can be added, subtracted and
+//! multiplied by public scalars locally.
+
+use std::marker::PhantomData;
+
+use num_traits::ConstZero;
+use rand::{CryptoRng, Rng};
+use rand_distr::{Distribution, Uniform};
+
+use crate::{integer::AsFrom, Field};
+
+/// Returns a [`Uniform`] distribution over the values of `F`.
+#[inline]
+fn uniform<F: Field>() -> Uniform<<F as Field>::ValueT> {
+    Uniform::new_inclusive(<<F as Field>::ValueT as ConstZero>::ZERO, F::MINUS_ONE)
+}
+
+/// A share of the [`Shamir`] scheme, the evaluation `y = f(x)` of the sharing polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShamirShare<T> {
+    /// The evaluation point, which is the index of the party starting from `1`.
+    pub x: T,
+    /// The evaluation.
+    pub y: T,
+}
+
+/// The Shamir `threshold`-out-of-`parties` secret sharing over `F`.
+#[derive(Debug, Clone, Copy)]
+pub struct Shamir<F: Field> {
+    threshold: usize,
+    parties: usize,
+    phantom: PhantomData<F>,
+}
+
+impl<F: Field> Shamir<F> {
+    /// Creates a new [`Shamir<F>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is `0`, `threshold > parties`, or `parties` is not less than the modulus.
+    pub fn new(threshold: usize, parties: usize) -> Self {
+        assert!(threshold > 0 && threshold <= parties);
+        assert!(<F as Field>::ValueT::try_from(parties).is_ok_and(|p| p < F::MODULUS_VALUE));
+        Self {
+            threshold,
+            parties,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the threshold of this [`Shamir<F>`].
+    #[inline]
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the parties count of this [`Shamir<F>`].
+    #[inline]
+    pub fn parties(&self) -> usize {
+        self.parties
+    }
+
+    /// Splits `secret` into one share for every party.
+    pub fn share<R>(&self, secret: <F as Field>::ValueT, rng: &mut R) -> Vec<ShamirShare<F::ValueT>>
+    where
+        R: Rng + CryptoRng,
+    {
+        let coeffs: Vec<F::ValueT> = uniform::<F>()
+            .sample_iter(rng)
+            .take(self.threshold - 1)
+            .collect();
+
+        (1..=self.parties)
+            .map(|i| {
+                let x = <F as Field>::ValueT::as_from(i);
+                // Horner's rule, from the highest coefficient to the secret.
+                let y = coeffs
+                    .iter()
+                    .rev()
+                    .fold(<F as Field>::ValueT::ZERO, |acc, &c| F::mul_add(acc, x, c));
+                ShamirShare {
+                    x,
+                    y: F::mul_add(y, x, secret),
+                }
+            })
+            .collect()
+    }
+
+    /// Reconstructs the secret from at least `threshold` shares,
+    /// by Lagrange interpolation at `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `threshold` shares, or two shares have the same point.
+    pub fn reconstruct(&self, shares: &[ShamirShare<F::ValueT>]) -> <F as Field>::ValueT {
+        assert!(shares.len() >= self.threshold, "not enough shares");
+        let shares = &shares[..self.threshold];
+
+        shares
+            .iter()
+            .enumerate()
+            .fold(<F as Field>::ValueT::ZERO, |acc, (i, share)| {
+                let (numerator, denominator) = shares
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .fold((F::ONE, F::ONE), |(num, den), (_, other)| {
+                        assert_ne!(other.x, share.x, "duplicate share point");
+                        (F::mul(num, other.x), F::mul(den, F::sub(other.x, share.x)))
+                    });
+                F::mul_add(share.y, F::div(numerator, denominator), acc)
+            })
+    }
+
+    /// Adds two shares of the same party.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shares belong to different parties.
+    #[inline]
+    pub fn add(a: ShamirShare<F::ValueT>, b: ShamirShare<F::ValueT>) -> ShamirShare<F::ValueT> {
+        assert_eq!(a.x, b.x);
+        ShamirShare {
+            x: a.x,
+            y: F::add(a.y, b.y),
+        }
+    }
+
+    /// Subtracts two shares of the same party.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shares belong to different parties.
+    #[inline]
+    pub fn sub(a: ShamirShare<F::ValueT>, b: ShamirShare<F::ValueT>) -> ShamirShare<F::ValueT> {
+        assert_eq!(a.x, b.x);
+        ShamirShare {
+            x: a.x,
+            y: F::sub(a.y, b.y),
+        }
+    }
+
+    /// Multiplies a share by a public `scalar`.
+    #[inline]
+    pub fn mul_scalar(share: ShamirShare<F::ValueT>, scalar: F::ValueT) -> ShamirShare<F::ValueT> {
+        ShamirShare {
+            x: share.x,
+            y: F::mul(share.y, scalar),
+        }
+    }
+
+    /// Adds a public `constant` to the shared secret, every party does this on its own share.
+    #[inline]
+    pub fn add_constant(
+        share: ShamirShare<F::ValueT>,
+        constant: F::ValueT,
+    ) -> ShamirShare<F::ValueT> {
+        ShamirShare {
+            x: share.x,
+            y: F::add(share.y, constant),
+        }
+    }
+}
+
+/// A share of the [`Replicated`] scheme.
+///
+/// The secret is `r₀ + r₁ + r₂`, and party `i` holds `(r_i, r_{i+1 mod 3})`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplicatedShare<T> {
+    /// The index of the party, in `0..3`.
+    pub party: usize,
+    /// `(r_i, r_{i+1 mod 3})`.
+    pub values: [T; 2],
+}
+
+/// The `2`-out-of-`3` replicated secret sharing over `F`.
+#[derive(Debug, Clone, Copy)]
+pub struct Replicated<F: Field> {
+    phantom: PhantomData<F>,
+}
+
+impl<F: Field> Replicated<F> {
+    /// Splits `secret` into three shares, any two of them can reconstruct it.
+    pub fn share<R>(secret: F::ValueT, rng: &mut R) -> [ReplicatedShare<F::ValueT>; 3]
+    where
+        R: Rng + CryptoRng,
+    {
+        let distr = uniform::<F>();
+        let r0 = distr.sample(rng);
+        let r1 = distr.sample(rng);
+        let r2 = F::sub(secret, F::add(r0, r1));
+        let r = [r0, r1, r2];
+
+        [0, 1, 2].map(|party| ReplicatedShare {
+            party,
+            values: [r[party], r[(party + 1) % 3]],
+        })
+    }
+
+    /// Reconstructs the secret from the shares of two different parties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shares belong to the same party.
+    pub fn reconstruct(
+        a: &ReplicatedShare<F::ValueT>,
+        b: &ReplicatedShare<F::ValueT>,
+    ) -> F::ValueT {
+        assert_ne!(a.party, b.party, "shares of the same party");
+        // `b` is either the next party of `a`, holding `r_{i+2}` as its second value,
+        // or the previous one, holding `r_{i+2}` as its first value.
+        let missing = if b.party == (a.party + 1) % 3 {
+            b.values[1]
+        } else {
+            b.values[0]
+        };
+        F::add(F::add(a.values[0], a.values[1]), missing)
+    }
+
+    /// Adds two shares of the same party.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shares belong to different parties.
+    #[inline]
+    pub fn add(
+        a: ReplicatedShare<F::ValueT>,
+        b: ReplicatedShare<F::ValueT>,
+    ) -> ReplicatedShare<F::ValueT> {
+        assert_eq!(a.party, b.party);
+        ReplicatedShare {
+            party: a.party,
+            values: [
+                F::add(a.values[0], b.values[0]),
+                F::add(a.values[1], b.values[1]),
+            ],
+        }
+    }
+
+    /// Subtracts two shares of the same party.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shares belong to different parties.
+    #[inline]
+    pub fn sub(
+        a: ReplicatedShare<F::ValueT>,
+        b: ReplicatedShare<F::ValueT>,
+    ) -> ReplicatedShare<F::ValueT> {
+        assert_eq!(a.party, b.party);
+        ReplicatedShare {
+            party: a.party,
+            values: [
+                F::sub(a.values[0], b.values[0]),
+                F::sub(a.values[1], b.values[1]),
+            ],
+        }
+    }
+
+    /// Multiplies a share by a public `scalar`.
+    #[inline]
+    pub fn mul_scalar(
+        share: ReplicatedShare<F::ValueT>,
+        scalar: F::ValueT,
+    ) -> ReplicatedShare<F::ValueT> {
+        ReplicatedShare {
+            party: share.party,
+            values: share.values.map(|v| F::mul(v, scalar)),
+        }
+    }
+
+    /// Adds a public `constant` to the shared secret, every party does this on its own share.
+    ///
+    /// The constant goes to `r₀`, which is held by party `0` and party `2`.
+    #[inline]
+    pub fn add_constant(
+        share: ReplicatedShare<F::ValueT>,
+        constant: F::ValueT,
+    ) -> ReplicatedShare<F::ValueT> {
+        let mut values = share.values;
+        match share.party {
+            0 => F::add_assign(&mut values[0], constant),
+            2 => F::add_assign(&mut values[1], constant),
+            _ => {}
+        }
+        ReplicatedShare {
+            party: share.party,
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{seq::SliceRandom, thread_rng};
+
+    use crate::U32FieldEval;
+
+    use super::*;
+
+    type Fp = U32FieldEval<132120577>;
+
+    #[test]
+    fn test_shamir() {
+        let mut rng = thread_rng();
+        let distr = uniform::<Fp>();
+        let shamir = Shamir::<Fp>::new(3, 5);
+
+        for _ in 0..20 {
+            let (a, b, c) = (
+                distr.sample(&mut rng),
+                distr.sample(&mut rng),
+                distr.sample(&mut rng),
+            );
+
+            let mut shares_a = shamir.share(a, &mut rng);
+            let shares_b = shamir.share(b, &mut rng);
+
+            shares_a.shuffle(&mut rng);
+            assert_eq!(shamir.reconstruct(&shares_a[..3]), a);
+            assert_eq!(shamir.reconstruct(&shares_a), a);
+            shares_a.sort_by_key(|s| s.x);
+
+            // a * c + b - c
+            let combined: Vec<_> = shares_a
+                .iter()
+                .zip(&shares_b)
+                .map(|(&sa, &sb)| {
+                    let s = Shamir::<Fp>::add(Shamir::<Fp>::mul_scalar(sa, c), sb);
+                    Shamir::<Fp>::add_constant(s, Fp::neg(c))
+                })
+                .collect();
+            assert_eq!(
+                shamir.reconstruct(&combined[2..]),
+                Fp::sub(Fp::mul_add(a, c, b), c)
+            );
+        }
+    }
+
+    #[test]
+    fn test_replicated() {
+        let mut rng = thread_rng();
+        let distr = uniform::<Fp>();
+
+        for _ in 0..20 {
+            let (a, b, c) = (
+                distr.sample(&mut rng),
+                distr.sample(&mut rng),
+                distr.sample(&mut rng),
+            );
+            let shares_a = Replicated::<Fp>::share(a, &mut rng);
+            let shares_b = Replicated::<Fp>::share(b, &mut rng);
+
+            let combined = [0, 1, 2].map(|i| {
+                let s = Replicated::<Fp>::sub(
+                    Replicated::<Fp>::mul_scalar(shares_a[i], c),
+                    shares_b[i],
+                );
+                Replicated::<Fp>::add_constant(s, c)
+            });
+            let expected = Fp::add(Fp::sub(Fp::mul(a, c), b), c);
+
+            for (i, j) in [(0, 1), (1, 2), (2, 0), (1, 0), (2, 1), (0, 2)] {
+                assert_eq!(Replicated::<Fp>::reconstruct(&shares_a[i], &shares_a[j]), a);
+                assert_eq!(
+                    Replicated::<Fp>::reconstruct(&combined[i], &combined[j]),
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! [`Arbitrary`] implementations, which produce valid values,
+//! so structured fuzzing reaches the decoders beyond their first checks.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial, NttPolynomial, Polynomial},
+    reduce::ModulusValue,
+    Field, NttField,
+};
+
+use super::{Envelope, Fingerprint, KeyBound, KeyRecord, LeBytes};
+
+/// Generates `len` values of the field `F`, all less than its modulus.
+pub fn arbitrary_field_values<'a, F: Field>(
+    u: &mut Unstructured<'a>,
+    len: usize,
+) -> Result<Vec<<F as Field>::ValueT>>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    (0..len)
+        .map(|_| Ok(<F as Field>::ValueT::arbitrary(u)? % F::MODULUS_VALUE))
+        .collect()
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Polynomial<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary(u).map(Polynomial::new)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NttPolynomial<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary(u).map(NttPolynomial::new)
+    }
+}
+
+impl<'a, F: Field> Arbitrary<'a> for FieldPolynomial<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        arbitrary_field_values::<F>(u, len).map(FieldPolynomial::new)
+    }
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for FieldNttPolynomial<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        arbitrary_field_values::<F>(u, len).map(FieldNttPolynomial::new)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for ModulusValue<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3u8)? {
+            0 => ModulusValue::Native,
+            1 => ModulusValue::PowerOf2(T::arbitrary(u)?),
+            2 => ModulusValue::Prime(T::arbitrary(u)?),
+            _ => ModulusValue::Others(T::arbitrary(u)?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Fingerprint {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        <[u8; 32]>::arbitrary(u).map(Fingerprint::from_bytes)
+    }
+}
+
+impl<'a, K: Arbitrary<'a> + LeBytes> Arbitrary<'a> for KeyRecord<K> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let owner = Option::<String>::arbitrary(u)?;
+        Ok(KeyRecord::new(K::arbitrary(u)?, owner))
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for KeyBound<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let fingerprint = Fingerprint::arbitrary(u)?;
+        Ok(KeyBound::new(fingerprint, T::arbitrary(u)?))
+    }
+}
+
+/// The envelope is sealed with an arbitrary key, so it is valid for that key only.
+impl<'a, T: Arbitrary<'a> + LeBytes> Arbitrary<'a> for Envelope<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let key = <&[u8]>::arbitrary(u)?;
+        let associated_data = <&[u8]>::arbitrary(u)?;
+        Ok(Envelope::seal(key, associated_data, &T::arbitrary(u)?))
+    }
+}
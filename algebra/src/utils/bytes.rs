@@ -0,0 +1,677 @@
+use rand::SeedableRng;
+
+use crate::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial, NttPolynomial, Polynomial},
+    random::{Block, Prg},
+    reduce::ModulusValue,
+    AlgebraError, Field, NttField,
+};
+
+/// Serialization in a fixed little-endian byte order.
+///
+/// The encoding only depends on the values, not on the endianness of the host
+/// or the layout of the intrinsics types, so the bytes are portable between platforms.
+///
+/// - Integers are written in little-endian order with their full width.
+/// - A [`Block`] is written as its 16 bytes, in the order [`Block::new`] takes them.
+/// - A sequence is written as its length in `u64`, followed by its elements.
+/// - A ciphertext starts with a header, see [`write_header`].
+///
+/// The crate only builds on `x86`, `x86_64` and `aarch64`, where [`Block`] has
+/// intrinsics, and all of them are little-endian, so no test runs on a big-endian
+/// host, e.g. `s390x` natively or under miri. The encoding only goes through
+/// `to_le_bytes` and `from_le_bytes` and the tests compare against written out bytes,
+/// which is what a big-endian host would have to reproduce.
+pub trait LeBytes: Sized {
+    /// Appends the encoding of `self` to `buf`.
+    fn write_le(&self, buf: &mut Vec<u8>);
+
+    /// Reads a value from the front of `bytes` within `limits`, and advances `bytes` past it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `bytes` is too short, holds an invalid value,
+    /// or a value beyond `limits`.
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError>;
+
+    /// Reads a value from the front of `bytes`, and advances `bytes` past it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `bytes` is too short or holds an invalid value.
+    #[inline]
+    fn read_le(bytes: &mut &[u8]) -> Result<Self, AlgebraError> {
+        Self::read_le_with(bytes, &DecodeLimits::UNLIMITED)
+    }
+
+    /// Returns the encoding of `self`.
+    #[inline]
+    fn to_le_vec(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_le(&mut buf);
+        buf
+    }
+
+    /// Decodes a value which takes the whole `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `bytes` is not a valid encoding,
+    /// or has trailing bytes.
+    #[inline]
+    fn from_le_slice(bytes: &[u8]) -> Result<Self, AlgebraError> {
+        Self::from_le_slice_with(bytes, &DecodeLimits::UNLIMITED)
+    }
+
+    /// Decodes a value which takes the whole `bytes`, within `limits`.
+    ///
+    /// Use it for bytes from an untrusted source: the size of `bytes` is checked
+    /// before anything is read, and the sizes declared in `bytes` before anything
+    /// is allocated for them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `bytes` is not a valid encoding,
+    /// has trailing bytes, or is beyond `limits`.
+    #[inline]
+    fn from_le_slice_with(mut bytes: &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        if bytes.len() > limits.max_bytes {
+            return Err(AlgebraError::DecodeErr);
+        }
+        let value = Self::read_le_with(&mut bytes, limits)?;
+        if bytes.is_empty() {
+            Ok(value)
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+}
+
+/// The bounds a decoder enforces while it reads untrusted bytes.
+///
+/// Every size is checked as soon as it is read, so an encoding beyond the limits
+/// is rejected before the memory for it is allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// The largest encoding, in bytes.
+    pub max_bytes: usize,
+    /// The largest dimension, i.e. the number of coefficients of a polynomial,
+    /// which is the ring degree of a RLWE ciphertext, or the dimension of a LWE ciphertext.
+    pub max_dimension: usize,
+    /// The largest number of levels of a gadget ciphertext, i.e. its decompose length.
+    pub max_levels: usize,
+}
+
+impl DecodeLimits {
+    /// No limits, for trusted bytes.
+    pub const UNLIMITED: Self = Self {
+        max_bytes: usize::MAX,
+        max_dimension: usize::MAX,
+        max_levels: usize::MAX,
+    };
+
+    /// Checks a `dimension` read from an encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `dimension` is larger than `max_dimension`.
+    #[inline]
+    pub fn check_dimension(&self, dimension: usize) -> Result<(), AlgebraError> {
+        if dimension <= self.max_dimension {
+            Ok(())
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+
+    /// Checks a number of `levels` read from an encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::DecodeErr`] if `levels` is larger than `max_levels`.
+    #[inline]
+    pub fn check_levels(&self, levels: usize) -> Result<(), AlgebraError> {
+        if levels <= self.max_levels {
+            Ok(())
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+}
+
+impl Default for DecodeLimits {
+    #[inline]
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// The version of the ciphertext encodings.
+///
+/// It must be bumped whenever the layout of a ciphertext changes. Encoders always
+/// write the current version, decoders accept every version from 1 up to the current
+/// one and upgrade the older layouts while reading them.
+///
+/// - Version 1: the header, followed by the parts of the ciphertext.
+/// - Version 2: the header is followed by a descriptor of the values,
+///   see [`write_width`] and [`write_modulus`], so a ciphertext can't be decoded
+///   with another integer type or field than it was encoded with.
+pub const FORMAT_VERSION: u8 = 2;
+
+/// Writes the header of a ciphertext encoding: the four bytes `tag` of its type,
+/// followed by [`FORMAT_VERSION`].
+///
+/// The parts of a ciphertext are written without header.
+#[inline]
+pub fn write_header(tag: &[u8; 4], buf: &mut Vec<u8>) {
+    buf.extend_from_slice(tag);
+    buf.push(FORMAT_VERSION);
+}
+
+/// Reads the header written by [`write_header`] from the front of `bytes`,
+/// and returns the version of the encoding which follows it.
+///
+/// # Errors
+///
+/// Returns [`AlgebraError::DecodeErr`] if `bytes` does not start with `tag`
+/// and a version between 1 and [`FORMAT_VERSION`].
+#[inline]
+pub fn read_header(tag: &[u8; 4], bytes: &mut &[u8]) -> Result<u8, AlgebraError> {
+    if take::<4>(bytes)? != *tag {
+        return Err(AlgebraError::DecodeErr);
+    }
+    match take::<1>(bytes)? {
+        [version @ 1..=FORMAT_VERSION] => Ok(version),
+        _ => Err(AlgebraError::DecodeErr),
+    }
+}
+
+/// Writes the width in bytes of the integer type `T`, as one byte.
+///
+/// It is the descriptor of the ciphertexts over integers since version 2.
+#[inline]
+pub fn write_width<T>(buf: &mut Vec<u8>) {
+    buf.push(core::mem::size_of::<T>() as u8);
+}
+
+/// Reads the descriptor written by [`write_width`] if the encoding has one,
+/// i.e. its `version` is at least 2.
+///
+/// # Errors
+///
+/// Returns [`AlgebraError::DecodeErr`] if the width is not the one of `T`.
+#[inline]
+pub fn read_width<T>(version: u8, bytes: &mut &[u8]) -> Result<(), AlgebraError> {
+    if version < 2 || u8::read_le(bytes)? as usize == core::mem::size_of::<T>() {
+        Ok(())
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+/// Writes the modulus of the field `F` as one value.
+///
+/// It is the descriptor of the ciphertexts over fields since version 2.
+#[inline]
+pub fn write_modulus<F: Field>(buf: &mut Vec<u8>)
+where
+    F::ValueT: LeBytes,
+{
+    F::MODULUS_VALUE.write_le(buf);
+}
+
+/// Reads the descriptor written by [`write_modulus`] if the encoding has one,
+/// i.e. its `version` is at least 2.
+///
+/// # Errors
+///
+/// Returns [`AlgebraError::DecodeErr`] if the modulus is not the one of `F`.
+#[inline]
+pub fn read_modulus<F: Field>(version: u8, bytes: &mut &[u8]) -> Result<(), AlgebraError>
+where
+    F::ValueT: LeBytes,
+{
+    if version < 2 || F::ValueT::read_le(bytes)? == F::MODULUS_VALUE {
+        Ok(())
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+/// Splits the first `N` bytes off `bytes`.
+#[inline]
+fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], AlgebraError> {
+    let (head, tail) = bytes
+        .split_first_chunk::<N>()
+        .ok_or(AlgebraError::DecodeErr)?;
+    *bytes = tail;
+    Ok(*head)
+}
+
+macro_rules! impl_le_bytes_for_unsigned {
+    ($($T:ty),*) => {
+        $(impl LeBytes for $T {
+            #[inline]
+            fn write_le(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+                take(bytes).map(<$T>::from_le_bytes)
+            }
+        })*
+    };
+}
+
+impl_le_bytes_for_unsigned!(u8, u16, u32, u64, u128);
+
+/// A float is written as its IEEE 754 bits, see [`f64::to_bits`].
+impl LeBytes for f64 {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.to_bits().write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        u64::read_le(bytes).map(f64::from_bits)
+    }
+}
+
+impl LeBytes for Block {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&<[u8; 16]>::from(*self));
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        take(bytes).map(|b| Block::new(&b))
+    }
+}
+
+/// A [`Prg`] is written as its seed followed by its word position in `u64`,
+/// so a decoded [`Prg`] continues with the output of the encoded one.
+impl LeBytes for Prg {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.seed().write_le(buf);
+        self.word_pos().write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let mut prg = Prg::from_seed(Block::read_le(bytes)?);
+        prg.set_word_pos(u64::read_le(bytes)?);
+        Ok(prg)
+    }
+}
+
+/// A modulus value is written as a byte, `0` for [`ModulusValue::Native`], `1` for
+/// [`ModulusValue::PowerOf2`], `2` for [`ModulusValue::Prime`] and `3` for [`ModulusValue::Others`],
+/// followed by the value unless it is native.
+impl<T: LeBytes> LeBytes for ModulusValue<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        match self {
+            ModulusValue::Native => buf.push(0),
+            ModulusValue::PowerOf2(value) => {
+                buf.push(1);
+                value.write_le(buf);
+            }
+            ModulusValue::Prime(value) => {
+                buf.push(2);
+                value.write_le(buf);
+            }
+            ModulusValue::Others(value) => {
+                buf.push(3);
+                value.write_le(buf);
+            }
+        }
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        match u8::read_le(bytes)? {
+            0 => Ok(ModulusValue::Native),
+            1 => T::read_le_with(bytes, limits).map(ModulusValue::PowerOf2),
+            2 => T::read_le_with(bytes, limits).map(ModulusValue::Prime),
+            3 => T::read_le_with(bytes, limits).map(ModulusValue::Others),
+            _ => Err(AlgebraError::DecodeErr),
+        }
+    }
+}
+
+/// Writes `values` as a sequence.
+#[inline]
+fn write_slice<T: LeBytes>(values: &[T], buf: &mut Vec<u8>) {
+    (values.len() as u64).write_le(buf);
+    values.iter().for_each(|v| v.write_le(buf));
+}
+
+/// Reads the length of a sequence, which can't be longer than the remaining `bytes`.
+#[inline]
+fn read_len(bytes: &mut &[u8]) -> Result<usize, AlgebraError> {
+    let len = usize::try_from(u64::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)?;
+    // every element takes at least one byte
+    if len <= bytes.len() {
+        Ok(len)
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+impl<T: LeBytes> LeBytes for Vec<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_slice(self, buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let len = read_len(bytes)?;
+        (0..len).map(|_| T::read_le_with(bytes, limits)).collect()
+    }
+}
+
+/// Reads a sequence of coefficients, e.g. of a polynomial or the vector of a LWE ciphertext.
+///
+/// # Errors
+///
+/// Returns [`AlgebraError::DecodeErr`] if `bytes` is not a valid sequence,
+/// or declares more than `max_dimension` coefficients.
+pub fn read_coeffs<T: LeBytes>(
+    bytes: &mut &[u8],
+    limits: &DecodeLimits,
+) -> Result<Vec<T>, AlgebraError> {
+    let len = read_len(bytes)?;
+    limits.check_dimension(len)?;
+    (0..len).map(|_| T::read_le_with(bytes, limits)).collect()
+}
+
+/// Reads a sequence of chunks of `stride` values, e.g. the buffer of a list of ciphertexts
+/// with `stride` values each.
+///
+/// # Errors
+///
+/// Returns [`AlgebraError::DecodeErr`] if `bytes` is not a valid sequence, its length is not
+/// a multiple of `stride`, or it declares more than `max_dimension` chunks.
+pub fn read_chunks<T: LeBytes>(
+    bytes: &mut &[u8],
+    stride: usize,
+    limits: &DecodeLimits,
+) -> Result<Vec<T>, AlgebraError> {
+    let len = read_len(bytes)?;
+    if stride == 0 || len % stride != 0 {
+        return Err(AlgebraError::DecodeErr);
+    }
+    limits.check_dimension(len / stride)?;
+    (0..len).map(|_| T::read_le_with(bytes, limits)).collect()
+}
+
+/// Reads the values of a polynomial over `F`, which must be less than the modulus.
+fn read_field_values<F: Field>(
+    bytes: &mut &[u8],
+    limits: &DecodeLimits,
+) -> Result<Vec<F::ValueT>, AlgebraError>
+where
+    F::ValueT: LeBytes,
+{
+    let values = read_coeffs::<F::ValueT>(bytes, limits)?;
+    if values.iter().all(|&v| v < F::MODULUS_VALUE) {
+        Ok(values)
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+impl<T: LeBytes> LeBytes for Polynomial<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_slice(self.as_slice(), buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_coeffs(bytes, limits).map(Polynomial::new)
+    }
+}
+
+impl<T: LeBytes> LeBytes for NttPolynomial<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_slice(self.as_slice(), buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_coeffs(bytes, limits).map(NttPolynomial::new)
+    }
+}
+
+impl<F: Field> LeBytes for FieldPolynomial<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_slice(self.as_slice(), buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_field_values::<F>(bytes, limits).map(FieldPolynomial::new)
+    }
+}
+
+impl<F: NttField> LeBytes for FieldNttPolynomial<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_slice(self.as_slice(), buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_field_values::<F>(bytes, limits).map(FieldNttPolynomial::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::U32FieldEval;
+
+    use super::*;
+
+    type Fp = U32FieldEval<132120577>;
+
+    #[test]
+    fn test_le_bytes_format() {
+        assert_eq!(0x01020304u32.to_le_vec(), [4, 3, 2, 1]);
+        assert_eq!(
+            Polynomial::new(vec![1u16, 0x0203]).to_le_vec(),
+            [2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 2]
+        );
+
+        let bytes: [u8; 16] = core::array::from_fn(|i| i as u8);
+        assert_eq!(Block::new(&bytes).to_le_vec(), bytes);
+        assert_eq!(Block::from_le_slice(&bytes).unwrap(), Block::new(&bytes));
+    }
+
+    #[test]
+    fn test_le_bytes_layout() {
+        // the expected bytes are written out, so a big-endian host has to produce them too
+        assert_eq!(0xabu8.to_le_vec(), [0xab]);
+        assert_eq!(0x0102u16.to_le_vec(), [2, 1]);
+        assert_eq!(0x0102030405060708u64.to_le_vec(), [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(0.5f64.to_le_vec(), [0, 0, 0, 0, 0, 0, 0xe0, 0x3f]);
+        let wide: u128 = 0x0f0e0d0c0b0a09080706050403020100;
+        let wide_bytes: Vec<u8> = (0..16).collect();
+        assert_eq!(wide.to_le_vec(), wide_bytes);
+        assert_eq!(u128::from_le_slice(&wide_bytes).unwrap(), wide);
+
+        assert_eq!(ModulusValue::<u16>::Native.to_le_vec(), [0]);
+        assert_eq!(ModulusValue::PowerOf2(0x0100u16).to_le_vec(), [1, 0, 1]);
+        assert_eq!(
+            ModulusValue::<u16>::from_le_slice(&[2, 0x11, 0]).unwrap(),
+            ModulusValue::Prime(0x11)
+        );
+        assert!(ModulusValue::<u16>::from_le_slice(&[4, 0, 0]).is_err());
+
+        let nested = vec![vec![0x0102u16], vec![]];
+        let nested_bytes = [
+            2, 0, 0, 0, 0, 0, 0, 0, // outer length
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 1, // `[0x0102]`
+            0, 0, 0, 0, 0, 0, 0, 0, // `[]`
+        ];
+        assert_eq!(nested.to_le_vec(), nested_bytes);
+        assert_eq!(
+            Vec::<Vec<u16>>::from_le_slice(&nested_bytes).unwrap(),
+            nested
+        );
+
+        let poly = <FieldPolynomial<Fp>>::new(vec![0x07df0001, 0x05060708]);
+        let poly_bytes = [2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0xdf, 7, 8, 7, 6, 5];
+        assert_eq!(poly.to_le_vec(), poly_bytes);
+        assert_eq!(
+            <FieldPolynomial<Fp>>::from_le_slice(&poly_bytes).unwrap(),
+            poly
+        );
+        let ntt_poly = <FieldNttPolynomial<Fp>>::new(poly.inner_data());
+        assert_eq!(ntt_poly.to_le_vec(), poly_bytes);
+        assert_eq!(
+            <FieldNttPolynomial<Fp>>::from_le_slice(&poly_bytes).unwrap(),
+            ntt_poly
+        );
+    }
+
+    #[test]
+    fn test_header() {
+        let mut buf = Vec::new();
+        write_header(b"TEST", &mut buf);
+        assert_eq!(buf, [b'T', b'E', b'S', b'T', FORMAT_VERSION]);
+
+        let mut bytes = &buf[..];
+        assert_eq!(read_header(b"TEST", &mut bytes).unwrap(), FORMAT_VERSION);
+        assert!(bytes.is_empty());
+
+        // an older version is still read
+        buf[4] = 1;
+        assert_eq!(read_header(b"TEST", &mut &buf[..]).unwrap(), 1);
+
+        // another type, a newer version, no version, truncated
+        assert!(read_header(b"TSET", &mut &buf[..]).is_err());
+        buf[4] = FORMAT_VERSION + 1;
+        assert!(read_header(b"TEST", &mut &buf[..]).is_err());
+        buf[4] = 0;
+        assert!(read_header(b"TEST", &mut &buf[..]).is_err());
+        assert!(read_header(b"TEST", &mut &buf[..4]).is_err());
+    }
+
+    #[test]
+    fn test_descriptor() {
+        let mut buf = Vec::new();
+        write_width::<u32>(&mut buf);
+        write_modulus::<Fp>(&mut buf);
+        assert_eq!(buf, [4, 1, 0, 0xe0, 7]);
+
+        let mut bytes = &buf[..];
+        assert!(read_width::<u32>(FORMAT_VERSION, &mut bytes).is_ok());
+        assert!(read_modulus::<Fp>(FORMAT_VERSION, &mut bytes).is_ok());
+        assert!(bytes.is_empty());
+
+        // another type, another field
+        assert!(read_width::<u64>(FORMAT_VERSION, &mut &buf[..]).is_err());
+        let other = 132120577u32.to_le_vec();
+        assert!(read_modulus::<U32FieldEval<65537>>(FORMAT_VERSION, &mut &other[..]).is_err());
+
+        // version 1 has no descriptor
+        let mut bytes = &buf[..];
+        assert!(read_width::<u64>(1, &mut bytes).is_ok());
+        assert!(read_modulus::<U32FieldEval<65537>>(1, &mut bytes).is_ok());
+        assert_eq!(bytes, buf);
+    }
+
+    #[test]
+    fn test_decode_limits() {
+        let poly = Polynomial::new(vec![1u32; 16]);
+        let bytes = poly.to_le_vec();
+
+        let limits = DecodeLimits {
+            max_bytes: bytes.len(),
+            max_dimension: 16,
+            max_levels: 0,
+        };
+        assert_eq!(
+            Polynomial::from_le_slice_with(&bytes, &limits).unwrap(),
+            poly
+        );
+
+        // too many bytes, too many coefficients
+        let fewer_bytes = DecodeLimits {
+            max_bytes: bytes.len() - 1,
+            ..limits
+        };
+        assert!(Polynomial::<u32>::from_le_slice_with(&bytes, &fewer_bytes).is_err());
+        let lower_dimension = DecodeLimits {
+            max_dimension: 15,
+            ..limits
+        };
+        assert!(Polynomial::<u32>::from_le_slice_with(&bytes, &lower_dimension).is_err());
+
+        // the declared length is rejected without reading the coefficients
+        let huge = (1u64 << 40).to_le_bytes();
+        assert!(<FieldPolynomial<Fp>>::from_le_slice_with(&huge, &lower_dimension).is_err());
+
+        // a sequence which is not a polynomial is not bounded by the dimension
+        let values = vec![1u8; 32];
+        assert_eq!(
+            Vec::<u8>::from_le_slice_with(&values.to_le_vec(), &lower_dimension).unwrap(),
+            values
+        );
+
+        assert!(limits.check_levels(1).is_err());
+        assert!(DecodeLimits::default().check_levels(usize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_le_bytes_round_trip() {
+        let mut rng = thread_rng();
+
+        let block: Block = rng.gen();
+        assert_eq!(Block::from_le_slice(&block.to_le_vec()).unwrap(), block);
+
+        let mut prg = Prg::from_seed(block);
+        prg.random_bytes(&mut [0; 21]);
+        let bytes = prg.to_le_vec();
+        assert_eq!(bytes.len(), 16 + 8);
+        assert_eq!(
+            Prg::from_le_slice(&bytes).unwrap().next_u64(),
+            prg.next_u64()
+        );
+
+        let poly = <FieldPolynomial<Fp>>::random(1024, &mut rng);
+        let bytes = poly.to_le_vec();
+        assert_eq!(bytes.len(), 8 + 4 * 1024);
+        assert_eq!(<FieldPolynomial<Fp>>::from_le_slice(&bytes).unwrap(), poly);
+
+        let ntt_poly = <FieldNttPolynomial<Fp>>::new(poly.clone().inner_data());
+        assert_eq!(
+            <FieldNttPolynomial<Fp>>::from_le_slice(&ntt_poly.to_le_vec()).unwrap(),
+            ntt_poly
+        );
+
+        // truncated, trailing bytes, value out of range
+        assert!(<FieldPolynomial<Fp>>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+        assert!(u32::from_le_slice(&[0; 5]).is_err());
+        let mut bad = bytes.clone();
+        bad[8..12].copy_from_slice(&132120577u32.to_le_bytes());
+        assert!(<FieldPolynomial<Fp>>::from_le_slice(&bad).is_err());
+        // a huge length
+        assert!(Vec::<u8>::from_le_slice(&u64::MAX.to_le_bytes()).is_err());
+    }
+}
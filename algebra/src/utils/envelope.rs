@@ -0,0 +1,164 @@
+use core::marker::PhantomData;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::AlgebraError;
+
+use super::{read_header, write_header, DecodeLimits, LeBytes};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The encoding of a value, e.g. a ciphertext or a key, authenticated with
+/// HMAC-SHA256 together with some associated data, e.g. a hash of the parameters
+/// or the id of the sender.
+///
+/// The tag is verified before the value is decoded, so bytes tampered with in
+/// transport are rejected before anything is allocated or evaluated for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Envelope<T> {
+    associated_data: Vec<u8>,
+    payload: Vec<u8>,
+    tag: [u8; 32],
+    value: PhantomData<T>,
+}
+
+impl<T: LeBytes> Envelope<T> {
+    /// Seals `value` and `associated_data` with `key`.
+    pub fn seal(key: &[u8], associated_data: &[u8], value: &T) -> Self {
+        let mut envelope = Self {
+            associated_data: associated_data.to_vec(),
+            payload: value.to_le_vec(),
+            tag: [0; 32],
+            value: PhantomData,
+        };
+        envelope.tag = envelope.mac(key).finalize().into_bytes().into();
+        envelope
+    }
+
+    /// Returns the associated data of this [`Envelope<T>`].
+    ///
+    /// It is only authenticated once the envelope is opened.
+    #[inline]
+    pub fn associated_data(&self) -> &[u8] {
+        &self.associated_data
+    }
+
+    /// Verifies the tag with `key`, then decodes the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::AuthenticationErr`] if the envelope was not sealed with `key`,
+    /// or was modified afterwards, and [`AlgebraError::DecodeErr`] if the value fails to decode.
+    #[inline]
+    pub fn open(&self, key: &[u8]) -> Result<T, AlgebraError> {
+        self.open_with(key, &DecodeLimits::UNLIMITED)
+    }
+
+    /// Verifies the tag with `key`, then decodes the value within `limits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::AuthenticationErr`] if the envelope was not sealed with `key`,
+    /// or was modified afterwards, and [`AlgebraError::DecodeErr`] if the value fails to decode.
+    pub fn open_with(&self, key: &[u8], limits: &DecodeLimits) -> Result<T, AlgebraError> {
+        self.mac(key)
+            .verify_slice(&self.tag)
+            .map_err(|_| AlgebraError::AuthenticationErr)?;
+        T::from_le_slice_with(&self.payload, limits)
+    }
+
+    /// Returns the MAC with `key` over the encoding of the envelope without its tag.
+    fn mac(&self, key: &[u8]) -> HmacSha256 {
+        let mut buf = Vec::with_capacity(self.associated_data.len() + self.payload.len() + 21);
+        write_header(b"ENVL", &mut buf);
+        self.associated_data.write_le(&mut buf);
+        self.payload.write_le(&mut buf);
+
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC takes keys of any length");
+        mac.update(&buf);
+        mac
+    }
+}
+
+/// The envelope is encoded after its header as the associated data and the encoding
+/// of the value, both as sequences of bytes, followed by the 32 bytes of the tag,
+/// which authenticates everything before it.
+impl<T: LeBytes> LeBytes for Envelope<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"ENVL", buf);
+        self.associated_data.write_le(buf);
+        self.payload.write_le(buf);
+        buf.extend_from_slice(&self.tag);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"ENVL", bytes)?;
+        let associated_data = Vec::read_le(bytes)?;
+        let payload = Vec::read_le(bytes)?;
+        let (tag, tail) = bytes
+            .split_first_chunk::<32>()
+            .ok_or(AlgebraError::DecodeErr)?;
+        *bytes = tail;
+        Ok(Self {
+            associated_data,
+            payload,
+            tag: *tag,
+            value: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"a key shared by client and server";
+
+    #[test]
+    fn test_envelope() {
+        let value = vec![1u32, 2, 3];
+        let envelope = Envelope::seal(KEY, b"params", &value);
+        let bytes = envelope.to_le_vec();
+
+        let decoded = Envelope::<Vec<u32>>::from_le_slice(&bytes).unwrap();
+        assert_eq!(decoded, envelope);
+        assert_eq!(decoded.associated_data(), b"params");
+        assert_eq!(decoded.open(KEY).unwrap(), value);
+
+        assert!(matches!(
+            decoded.open(b"another key"),
+            Err(AlgebraError::AuthenticationErr)
+        ));
+        assert!(Envelope::<Vec<u32>>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_envelope_tampering() {
+        let bytes = Envelope::seal(KEY, b"params", &vec![1u32, 2, 3]).to_le_vec();
+
+        // every flipped bit, in the associated data, the value or the tag, is detected
+        for i in 5..bytes.len() {
+            let mut bad = bytes.clone();
+            bad[i] ^= 1;
+            if let Ok(envelope) = Envelope::<Vec<u32>>::from_le_slice(&bad) {
+                assert!(matches!(
+                    envelope.open(KEY),
+                    Err(AlgebraError::AuthenticationErr)
+                ));
+            }
+        }
+
+        // the limits apply to the value once it is authenticated
+        let envelope = Envelope::<Vec<u32>>::from_le_slice(&bytes).unwrap();
+        let limits = DecodeLimits {
+            max_bytes: 8,
+            ..DecodeLimits::UNLIMITED
+        };
+        assert!(matches!(
+            envelope.open_with(KEY, &limits),
+            Err(AlgebraError::DecodeErr)
+        ));
+    }
+}
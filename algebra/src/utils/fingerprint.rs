@@ -0,0 +1,265 @@
+use core::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::AlgebraError;
+
+use super::{read_header, write_header, DecodeLimits, LeBytes};
+
+/// The fingerprint of a key: the SHA-256 digest of its [`LeBytes`] encoding.
+///
+/// It only depends on the content of the key, so the same key has the same
+/// fingerprint on every machine. It is computed over the current encoding,
+/// so a key read from an older [`FORMAT_VERSION`](super::FORMAT_VERSION) gets
+/// the fingerprint of its upgraded encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint([u8; 32]);
+
+impl Fingerprint {
+    /// Computes the fingerprint of `key`.
+    #[inline]
+    pub fn of<K: LeBytes>(key: &K) -> Self {
+        Self::of_bytes(&key.to_le_vec())
+    }
+
+    /// Computes the fingerprint of the key with the encoding `bytes`.
+    #[inline]
+    pub fn of_bytes(bytes: &[u8]) -> Self {
+        Self(Sha256::digest(bytes).into())
+    }
+
+    /// Creates a [`Fingerprint`] from its digest.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the digest of this [`Fingerprint`].
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Formats the digest in lowercase hexadecimal.
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+/// A fingerprint is encoded as its 32 bytes.
+impl LeBytes for Fingerprint {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let (head, tail) = bytes
+            .split_first_chunk::<32>()
+            .ok_or(AlgebraError::DecodeErr)?;
+        *bytes = tail;
+        Ok(Self(*head))
+    }
+}
+
+/// A key with optional metadata about its owner, e.g. the id of a client.
+///
+/// The owner is stored with the key, but it is not part of its [`Fingerprint`],
+/// so a key keeps its identity when the metadata changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRecord<K> {
+    key: K,
+    owner: Option<String>,
+}
+
+impl<K: LeBytes> KeyRecord<K> {
+    /// Creates a new [`KeyRecord<K>`].
+    #[inline]
+    pub fn new(key: K, owner: Option<String>) -> Self {
+        Self { key, owner }
+    }
+
+    /// Returns a reference to the key of this [`KeyRecord<K>`].
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the owner of the key, if it has one.
+    #[inline]
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Returns the key of this [`KeyRecord<K>`].
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Computes the fingerprint of the key.
+    #[inline]
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::of(&self.key)
+    }
+}
+
+/// The record is encoded after its header as a byte `0` without owner,
+/// or `1` followed by the owner as a sequence of UTF-8 bytes,
+/// followed by the encoding of the key.
+impl<K: LeBytes> LeBytes for KeyRecord<K> {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"KREC", buf);
+        match &self.owner {
+            None => buf.push(0),
+            Some(owner) => {
+                buf.push(1);
+                owner.as_bytes().to_vec().write_le(buf);
+            }
+        }
+        self.key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"KREC", bytes)?;
+        let owner = match u8::read_le(bytes)? {
+            0 => None,
+            1 => {
+                Some(String::from_utf8(Vec::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)?)
+            }
+            _ => return Err(AlgebraError::DecodeErr),
+        };
+        let key = K::read_le_with(bytes, limits)?;
+        Ok(Self { key, owner })
+    }
+}
+
+/// A value, e.g. a ciphertext, bound to the [`Fingerprint`] of the key it belongs to.
+///
+/// A server holding the keys of many clients checks the binding before it
+/// evaluates anything, instead of computing garbage with the key of another client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBound<T> {
+    fingerprint: Fingerprint,
+    value: T,
+}
+
+impl<T> KeyBound<T> {
+    /// Binds `value` to the key with `fingerprint`.
+    #[inline]
+    pub fn new(fingerprint: Fingerprint, value: T) -> Self {
+        Self { fingerprint, value }
+    }
+
+    /// Returns the fingerprint of the key this value belongs to.
+    #[inline]
+    pub fn fingerprint(&self) -> &Fingerprint {
+        &self.fingerprint
+    }
+
+    /// Returns the value if it belongs to the key with `fingerprint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::KeyMismatch`] if the value belongs to another key.
+    #[inline]
+    pub fn check(&self, fingerprint: &Fingerprint) -> Result<&T, AlgebraError> {
+        if self.fingerprint == *fingerprint {
+            Ok(&self.value)
+        } else {
+            Err(AlgebraError::KeyMismatch)
+        }
+    }
+
+    /// Returns the value without checking the key it belongs to.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// The bound value is encoded after its header as the fingerprint,
+/// followed by the encoding of the value.
+impl<T: LeBytes> LeBytes for KeyBound<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"KBND", buf);
+        self.fingerprint.write_le(buf);
+        self.value.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"KBND", bytes)?;
+        let fingerprint = Fingerprint::read_le(bytes)?;
+        let value = T::read_le_with(bytes, limits)?;
+        Ok(Self { fingerprint, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint() {
+        // SHA-256 of the encoding `[3, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3]`
+        let key = vec![1u8, 2, 3];
+        let fingerprint = Fingerprint::of(&key);
+        assert_eq!(
+            fingerprint.to_string(),
+            "265540042a8cde29ccc2ba937d5b4dd770b6d9efd2a7a8a07545d22a960b6d7c"
+        );
+        assert_ne!(fingerprint, Fingerprint::of(&vec![1u8, 2, 4]));
+        assert_eq!(
+            Fingerprint::from_le_slice(&fingerprint.to_le_vec()).unwrap(),
+            fingerprint
+        );
+        assert!(Fingerprint::from_le_slice(&[0; 31]).is_err());
+    }
+
+    #[test]
+    fn test_key_record() {
+        let record = KeyRecord::new(vec![1u8, 2, 3], Some("client 7".to_string()));
+        let bytes = record.to_le_vec();
+        let decoded = KeyRecord::<Vec<u8>>::from_le_slice(&bytes).unwrap();
+        assert_eq!(decoded, record);
+        assert_eq!(decoded.owner(), Some("client 7"));
+
+        // the owner is not part of the fingerprint
+        let anonymous = KeyRecord::new(vec![1u8, 2, 3], None);
+        assert_eq!(anonymous.fingerprint(), record.fingerprint());
+        assert_eq!(
+            KeyRecord::<Vec<u8>>::from_le_slice(&anonymous.to_le_vec()).unwrap(),
+            anonymous
+        );
+
+        // an invalid owner flag, an owner which is not UTF-8
+        let mut bad = bytes.clone();
+        bad[5] = 2;
+        assert!(KeyRecord::<Vec<u8>>::from_le_slice(&bad).is_err());
+        let mut bad = bytes.clone();
+        bad[14] = 0xff;
+        assert!(KeyRecord::<Vec<u8>>::from_le_slice(&bad).is_err());
+    }
+
+    #[test]
+    fn test_key_bound() {
+        let key = vec![1u8, 2, 3];
+        let other = vec![4u8];
+        let bound = KeyBound::new(Fingerprint::of(&key), 42u32);
+
+        assert_eq!(bound.check(&Fingerprint::of(&key)).unwrap(), &42);
+        assert!(matches!(
+            bound.check(&Fingerprint::of(&other)),
+            Err(AlgebraError::KeyMismatch)
+        ));
+
+        let decoded = KeyBound::<u32>::from_le_slice(&bound.to_le_vec()).unwrap();
+        assert_eq!(decoded, bound);
+        assert_eq!(decoded.into_inner(), 42);
+    }
+}
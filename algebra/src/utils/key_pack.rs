@@ -0,0 +1,313 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::AlgebraError;
+
+use super::{read_header, write_header, DecodeLimits, Fingerprint, LeBytes};
+
+/// The length of a header, see [`write_header`].
+const HEADER_LEN: u64 = 5;
+
+/// The entry of one key in a [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    name: String,
+    fingerprint: Fingerprint,
+    offset: u64,
+    len: u64,
+}
+
+impl ManifestEntry {
+    /// Returns the name of the key.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the fingerprint of the key.
+    #[inline]
+    pub fn fingerprint(&self) -> &Fingerprint {
+        &self.fingerprint
+    }
+
+    /// Returns the length of the encoding of the key, in bytes.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the encoding of the key is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The entry is encoded as its name as a sequence of UTF-8 bytes, the fingerprint,
+/// and the offset and the length of the key in `u64`.
+impl LeBytes for ManifestEntry {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.name.as_bytes().to_vec().write_le(buf);
+        self.fingerprint.write_le(buf);
+        self.offset.write_le(buf);
+        self.len.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let name = String::from_utf8(Vec::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)?;
+        let fingerprint = Fingerprint::read_le(bytes)?;
+        let offset = u64::read_le(bytes)?;
+        let len = u64::read_le(bytes)?;
+        Ok(Self {
+            name,
+            fingerprint,
+            offset,
+            len,
+        })
+    }
+}
+
+/// The table of contents of a [`KeyPack`]: the name, fingerprint and position of every key.
+///
+/// A server reads the manifest with [`Manifest::read_from`], then loads only the keys
+/// its workload needs with [`Manifest::load`], e.g. the key switching keys without
+/// the blind rotation key, and never reads the others.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Returns the entries of this [`Manifest`], in the order of the keys in the pack.
+    #[inline]
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    /// Returns the entry of the key named `name`.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&ManifestEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Reads the header and the manifest of a key pack from `reader`, within `limits`,
+    /// without reading any key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `reader`, or an error of kind [`io::ErrorKind::InvalidData`]
+    /// if the manifest is not valid or larger than `max_bytes`.
+    pub fn read_from<R: Read>(reader: &mut R, limits: &DecodeLimits) -> io::Result<Self> {
+        let mut prefix = [0; HEADER_LEN as usize + 8];
+        reader.read_exact(&mut prefix)?;
+        let mut bytes = &prefix[..];
+        read_header(b"KPCK", &mut bytes).map_err(invalid_data)?;
+
+        let len = u64::read_le(&mut bytes).map_err(invalid_data)?;
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|&len| len <= limits.max_bytes)
+            .ok_or_else(|| invalid_data(AlgebraError::DecodeErr))?;
+        let mut manifest = vec![0; len];
+        reader.read_exact(&mut manifest)?;
+        Self::from_le_slice_with(&manifest, limits).map_err(invalid_data)
+    }
+
+    /// Loads the key named `name` from the key pack in `reader`, within `limits`.
+    ///
+    /// Only the bytes of that key are read, and they are checked against its fingerprint
+    /// before they are decoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::NotFound`] if the pack has no key named `name`,
+    /// the error of `reader`, or an error of kind [`io::ErrorKind::InvalidData`] if the key
+    /// does not match its fingerprint, fails to decode, or is larger than `max_bytes`.
+    pub fn load<K, R>(&self, reader: &mut R, name: &str, limits: &DecodeLimits) -> io::Result<K>
+    where
+        K: LeBytes,
+        R: Read + Seek,
+    {
+        let entry = self
+            .get(name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, name.to_owned()))?;
+        let len = usize::try_from(entry.len)
+            .ok()
+            .filter(|&len| len <= limits.max_bytes)
+            .ok_or_else(|| invalid_data(AlgebraError::DecodeErr))?;
+
+        let manifest_len = self.to_le_vec().len() as u64;
+        reader.seek(SeekFrom::Start(
+            HEADER_LEN + 8 + manifest_len + entry.offset,
+        ))?;
+        let mut bytes = vec![0; len];
+        reader.read_exact(&mut bytes)?;
+
+        if Fingerprint::of_bytes(&bytes) != entry.fingerprint {
+            return Err(invalid_data(AlgebraError::KeyMismatch));
+        }
+        K::from_le_slice_with(&bytes, limits).map_err(invalid_data)
+    }
+}
+
+/// The manifest is encoded as the sequence of its entries. The keys follow each other
+/// without gaps in the order of the entries, and have different names.
+impl LeBytes for Manifest {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.entries.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let entries = Vec::<ManifestEntry>::read_le_with(bytes, limits)?;
+
+        let mut offset = 0u64;
+        for (i, entry) in entries.iter().enumerate() {
+            if entry.offset != offset || entries[..i].iter().any(|e| e.name == entry.name) {
+                return Err(AlgebraError::DecodeErr);
+            }
+            offset = offset
+                .checked_add(entry.len)
+                .ok_or(AlgebraError::DecodeErr)?;
+        }
+        Ok(Self { entries })
+    }
+}
+
+/// A pack of keys, e.g. the evaluation keys of a client, behind a [`Manifest`],
+/// so that a server loads only the keys it needs.
+///
+/// The pack is written after its header as the length of its manifest in `u64`,
+/// the manifest, and the encodings of the keys one after another.
+#[derive(Debug, Clone, Default)]
+pub struct KeyPack {
+    manifest: Manifest,
+    keys: Vec<u8>,
+}
+
+impl KeyPack {
+    /// Creates a new empty [`KeyPack`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `key` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pack already has a key named `name`.
+    pub fn push<K: LeBytes>(&mut self, name: impl Into<String>, key: &K) {
+        let name = name.into();
+        assert!(self.manifest.get(&name).is_none(), "duplicate key {name}");
+
+        let offset = self.keys.len();
+        key.write_le(&mut self.keys);
+        self.manifest.entries.push(ManifestEntry {
+            name,
+            fingerprint: Fingerprint::of_bytes(&self.keys[offset..]),
+            offset: offset as u64,
+            len: (self.keys.len() - offset) as u64,
+        });
+    }
+
+    /// Returns the manifest of this [`KeyPack`].
+    #[inline]
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    /// Writes this [`KeyPack`] to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `writer`.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let manifest = self.manifest.to_le_vec();
+        let mut prefix = Vec::with_capacity(HEADER_LEN as usize + 8);
+        write_header(b"KPCK", &mut prefix);
+        (manifest.len() as u64).write_le(&mut prefix);
+
+        writer.write_all(&prefix)?;
+        writer.write_all(&manifest)?;
+        writer.write_all(&self.keys)
+    }
+}
+
+#[inline]
+fn invalid_data(err: AlgebraError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_key_pack() {
+        let mut pack = KeyPack::new();
+        pack.push("a", &vec![1u32, 2, 3]);
+        pack.push("b", &vec![4u64; 100]);
+        pack.push("c", &7u8);
+        assert_eq!(pack.manifest().entries().len(), 3);
+        assert_eq!(
+            pack.manifest().get("b").unwrap().fingerprint(),
+            &Fingerprint::of(&vec![4u64; 100])
+        );
+
+        let mut bytes = Vec::new();
+        pack.write_to(&mut bytes).unwrap();
+        let mut reader = Cursor::new(bytes.clone());
+        let limits = DecodeLimits::default();
+        let manifest = Manifest::read_from(&mut reader, &limits).unwrap();
+        assert_eq!(&manifest, pack.manifest());
+
+        assert_eq!(
+            manifest.load::<u8, _>(&mut reader, "c", &limits).unwrap(),
+            7
+        );
+        assert_eq!(
+            manifest
+                .load::<Vec<u32>, _>(&mut reader, "a", &limits)
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+        let err = manifest
+            .load::<u8, _>(&mut reader, "d", &limits)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        // a key larger than the limits is not read
+        let small = DecodeLimits {
+            max_bytes: 100,
+            ..limits
+        };
+        assert!(manifest
+            .load::<Vec<u64>, _>(&mut reader, "b", &small)
+            .is_err());
+
+        // a corrupted key does not match its fingerprint
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        let err = manifest
+            .load::<u8, _>(&mut Cursor::new(bytes), "c", &limits)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_manifest_rejects_invalid_layouts() {
+        let mut pack = KeyPack::new();
+        pack.push("a", &1u8);
+        pack.push("b", &2u8);
+        let mut manifest = pack.manifest().clone();
+
+        manifest.entries[1].name = "a".to_string();
+        assert!(Manifest::from_le_slice(&manifest.to_le_vec()).is_err());
+
+        manifest.entries[1].name = "b".to_string();
+        manifest.entries[1].offset = 0;
+        assert!(Manifest::from_le_slice(&manifest.to_le_vec()).is_err());
+    }
+}
@@ -1,7 +1,22 @@
 //! Defines some utils.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod bytes;
+mod envelope;
+mod fingerprint;
+mod key_pack;
 mod pool;
 mod reverse;
 
+#[cfg(feature = "arbitrary")]
+pub use arbitrary::arbitrary_field_values;
+pub use bytes::{
+    read_chunks, read_coeffs, read_header, read_modulus, read_width, write_header, write_modulus,
+    write_width, DecodeLimits, LeBytes, FORMAT_VERSION,
+};
+pub use envelope::Envelope;
+pub use fingerprint::{Fingerprint, KeyBound, KeyRecord};
+pub use key_pack::{KeyPack, Manifest, ManifestEntry};
 pub use pool::Pool;
 pub use reverse::ReverseLsbs;
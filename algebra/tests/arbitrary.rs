@@ -0,0 +1,62 @@
+use algebra::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial, NttPolynomial, Polynomial},
+    reduce::ModulusValue,
+    utils::{Envelope, Fingerprint, KeyBound, KeyRecord, LeBytes},
+    U32FieldEval,
+};
+use arbitrary::{Arbitrary, Unstructured};
+use rand::{thread_rng, Rng, RngCore};
+
+type FF = U32FieldEval<132120577>;
+
+const ROUNDS: usize = 64;
+const MUTATIONS: usize = 64;
+
+/// Decodes truncated and corrupted copies of `bytes`, none of which may panic.
+fn check_mutations<T: LeBytes>(bytes: &[u8], rng: &mut impl Rng) {
+    for _ in 0..MUTATIONS {
+        let mut bad = bytes.to_vec();
+        let i = rng.gen_range(0..bad.len());
+        match rng.gen_range(0..3) {
+            0 => bad.truncate(i),
+            1 => bad[i] ^= rng.gen::<u8>() | 1,
+            _ => bad[i..(i + 8).min(bytes.len())].fill(0xff),
+        }
+        let _ = T::from_le_slice(&bad);
+    }
+}
+
+/// Checks that arbitrary values survive the round trip,
+/// and that random and corrupted encodings are rejected without panics.
+fn check<T: for<'a> Arbitrary<'a> + LeBytes>() {
+    let mut rng = thread_rng();
+    for _ in 0..ROUNDS {
+        let mut data = vec![0u8; rng.gen_range(0..1024)];
+        rng.fill_bytes(&mut data);
+        let _ = T::from_le_slice(&data);
+
+        let Ok(value) = T::arbitrary(&mut Unstructured::new(&data)) else {
+            continue;
+        };
+        let bytes = value.to_le_vec();
+        assert_eq!(T::from_le_slice(&bytes).unwrap().to_le_vec(), bytes);
+        check_mutations::<T>(&bytes, &mut rng);
+    }
+}
+
+#[test]
+fn test_arbitrary_polynomials() {
+    check::<Polynomial<u32>>();
+    check::<NttPolynomial<u64>>();
+    check::<FieldPolynomial<FF>>();
+    check::<FieldNttPolynomial<FF>>();
+}
+
+#[test]
+fn test_arbitrary_keys() {
+    check::<ModulusValue<u32>>();
+    check::<Fingerprint>();
+    check::<KeyRecord<Vec<u8>>>();
+    check::<KeyBound<FieldPolynomial<FF>>>();
+    check::<Envelope<Polynomial<u32>>>();
+}
@@ -132,3 +132,30 @@ fn test_decompose() {
         None => assert_eq!(compose, a),
     };
 }
+
+#[test]
+fn test_slice_ops() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+
+    let a: Vec<ValueT> = (&mut rng).sample_iter(distr).take(64).collect();
+    let b: Vec<ValueT> = (&mut rng).sample_iter(distr).take(64).collect();
+    let c: Vec<ValueT> = (&mut rng).sample_iter(distr).take(64).collect();
+
+    let mut x = a.clone();
+    FF::add_assign_slice(&mut x, &b);
+    FF::sub_assign_slice(&mut x, &c);
+    FF::neg_assign_slice(&mut x);
+    let expected: Vec<ValueT> = (0..64)
+        .map(|i| FF::neg(FF::sub(FF::add(a[i], b[i]), c[i])))
+        .collect();
+    assert_eq!(x, expected);
+
+    let mut x = a.clone();
+    FF::mul_assign_pointwise(&mut x, &b);
+    FF::add_mul_assign_pointwise(&mut x, &b, &c);
+    let expected: Vec<ValueT> = (0..64)
+        .map(|i| FF::mul_add(b[i], c[i], FF::mul(a[i], b[i])))
+        .collect();
+    assert_eq!(x, expected);
+}
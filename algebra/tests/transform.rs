@@ -2,6 +2,7 @@ use algebra::{
     modulus::BarrettModulus,
     ntt::{NttTable, NumberTheoryTransform, TableWithShoupRoot},
     reduce::{ReduceAdd, ReduceAddAssign, ReduceMul, ReduceSubAssign},
+    NttField, U64FieldEval,
 };
 use rand::{distributions::Uniform, prelude::Distribution, thread_rng, Rng};
 
@@ -87,6 +88,35 @@ fn test_cal() {
     assert_eq!(ntt_add, add);
 }
 
+#[test]
+fn test_cal_62_bits_field() {
+    const M62: P = 4611686018425815041;
+    type Fp62 = U64FieldEval<M62>;
+
+    let mut rng = thread_rng();
+    let modulus = <BarrettModulus<P>>::new(M62);
+    let table = Fp62::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let dis = Uniform::new(0, M62);
+
+    let mut a: Vec<P> = dis.sample_iter(&mut rng).take(N).collect();
+    let mut b: Vec<P> = dis.sample_iter(&mut rng).take(N).collect();
+
+    let mul = naive_mul(&a, &b, &modulus);
+
+    table.transform_slice(&mut a);
+    table.transform_slice(&mut b);
+
+    let mut ntt_mul: Vec<P> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(&x, &y)| modulus.reduce_mul(x, y))
+        .collect();
+    table.inverse_transform_slice(&mut ntt_mul);
+
+    assert_eq!(ntt_mul, mul);
+}
+
 #[test]
 fn test_transform_monomial() {
     let mut rng = thread_rng();
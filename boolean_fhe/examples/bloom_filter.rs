@@ -0,0 +1,63 @@
+use boolean_fhe::{
+    BloomFilter, BloomFilterParameters, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS,
+};
+
+mod common;
+
+use common::generate_keys;
+
+type Msg = u8;
+
+const ITEM_COUNT: usize = 64;
+const FALSE_POSITIVE_RATE: f64 = 0.01;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // the client side deny-list
+    let deny_list: Vec<String> = (0..ITEM_COUNT).map(|i| format!("user-{i}")).collect();
+    let parameters = BloomFilterParameters::optimal(ITEM_COUNT, FALSE_POSITIVE_RATE);
+    let filter = BloomFilter::from_items(parameters, &deny_list);
+    println!(
+        "Filter of {} bits with {} hashes, expected false positive rate: {:.4}",
+        parameters.size(),
+        parameters.hash_count(),
+        parameters.false_positive_rate(ITEM_COUNT)
+    );
+
+    // check the false positive rate on clear bits first
+    let sim = SimulatedEvaluator::new();
+    let clear = filter.encrypt(|bit| bit);
+    assert!(deny_list.iter().all(|item| clear.query(&sim, item)));
+    let trials = 100_000;
+    let false_positives = (0..trials)
+        .filter(|i| clear.query(&sim, format!("guest-{i}")))
+        .count();
+    println!(
+        "Simulation done, measured false positive rate: {:.4}\n",
+        false_positives as f64 / trials as f64
+    );
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let start = std::time::Instant::now();
+    let encrypted = filter.encrypt(|bit| enc.encrypt(bit as Msg, &mut rand::thread_rng()));
+    println!("Filter encrypted in {:?}", start.elapsed());
+
+    for item in ["user-7", "user-42", "guest-1", "guest-2"] {
+        sim.reset();
+        let expected = clear.query(&sim, item);
+
+        let start = std::time::Instant::now();
+        let ct = encrypted.query(&eval, item);
+        let elapsed = start.elapsed();
+
+        let denied = dec.decrypt::<Msg>(&ct) == 1;
+        assert_eq!(denied, expected);
+        assert_eq!(denied, filter.contains(item));
+        println!(
+            "{item} denied: {denied}, {} bootstraps in {elapsed:?}",
+            sim.bootstrap_count()
+        );
+    }
+}
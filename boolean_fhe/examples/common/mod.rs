@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use boolean_fhe::{BooleanFheParameters, Decryptor, Encryptor, Evaluator, KeyGen};
+use fhe_core::LweCiphertext;
+use rand::{CryptoRng, Rng};
+
+/// The encryptor, the decryptor and the evaluator of one secret key.
+pub type Keys<C, LweModulus, Q> = (
+    Encryptor<C, LweModulus>,
+    Decryptor<C, LweModulus>,
+    Evaluator<C, LweModulus, Q>,
+);
+
+/// Generates the keys of `params`, and reports how long it took.
+pub fn generate_keys<C, LweModulus, Q, R>(
+    params: BooleanFheParameters<C, LweModulus, Q>,
+    rng: &mut R,
+) -> Keys<C, LweModulus, Q>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    R: Rng + CryptoRng,
+{
+    let start = std::time::Instant::now();
+    let sk = KeyGen::generate_secret_key(params, rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, rng);
+    println!("Key Generation done in {:?}!\n", start.elapsed());
+
+    (enc, dec, eval)
+}
+
+/// Splits the low `width` bits of `x` in little-endian order.
+pub fn to_bits(x: u64, width: usize) -> Vec<bool> {
+    (0..width).map(|i| (x >> i) & 1 == 1).collect()
+}
+
+/// Collects little-endian bits into an integer.
+pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> u64 {
+    bits.into_iter()
+        .enumerate()
+        .fold(0, |acc, (i, b)| acc | ((b as u64) << i))
+}
+
+/// Encrypts the low `width` bits of `x` in little-endian order.
+pub fn encrypt_bits<C, LweModulus, R>(
+    enc: &Encryptor<C, LweModulus>,
+    x: u64,
+    width: usize,
+    rng: &mut R,
+) -> Vec<LweCiphertext<C>>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    R: Rng + CryptoRng,
+    u8: TryInto<C>,
+{
+    to_bits(x, width)
+        .into_iter()
+        .map(|b| enc.encrypt(b as u8, rng))
+        .collect()
+}
+
+/// Decrypts little-endian encrypted bits into an integer.
+pub fn decrypt_bits<C, LweModulus>(dec: &Decryptor<C, LweModulus>, bits: &[LweCiphertext<C>]) -> u64
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    u8: TryFrom<C>,
+{
+    from_bits(bits.iter().map(|c| dec.decrypt::<u8>(c) == 1))
+}
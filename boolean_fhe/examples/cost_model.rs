@@ -0,0 +1,61 @@
+use boolean_fhe::{
+    estimate_cost, BooleanEvaluator, CostModel, OpCode, RecordingEvaluator, SimulatedEvaluator,
+    DEFAULT_128_BITS_PARAMETERS,
+};
+use rand::Rng;
+
+mod common;
+
+use common::{encrypt_bits, generate_keys};
+
+type Msg = u8;
+
+const BITS: usize = 4;
+
+/// Returns `x == y` for two little-endian bit vectors.
+fn equal<E: BooleanEvaluator>(eval: &E, x: &[E::Bit], y: &[E::Bit]) -> E::Bit {
+    let mut eq = eval.xnor(&x[0], &y[0]);
+    for (a, b) in x.iter().zip(y).skip(1) {
+        eq = eval.and(&eq, &eval.xnor(a, b));
+    }
+    eq
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let model = CostModel::calibrate(&eval, &enc.encrypt(0 as Msg, &mut rng), 10);
+    println!("Calibrated: {model:?}");
+    println!("Estimated mux: {:?}", estimate_cost(OpCode::Mux, &model));
+
+    // plan the circuit on clear bits
+    let sim = SimulatedEvaluator::new();
+    let recorder = RecordingEvaluator::new(&sim);
+    let inputs: Vec<_> = (0..2 * BITS).map(|_| recorder.input(false)).collect();
+    let (x, y) = inputs.split_at(BITS);
+    equal(&recorder, x, y);
+    let estimate = model.estimate_log(&recorder.into_log());
+
+    // then run it on ciphertexts
+    let x: u64 = rng.gen_range(0..1 << BITS);
+    let y = if rng.gen() {
+        x
+    } else {
+        rng.gen_range(0..1 << BITS)
+    };
+    let cx = encrypt_bits(&enc, x, BITS, &mut rng);
+    let cy = encrypt_bits(&enc, y, BITS, &mut rng);
+
+    let start = std::time::Instant::now();
+    let ct = equal(&eval, &cx, &cy);
+    let elapsed = start.elapsed();
+
+    let m: Msg = dec.decrypt(&ct);
+    assert_eq!(m == 1, x == y);
+    println!(
+        "{x} == {y}: {}, estimated {estimate:?}, measured {elapsed:?}",
+        m == 1
+    );
+}
@@ -0,0 +1,34 @@
+use boolean_fhe::{FheCounter, DEFAULT_128_BITS_PARAMETERS};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, generate_keys};
+
+type Msg = u8;
+
+const WIDTH: usize = 4;
+const UPDATES: usize = 20;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let mut counter = FheCounter::new(WIDTH, eval.parameters().lwe_dimension());
+    let mut expected = 0u64;
+
+    for _ in 0..UPDATES {
+        let m: bool = rng.gen();
+        expected += m as u64;
+
+        let start = std::time::Instant::now();
+        counter.add_bit(&enc.encrypt(m as Msg, &mut rng), &eval);
+        println!("Time elapsed in add_bit() is: {:?}", start.elapsed());
+    }
+
+    let count = decrypt_bits(&dec, counter.bits());
+
+    assert_eq!(count, expected % (1 << WIDTH));
+    println!("count: {count}, expected: {expected} mod {}", 1 << WIDTH);
+}
@@ -0,0 +1,69 @@
+use boolean_fhe::{borrowing_sub, carrying_add, BooleanEvaluator, DEFAULT_128_BITS_PARAMETERS};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, generate_keys};
+
+type Msg = u8;
+
+const BITS: usize = 4;
+const MAX: u64 = (1 << BITS) - 1;
+
+/// Interprets a `BITS`-bit word as two's complement.
+fn signed(x: u64) -> i64 {
+    ((x << (64 - BITS)) as i64) >> (64 - BITS)
+}
+
+/// Adds two words of `2 * BITS` bits by chaining the carry of the low halves.
+fn add_two_words<E: BooleanEvaluator>(evaluator: &E, x: &[E::Bit], y: &[E::Bit]) -> Vec<E::Bit> {
+    let (mut low, flags) = carrying_add(evaluator, &x[..BITS], &y[..BITS], None);
+    let (high, _) = carrying_add(evaluator, &x[BITS..], &y[BITS..], Some(&flags.carry));
+    low.extend(high);
+    low
+}
+
+/// Returns `(x < y unsigned, x < y signed)` from the flags of `x - y`.
+fn less_than<E: BooleanEvaluator>(evaluator: &E, x: &[E::Bit], y: &[E::Bit]) -> (E::Bit, E::Bit) {
+    let (difference, flags) = borrowing_sub(evaluator, x, y, None);
+    let signed = evaluator.xor(difference.last().unwrap(), &flags.overflow);
+    (flags.carry, signed)
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let decrypt = |c: &_| dec.decrypt::<Msg>(c) == 1;
+
+    let x = rng.gen_range(0..=MAX);
+    let y = rng.gen_range(0..=MAX);
+    let cx = encrypt_bits(&enc, x, BITS, &mut rng);
+    let cy = encrypt_bits(&enc, y, BITS, &mut rng);
+    let start = std::time::Instant::now();
+    let (unsigned, signed_lt) = less_than(&eval, &cx, &cy);
+    println!(
+        "{x} < {y}: {}, {} < {}: {}, in {:?}",
+        decrypt(&unsigned),
+        signed(x),
+        signed(y),
+        decrypt(&signed_lt),
+        start.elapsed()
+    );
+    assert_eq!(decrypt(&unsigned), x < y);
+    assert_eq!(decrypt(&signed_lt), signed(x) < signed(y));
+
+    let x = rng.gen_range(0..256);
+    let y = rng.gen_range(0..256);
+    let cx = encrypt_bits(&enc, x, 2 * BITS, &mut rng);
+    let cy = encrypt_bits(&enc, y, 2 * BITS, &mut rng);
+    let start = std::time::Instant::now();
+    let sum = add_two_words(&eval, &cx, &cy);
+    let decrypted = decrypt_bits(&dec, &sum);
+    println!(
+        "{x} + {y} = {decrypted} (mod 256), in {:?}",
+        start.elapsed()
+    );
+    assert_eq!(decrypted, (x + y) & 255);
+}
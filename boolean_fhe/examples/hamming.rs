@@ -0,0 +1,82 @@
+use boolean_fhe::{
+    hamming_distance, nearest_neighbors, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS,
+};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, from_bits, generate_keys};
+
+type Msg = u8;
+
+const BITS: usize = 32;
+const COUNT: usize = 4;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // the server side enrolled templates
+    let templates: Vec<Vec<bool>> = (0..COUNT)
+        .map(|_| (0..BITS).map(|_| rng.gen()).collect())
+        .collect();
+
+    // the client side probe, a noisy reading of one template
+    let enrolled = rng.gen_range(0..COUNT);
+    let probe: Vec<bool> = templates[enrolled]
+        .iter()
+        .map(|&b| b ^ rng.gen_bool(0.1))
+        .collect();
+
+    let expected: Vec<u64> = templates
+        .iter()
+        .map(|t| t.iter().zip(&probe).filter(|(a, b)| a != b).count() as u64)
+        .collect();
+    let nearest = (0..COUNT).min_by_key(|&i| (expected[i], i)).unwrap();
+    println!("distances: {expected:?}, nearest template: {nearest}");
+
+    // check the circuit on clear bits first
+    let sim = SimulatedEvaluator::new();
+    let distances: Vec<Vec<bool>> = templates
+        .iter()
+        .map(|t| hamming_distance(&sim, &probe, t))
+        .collect();
+    assert_eq!(
+        distances
+            .iter()
+            .map(|d| from_bits(d.iter().copied()))
+            .collect::<Vec<_>>(),
+        expected
+    );
+    println!(
+        "Simulation done, {} bootstraps per distance\n",
+        sim.bootstrap_count() / COUNT
+    );
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let encrypted: Vec<_> = probe
+        .iter()
+        .map(|&b| enc.encrypt(b as Msg, &mut rng))
+        .collect();
+
+    let start = std::time::Instant::now();
+    let distances: Vec<Vec<_>> = templates
+        .iter()
+        .map(|t| hamming_distance(&eval, &encrypted, t))
+        .collect();
+    println!(
+        "Time elapsed in hamming_distance() is: {:?}",
+        start.elapsed()
+    );
+
+    let decrypt = |bits: &[_]| decrypt_bits(&dec, bits);
+    let result: Vec<u64> = distances.iter().map(|d| decrypt(d)).collect();
+    assert_eq!(result, expected);
+
+    let neighbor = nearest_neighbors(&eval, distances, 1).pop().unwrap();
+    assert_eq!(decrypt(&neighbor.index), nearest as u64);
+    println!(
+        "distances: {result:?}, nearest template: {}",
+        decrypt(&neighbor.index)
+    );
+}
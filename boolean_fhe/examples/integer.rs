@@ -0,0 +1,77 @@
+use boolean_fhe::{OverflowMode, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, from_bits, generate_keys, to_bits};
+
+const BITS: usize = 4;
+const MAX: u64 = (1 << BITS) - 1;
+
+/// The expected result of `op` in `mode`, the checked flag is the bit `BITS`.
+fn expected(op: &str, mode: OverflowMode, x: u64, y: u64) -> u64 {
+    let (value, overflow) = match op {
+        "add" => (x + y, x + y > MAX),
+        "sub" => (x.wrapping_sub(y), x < y),
+        "mul" => (x * y, x * y > MAX),
+        _ => unreachable!(),
+    };
+    match mode {
+        OverflowMode::Wrapping => value & MAX,
+        OverflowMode::Saturating if overflow => {
+            if op == "sub" {
+                0
+            } else {
+                MAX
+            }
+        }
+        OverflowMode::Saturating => value,
+        OverflowMode::Checked => (value & MAX) | ((overflow as u64) << BITS),
+    }
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let modes = [
+        OverflowMode::Wrapping,
+        OverflowMode::Saturating,
+        OverflowMode::Checked,
+    ];
+
+    let sim = SimulatedEvaluator::new();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let x = rng.gen_range(0..=MAX);
+    let y = rng.gen_range(0..=MAX);
+    let cx = encrypt_bits(&enc, x, BITS, &mut rng);
+    let cy = encrypt_bits(&enc, y, BITS, &mut rng);
+    let (x_bits, y_bits) = (to_bits(x, BITS), to_bits(y, BITS));
+
+    for mode in modes {
+        for op in ["add", "sub", "mul"] {
+            sim.reset();
+            let clear = match op {
+                "add" => mode.add(&sim, &x_bits, &y_bits),
+                "sub" => mode.sub(&sim, &x_bits, &y_bits),
+                _ => mode.mul(&sim, &x_bits, &y_bits),
+            };
+
+            let start = std::time::Instant::now();
+            let result = match op {
+                "add" => mode.add(&eval, &cx, &cy),
+                "sub" => mode.sub(&eval, &cx, &cy),
+                _ => mode.mul(&eval, &cx, &cy),
+            };
+            let elapsed = start.elapsed();
+
+            let decrypted = decrypt_bits(&dec, &result);
+            assert_eq!(decrypted, from_bits(clear));
+            assert_eq!(decrypted, expected(op, mode, x, y));
+            println!(
+                "{mode:?} {x} {op} {y}: {decrypted}, {} bootstraps in {elapsed:?}",
+                sim.bootstrap_count()
+            );
+        }
+    }
+}
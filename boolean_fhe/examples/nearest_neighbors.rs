@@ -0,0 +1,102 @@
+use boolean_fhe::{
+    nearest_neighbors, squared_distances, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS,
+};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, from_bits, generate_keys, to_bits};
+
+const DIMENSION: usize = 2;
+const BITS: usize = 3;
+const COUNT: usize = 6;
+const K: usize = 2;
+const WIDTH: usize = 8;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // the server side plaintext database
+    let database: Vec<Vec<u64>> = (0..COUNT)
+        .map(|_| {
+            (0..DIMENSION)
+                .map(|_| rng.gen_range(0..1 << BITS))
+                .collect()
+        })
+        .collect();
+
+    // the client side query
+    let query: Vec<u64> = (0..DIMENSION)
+        .map(|_| rng.gen_range(0..1 << BITS))
+        .collect();
+    println!("database: {database:?}\nquery: {query:?}");
+
+    let expected_distances: Vec<u64> = database
+        .iter()
+        .map(|y| {
+            query
+                .iter()
+                .zip(y)
+                .map(|(&x, &y)| x.abs_diff(y).pow(2))
+                .sum()
+        })
+        .collect();
+    let mut expected: Vec<(u64, usize)> = expected_distances.iter().copied().zip(0..).collect();
+    expected.sort();
+    expected.truncate(K);
+
+    // check the circuit on clear bits first
+    let sim = SimulatedEvaluator::new();
+    let clear_query: Vec<Vec<bool>> = query.iter().map(|&x| to_bits(x, BITS)).collect();
+    let distances = squared_distances(&sim, &clear_query, &database, WIDTH);
+    let distance_bootstraps = sim.bootstrap_count();
+    assert_eq!(
+        distances
+            .iter()
+            .map(|d| from_bits(d.iter().copied()))
+            .collect::<Vec<_>>(),
+        expected_distances
+    );
+    let neighbors = nearest_neighbors(&sim, distances, K);
+    let clear: Vec<(u64, usize)> = neighbors
+        .iter()
+        .map(|n| {
+            (
+                from_bits(n.distance.iter().copied()),
+                from_bits(n.index.iter().copied()) as usize,
+            )
+        })
+        .collect();
+    assert_eq!(clear, expected);
+    println!(
+        "Simulation done, {distance_bootstraps} bootstraps for the distances, {} for the selection\n",
+        sim.bootstrap_count() - distance_bootstraps
+    );
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let encrypted: Vec<Vec<_>> = query
+        .iter()
+        .map(|&x| encrypt_bits(&enc, x, BITS, &mut rng))
+        .collect();
+
+    let start = std::time::Instant::now();
+    let distances = squared_distances(&eval, &encrypted, &database, WIDTH);
+    let neighbors = nearest_neighbors(&eval, distances, K);
+    println!(
+        "Time elapsed in nearest neighbors is: {:?}",
+        start.elapsed()
+    );
+
+    let result: Vec<(u64, usize)> = neighbors
+        .iter()
+        .map(|n| {
+            (
+                decrypt_bits(&dec, &n.distance),
+                decrypt_bits(&dec, &n.index) as usize,
+            )
+        })
+        .collect();
+    assert_eq!(result, expected);
+    println!("nearest neighbors (distance, index): {result:?}");
+}
@@ -0,0 +1,52 @@
+use boolean_fhe::{reduce, ReduceOp, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, from_bits, generate_keys, to_bits};
+
+const BITS: usize = 4;
+const COUNT: usize = 8;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let values: Vec<u64> = (0..COUNT).map(|_| rng.gen_range(0..1 << BITS)).collect();
+    println!("values: {values:?}");
+
+    let encrypted: Vec<Vec<_>> = values
+        .iter()
+        .map(|&v| encrypt_bits(&enc, v, BITS, &mut rng))
+        .collect();
+
+    let sim = SimulatedEvaluator::new();
+    for (op, expected) in [
+        (ReduceOp::Add, values.iter().sum::<u64>() % (1 << BITS)),
+        (ReduceOp::Min, *values.iter().min().unwrap()),
+        (ReduceOp::Max, *values.iter().max().unwrap()),
+        (
+            ReduceOp::And,
+            values.iter().fold(u64::MAX, |a, &v| a & v) & ((1 << BITS) - 1),
+        ),
+        (ReduceOp::Or, values.iter().fold(0, |a, &v| a | v)),
+        (ReduceOp::Xor, values.iter().fold(0, |a, &v| a ^ v)),
+    ] {
+        sim.reset();
+        let clear: Vec<Vec<bool>> = values.iter().map(|&v| to_bits(v, BITS)).collect();
+        let simulated = from_bits(reduce(&sim, clear, op).unwrap());
+        assert_eq!(simulated, expected);
+
+        let start = std::time::Instant::now();
+        let result = reduce(&eval, encrypted.clone(), op).unwrap();
+        let elapsed = start.elapsed();
+
+        let decrypted = decrypt_bits(&dec, &result);
+        assert_eq!(decrypted, expected);
+        println!(
+            "{op:?}: {decrypted}, {} bootstraps in {elapsed:?}",
+            sim.bootstrap_count()
+        );
+    }
+}
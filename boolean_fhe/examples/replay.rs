@@ -0,0 +1,65 @@
+use boolean_fhe::{
+    BooleanEvaluator, RecordingEvaluator, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS,
+};
+use rand::Rng;
+
+mod common;
+
+use common::generate_keys;
+
+type Msg = u8;
+
+const BITS: usize = 4;
+
+/// Returns `x > y` for two little-endian bit vectors.
+fn greater_than<E: BooleanEvaluator>(eval: &E, x: &[E::Bit], y: &[E::Bit]) -> E::Bit {
+    let mut gt = eval.and(&x[0], &eval.not(&y[0]));
+    for (a, b) in x.iter().zip(y).skip(1) {
+        // if a == b {gt} else {a}
+        let eq = eval.xnor(a, b);
+        gt = eval.mux(&eq, &gt, a);
+    }
+    gt
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    let x: u64 = rng.gen_range(0..1 << BITS);
+    let y: u64 = rng.gen_range(0..1 << BITS);
+    let bits: Vec<bool> = (0..BITS)
+        .flat_map(|i| [(x >> i) & 1 == 1, (y >> i) & 1 == 1])
+        .collect();
+
+    // record the evaluation on ciphertexts
+    let recorder = RecordingEvaluator::new(&eval);
+    let inputs: Vec<_> = bits
+        .iter()
+        .map(|&b| recorder.input(enc.encrypt(b as Msg, &mut rng)))
+        .collect();
+    let (cx, cy): (Vec<_>, Vec<_>) = inputs
+        .chunks(2)
+        .map(|c| (c[0].clone(), c[1].clone()))
+        .unzip();
+
+    let start = std::time::Instant::now();
+    let result = greater_than(&recorder, &cx, &cy);
+    println!("Time elapsed in greater_than() is: {:?}", start.elapsed());
+    let encrypted = dec.decrypt::<Msg>(result.bit()) == 1;
+
+    // replay the log on the clear inputs
+    let log = recorder.into_log();
+    for (id, operation) in log.operations().iter().enumerate() {
+        let inputs = &operation.inputs[..operation.op.arity()];
+        println!("{id:>3}: {:?} {inputs:?}", operation.op);
+    }
+
+    let sim = SimulatedEvaluator::new();
+    let replayed = log.replay(&sim, &bits)[result.id()];
+
+    println!("{x} > {y}: encrypted {encrypted}, replayed {replayed}");
+    assert_eq!(encrypted, x > y);
+    assert_eq!(replayed, encrypted);
+}
@@ -0,0 +1,50 @@
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use rand::{rngs::StdRng, SeedableRng};
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, generate_keys};
+
+const COUNT_BITS: usize = 4;
+const AGGREGATE_BITS: usize = 8;
+const THRESHOLD: u64 = 5;
+
+/// The seed agreed on by all replicas for one request.
+const REQUEST_SEED: u64 = 0x5eed;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // every replica receives the same evaluation key
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+    let replicas = [eval.clone(), eval];
+
+    // below the threshold the output is random, so it shows the rng is the only source
+    let count = encrypt_bits(&enc, THRESHOLD - 1, COUNT_BITS, &mut rng);
+    let aggregate = encrypt_bits(&enc, 0xA5, AGGREGATE_BITS, &mut rng);
+
+    let start = std::time::Instant::now();
+    let outputs: Vec<_> = replicas
+        .iter()
+        .map(|eval| {
+            let mut request_rng = StdRng::seed_from_u64(REQUEST_SEED);
+            eval.threshold_release(&count, THRESHOLD, &aggregate, &mut request_rng)
+        })
+        .collect();
+    println!(
+        "{} replicas evaluated in {:?}",
+        replicas.len(),
+        start.elapsed()
+    );
+    assert!(outputs.windows(2).all(|w| w[0] == w[1]));
+
+    let released = decrypt_bits(&dec, &outputs[0]);
+    println!("All replicas agree on the output, which decrypts to {released}");
+
+    let mut other_rng = StdRng::seed_from_u64(REQUEST_SEED + 1);
+    let other = replicas[0].threshold_release(&count, THRESHOLD, &aggregate, &mut other_rng);
+    println!(
+        "Another seed gives the same output: {}",
+        other == outputs[0]
+    );
+}
@@ -0,0 +1,38 @@
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use rand::Rng;
+
+mod common;
+
+use common::{encrypt_bits, generate_keys};
+
+type Msg = u8;
+
+const BITS: usize = 6;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    // the server side plaintext set, `81` is wider than `BITS` and never matches
+    let set: Vec<u64> = vec![3, 17, 42, 63, 81];
+    let narrow = &set[..4];
+
+    for _ in 0..4 {
+        let x: u64 = if rng.gen_bool(0.5) {
+            narrow[rng.gen_range(0..narrow.len())]
+        } else {
+            rng.gen_range(0..1 << BITS)
+        };
+
+        let element = encrypt_bits(&enc, x, BITS, &mut rng);
+
+        let start = std::time::Instant::now();
+        let ct = eval.contains(&element, &set);
+        println!("Time elapsed in contains() is: {:?}", start.elapsed());
+
+        let m: Msg = dec.decrypt(&ct);
+        assert_eq!(m == 1, set.contains(&x));
+        println!("{x} in set: {}", m == 1);
+    }
+}
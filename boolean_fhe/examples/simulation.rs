@@ -0,0 +1,57 @@
+use boolean_fhe::{BooleanEvaluator, SimulatedEvaluator, DEFAULT_128_BITS_PARAMETERS};
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, from_bits, generate_keys, to_bits};
+
+const BITS: usize = 4;
+
+/// Ripple-carry addition of two little-endian bit vectors, modulo `2^BITS`.
+fn add<E: BooleanEvaluator>(eval: &E, x: &[E::Bit], y: &[E::Bit]) -> Vec<E::Bit> {
+    let mut carry: Option<E::Bit> = None;
+    x.iter()
+        .zip(y)
+        .map(|(a, b)| match carry.take() {
+            None => {
+                carry = Some(eval.and(a, b));
+                eval.xor(a, b)
+            }
+            Some(c) => {
+                let sum = eval.xor(&eval.xor(a, b), &c);
+                carry = Some(eval.majority(a, b, &c));
+                sum
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let x: u64 = rng.gen_range(0..1 << BITS);
+    let y: u64 = rng.gen_range(0..1 << BITS);
+
+    // debug the circuit on clear bits first
+    let sim = SimulatedEvaluator::new();
+    let start = std::time::Instant::now();
+    let simulated = from_bits(add(&sim, &to_bits(x, BITS), &to_bits(y, BITS)));
+    println!(
+        "Simulated: {x} + {y} = {simulated} with {} bootstraps in {:?}",
+        sim.bootstrap_count(),
+        start.elapsed()
+    );
+    assert_eq!(simulated, (x + y) % (1 << BITS));
+
+    // then run the same circuit on ciphertexts
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+    let cx = encrypt_bits(&enc, x, BITS, &mut rng);
+    let cy = encrypt_bits(&enc, y, BITS, &mut rng);
+
+    let start = std::time::Instant::now();
+    let sum = add(&eval, &cx, &cy);
+    let elapsed = start.elapsed();
+    let encrypted = decrypt_bits(&dec, &sum);
+    println!("Encrypted: {x} + {y} = {encrypted} in {elapsed:?}");
+    assert_eq!(encrypted, simulated);
+}
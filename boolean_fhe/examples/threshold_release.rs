@@ -0,0 +1,37 @@
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, encrypt_bits, generate_keys};
+
+const COUNT_BITS: usize = 4;
+const AGGREGATE_BITS: usize = 8;
+const THRESHOLD: u64 = 5;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    for count in [THRESHOLD - 1, THRESHOLD, rng.gen_range(0..1 << COUNT_BITS)] {
+        let aggregate: u64 = rng.gen_range(0..1 << AGGREGATE_BITS);
+
+        let count_ct = encrypt_bits(&enc, count, COUNT_BITS, &mut rng);
+        let aggregate_ct = encrypt_bits(&enc, aggregate, AGGREGATE_BITS, &mut rng);
+
+        let start = std::time::Instant::now();
+        let ct = eval.threshold_release(&count_ct, THRESHOLD, &aggregate_ct, &mut rng);
+        println!(
+            "Time elapsed in threshold_release() is: {:?}",
+            start.elapsed()
+        );
+
+        let released = decrypt_bits(&dec, &ct);
+
+        if count >= THRESHOLD {
+            assert_eq!(released, aggregate);
+        }
+        println!("count: {count}, aggregate: {aggregate}, released: {released}");
+    }
+}
@@ -0,0 +1,47 @@
+use boolean_fhe::TOY_PARAMETERS;
+
+mod common;
+
+use common::generate_keys;
+
+type Msg = u8;
+
+const ROUNDS: usize = 100;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    // toy parameters, 16-bits arithmetic everywhere and not secure
+    let (enc, dec, eval) = generate_keys(*TOY_PARAMETERS, &mut rng);
+
+    let mut max_noise = 0u16;
+    let start = std::time::Instant::now();
+    for _ in 0..ROUNDS {
+        for (a, b, c) in itertools::iproduct!(0..2, 0..2, 0..2) {
+            let x = enc.encrypt(a, &mut rng);
+            let y = enc.encrypt(b, &mut rng);
+            let z = enc.encrypt(c, &mut rng);
+
+            let results = [
+                (eval.nand(&x, &y), (a & b) ^ 1),
+                (eval.and(&x, &y), a & b),
+                (eval.or(&x, &y), a | b),
+                (eval.nor(&x, &y), (a | b) ^ 1),
+                (eval.xor(&x, &y), a ^ b),
+                (eval.xnor(&x, &y), (a ^ b) ^ 1),
+                (eval.majority(&x, &y, &z), (a & b) | (b & c) | (a & c)),
+                (eval.mux(&x, &y, &z), if a == 1 { b } else { c }),
+            ];
+            for (ct, expected) in results {
+                let (m, noise) = dec.decrypt_with_noise::<Msg>(&ct);
+                assert_eq!(m, expected, "Noise: {noise}");
+                max_noise = max_noise.max(noise);
+            }
+        }
+    }
+    let gates = ROUNDS * 8 * 8;
+    println!(
+        "{gates} gates done in {:?}, max noise: {max_noise}",
+        start.elapsed()
+    );
+}
@@ -0,0 +1,46 @@
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use rand::Rng;
+
+mod common;
+
+use common::{decrypt_bits, generate_keys};
+
+type Msg = u8;
+
+const COUNT: usize = 6;
+const WIDTH: usize = 8;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let (enc, dec, eval) = generate_keys(*DEFAULT_128_BITS_PARAMETERS, &mut rng);
+
+    // the server side plaintext weights
+    let weights: Vec<u64> = (0..COUNT).map(|_| rng.gen_range(0..32)).collect();
+
+    // the client side selection bits
+    let selection: Vec<bool> = (0..COUNT).map(|_| rng.gen()).collect();
+    let encrypted: Vec<_> = selection
+        .iter()
+        .map(|&s| enc.encrypt(s as Msg, &mut rng))
+        .collect();
+
+    let start = std::time::Instant::now();
+    let ct = eval.weighted_popcount(&encrypted, &weights, WIDTH);
+    println!(
+        "Time elapsed in weighted_popcount() is: {:?}",
+        start.elapsed()
+    );
+
+    let score = decrypt_bits(&dec, &ct);
+
+    let expected: u64 = selection
+        .iter()
+        .zip(weights.iter())
+        .filter(|(&s, _)| s)
+        .map(|(_, &w)| w)
+        .sum();
+
+    assert_eq!(score, expected % (1 << WIDTH));
+    println!("weights: {weights:?}\nselection: {selection:?}\nscore: {score}");
+}
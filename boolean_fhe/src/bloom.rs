@@ -0,0 +1,218 @@
+use std::f64::consts::LN_2;
+
+use crate::{reduce, BooleanEvaluator, ReduceOp};
+
+/// The shape of a Bloom filter, shared by the client and the server.
+///
+/// An item is hashed to `hash_count` positions among `size` bits by double
+/// hashing, `h_1 + i·h_2 mod size`. The hashes are a fixed function of the
+/// item bytes, so both sides agree on the positions on every platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BloomFilterParameters {
+    size: usize,
+    hash_count: usize,
+}
+
+impl BloomFilterParameters {
+    /// Creates a new [`BloomFilterParameters`] with `size` bits and `hash_count` hashes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `hash_count` is `0`.
+    pub fn new(size: usize, hash_count: usize) -> Self {
+        assert!(size > 0 && hash_count > 0);
+        Self { size, hash_count }
+    }
+
+    /// Returns the smallest parameters whose false positive rate
+    /// is at most `false_positive_rate` after inserting `item_count` items.
+    ///
+    /// The size is `⌈-n·ln(p) / ln(2)^2⌉` and the hash count is `round(m/n·ln(2))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item_count` is `0`, or `false_positive_rate` is not in `(0, 1)`.
+    pub fn optimal(item_count: usize, false_positive_rate: f64) -> Self {
+        assert!(item_count > 0);
+        assert!(false_positive_rate > 0.0 && false_positive_rate < 1.0);
+
+        let n = item_count as f64;
+        let size = (-n * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as usize;
+        let hash_count = ((size as f64 / n * LN_2).round() as usize).max(1);
+        Self::new(size, hash_count)
+    }
+
+    /// Returns the number of bits of the filter.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of hashes of every item.
+    #[inline]
+    pub fn hash_count(&self) -> usize {
+        self.hash_count
+    }
+
+    /// Returns the expected false positive rate `(1 - e^(-k·n/m))^k`
+    /// after inserting `item_count` distinct items.
+    pub fn false_positive_rate(&self, item_count: usize) -> f64 {
+        let k = self.hash_count as f64;
+        let fill = 1.0 - (-k * item_count as f64 / self.size as f64).exp();
+        fill.powf(k)
+    }
+
+    /// Returns the distinct positions of `item` in increasing order.
+    pub fn positions(&self, item: impl AsRef<[u8]>) -> Vec<usize> {
+        let item = item.as_ref();
+        let h1 = hash(item, 0);
+        let h2 = hash(item, 1) | 1;
+        let size = self.size as u64;
+
+        let mut positions: Vec<usize> = (0..self.hash_count as u64)
+            .map(|i| (h1.wrapping_add(i.wrapping_mul(h2)) % size) as usize)
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+}
+
+/// FNV-1a over `bytes` followed by the splitmix64 finalizer.
+fn hash(bytes: &[u8], seed: u64) -> u64 {
+    let mut h = 0xcbf29ce484222325 ^ seed.wrapping_mul(0x9e3779b97f4a7c15);
+    for &b in bytes {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x100000001b3);
+    }
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d049bb133111eb);
+    h ^ (h >> 31)
+}
+
+/// A clear Bloom filter, built by the client before encryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    parameters: BloomFilterParameters,
+    bits: Vec<bool>,
+}
+
+impl BloomFilter {
+    /// Creates an empty [`BloomFilter`].
+    pub fn new(parameters: BloomFilterParameters) -> Self {
+        Self {
+            parameters,
+            bits: vec![false; parameters.size],
+        }
+    }
+
+    /// Creates a [`BloomFilter`] containing every item of `items`.
+    pub fn from_items<I>(parameters: BloomFilterParameters, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut filter = Self::new(parameters);
+        items.into_iter().for_each(|item| filter.insert(item));
+        filter
+    }
+
+    /// Returns the parameters of this [`BloomFilter`].
+    #[inline]
+    pub fn parameters(&self) -> BloomFilterParameters {
+        self.parameters
+    }
+
+    /// Returns the bits of this [`BloomFilter`].
+    #[inline]
+    pub fn bits(&self) -> &[bool] {
+        &self.bits
+    }
+
+    /// Inserts `item` into this [`BloomFilter`].
+    pub fn insert(&mut self, item: impl AsRef<[u8]>) {
+        for i in self.parameters.positions(item) {
+            self.bits[i] = true;
+        }
+    }
+
+    /// Returns `true` if `item` may be in this [`BloomFilter`],
+    /// `false` if it is certainly not.
+    pub fn contains(&self, item: impl AsRef<[u8]>) -> bool {
+        self.parameters
+            .positions(item)
+            .into_iter()
+            .all(|i| self.bits[i])
+    }
+
+    /// Encrypts every bit of this [`BloomFilter`] with `encrypt`.
+    pub fn encrypt<B, F>(&self, encrypt: F) -> EncryptedBloomFilter<B>
+    where
+        F: FnMut(bool) -> B,
+    {
+        EncryptedBloomFilter {
+            parameters: self.parameters,
+            bits: self.bits.iter().copied().map(encrypt).collect(),
+        }
+    }
+}
+
+/// A Bloom filter with encrypted bits, queried by the server.
+///
+/// The client keeps the list private, the server learns neither
+/// the list nor the answers of its queries.
+#[derive(Debug, Clone)]
+pub struct EncryptedBloomFilter<B> {
+    parameters: BloomFilterParameters,
+    bits: Vec<B>,
+}
+
+impl<B> EncryptedBloomFilter<B> {
+    /// Creates a new [`EncryptedBloomFilter<B>`] from its encrypted `bits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `bits` is not the size of `parameters`.
+    pub fn new(parameters: BloomFilterParameters, bits: Vec<B>) -> Self {
+        assert_eq!(bits.len(), parameters.size);
+        Self { parameters, bits }
+    }
+
+    /// Returns the parameters of this [`EncryptedBloomFilter<B>`].
+    #[inline]
+    pub fn parameters(&self) -> BloomFilterParameters {
+        self.parameters
+    }
+
+    /// Returns the encrypted bits of this [`EncryptedBloomFilter<B>`].
+    #[inline]
+    pub fn bits(&self) -> &[B] {
+        &self.bits
+    }
+
+    /// Performs the homomorphic membership query of the plaintext `item`.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: plaintext `item`.
+    /// * Output: ciphertext with message `true` if `item` may be in the filter,
+    ///   `false` if it is certainly not.
+    ///
+    /// The output is the and of the bits at the positions of `item`, combined in
+    /// a balanced tree. It costs one bootstrap less than the number of distinct positions.
+    pub fn query<E>(&self, evaluator: &E, item: impl AsRef<[u8]>) -> B
+    where
+        E: BooleanEvaluator<Bit = B>,
+        B: Clone + Send + Sync,
+    {
+        let selected = self
+            .parameters
+            .positions(item)
+            .into_iter()
+            .map(|i| vec![self.bits[i].clone()])
+            .collect();
+        reduce(evaluator, selected, ReduceOp::And)
+            .and_then(|mut bit| bit.pop())
+            .unwrap()
+    }
+}
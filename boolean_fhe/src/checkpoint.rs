@@ -0,0 +1,122 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+};
+
+use algebra::{
+    integer::UnsignedInteger,
+    random::Prg,
+    utils::{read_header, write_header, DecodeLimits, Fingerprint, LeBytes},
+    AlgebraError,
+};
+use fhe_core::LweCiphertext;
+
+/// The state of a long evaluation, saved so that it resumes after a restart.
+///
+/// It holds the number of steps done, the intermediate ciphertexts, and the [`Prg`]
+/// the evaluation draws its randomness from at its current position, so a resumed
+/// evaluation gives the same output as an uninterrupted one. Bootstrapping itself
+/// is deterministic, so nothing else has to be saved.
+///
+/// A checkpoint is taken with [`Evaluator::checkpoint`](crate::Evaluator::checkpoint)
+/// and bound to the fingerprint of its evaluation key, so it only resumes with
+/// [`Evaluator::resume`](crate::Evaluator::resume) of the same key.
+#[derive(Debug, Clone)]
+pub struct Checkpoint<C: UnsignedInteger> {
+    fingerprint: Fingerprint,
+    step: u64,
+    state: Vec<LweCiphertext<C>>,
+    rng: Prg,
+}
+
+impl<C: UnsignedInteger> Checkpoint<C> {
+    /// Creates a new [`Checkpoint<C>`].
+    #[inline]
+    pub(crate) fn new(
+        fingerprint: Fingerprint,
+        step: u64,
+        state: Vec<LweCiphertext<C>>,
+        rng: Prg,
+    ) -> Self {
+        Self {
+            fingerprint,
+            step,
+            state,
+            rng,
+        }
+    }
+
+    /// Returns the fingerprint of the evaluation key of this [`Checkpoint<C>`].
+    #[inline]
+    pub fn fingerprint(&self) -> &Fingerprint {
+        &self.fingerprint
+    }
+
+    /// Returns the number of steps done when this [`Checkpoint<C>`] was taken.
+    #[inline]
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Drops self, and returns the step, the ciphertexts and the [`Prg`].
+    #[inline]
+    pub(crate) fn into_parts(self) -> (u64, Vec<LweCiphertext<C>>, Prg) {
+        (self.step, self.state, self.rng)
+    }
+}
+
+impl<C: UnsignedInteger + LeBytes> Checkpoint<C> {
+    /// Saves this [`Checkpoint<C>`] to `path`.
+    ///
+    /// The encoding is written and synced to a temporary file next to `path`,
+    /// which then replaces `path`, so a crash while saving leaves the previous
+    /// checkpoint intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the file system if the checkpoint fails to be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        let mut file = File::create(&tmp)?;
+        file.write_all(&self.to_le_vec())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Loads a [`Checkpoint<C>`] saved to `path`, within `limits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the file system if the file fails to be read,
+    /// and an error of kind [`io::ErrorKind::InvalidData`] if it is not a valid checkpoint.
+    pub fn load(path: impl AsRef<Path>, limits: &DecodeLimits) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        Self::from_le_slice_with(&bytes, limits)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+/// The checkpoint is encoded after its header as the fingerprint of its key,
+/// the step in `u64`, the sequence of ciphertexts and the [`Prg`].
+impl<C: UnsignedInteger + LeBytes> LeBytes for Checkpoint<C> {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"CKPT", buf);
+        self.fingerprint.write_le(buf);
+        self.step.write_le(buf);
+        self.state.write_le(buf);
+        self.rng.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"CKPT", bytes)?;
+        let fingerprint = Fingerprint::read_le(bytes)?;
+        let step = u64::read_le(bytes)?;
+        let state = Vec::read_le_with(bytes, limits)?;
+        let rng = Prg::read_le(bytes)?;
+        Ok(Self::new(fingerprint, step, state, rng))
+    }
+}
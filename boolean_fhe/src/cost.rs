@@ -0,0 +1,138 @@
+use std::time::{Duration, Instant};
+
+use algebra::{
+    utils::{read_header, write_header, DecodeLimits, LeBytes},
+    AlgebraError,
+};
+
+use crate::{BooleanEvaluator, OpCode, OperationLog};
+
+/// Per-operation latency estimates of a [`BooleanEvaluator`] backend.
+///
+/// Every gate except `not` is dominated by its bootstraps, so the model keeps the
+/// latency of one bootstrapped gate and one `not`, and scales them by
+/// [`OpCode::bootstraps`]. A model belongs to one backend and parameter set,
+/// it's either measured on the running machine with [`calibrate`](CostModel::calibrate)
+/// or restored from earlier measurements with [`new`](CostModel::new).
+///
+/// A calibrated model is saved with [`LeBytes::to_le_vec`] and loaded back with
+/// [`LeBytes::from_le_slice`], so it is measured once and stored per backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostModel {
+    bootstrap: Duration,
+    not: Duration,
+}
+
+impl CostModel {
+    /// Creates a new [`CostModel`] with the latency of one bootstrapped gate and one `not`.
+    #[inline]
+    pub fn new(bootstrap: Duration, not: Duration) -> Self {
+        Self { bootstrap, not }
+    }
+
+    /// Measures the gate latencies of `evaluator`, averaged over `samples` runs.
+    ///
+    /// `bit` is any valid input of `evaluator`, e.g. an encryption of `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is `0`.
+    pub fn calibrate<E: BooleanEvaluator>(evaluator: &E, bit: &E::Bit, samples: u32) -> Self {
+        assert!(samples > 0);
+
+        let start = Instant::now();
+        let mut c = bit.clone();
+        for _ in 0..samples {
+            c = evaluator.and(&c, bit);
+        }
+        let bootstrap = start.elapsed() / samples;
+
+        let start = Instant::now();
+        for _ in 0..samples {
+            c = evaluator.not(&c);
+        }
+        let not = start.elapsed() / samples;
+
+        Self { bootstrap, not }
+    }
+
+    /// Returns the latency of one bootstrapped gate.
+    #[inline]
+    pub fn bootstrap_latency(&self) -> Duration {
+        self.bootstrap
+    }
+
+    /// Returns the latency of one `not`.
+    #[inline]
+    pub fn not_latency(&self) -> Duration {
+        self.not
+    }
+
+    /// Returns the estimated latency of `op`.
+    #[inline]
+    pub fn estimate_cost(&self, op: OpCode) -> Duration {
+        match op {
+            OpCode::Input => Duration::ZERO,
+            OpCode::Not => self.not,
+            // the selector is negated for its second branch
+            OpCode::Mux => self.bootstrap * op.bootstraps() + self.not,
+            _ => self.bootstrap * op.bootstraps(),
+        }
+    }
+
+    /// Returns the estimated latency of evaluating `log` one operation at a time.
+    ///
+    /// Gates which run in parallel overlap, so this is an upper bound of the wall time
+    /// on a multi-core machine.
+    pub fn estimate_log(&self, log: &OperationLog) -> Duration {
+        log.operations()
+            .iter()
+            .map(|operation| self.estimate_cost(operation.op))
+            .sum()
+    }
+}
+
+/// The model is encoded after its header as the two latencies,
+/// each as whole seconds in `u64` and nanoseconds in `u32`.
+impl LeBytes for CostModel {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"COST", buf);
+        write_duration(self.bootstrap, buf);
+        write_duration(self.not, buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"COST", bytes)?;
+        let bootstrap = read_duration(bytes)?;
+        let not = read_duration(bytes)?;
+        Ok(Self { bootstrap, not })
+    }
+}
+
+#[inline]
+fn write_duration(duration: Duration, buf: &mut Vec<u8>) {
+    duration.as_secs().write_le(buf);
+    duration.subsec_nanos().write_le(buf);
+}
+
+#[inline]
+fn read_duration(bytes: &mut &[u8]) -> Result<Duration, AlgebraError> {
+    let secs = u64::read_le(bytes)?;
+    let nanos = u32::read_le(bytes)?;
+    if nanos < 1_000_000_000 {
+        Ok(Duration::new(secs, nanos))
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+/// Returns the estimated latency of `op` on the backend calibrated in `params`.
+///
+/// The latencies come from calibration, not from the scheme parameters alone,
+/// so `params` is the [`CostModel`] measured for a backend and parameter set.
+/// This is the same as [`CostModel::estimate_cost`].
+#[inline]
+pub fn estimate_cost(op: OpCode, params: &CostModel) -> Duration {
+    params.estimate_cost(op)
+}
@@ -0,0 +1,109 @@
+use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use fhe_core::LweCiphertext;
+
+use crate::Evaluator;
+
+/// An encrypted counter which sums encrypted bits.
+///
+/// The count is stored as `width` encrypted bits in little-endian order and
+/// wraps around modulo `2^width`. Every update goes through bootstrapped gates,
+/// so the noise stays bounded no matter how many updates are applied.
+///
+/// The number of updates is public, so the counter knows which high bits are
+/// still trivially `false` and skips their gates.
+///
+/// # Bootstrapping schedule
+///
+/// The counter does not track a noise budget or delay bootstrapping until the
+/// budget runs out. Every update bootstraps right away, so [`FheCounter::bits`]
+/// always holds fresh ciphertexts of the exact count.
+///
+/// Delaying would not save much. A boolean ciphertext has plaintext modulus `4`,
+/// so an unbootstrapped sum holds at most three bits, as in
+/// [`Evaluator::majority`]. Folding two pending bits with full adders costs two
+/// bootstraps per bit position, [`Evaluator::xor3`] and [`Evaluator::majority`],
+/// against four for two rounds of half adders. That saves at most half of the
+/// bootstraps, but the bits would only hold the count after a flush.
+///
+/// # Cost
+///
+/// With `a = min(bit_length(k - 1), width)` active bits, the `k`-th update costs
+/// `2 * a` bootstraps, or `2 * a - 1` once `a == width`. The first update is free.
+/// `K` updates cost about `2 * K * log2(K)` bootstraps in total, in `a` sequential
+/// rounds of two parallel gates per update.
+#[derive(Clone)]
+pub struct FheCounter<C: UnsignedInteger> {
+    bits: Vec<LweCiphertext<C>>,
+    updates: u64,
+}
+
+impl<C: UnsignedInteger> FheCounter<C> {
+    /// Creates a new [`FheCounter<C>`] with value `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is `0` or larger than `64`.
+    #[inline]
+    pub fn new(width: usize, lwe_dimension: usize) -> Self {
+        assert!(width > 0 && width <= 64);
+        Self {
+            bits: vec![LweCiphertext::zero(lwe_dimension); width],
+            updates: 0,
+        }
+    }
+
+    /// Returns the bit width of this [`FheCounter<C>`].
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Returns the number of updates applied to this [`FheCounter<C>`].
+    #[inline]
+    pub fn updates(&self) -> u64 {
+        self.updates
+    }
+
+    /// Returns the encrypted bits of the count in little-endian order.
+    #[inline]
+    pub fn bits(&self) -> &[LweCiphertext<C>] {
+        &self.bits
+    }
+
+    /// Drops self, and returns the encrypted bits of the count.
+    #[inline]
+    pub fn into_bits(self) -> Vec<LweCiphertext<C>> {
+        self.bits
+    }
+
+    /// Performs `self += bit` with a ripple of half adders.
+    pub fn add_bit<LweModulus, Q>(
+        &mut self,
+        bit: &LweCiphertext<C>,
+        evaluator: &Evaluator<C, LweModulus, Q>,
+    ) where
+        LweModulus: RingReduce<C>,
+        Q: NttField,
+    {
+        let width = self.bits.len();
+        // only the low `active` bits may be `true` before this update
+        let active = ((u64::BITS - self.updates.leading_zeros()) as usize).min(width);
+
+        let mut carry = bit.clone();
+        for (i, b) in self.bits[..active].iter_mut().enumerate() {
+            if i + 1 == width {
+                *b = evaluator.xor(b, &carry);
+            } else {
+                let (sum, c) =
+                    rayon::join(|| evaluator.xor(b, &carry), || evaluator.and(b, &carry));
+                *b = sum;
+                carry = c;
+            }
+        }
+        if active < width {
+            self.bits[active] = carry;
+        }
+
+        self.updates = self.updates.saturating_add(1);
+    }
+}
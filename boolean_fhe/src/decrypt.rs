@@ -1,5 +1,5 @@
 use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
-use fhe_core::{LweCiphertext, LweParameters, LweSecretKey};
+use fhe_core::{LweCiphertext, LweParameters, LweSecretKey, SecurityProfile};
 
 use crate::SecretKeyPack;
 
@@ -7,6 +7,7 @@ use crate::SecretKeyPack;
 pub struct Decryptor<C: UnsignedInteger, LweModulus: RingReduce<C>> {
     lwe_secret_key: LweSecretKey<C>,
     params: LweParameters<C, LweModulus>,
+    profile: SecurityProfile,
 }
 
 impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Decryptor<C, LweModulus> {
@@ -16,16 +17,35 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Decryptor<C, LweModulus> {
         Self {
             lwe_secret_key: sk.lwe_secret_key().clone(),
             params: *sk.lwe_params(),
+            profile: SecurityProfile::default(),
         }
     }
 
+    /// Returns the security profile of this [`Decryptor<C, LweModulus>`].
+    #[inline]
+    pub fn security_profile(&self) -> SecurityProfile {
+        self.profile
+    }
+
+    /// Sets the security profile used by [`Decryptor::decrypt`].
+    #[inline]
+    pub fn set_security_profile(&mut self, profile: SecurityProfile) {
+        self.profile = profile;
+    }
+
     /// Decrypt a ciphertext into a message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decoded message cannot fit in `M`.
     #[inline]
     pub fn decrypt<M>(&self, cipher_text: &LweCiphertext<C>) -> M
     where
         M: TryFrom<C>,
     {
-        self.lwe_secret_key.decrypt(cipher_text, &self.params)
+        self.lwe_secret_key
+            .decrypt_with_profile(cipher_text, &self.params, self.profile)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Decrypt a ciphertext into a bool message and an error.
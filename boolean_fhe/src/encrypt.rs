@@ -1,5 +1,5 @@
 use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
-use fhe_core::{LweCiphertext, LweParameters, LweSecretKey};
+use fhe_core::{LweCiphertext, LweParameters, LweSecretKey, SecurityProfile};
 
 use crate::SecretKeyPack;
 
@@ -7,6 +7,7 @@ use crate::SecretKeyPack;
 pub struct Encryptor<C: UnsignedInteger, LweModulus: RingReduce<C>> {
     lwe_secret_key: LweSecretKey<C>,
     params: LweParameters<C, LweModulus>,
+    profile: SecurityProfile,
 }
 
 impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Encryptor<C, LweModulus> {
@@ -16,16 +17,35 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Encryptor<C, LweModulus> {
         Self {
             lwe_secret_key: sk.lwe_secret_key().clone(),
             params: *sk.lwe_params(),
+            profile: SecurityProfile::default(),
         }
     }
 
+    /// Returns the security profile of this [`Encryptor<C, LweModulus>`].
+    #[inline]
+    pub fn security_profile(&self) -> SecurityProfile {
+        self.profile
+    }
+
+    /// Sets the security profile used by [`Encryptor::encrypt`].
+    #[inline]
+    pub fn set_security_profile(&mut self, profile: SecurityProfile) {
+        self.profile = profile;
+    }
+
     /// Encrypt a bool message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message exceeds the message space.
     #[inline]
     pub fn encrypt<M, R>(&self, message: M, rng: &mut R) -> LweCiphertext<C>
     where
         M: TryInto<C>,
         R: rand::Rng + rand::CryptoRng,
     {
-        self.lwe_secret_key.encrypt(message, &self.params, rng)
+        self.lwe_secret_key
+            .encrypt_with_profile(message, &self.params, self.profile, rng)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
@@ -1,19 +1,29 @@
-use std::sync::Arc;
+use std::{
+    io::{self, Write},
+    sync::{Arc, OnceLock},
+};
 
 use algebra::{
     integer::UnsignedInteger,
+    ntt::NttTable,
     polynomial::FieldPolynomial,
+    random::Prg,
     reduce::{ModulusValue, ReduceAddAssign, RingReduce},
-    Field, NttField,
+    utils::{read_header, write_header, DecodeLimits, Fingerprint, KeyBound, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use fhe_core::{
-    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace, BlindRotationKey,
-    LweCiphertext, LweKeySwitchingKeyRlweMode, LweSecretKey, LweSecretKeyType,
+    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace, utils::Pool,
+    BlindRotationKey, LweCiphertext, LweKeySwitchingKeyRlweMode, LweSecretKey, LweSecretKeyType,
     NonPowOf2LweKeySwitchingKey, PowOf2LweKeySwitchingKey, RingSecretKeyType,
 };
 use rand::{CryptoRng, Rng};
+use rayon::prelude::*;
 
-use crate::{parameter::Steps, BooleanFheParameters, LookUpTable, SecretKeyPack};
+use crate::{
+    integer::add_weighted, parameter::Steps, BooleanFheParameters, Checkpoint, LookUpTable,
+    SecretKeyPack,
+};
 
 /// A enum type for different key switching purposes.
 #[derive(Clone)]
@@ -90,6 +100,43 @@ impl<C: UnsignedInteger, Q: NttField> KeySwitchingKey<C, Q> {
     }
 }
 
+/// The key is encoded as a byte, in the order of the variants,
+/// followed by the encoding of the key switching key, if any.
+impl<C, Q> LeBytes for KeySwitchingKey<C, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        match self {
+            KeySwitchingKey::PowOf2DimensionLwe(key) => {
+                buf.push(0);
+                key.write_le(buf);
+            }
+            KeySwitchingKey::PowOf2ModulusLwe(key) => {
+                buf.push(1);
+                key.write_le(buf);
+            }
+            KeySwitchingKey::NonPowOf2ModulusLwe(key) => {
+                buf.push(2);
+                key.write_le(buf);
+            }
+            KeySwitchingKey::None => buf.push(3),
+        }
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        match u8::read_le(bytes)? {
+            0 => LeBytes::read_le_with(bytes, limits).map(KeySwitchingKey::PowOf2DimensionLwe),
+            1 => LeBytes::read_le_with(bytes, limits).map(KeySwitchingKey::PowOf2ModulusLwe),
+            2 => LeBytes::read_le_with(bytes, limits).map(KeySwitchingKey::NonPowOf2ModulusLwe),
+            3 => Ok(KeySwitchingKey::None),
+            _ => Err(AlgebraError::DecodeErr),
+        }
+    }
+}
+
 /// The evaluator of the homomorphic encryption scheme.
 #[derive(Clone)]
 pub struct EvaluationKey<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> {
@@ -99,6 +146,10 @@ pub struct EvaluationKey<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttFi
     key_switching_key: KeySwitchingKey<C, Q>,
     /// The parameters of the fully homomorphic encryption scheme.
     parameters: BooleanFheParameters<C, LweModulus, Q>,
+    /// Reusable buffers for the key switched ciphertexts.
+    space: Pool<LweCiphertext<<Q as Field>::ValueT>>,
+    /// The fingerprint, computed when it is first asked for.
+    fingerprint: OnceLock<Fingerprint>,
 }
 
 impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C, LweModulus, Q> {
@@ -124,10 +175,72 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
             Arc::clone(secret_key_pack.ntt_table()),
             rng,
         );
+        let key_switching_key = Self::generate_key_switching_key(secret_key_pack, rng);
+
+        Self {
+            blind_rotation_key,
+            key_switching_key,
+            parameters: *parameters,
+            space: Pool::new(),
+            fingerprint: OnceLock::new(),
+        }
+    }
+
+    /// Generates the [`EvaluationKey`] of the given [`SecretKeyPack`] straight into
+    /// its encoding in `writer`.
+    ///
+    /// The blind rotation key, which makes up most of the evaluation key, is written
+    /// one RGSW ciphertext at a time with [`BlindRotationKey::generate_to`], so keys
+    /// larger than the memory are generated to a file. The bytes are those of the key
+    /// [`EvaluationKey::new`] returns for an equally seeded `rng`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `writer`.
+    pub fn generate_to<R, W>(
+        writer: &mut W,
+        secret_key_pack: &SecretKeyPack<C, LweModulus, Q>,
+        rng: &mut R,
+    ) -> io::Result<()>
+    where
+        R: Rng + CryptoRng,
+        W: Write,
+        C: LeBytes,
+        <Q as Field>::ValueT: LeBytes,
+    {
+        let parameters = secret_key_pack.parameters();
+        let mut buf = Vec::new();
+        write_header(b"EVKY", &mut buf);
+        parameters.write_le(&mut buf);
+        writer.write_all(&buf)?;
+
+        BlindRotationKey::generate_to(
+            writer,
+            secret_key_pack.lwe_secret_key(),
+            secret_key_pack.ntt_rlwe_secret_key(),
+            parameters.blind_rotation_basis(),
+            parameters.ring_noise_distribution(),
+            secret_key_pack.ntt_table(),
+            rng,
+        )?;
+
+        buf.clear();
+        Self::generate_key_switching_key(secret_key_pack, rng).write_le(&mut buf);
+        writer.write_all(&buf)
+    }
 
+    /// Generates the key switching key of the given [`SecretKeyPack`].
+    fn generate_key_switching_key<R>(
+        secret_key_pack: &SecretKeyPack<C, LweModulus, Q>,
+        rng: &mut R,
+    ) -> KeySwitchingKey<C, Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        let parameters = secret_key_pack.parameters();
         let s_in = secret_key_pack.rlwe_secret_key();
         let s_out = secret_key_pack.lwe_secret_key();
-        let key_switching_key = match parameters.steps() {
+        match parameters.steps() {
             Steps::BrMsKs => {
                 let lwe_cipher_modulus_minus_one = parameters.lwe_cipher_modulus_minus_one();
                 let s_in = LweSecretKey::from_rlwe_secret_key(s_in, lwe_cipher_modulus_minus_one);
@@ -170,12 +283,6 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                 KeySwitchingKey::NonPowOf2ModulusLwe(ksk)
             }
             Steps::BrMs => KeySwitchingKey::None,
-        };
-
-        Self {
-            blind_rotation_key,
-            key_switching_key,
-            parameters: *parameters,
         }
     }
 
@@ -212,7 +319,8 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                     _ => panic!("Unable to get the corresponding key switching key!"),
                 };
 
-                c = ksk.key_switch(&cipher, parameters.lwe_cipher_modulus());
+                // `c` already has the output dimension, reuse its buffer.
+                ksk.key_switch_inplace(&cipher, parameters.lwe_cipher_modulus(), &mut c);
             }
             Steps::BrKsRlevMs => {
                 let ksk = match self.key_switching_key {
@@ -220,14 +328,16 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                     _ => panic!("Unable to get the corresponding key switching key!"),
                 };
 
-                let key_switched = ksk.key_switch_for_rlwe(acc);
+                let mut key_switched = self.key_switched_buffer();
+                ksk.key_switch_for_rlwe_inplace(acc, &mut key_switched);
 
                 lwe_modulus_switch_inplace(
-                    key_switched,
+                    &key_switched,
                     Q::MODULUS_VALUE,
                     parameters.lwe_cipher_modulus_value(),
                     &mut c,
                 );
+                self.space.store(key_switched);
             }
             Steps::BrKsLevMs => {
                 let acc = acc.extract_lwe_locally();
@@ -235,19 +345,23 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                     .key_switching_key
                     .as_non_pow_of_2_modulus_lwe()
                     .unwrap();
-                let temp = ksk.key_switch(&acc, Q::MODULUS);
 
-                c = lwe_modulus_switch(
-                    &temp,
-                    parameters.ring_modulus(),
+                let mut key_switched = self.key_switched_buffer();
+                ksk.key_switch_inplace(&acc, Q::MODULUS, &mut key_switched);
+
+                lwe_modulus_switch_inplace(
+                    &key_switched,
+                    Q::MODULUS_VALUE,
                     parameters.lwe_cipher_modulus_value(),
+                    &mut c,
                 );
+                self.space.store(key_switched);
             }
             Steps::BrMs => {
                 let lwe = acc.extract_lwe_locally();
 
                 lwe_modulus_switch_inplace(
-                    lwe,
+                    &lwe,
                     Q::MODULUS_VALUE,
                     parameters.lwe_cipher_modulus_value(),
                     &mut c,
@@ -257,9 +371,103 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
 
         c
     }
+
+    /// Takes a buffer for the key switched ciphertext from the pool.
+    fn key_switched_buffer(&self) -> LweCiphertext<<Q as Field>::ValueT> {
+        self.space
+            .get()
+            .unwrap_or_else(|| LweCiphertext::zero(self.parameters.lwe_dimension()))
+    }
+}
+
+impl<C, LweModulus, Q> EvaluationKey<C, LweModulus, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    /// Returns the fingerprint of this [`EvaluationKey<C, LweModulus, Q>`].
+    #[inline]
+    pub fn fingerprint(&self) -> Fingerprint {
+        *self.fingerprint.get_or_init(|| Fingerprint::of(self))
+    }
+
+    /// Checks that the keys fit the parameters, so that bootstrapping can't panic.
+    fn is_consistent(&self) -> bool {
+        let parameters = &self.parameters;
+        let lwe_dimension = parameters.lwe_dimension();
+        let ring_dimension = parameters.ring_dimension();
+
+        let is_valid_blind_rotation_key = self.blind_rotation_key.lwe_dimension() == lwe_dimension
+            && self.blind_rotation_key.ntt_table().dimension() == ring_dimension
+            && matches!(
+                (&self.blind_rotation_key, parameters.lwe_secret_key_type()),
+                (BlindRotationKey::Binary(_), LweSecretKeyType::Binary)
+                    | (BlindRotationKey::Ternary(_), LweSecretKeyType::Ternary)
+            );
+
+        let params = parameters.key_switching_params();
+        let is_valid_key_switching_key = match (parameters.steps(), &self.key_switching_key) {
+            (Steps::BrMsKs, KeySwitchingKey::PowOf2ModulusLwe(ksk)) => {
+                ksk.key_switching_params() == params
+            }
+            (Steps::BrKsRlevMs, KeySwitchingKey::PowOf2DimensionLwe(ksk)) => {
+                ksk.key_switching_params() == params
+            }
+            (Steps::BrKsLevMs, KeySwitchingKey::NonPowOf2ModulusLwe(ksk)) => {
+                ksk.key_switching_params() == params && ksk.basis().modulus() == Q::MODULUS_VALUE
+            }
+            (Steps::BrMs, KeySwitchingKey::None) => lwe_dimension == ring_dimension,
+            _ => false,
+        };
+
+        is_valid_blind_rotation_key
+            && is_valid_key_switching_key
+            && C::try_from(ring_dimension << 1).is_ok()
+    }
+}
+
+/// The key is encoded after its header as its parameters,
+/// followed by its blind rotation key and its key switching key.
+///
+/// Decoding checks that the keys fit the parameters and each other.
+impl<C, LweModulus, Q> LeBytes for EvaluationKey<C, LweModulus, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"EVKY", buf);
+        self.parameters.write_le(buf);
+        self.blind_rotation_key.write_le(buf);
+        self.key_switching_key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"EVKY", bytes)?;
+        let key = Self {
+            parameters: LeBytes::read_le_with(bytes, limits)?,
+            blind_rotation_key: LeBytes::read_le_with(bytes, limits)?,
+            key_switching_key: LeBytes::read_le_with(bytes, limits)?,
+            space: Pool::new(),
+            fingerprint: OnceLock::new(),
+        };
+        if key.is_consistent() {
+            Ok(key)
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
 }
 
 /// Evaluator
+///
+/// Every operation is deterministic, except those taking an `rng` argument,
+/// which draw all their randomness from it. Replicas holding the same evaluation
+/// key and passing equally seeded generators produce identical ciphertexts.
 #[derive(Clone)]
 pub struct Evaluator<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> {
     ek: EvaluationKey<C, LweModulus, Q>,
@@ -274,6 +482,28 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         }
     }
 
+    /// Generates the evaluation key of `sk` straight into the encoding of an
+    /// [`Evaluator`] in `writer`, one RGSW ciphertext of the blind rotation key
+    /// at a time, so that keys larger than the memory are generated to a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `writer`.
+    #[inline]
+    pub fn generate_to<R, W>(
+        writer: &mut W,
+        sk: &SecretKeyPack<C, LweModulus, Q>,
+        rng: &mut R,
+    ) -> io::Result<()>
+    where
+        R: Rng + CryptoRng,
+        W: Write,
+        C: LeBytes,
+        <Q as Field>::ValueT: LeBytes,
+    {
+        EvaluationKey::generate_to(writer, sk, rng)
+    }
+
     /// Returns a reference to the parameters of this [`Evaluator<F>`].
     #[inline]
     pub fn parameters(&self) -> &BooleanFheParameters<C, LweModulus, Q> {
@@ -469,6 +699,39 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         self.bootstrap(add, lut)
     }
 
+    /// Performs the homomorphic xor operation of three bits.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertext `c0`, with message `a`.
+    /// * Input: ciphertext `c1`, with message `b`.
+    /// * Input: ciphertext `c2`, with message `c`.
+    /// * Output: ciphertext with message `a xor b xor c`.
+    ///
+    /// `2 * (a + b + c)` is `0` or `2` modulo `4` by the parity of the three bits,
+    /// so a single bootstrap with the look-up table of [`Evaluator::xor`] computes it,
+    /// with the same noise bound as [`Evaluator::majority`].
+    pub fn xor3(
+        &self,
+        c0: &LweCiphertext<C>,
+        c1: &LweCiphertext<C>,
+        c2: &LweCiphertext<C>,
+    ) -> LweCiphertext<C> {
+        let parameters = self.parameters();
+        let cipher_modulus = parameters.lwe_cipher_modulus();
+
+        let mut add = c0.add_reduce_component_wise_ref(c1, cipher_modulus);
+        add.add_reduce_assign_component_wise(c2, cipher_modulus);
+        add.mul_scalar_reduce_assign(C::ONE + C::ONE, cipher_modulus);
+
+        let lut = xor_lut(
+            parameters.ring_dimension(),
+            parameters.lwe_plain_modulus().as_into(),
+        );
+
+        self.bootstrap(add, lut)
+    }
+
     /// Performs the homomorphic mux operation.
     ///
     /// # Arguments
@@ -501,6 +764,244 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
 
         self.bootstrap(t0, lut)
     }
+
+    /// Performs the homomorphic set membership test.
+    ///
+    /// The server holds the plaintext `set`, the client sends `element` as
+    /// encrypted bits in little-endian order.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `element`, with message bits `x_0, x_1, ...`.
+    /// * Input: plaintext values `set`.
+    /// * Output: ciphertext with message `true` if `x` is in `set`, otherwise `false`.
+    ///
+    /// Each set entry costs `element.len() - 1` bootstraps for the equality
+    /// test and one more bootstrap for the final or, the entries are evaluated in parallel.
+    /// Entries with more bits than `element` can never match, they are skipped,
+    /// so an empty set or a set of wider entries gives a trivial encryption of `false`.
+    ///
+    /// The set is not compiled into a single test polynomial. A bootstrap looks up
+    /// the phase of one LWE ciphertext, and with the message modulus `t = 4` and a
+    /// negacyclic table only two inputs can be mapped freely, since `f(m + t/2) = -f(m)`.
+    /// A multi-bit `x` does not fit in one lookup, and LWE ciphertexts have no slots
+    /// to batch the entries across. The equality tests are the per-bit fallback of such
+    /// a table, one bootstrap per literal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is empty or has more than `64` bits.
+    pub fn contains(&self, element: &[LweCiphertext<C>], set: &[u64]) -> LweCiphertext<C> {
+        assert!(!element.is_empty() && element.len() <= 64);
+
+        let bits = element.len() as u32;
+        let not_element: Vec<LweCiphertext<C>> = element.iter().map(|c| self.not(c)).collect();
+
+        set.par_iter()
+            // wider values can never be equal to `x`
+            .filter(|&&value| bits == 64 || value >> bits == 0)
+            .map(|&value| {
+                // x_i if the i-th bit of `value` is set, otherwise !x_i
+                let mut literals = element
+                    .iter()
+                    .zip(not_element.iter())
+                    .enumerate()
+                    .map(|(i, (x, not_x))| if (value >> i) & 1 == 1 { x } else { not_x });
+                let first = literals.next().unwrap().clone();
+                literals.fold(first, |acc, literal| self.and(&acc, literal))
+            })
+            .reduce_with(|a, b| self.or(&a, &b))
+            .unwrap_or_else(|| LweCiphertext::zero(self.parameters().lwe_dimension()))
+    }
+
+    /// Performs the homomorphic weighted popcount.
+    ///
+    /// The client sends encrypted selection bits, the server holds the plaintext `weights`.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `selection`, with message bits `s_0, s_1, ...`.
+    /// * Input: plaintext values `weights`, with values `w_0, w_1, ...`.
+    /// * Output: `width` ciphertexts, the little-endian bits of `sum(s_i * w_i) mod 2^width`.
+    ///
+    /// The products `s_i * w_i` are free: bit `j` of `s_i * w_i` is `s_i` if bit `j` of `w_i`
+    /// is set and trivially `false` otherwise. The products are accumulated with ripple-carry
+    /// adders which skip the trivially `false` inputs, so sparse weights are cheaper.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `selection` and `weights` are not equal,
+    /// or `width` is `0` or larger than `64`.
+    pub fn weighted_popcount(
+        &self,
+        selection: &[LweCiphertext<C>],
+        weights: &[u64],
+        width: usize,
+    ) -> Vec<LweCiphertext<C>> {
+        assert_eq!(selection.len(), weights.len());
+        assert!(width > 0 && width <= 64);
+
+        // `None` is a trivial encryption of `false`
+        let mut acc: Vec<Option<LweCiphertext<C>>> = vec![None; width];
+
+        for (s, &w) in selection.iter().zip(weights) {
+            add_weighted(self, &mut acc, s, w);
+        }
+
+        let lwe_dimension = self.parameters().lwe_dimension();
+        acc.into_iter()
+            .map(|a| a.unwrap_or_else(|| LweCiphertext::zero(lwe_dimension)))
+            .collect()
+    }
+
+    /// Performs the homomorphic threshold release of an aggregate.
+    ///
+    /// The aggregate is only released if the encrypted count reaches the public
+    /// `threshold`, otherwise every bit of it is replaced by a random bit, so the
+    /// output looks like a valid aggregate either way.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `count`, the little-endian bits of a count `n`.
+    /// * Input: plaintext value `threshold`.
+    /// * Input: ciphertexts `aggregate`, with message bits `a_0, a_1, ...`.
+    /// * Output: ciphertexts with message bits `a_i` if `n >= threshold`,
+    ///   otherwise uniformly random bits drawn from `rng`.
+    ///
+    /// The masks are drawn from `rng` in order before any bootstrap, so the output
+    /// only depends on the inputs and the state of `rng`.
+    ///
+    /// The comparison with the public threshold costs one bootstrap per bit of `count`
+    /// above the lowest set bit of `threshold`. Each output bit costs one more bootstrap,
+    /// since a mux with a public bit is a single `and` or `or`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is empty or has more than `64` bits.
+    pub fn threshold_release<R>(
+        &self,
+        count: &[LweCiphertext<C>],
+        threshold: u64,
+        aggregate: &[LweCiphertext<C>],
+        rng: &mut R,
+    ) -> Vec<LweCiphertext<C>>
+    where
+        R: Rng + CryptoRng,
+    {
+        assert!(!count.is_empty() && count.len() <= 64);
+
+        let masks: Vec<bool> = aggregate.iter().map(|_| rng.gen()).collect();
+
+        let ge = if count.len() < 64 && threshold >> count.len() != 0 {
+            // `n < threshold` for any count, the masks still go through the bootstraps
+            // below, so they are fresh encryptions instead of trivial ones.
+            LweCiphertext::zero(self.parameters().lwe_dimension())
+        } else {
+            // `n >= threshold` on the low bits, `None` is a trivial encryption of `true`
+            let mut ge: Option<LweCiphertext<C>> = None;
+            for (i, x) in count.iter().enumerate() {
+                ge = match (ge, (threshold >> i) & 1 == 1) {
+                    (None, false) => None,
+                    (None, true) => Some(x.clone()),
+                    (Some(g), false) => Some(self.or(x, &g)),
+                    (Some(g), true) => Some(self.and(x, &g)),
+                };
+            }
+
+            let Some(ge) = ge else {
+                return aggregate.to_vec();
+            };
+            ge
+        };
+        let not_ge = self.not(&ge);
+
+        aggregate
+            .par_iter()
+            .zip(masks)
+            .map(|(a, m)| {
+                // if ge {a} else {m}
+                if m {
+                    self.or(a, &not_ge)
+                } else {
+                    self.and(a, &ge)
+                }
+            })
+            .collect()
+    }
+}
+
+impl<C, LweModulus, Q> Evaluator<C, LweModulus, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    /// Returns the fingerprint of the evaluation key of this [`Evaluator<C, LweModulus, Q>`].
+    ///
+    /// A client binds its ciphertexts to it with [`KeyBound::new`], so a server
+    /// holding the evaluators of many clients can pick the right one.
+    #[inline]
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.ek.fingerprint()
+    }
+
+    /// Returns the ciphertext if it belongs to the evaluation key of this [`Evaluator<C, LweModulus, Q>`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::KeyMismatch`] if the ciphertext belongs to another key.
+    #[inline]
+    pub fn check<'a>(
+        &self,
+        ciphertext: &'a KeyBound<LweCiphertext<C>>,
+    ) -> Result<&'a LweCiphertext<C>, AlgebraError> {
+        ciphertext.check(&self.fingerprint())
+    }
+
+    /// Takes a [`Checkpoint<C>`] of an evaluation after `step` steps,
+    /// with its intermediate ciphertexts `state` and the `rng` it draws from.
+    #[inline]
+    pub fn checkpoint(&self, step: u64, state: &[LweCiphertext<C>], rng: &Prg) -> Checkpoint<C> {
+        Checkpoint::new(self.fingerprint(), step, state.to_vec(), rng.clone())
+    }
+
+    /// Returns the step, the intermediate ciphertexts and the rng of `checkpoint`,
+    /// to resume the evaluation from there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::KeyMismatch`] if the checkpoint was taken with another key.
+    #[inline]
+    pub fn resume(
+        &self,
+        checkpoint: Checkpoint<C>,
+    ) -> Result<(u64, Vec<LweCiphertext<C>>, Prg), AlgebraError> {
+        if *checkpoint.fingerprint() == self.fingerprint() {
+            Ok(checkpoint.into_parts())
+        } else {
+            Err(AlgebraError::KeyMismatch)
+        }
+    }
+}
+
+/// The evaluator is encoded as its evaluation key.
+impl<C, LweModulus, Q> LeBytes for Evaluator<C, LweModulus, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.ek.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        EvaluationKey::read_le_with(bytes, limits).map(|ek| Self { ek })
+    }
 }
 
 /// init lut for bootstrapping which performs homomorphic `nand`.
@@ -564,7 +1065,7 @@ where
     [q_div_8, neg_q_div_8, neg_q_div_8, q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus)
 }
 
-/// init lut for bootstrapping which performs homomorphic `xor`.
+/// init lut for bootstrapping which performs homomorphic `xor` or `xor3`.
 fn xor_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
 where
     F: NttField,
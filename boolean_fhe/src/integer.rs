@@ -0,0 +1,396 @@
+use rayon::prelude::*;
+
+use crate::{reduce, BooleanEvaluator, ReduceOp};
+
+/// How encrypted integer operations handle results out of the word range.
+///
+/// Every value is an unsigned word of little-endian bits, all of the same width.
+/// [`OverflowMode::Wrapping`] is the cheapest, the other modes also compute
+/// whether the operation overflowed, which costs a few more bootstraps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// The result is taken modulo `2^width`.
+    Wrapping,
+    /// The result is clamped to `[0, 2^width - 1]`.
+    Saturating,
+    /// The result is taken modulo `2^width`, followed by an extra bit
+    /// which is `true` iff the operation overflowed.
+    Checked,
+}
+
+impl OverflowMode {
+    /// Computes `x + y` with `evaluator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` have different widths, or are empty.
+    pub fn add<E: BooleanEvaluator>(
+        self,
+        evaluator: &E,
+        x: &[E::Bit],
+        y: &[E::Bit],
+    ) -> Vec<E::Bit> {
+        check_widths::<E>(x, y);
+        let (sum, carry) = ripple_add(evaluator, x, y, None, self != OverflowMode::Wrapping);
+        self.resolve(evaluator, sum, carry, true)
+    }
+
+    /// Computes `x - y` with `evaluator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` have different widths, or are empty.
+    pub fn sub<E: BooleanEvaluator>(
+        self,
+        evaluator: &E,
+        x: &[E::Bit],
+        y: &[E::Bit],
+    ) -> Vec<E::Bit> {
+        check_widths::<E>(x, y);
+        let (difference, borrow) =
+            ripple_sub(evaluator, x, y, None, self != OverflowMode::Wrapping);
+        self.resolve(evaluator, difference, borrow, false)
+    }
+
+    /// Computes `x * y` with `evaluator`.
+    ///
+    /// The wrapping product only computes the low half of the partial products,
+    /// the other modes need the full product to detect the overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` have different widths, or are empty.
+    pub fn mul<E: BooleanEvaluator>(
+        self,
+        evaluator: &E,
+        x: &[E::Bit],
+        y: &[E::Bit],
+    ) -> Vec<E::Bit> {
+        check_widths::<E>(x, y);
+        if self == OverflowMode::Wrapping {
+            return wrapping_mul(evaluator, x, y);
+        }
+
+        let (low, overflow) = overflowing_mul(evaluator, x, y);
+        self.resolve(evaluator, low, Some(overflow), true)
+    }
+
+    /// Applies this mode on a wrapped result and its overflow flag,
+    /// which saturates to the maximum if `upward` or to `0` otherwise.
+    fn resolve<E: BooleanEvaluator>(
+        self,
+        evaluator: &E,
+        mut value: Vec<E::Bit>,
+        overflow: Option<E::Bit>,
+        upward: bool,
+    ) -> Vec<E::Bit> {
+        match (self, overflow) {
+            (OverflowMode::Wrapping, _) => value,
+            (OverflowMode::Saturating, Some(overflow)) if upward => value
+                .par_iter()
+                .map(|bit| evaluator.or(bit, &overflow))
+                .collect(),
+            (OverflowMode::Saturating, Some(overflow)) => {
+                let no_overflow = evaluator.not(&overflow);
+                value
+                    .par_iter()
+                    .map(|bit| evaluator.and(bit, &no_overflow))
+                    .collect()
+            }
+            (OverflowMode::Checked, Some(overflow)) => {
+                value.push(overflow);
+                value
+            }
+            _ => unreachable!("the overflow flag is computed for non-wrapping modes"),
+        }
+    }
+}
+
+/// The flags of an encrypted addition or subtraction.
+///
+/// Comparisons follow from the flags of `x - y`: `x < y` as unsigned words
+/// is `carry`, and as two's complement words it's the sign bit of the
+/// difference xor `overflow`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArithmeticFlags<B> {
+    /// The carry out of an addition or the borrow out of a subtraction,
+    /// which is the unsigned overflow.
+    pub carry: B,
+    /// The two's complement overflow.
+    pub overflow: B,
+}
+
+/// Computes `x + y + carry` with `evaluator`, returns the sum modulo `2^width` and its flags.
+///
+/// Words wider than a single operation are added by chaining the carry from
+/// the low word to the high word, the overflow of the highest word is the
+/// signed overflow of the whole sum.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different widths, or are empty.
+pub fn carrying_add<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+    carry: Option<&E::Bit>,
+) -> (Vec<E::Bit>, ArithmeticFlags<E::Bit>) {
+    check_widths::<E>(x, y);
+    let msb = x.len() - 1;
+    let (mut sum, carry_in) = ripple_add(evaluator, &x[..msb], &y[..msb], carry.cloned(), true);
+
+    let (s, carry) = match &carry_in {
+        Some(c) => full_add(evaluator, &x[msb], &y[msb], c, true),
+        None => half_add(evaluator, &x[msb], &y[msb], true),
+    };
+    sum.push(s);
+    let carry = carry.unwrap();
+
+    // the signed overflow is the carry into the sign bit xor the carry out of it
+    let overflow = match carry_in {
+        Some(c) => evaluator.xor(&c, &carry),
+        None => carry.clone(),
+    };
+    (sum, ArithmeticFlags { carry, overflow })
+}
+
+/// Computes `x - y - borrow` with `evaluator`, returns the difference modulo `2^width` and its flags.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different widths, or are empty.
+pub fn borrowing_sub<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+    borrow: Option<&E::Bit>,
+) -> (Vec<E::Bit>, ArithmeticFlags<E::Bit>) {
+    check_widths::<E>(x, y);
+    let msb = x.len() - 1;
+    let (mut difference, borrow_in) =
+        ripple_sub(evaluator, &x[..msb], &y[..msb], borrow.cloned(), true);
+
+    let (d, borrow) = ripple_sub(evaluator, &x[msb..], &y[msb..], borrow_in.clone(), true);
+    difference.extend(d);
+    let borrow = borrow.unwrap();
+
+    let overflow = match borrow_in {
+        Some(b) => evaluator.xor(&b, &borrow),
+        None => borrow.clone(),
+    };
+    (
+        difference,
+        ArithmeticFlags {
+            carry: borrow,
+            overflow,
+        },
+    )
+}
+
+/// Computes `x * y` with `evaluator` on unsigned words, returns the product modulo
+/// `2^width` and whether the product overflowed.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different widths, or are empty.
+pub fn overflowing_mul<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+) -> (Vec<E::Bit>, E::Bit) {
+    check_widths::<E>(x, y);
+    let (low, high) = full_mul(evaluator, x, y);
+    let overflow = reduce(
+        evaluator,
+        high.into_iter().map(|bit| vec![bit]).collect(),
+        ReduceOp::Or,
+    )
+    .map(|mut bits| bits.pop().unwrap())
+    // a single bit product can't overflow, `x & !x` is an encrypted `false`
+    .unwrap_or_else(|| evaluator.and(&x[0], &evaluator.not(&x[0])));
+    (low, overflow)
+}
+
+fn check_widths<E: BooleanEvaluator>(x: &[E::Bit], y: &[E::Bit]) {
+    assert_eq!(x.len(), y.len());
+    assert!(!x.is_empty());
+}
+
+/// Ripple-carry addition of two little-endian words and an optional carry.
+///
+/// `y` may be shorter than `x`, its missing high bits are `false`.
+/// The sum has the width of `x`, the carry out is computed only if `carry_out`.
+pub(crate) fn ripple_add<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+    mut carry: Option<E::Bit>,
+    carry_out: bool,
+) -> (Vec<E::Bit>, Option<E::Bit>) {
+    debug_assert!(y.len() <= x.len());
+    let width = x.len();
+    let sum = x
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let need_carry = carry_out || i + 1 < width;
+            let (s, c) = match (y.get(i), carry.take()) {
+                (None, None) => (a.clone(), None),
+                (Some(b), None) => half_add(evaluator, a, b, need_carry),
+                (None, Some(c)) => half_add(evaluator, a, &c, need_carry),
+                (Some(b), Some(c)) => full_add(evaluator, a, b, &c, need_carry),
+            };
+            carry = c;
+            s
+        })
+        .collect();
+    (sum, carry)
+}
+
+/// Adds `bit * weight` to the little-endian word `acc` modulo `2^acc.len()`,
+/// where `None` is a trivial encryption of `false`.
+///
+/// Bit `j` of `bit * weight` is `bit` if bit `j` of `weight` is set and trivially
+/// `false` otherwise. The ripple-carry adder skips the trivially `false` inputs,
+/// so sparse weights are cheaper.
+pub(crate) fn add_weighted<E: BooleanEvaluator>(
+    evaluator: &E,
+    acc: &mut [Option<E::Bit>],
+    bit: &E::Bit,
+    weight: u64,
+) {
+    debug_assert!(acc.len() <= 64);
+    let width = acc.len();
+    let mut carry: Option<E::Bit> = None;
+    for (j, a) in acc.iter_mut().enumerate() {
+        let y = ((weight >> j) & 1 == 1).then_some(bit);
+        if y.is_none() && carry.is_none() {
+            if weight >> j == 0 {
+                break;
+            }
+            continue;
+        }
+
+        let last = j + 1 == width;
+        let inputs: Vec<&E::Bit> = a.iter().chain(y).chain(carry.iter()).collect();
+        let (sum, c) = match inputs[..] {
+            [x] => (x.clone(), None),
+            [x, y] if last => (evaluator.xor(x, y), None),
+            [x, y] => half_add(evaluator, x, y, true),
+            [x, y, z] if last => (evaluator.xor3(x, y, z), None),
+            [x, y, z] => full_add(evaluator, x, y, z, true),
+            _ => unreachable!(),
+        };
+        *a = Some(sum);
+        carry = c;
+    }
+}
+
+/// Ripple-borrow subtraction of two little-endian words of the same width and
+/// an optional borrow. The borrow out is computed only if `borrow_out`.
+pub(crate) fn ripple_sub<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+    mut borrow: Option<E::Bit>,
+    borrow_out: bool,
+) -> (Vec<E::Bit>, Option<E::Bit>) {
+    debug_assert_eq!(x.len(), y.len());
+    let width = x.len();
+    let difference = x
+        .iter()
+        .zip(y)
+        .enumerate()
+        .map(|(i, (a, b))| {
+            let need_borrow = borrow_out || i + 1 < width;
+            let not_a = evaluator.not(a);
+            let (d, c) = match borrow.take() {
+                None if need_borrow => {
+                    let (d, c) = rayon::join(|| evaluator.xor(a, b), || evaluator.and(&not_a, b));
+                    (d, Some(c))
+                }
+                None => (evaluator.xor(a, b), None),
+                Some(c) if need_borrow => {
+                    let (d, c) = rayon::join(
+                        || evaluator.xor3(a, b, &c),
+                        || evaluator.majority(&not_a, b, &c),
+                    );
+                    (d, Some(c))
+                }
+                Some(c) => (evaluator.xor3(a, b, &c), None),
+            };
+            borrow = c;
+            d
+        })
+        .collect();
+    (difference, borrow)
+}
+
+pub(crate) fn half_add<E: BooleanEvaluator>(
+    evaluator: &E,
+    a: &E::Bit,
+    b: &E::Bit,
+    need_carry: bool,
+) -> (E::Bit, Option<E::Bit>) {
+    if need_carry {
+        let (s, c) = rayon::join(|| evaluator.xor(a, b), || evaluator.and(a, b));
+        (s, Some(c))
+    } else {
+        (evaluator.xor(a, b), None)
+    }
+}
+
+pub(crate) fn full_add<E: BooleanEvaluator>(
+    evaluator: &E,
+    a: &E::Bit,
+    b: &E::Bit,
+    c: &E::Bit,
+    need_carry: bool,
+) -> (E::Bit, Option<E::Bit>) {
+    if need_carry {
+        let (s, c) = rayon::join(|| evaluator.xor3(a, b, c), || evaluator.majority(a, b, c));
+        (s, Some(c))
+    } else {
+        (evaluator.xor3(a, b, c), None)
+    }
+}
+
+/// The partial product `x * b` of a word and a bit.
+fn partial_product<E: BooleanEvaluator>(evaluator: &E, x: &[E::Bit], b: &E::Bit) -> Vec<E::Bit> {
+    x.par_iter().map(|a| evaluator.and(a, b)).collect()
+}
+
+/// Shift-and-add multiplication modulo `2^width`.
+fn wrapping_mul<E: BooleanEvaluator>(evaluator: &E, x: &[E::Bit], y: &[E::Bit]) -> Vec<E::Bit> {
+    let width = x.len();
+    let mut product = partial_product(evaluator, x, &y[0]);
+    for (i, b) in y.iter().enumerate().skip(1) {
+        let row = partial_product(evaluator, &x[..width - i], b);
+        let (sum, _) = ripple_add(evaluator, &product[i..], &row, None, false);
+        product.truncate(i);
+        product.extend(sum);
+    }
+    product
+}
+
+/// Shift-and-add multiplication, returns the low and the high half of the product.
+///
+/// The high half is empty for single bit words.
+fn full_mul<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+) -> (Vec<E::Bit>, Vec<E::Bit>) {
+    let mut low = Vec::with_capacity(x.len());
+    // the running sum shifted right by the number of finished low bits
+    let mut acc = partial_product(evaluator, x, &y[0]);
+    for b in &y[1..] {
+        low.push(acc.remove(0));
+        let row = partial_product(evaluator, x, b);
+        let (mut sum, carry) = ripple_add(evaluator, &row, &acc, None, true);
+        sum.extend(carry);
+        acc = sum;
+    }
+    low.push(acc.remove(0));
+    (low, acc)
+}
@@ -1,7 +1,17 @@
-use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use algebra::{
+    integer::UnsignedInteger,
+    random::{Block, Prg},
+    reduce::RingReduce,
+    NttField,
+};
 use rand::{CryptoRng, Rng};
 
-use crate::{BooleanFheParameters, SecretKeyPack};
+use crate::{BooleanFheParameters, Evaluator, SecretKeyPack};
+
+/// The last path index for deriving secret keys.
+const SECRET_KEY_INDEX: u64 = 0;
+/// The last path index for deriving evaluation keys.
+const EVALUATION_KEY_INDEX: u64 = 1;
 
 /// Struct of key generation.
 pub struct KeyGen;
@@ -21,4 +31,46 @@ impl KeyGen {
     {
         SecretKeyPack::new(params, rng)
     }
+
+    /// Derive the secret key for the dataset or session identified by `path`
+    /// from a master seed.
+    ///
+    /// The same `master_seed` and `path` always give the same key,
+    /// keys of different paths are independent.
+    #[inline]
+    pub fn derive_secret_key<C, LweModulus, Q>(
+        params: BooleanFheParameters<C, LweModulus, Q>,
+        master_seed: Block,
+        path: &[u64],
+    ) -> SecretKeyPack<C, LweModulus, Q>
+    where
+        C: UnsignedInteger,
+        LweModulus: RingReduce<C>,
+        Q: NttField,
+    {
+        let seed = Prg::derive_seed(master_seed, path);
+        let mut rng = Prg::derive(seed, &[SECRET_KEY_INDEX]);
+        SecretKeyPack::new(params, &mut rng)
+    }
+
+    /// Derive the evaluator matching [`KeyGen::derive_secret_key`]
+    /// with the same `master_seed` and `path`.
+    ///
+    /// The randomness of the evaluation key is separated from the secret key's,
+    /// so it can be regenerated on demand instead of being stored.
+    #[inline]
+    pub fn derive_evaluator<C, LweModulus, Q>(
+        secret_key_pack: &SecretKeyPack<C, LweModulus, Q>,
+        master_seed: Block,
+        path: &[u64],
+    ) -> Evaluator<C, LweModulus, Q>
+    where
+        C: UnsignedInteger,
+        LweModulus: RingReduce<C>,
+        Q: NttField,
+    {
+        let seed = Prg::derive_seed(master_seed, path);
+        let mut rng = Prg::derive(seed, &[EVALUATION_KEY_INDEX]);
+        Evaluator::new(secret_key_pack, &mut rng)
+    }
 }
@@ -0,0 +1,181 @@
+use rayon::prelude::*;
+
+use crate::{
+    integer::{add_weighted, ripple_sub},
+    reduce::{less_than, reduce},
+    BooleanEvaluator, ReduceOp,
+};
+
+/// A neighbor selected by [`nearest_neighbors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbor<B> {
+    /// The little-endian bits of the distance to the query.
+    pub distance: Vec<B>,
+    /// The little-endian bits of the index of the neighbor in the database.
+    pub index: Vec<B>,
+}
+
+/// Computes the squared Euclidean distances between an encrypted query and
+/// every plaintext vector of `database`.
+///
+/// # Arguments
+///
+/// * Input: `query`, the little-endian words `x_0, x_1, ...`, all of the same width.
+/// * Input: plaintext vectors `database`, each with values `y_0, y_1, ...`.
+/// * Output: for every vector, `width` ciphertexts, the little-endian bits of
+///   `sum((x_i - y_i)^2) mod 2^width`.
+///
+/// The distance is expanded into `sum(x_i^2) + sum(y_i^2) - 2 * sum(x_i * y_i)`.
+/// The first sum only depends on the query, it is computed once with one bootstrap
+/// for every pair of bits of the same word. The other sums have plaintext weights,
+/// they are accumulated as in [`Evaluator::weighted_popcount`](crate::Evaluator::weighted_popcount),
+/// so a vector only costs additions and one subtraction. The vectors are processed in parallel.
+///
+/// The arithmetic is not packed: every bit is its own LWE ciphertext and every gate
+/// is bootstrapped, so the cost grows with the number of vectors. Packing many
+/// coordinates or vectors into the slots of one ciphertext needs a BFV-style scheme
+/// with batched encoding and relinearization, which this crate does not have.
+///
+/// `width` must hold the largest distance for the output to be exact.
+///
+/// # Panics
+///
+/// Panics if `query` or its words are empty, the words have different widths,
+/// a vector of `database` is not as long as `query`, or `width` is `0` or larger than `64`.
+pub fn squared_distances<E: BooleanEvaluator>(
+    evaluator: &E,
+    query: &[Vec<E::Bit>],
+    database: &[Vec<u64>],
+    width: usize,
+) -> Vec<Vec<E::Bit>> {
+    assert!(!query.is_empty() && !query[0].is_empty());
+    assert!(query.iter().all(|x| x.len() == query[0].len()));
+    assert!(width > 0 && width <= 64);
+    assert!(database.iter().all(|y| y.len() == query.len()));
+
+    let mask = u64::MAX >> (64 - width);
+    let (zero, one) = constants(evaluator, &query[0][0]);
+
+    // x^2 = sum(x_j * 2^2j) + sum(x_j * x_k * 2^(j+k+1)) for j < k
+    let mut squares = vec![None; width];
+    for x in query {
+        for (j, a) in x.iter().enumerate() {
+            add_weighted(evaluator, &mut squares, a, power_of_two(2 * j) & mask);
+            for (k, b) in x.iter().enumerate().skip(j + 1) {
+                let weight = power_of_two(j + k + 1) & mask;
+                if weight != 0 {
+                    add_weighted(evaluator, &mut squares, &evaluator.and(a, b), weight);
+                }
+            }
+        }
+    }
+
+    database
+        .par_iter()
+        .map(|y| {
+            let mut sum = squares.clone();
+            let constant = y
+                .iter()
+                .fold(0u64, |s, &v| s.wrapping_add(v.wrapping_mul(v)));
+            add_weighted(evaluator, &mut sum, &one, constant & mask);
+
+            let mut products = vec![None; width];
+            for (x, &v) in query.iter().zip(y) {
+                for (j, a) in x.iter().enumerate() {
+                    let weight = v.checked_shl(j as u32 + 1).unwrap_or(0) & mask;
+                    add_weighted(evaluator, &mut products, a, weight);
+                }
+            }
+
+            let sum = fill(sum, &zero);
+            let products = fill(products, &zero);
+            ripple_sub(evaluator, &sum, &products, None, false).0
+        })
+        .collect()
+}
+
+/// Selects the `k` smallest of the encrypted `distances` and their indices.
+///
+/// Every distance is extended with its index as the low bits, so the words are
+/// distinct and their minimum, found by [`reduce`] with [`ReduceOp::Min`], carries
+/// the index of the smallest distance. Ties are broken by the smallest index.
+/// Each next neighbor is the minimum among the words above the previous one,
+/// the other words are masked to the largest word with one comparison and one
+/// bootstrap per bit.
+///
+/// Returns the neighbors by increasing distance, fewer than `k` if there are
+/// fewer distances.
+///
+/// # Panics
+///
+/// Panics if a distance is empty, or the distances have different widths.
+pub fn nearest_neighbors<E: BooleanEvaluator>(
+    evaluator: &E,
+    distances: Vec<Vec<E::Bit>>,
+    k: usize,
+) -> Vec<Neighbor<E::Bit>> {
+    let Some(first) = distances.first() else {
+        return Vec::new();
+    };
+    assert!(!first.is_empty());
+    assert!(distances.iter().all(|d| d.len() == first.len()));
+
+    let count = distances.len();
+    let index_width = ((usize::BITS - (count - 1).leading_zeros()) as usize).max(1);
+    let (zero, one) = constants(evaluator, &first[0]);
+
+    let words: Vec<Vec<E::Bit>> = distances
+        .into_iter()
+        .enumerate()
+        .map(|(i, distance)| {
+            let mut word: Vec<E::Bit> = (0..index_width)
+                .map(|j| if (i >> j) & 1 == 1 { &one } else { &zero }.clone())
+                .collect();
+            word.extend(distance);
+            word
+        })
+        .collect();
+
+    let mut neighbors: Vec<Neighbor<E::Bit>> = Vec::with_capacity(k.min(count));
+    let mut previous: Option<Vec<E::Bit>> = None;
+    for _ in 0..k.min(count) {
+        let candidates = match &previous {
+            None => words.clone(),
+            Some(previous) => words
+                .par_iter()
+                .map(|word| {
+                    // `word <= previous` is masked to all ones
+                    let selected = evaluator.not(&less_than(evaluator, previous, word).unwrap());
+                    word.par_iter()
+                        .map(|b| evaluator.or(b, &selected))
+                        .collect()
+                })
+                .collect(),
+        };
+
+        let minimum = reduce(evaluator, candidates, ReduceOp::Min).unwrap();
+        neighbors.push(Neighbor {
+            distance: minimum[index_width..].to_vec(),
+            index: minimum[..index_width].to_vec(),
+        });
+        previous = Some(minimum);
+    }
+    neighbors
+}
+
+/// Encryptions of `false` and `true`, derived from any ciphertext `x` with one bootstrap.
+fn constants<E: BooleanEvaluator>(evaluator: &E, x: &E::Bit) -> (E::Bit, E::Bit) {
+    let zero = evaluator.and(x, &evaluator.not(x));
+    let one = evaluator.not(&zero);
+    (zero, one)
+}
+
+fn fill<B: Clone>(word: Vec<Option<B>>, zero: &B) -> Vec<B> {
+    word.into_iter()
+        .map(|bit| bit.unwrap_or_else(|| zero.clone()))
+        .collect()
+}
+
+fn power_of_two(exponent: usize) -> u64 {
+    1u64.checked_shl(exponent as u32).unwrap_or(0)
+}
@@ -5,8 +5,19 @@
 
 mod parameter;
 
+mod bloom;
+mod checkpoint;
+mod cost;
+mod counter;
 mod evaluate;
+mod integer;
+mod knn;
 mod lut;
+mod noise;
+mod popcount;
+mod reduce;
+mod replay;
+mod simulate;
 
 mod decrypt;
 mod encrypt;
@@ -15,8 +26,19 @@ mod secret_key;
 
 pub use parameter::*;
 
+pub use bloom::{BloomFilter, BloomFilterParameters, EncryptedBloomFilter};
+pub use checkpoint::Checkpoint;
+pub use cost::{estimate_cost, CostModel};
+pub use counter::FheCounter;
 pub use evaluate::{Evaluator, KeySwitchingKey};
+pub use integer::{borrowing_sub, carrying_add, overflowing_mul, ArithmeticFlags, OverflowMode};
+pub use knn::{nearest_neighbors, squared_distances, Neighbor};
 pub use lut::LookUpTable;
+pub use noise::{NoiseReport, NoiseStatistics};
+pub use popcount::{hamming_distance, popcount};
+pub use reduce::{reduce, ReduceOp};
+pub use replay::{OpCode, Operation, OperationLog, RecordedBit, RecordingEvaluator};
+pub use simulate::{BooleanEvaluator, SimulatedEvaluator};
 
 pub use decrypt::Decryptor;
 pub use encrypt::Encryptor;
@@ -0,0 +1,387 @@
+use algebra::{
+    integer::{AsInto, Bits, UnsignedInteger},
+    reduce::{ModulusValue, RingReduce},
+    NttField,
+};
+use fhe_core::{
+    lwe_modulus_switch_assign,
+    noise::{
+        balanced_digit_second_moment, failure_probability, inner_product_variance,
+        lwe_secret_second_moment, modulus_switch_variance, ring_secret_second_moment,
+        scale_variance, subgaussian_bound, sum_variance, uniform_variance,
+    },
+    LweCiphertext, LweSecretKeyType,
+};
+use rand::{CryptoRng, Rng};
+
+use crate::{BooleanFheParameters, Evaluator, SecretKeyPack, Steps};
+
+/// The noise of one operation sequence, measured with a secret key over many runs
+/// and predicted by the analytic model of [`fhe_core::noise`].
+///
+/// Noises are measured in the integers modulo the modulus of the ciphertexts they
+/// belong to, and `bound` is the noise from which those ciphertexts decrypt wrongly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseStatistics {
+    samples: usize,
+    measured_variance: f64,
+    max_noise: f64,
+    predicted_variance: f64,
+    bound: f64,
+}
+
+impl NoiseStatistics {
+    /// Computes the statistics of the measured `noises` against `predicted_variance`.
+    fn new(noises: &[f64], predicted_variance: f64, bound: f64) -> Self {
+        Self {
+            samples: noises.len(),
+            measured_variance: noises.iter().map(|e| e * e).sum::<f64>() / noises.len() as f64,
+            max_noise: noises.iter().fold(0.0, |max, e| e.abs().max(max)),
+            predicted_variance,
+            bound,
+        }
+    }
+
+    /// Returns the number of measured ciphertexts.
+    #[inline]
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Returns the measured variance `E[e^2]` of the noise.
+    #[inline]
+    pub fn measured_variance(&self) -> f64 {
+        self.measured_variance
+    }
+
+    /// Returns the measured standard deviation of the noise.
+    #[inline]
+    pub fn measured_standard_deviation(&self) -> f64 {
+        self.measured_variance.sqrt()
+    }
+
+    /// Returns the largest measured absolute noise.
+    #[inline]
+    pub fn max_noise(&self) -> f64 {
+        self.max_noise
+    }
+
+    /// Returns the variance of the noise predicted by the model.
+    #[inline]
+    pub fn predicted_variance(&self) -> f64 {
+        self.predicted_variance
+    }
+
+    /// Returns the standard deviation of the noise predicted by the model.
+    #[inline]
+    pub fn predicted_standard_deviation(&self) -> f64 {
+        self.predicted_variance.sqrt()
+    }
+
+    /// Returns the measured variance divided by the predicted one,
+    /// which is close to `1` when the model is accurate.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.measured_variance / self.predicted_variance
+    }
+
+    /// Returns the noise from which a ciphertext decrypts wrongly.
+    #[inline]
+    pub fn bound(&self) -> f64 {
+        self.bound
+    }
+
+    /// Returns the probability that a ciphertext decrypts wrongly under the model.
+    #[inline]
+    pub fn failure_probability(&self) -> f64 {
+        failure_probability(self.predicted_standard_deviation(), self.bound)
+    }
+
+    /// Returns the noise that the model expects to be exceeded with probability
+    /// at most `failure_probability`, to compare with [`max_noise`](Self::max_noise).
+    #[inline]
+    pub fn subgaussian_bound(&self, failure_probability: f64) -> f64 {
+        subgaussian_bound(self.predicted_standard_deviation(), failure_probability)
+    }
+}
+
+/// Measured noise against the analytic model for the standard operation sequences
+/// of a bootstrapped gate, so that a parameter set is checked against the model
+/// used to choose it.
+///
+/// The sequences follow one gate from its inputs to its output:
+/// - a fresh encryption;
+/// - the sum of two fresh encryptions, which is the input of a gate;
+/// - the input of a gate switched to the modulus `2N` of the blind rotation;
+/// - the output of a bootstrapped gate.
+///
+/// The model of the bootstrapping adds the noise of the keys through the gadget
+/// decompositions, the error of the approximate decompositions, and the rounding
+/// of the modulus switching, for each [`Steps`].
+///
+/// The model takes the rounding errors of a modulus switching as uniform with zero mean.
+/// When `q / 2N` is small, they are biased towards the same side, so the noise of
+/// [`modulus_switch`](Self::modulus_switch) grows with the weight of a binary key
+/// and its ratio exceeds `1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseReport {
+    fresh: NoiseStatistics,
+    gate_input: NoiseStatistics,
+    modulus_switch: NoiseStatistics,
+    bootstrap: NoiseStatistics,
+}
+
+impl NoiseReport {
+    /// Measures every sequence over `samples` runs with random messages.
+    ///
+    /// `evaluator` must be generated from `secret_key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is `0`.
+    pub fn measure<C, LweModulus, Q, R>(
+        secret_key: &SecretKeyPack<C, LweModulus, Q>,
+        evaluator: &Evaluator<C, LweModulus, Q>,
+        samples: usize,
+        rng: &mut R,
+    ) -> Self
+    where
+        C: UnsignedInteger,
+        LweModulus: RingReduce<C>,
+        Q: NttField,
+        R: Rng + CryptoRng,
+    {
+        assert!(samples > 0);
+
+        let parameters = secret_key.parameters();
+        let cipher_modulus = parameters.lwe_cipher_modulus();
+        let q = modulus_as_f64(parameters.lwe_cipher_modulus_value());
+        let t: f64 = parameters.lwe_plain_modulus().as_into();
+        let twice_ring_dimension = parameters.ring_dimension() << 1;
+
+        let encrypt = |rng: &mut R| {
+            let message = if rng.gen() { C::ONE } else { C::ZERO };
+            secret_key.encrypt(message, rng)
+        };
+        let noise = |c: &LweCiphertext<C>| secret_key.decrypt_with_noise::<C>(c).1.as_into();
+
+        let mut fresh = Vec::with_capacity(samples);
+        let mut gate_input = Vec::with_capacity(samples);
+        let mut modulus_switch = Vec::with_capacity(samples);
+        let mut bootstrap = Vec::with_capacity(samples);
+        for _ in 0..samples {
+            let c0 = encrypt(rng);
+            let c1 = encrypt(rng);
+            fresh.push(noise(&c0));
+
+            let mut add = c0.add_reduce_component_wise_ref(&c1, cipher_modulus);
+            gate_input.push(noise(&add));
+
+            bootstrap.push(noise(&evaluator.and(&c0, &c1)));
+
+            lwe_modulus_switch_assign(
+                &mut add,
+                parameters.lwe_cipher_modulus_value(),
+                C::try_from(twice_ring_dimension).ok().unwrap(),
+            );
+            modulus_switch.push(switched_noise(secret_key, &add, twice_ring_dimension));
+        }
+
+        let fresh_variance = parameters.lwe_noise_standard_deviation().powi(2);
+        let gate_input_variance = sum_variance([fresh_variance, fresh_variance]);
+        let modulus_switch_variance = switch_variance(
+            parameters,
+            gate_input_variance,
+            q,
+            twice_ring_dimension as f64,
+        );
+
+        Self {
+            fresh: NoiseStatistics::new(&fresh, fresh_variance, q / (2.0 * t)),
+            gate_input: NoiseStatistics::new(&gate_input, gate_input_variance, q / (2.0 * t)),
+            modulus_switch: NoiseStatistics::new(
+                &modulus_switch,
+                modulus_switch_variance,
+                twice_ring_dimension as f64 / (2.0 * t),
+            ),
+            bootstrap: NoiseStatistics::new(
+                &bootstrap,
+                bootstrap_variance(parameters),
+                q / (2.0 * t),
+            ),
+        }
+    }
+
+    /// Returns the noise of a fresh encryption.
+    #[inline]
+    pub fn fresh(&self) -> &NoiseStatistics {
+        &self.fresh
+    }
+
+    /// Returns the noise of the sum of two fresh encryptions.
+    #[inline]
+    pub fn gate_input(&self) -> &NoiseStatistics {
+        &self.gate_input
+    }
+
+    /// Returns the noise of the input of a gate switched to the modulus `2N`.
+    #[inline]
+    pub fn modulus_switch(&self) -> &NoiseStatistics {
+        &self.modulus_switch
+    }
+
+    /// Returns the noise of the output of a bootstrapped gate.
+    #[inline]
+    pub fn bootstrap(&self) -> &NoiseStatistics {
+        &self.bootstrap
+    }
+}
+
+/// Returns the noise of `c`, encrypted under the lwe secret key of `secret_key`
+/// modulo `modulus` instead of the lwe cipher modulus.
+fn switched_noise<C, LweModulus, Q>(
+    secret_key: &SecretKeyPack<C, LweModulus, Q>,
+    c: &LweCiphertext<C>,
+    modulus: usize,
+) -> f64
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    let modulus = modulus as i64;
+    let a_mul_s: i64 = c
+        .a()
+        .iter()
+        .zip(secret_key.lwe_secret_key().as_ref())
+        .map(|(&a, &s)| {
+            let a: i64 = a.as_into();
+            if s.is_zero() {
+                0
+            } else if s.is_one() {
+                a
+            } else {
+                -a
+            }
+        })
+        .sum();
+    let b: i64 = c.b().as_into();
+    let phase = (b - a_mul_s).rem_euclid(modulus) as f64;
+
+    let t: f64 = secret_key.parameters().lwe_plain_modulus().as_into();
+    let delta = modulus as f64 / t;
+    phase - (phase / delta).round() * delta
+}
+
+/// Returns the variance of a noise with variance `variance` after
+/// the modulus switching from `q` to `modulus`.
+fn switch_variance<C, LweModulus, Q>(
+    parameters: &BooleanFheParameters<C, LweModulus, Q>,
+    variance: f64,
+    q: f64,
+    modulus: f64,
+) -> f64
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    let scaled = scale_variance(variance, modulus / q);
+    if modulus < q {
+        let key_second_moment = lwe_secret_second_moment(parameters.lwe_secret_key_type());
+        scaled + modulus_switch_variance(parameters.lwe_dimension(), key_second_moment)
+    } else {
+        // scaling up is exact
+        scaled
+    }
+}
+
+/// Returns the variance of the output noise of a bootstrapped gate predicted by the model.
+fn bootstrap_variance<C, LweModulus, Q>(parameters: &BooleanFheParameters<C, LweModulus, Q>) -> f64
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    let lwe_dimension = parameters.lwe_dimension();
+    let ring_dimension = parameters.ring_dimension();
+    let q = modulus_as_f64(parameters.lwe_cipher_modulus_value());
+    let ring_modulus: f64 = parameters.ring_modulus().as_into();
+    let ring_modulus_bits = Q::ValueT::BITS - parameters.ring_modulus().leading_zeros();
+    let ring_key_second_moment = ring_secret_second_moment(
+        parameters.ring_secret_key_type(),
+        parameters.ring_noise_standard_deviation(),
+    );
+
+    // every step of the blind rotation multiplies the accumulator by the RGSW
+    // encryption of a key coefficient, a ternary coefficient takes two of them
+    let basis = parameters.blind_rotation_basis();
+    let rgsw_count = match parameters.lwe_secret_key_type() {
+        LweSecretKeyType::Binary => 1.0,
+        LweSecretKeyType::Ternary => 2.0,
+    };
+    let external_product = rgsw_count
+        * inner_product_variance(
+            2 * basis.decompose_length() * ring_dimension,
+            parameters.ring_noise_standard_deviation().powi(2),
+            balanced_digit_second_moment(basis.log_basis()),
+        )
+        + lwe_secret_second_moment(parameters.lwe_secret_key_type())
+            * approximation_variance(basis.drop_bits(), ring_dimension, ring_key_second_moment);
+    let blind_rotation = lwe_dimension as f64 * external_product;
+
+    // the key switching from the ring key has the same shape for every step
+    let key_switching = |modulus_bits: u32| {
+        let log_basis = parameters.key_switching_basis_bits();
+        let length = parameters
+            .key_switching_reverse_length()
+            .unwrap_or((modulus_bits / log_basis) as usize);
+        let drop_bits = modulus_bits - length as u32 * log_basis;
+        inner_product_variance(
+            ring_dimension * length,
+            parameters.key_switching_noise_standard_deviation().powi(2),
+            balanced_digit_second_moment(log_basis),
+        ) + inner_product_variance(
+            ring_dimension,
+            uniform_variance(2.0f64.powi(drop_bits as i32)),
+            ring_key_second_moment,
+        )
+    };
+    // the modulus switching from `Q` to `q` rounds the coefficients of a key of `dimension`
+    let switch = |variance: f64, dimension: usize, key_second_moment: f64| {
+        scale_variance(variance, q / ring_modulus)
+            + modulus_switch_variance(dimension, key_second_moment)
+    };
+
+    let lwe_key_second_moment = lwe_secret_second_moment(parameters.lwe_secret_key_type());
+    match parameters.steps() {
+        Steps::BrMsKs => {
+            let q_bits = parameters.lwe_cipher_modulus_value().log_modulus();
+            switch(blind_rotation, ring_dimension, ring_key_second_moment) + key_switching(q_bits)
+        }
+        Steps::BrKsRlevMs | Steps::BrKsLevMs => switch(
+            blind_rotation + key_switching(ring_modulus_bits),
+            lwe_dimension,
+            lwe_key_second_moment,
+        ),
+        Steps::BrMs => switch(blind_rotation, lwe_dimension, lwe_key_second_moment),
+    }
+}
+
+/// Returns the variance of the error of an approximate decomposition dropping
+/// `drop_bits` bits of both parts of a RLWE ciphertext of `dimension`.
+#[inline]
+fn approximation_variance(drop_bits: u32, dimension: usize, key_second_moment: f64) -> f64 {
+    let variance = uniform_variance(2.0f64.powi(drop_bits as i32));
+    variance + inner_product_variance(dimension, variance, key_second_moment)
+}
+
+#[inline]
+fn modulus_as_f64<C: UnsignedInteger>(modulus: ModulusValue<C>) -> f64 {
+    match modulus {
+        ModulusValue::Native => 2.0f64.powi(C::BITS as i32),
+        ModulusValue::PowerOf2(value)
+        | ModulusValue::Prime(value)
+        | ModulusValue::Others(value) => value.as_into(),
+    }
+}
@@ -0,0 +1,77 @@
+use algebra::{
+    integer::{AsInto, UnsignedInteger},
+    reduce::RingReduce,
+    Field, NttField,
+};
+use fhe_core::FHECoreError;
+
+use super::{BooleanFheParameters, ConstParameters};
+
+/// The ring dimension `N` and ring modulus `Q` fixed at compile time.
+///
+/// `N` must be a power of two and `Q ≡ 1 (mod 2N)`. The invariants are checked
+/// when [`CheckedParameters::parameters`] or the associated constants are used, so an
+/// invalid pair fails the build instead of [`BooleanFheParameters::new`]
+/// returning an error at runtime.
+///
+/// ```compile_fail
+/// use boolean_fhe::CheckedParameters;
+///
+/// // `132120576` is not divisible by `2 * 3000`.
+/// let n = CheckedParameters::<3000, 132120577>::RING_DIMENSION;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CheckedParameters<const N: usize, const Q: u64>;
+
+impl<const N: usize, const Q: u64> CheckedParameters<N, Q> {
+    const VALID: () = {
+        assert!(N.is_power_of_two(), "ring dimension must be a power of two");
+        assert!(N <= (u64::MAX >> 1) as usize, "ring dimension is too large");
+        assert!(
+            Q % (2 * N as u64) == 1,
+            "ring modulus must be 1 modulo twice the ring dimension"
+        );
+    };
+
+    /// **Ring** polynomial dimension, refers to **N** in the paper.
+    pub const RING_DIMENSION: usize = {
+        let () = Self::VALID;
+        N
+    };
+
+    /// **Ring** polynomial modulus, refers to **Q** in the paper.
+    pub const RING_MODULUS: u64 = {
+        let () = Self::VALID;
+        Q
+    };
+
+    /// Creates [`BooleanFheParameters`] whose ring is checked at compile time.
+    ///
+    /// The other parameters are validated at runtime as in [`BooleanFheParameters::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::CheckedParametersMismatch`] if `params.ring_dimension` is not `N`,
+    /// or `params.ring_modulus` or the modulus of `F` is not `Q`.
+    pub fn parameters<C, LweModulus, F>(
+        params: ConstParameters<C, <F as Field>::ValueT>,
+    ) -> Result<BooleanFheParameters<C, LweModulus, F>, FHECoreError>
+    where
+        C: UnsignedInteger,
+        LweModulus: RingReduce<C>,
+        F: NttField,
+    {
+        let modulus: u64 = params.ring_modulus.as_into();
+        if params.ring_dimension != Self::RING_DIMENSION
+            || params.ring_modulus != <F as Field>::MODULUS_VALUE
+            || modulus != Self::RING_MODULUS
+        {
+            return Err(FHECoreError::CheckedParametersMismatch {
+                ring_dimension: Self::RING_DIMENSION,
+                ring_modulus: Self::RING_MODULUS,
+            });
+        }
+
+        BooleanFheParameters::new(params)
+    }
+}
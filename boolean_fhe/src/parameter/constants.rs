@@ -1,30 +1,75 @@
 use std::sync::LazyLock;
 
+#[cfg(feature = "toy")]
+use algebra::U16FieldEval;
 use algebra::{modulus::PowOf2Modulus, reduce::ModulusValue, Field, U32FieldEval};
 use fhe_core::{LweSecretKeyType, RingSecretKeyType};
 
-use super::{BooleanFheParameters, ConstParameters, Steps};
+use super::{BooleanFheParameters, CheckedParameters, ConstParameters, Steps};
 
 type Fp = U32FieldEval<132120577>;
+type Ring = CheckedParameters<1024, 132120577>;
 
 /// Default 128-bits security Parameters
 pub static DEFAULT_128_BITS_PARAMETERS: LazyLock<
     BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>,
 > = LazyLock::new(|| {
-    BooleanFheParameters::<u16, PowOf2Modulus<u16>, Fp>::new(ConstParameters {
+    Ring::parameters::<u16, PowOf2Modulus<u16>, Fp>(ConstParameters {
         lwe_dimension: 512,
         lwe_plain_modulus: 4,
         lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 14),
         lwe_noise_standard_deviation: 3.20,
         lwe_secret_key_type: LweSecretKeyType::Binary,
-        ring_dimension: 1024,
+        ring_dimension: Ring::RING_DIMENSION,
         ring_modulus: Fp::MODULUS_VALUE,
         ring_noise_standard_deviation: 3.20 * ((1 << 1) as f64),
         ring_secret_key_type: RingSecretKeyType::Ternary,
         blind_rotation_basis_bits: 7,
+        blind_rotation_reverse_length: None,
         key_switching_basis_bits: 2,
+        key_switching_reverse_length: None,
         key_switching_standard_deviation: 3.2 * ((1 << 1) as f64),
         steps: Steps::BrKsLevMs,
     })
     .unwrap()
 });
+
+/// The ring field of [`TOY_PARAMETERS`].
+#[cfg(feature = "toy")]
+pub type ToyField = U16FieldEval<12289>;
+#[cfg(feature = "toy")]
+type ToyRing = CheckedParameters<256, 12289>;
+
+/// The constants of [`TOY_PARAMETERS`], **not secure**.
+///
+/// Tests and examples which need other steps or lengths start from these,
+/// e.g. `ConstParameters { steps: Steps::BrMsKs, ..TOY_CONST_PARAMETERS }`.
+#[cfg(feature = "toy")]
+pub const TOY_CONST_PARAMETERS: ConstParameters<u16, u16> = ConstParameters {
+    lwe_dimension: 32,
+    lwe_plain_modulus: 4,
+    lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 10),
+    lwe_noise_standard_deviation: 1.0,
+    lwe_secret_key_type: LweSecretKeyType::Binary,
+    ring_dimension: ToyRing::RING_DIMENSION,
+    ring_modulus: ToyField::MODULUS_VALUE,
+    ring_noise_standard_deviation: 0.5,
+    ring_secret_key_type: RingSecretKeyType::Ternary,
+    blind_rotation_basis_bits: 2,
+    blind_rotation_reverse_length: None,
+    key_switching_basis_bits: 2,
+    key_switching_reverse_length: None,
+    key_switching_standard_deviation: 0.5,
+    steps: Steps::BrKsLevMs,
+};
+
+/// Toy Parameters with 16-bits coefficients everywhere, **not secure**.
+///
+/// The ring modulus `12289` fits in `u16`, so the whole bootstrapping runs on
+/// 16-bits arithmetic with small dimensions. It finishes in milliseconds,
+/// which is meant for unit tests and teaching, never for real data.
+#[cfg(feature = "toy")]
+pub static TOY_PARAMETERS: LazyLock<BooleanFheParameters<u16, PowOf2Modulus<u16>, ToyField>> =
+    LazyLock::new(|| {
+        ToyRing::parameters::<u16, PowOf2Modulus<u16>, ToyField>(TOY_CONST_PARAMETERS).unwrap()
+    });
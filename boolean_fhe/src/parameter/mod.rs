@@ -4,14 +4,20 @@ use algebra::decompose::NonPowOf2ApproxSignedBasis;
 use algebra::integer::Bits;
 use algebra::random::DiscreteGaussian;
 use algebra::reduce::{ModulusValue, RingReduce};
-use algebra::Field;
+use algebra::utils::{
+    read_header, read_modulus, read_width, write_header, write_modulus, write_width, DecodeLimits,
+    LeBytes,
+};
 use algebra::{integer::UnsignedInteger, NttField};
+use algebra::{AlgebraError, Field};
 use fhe_core::{FHECoreError, GadgetRlweParameters as BlindRotationParameters};
 use fhe_core::{KeySwitchingParameters, LweParameters, LweSecretKeyType, RingSecretKeyType};
 
+mod checked;
 mod constants;
 mod steps;
 
+pub use checked::CheckedParameters;
 pub use constants::*;
 pub use steps::Steps;
 
@@ -42,12 +48,18 @@ pub struct ConstParameters<C: UnsignedInteger, Q> {
 
     /// Decompose basis' bits for `Q` used for blind rotation accumulator.
     pub blind_rotation_basis_bits: u32,
+    /// The approximate decomposition length for blind rotation,
+    /// `None` for the full length.
+    pub blind_rotation_reverse_length: Option<usize>,
 
     /// The steps of whole bootstrapping.
     pub steps: Steps,
 
     /// Decompose basis' bits for `Q` or `q` used for key switching.
     pub key_switching_basis_bits: u32,
+    /// The approximate decomposition length for key switching,
+    /// `None` for the full length.
+    pub key_switching_reverse_length: Option<usize>,
     /// The noise error's standard deviation for key switching **rlwe** or **lwe**.
     pub key_switching_standard_deviation: f64,
 }
@@ -57,10 +69,30 @@ pub struct ConstParameters<C: UnsignedInteger, Q> {
 pub struct BooleanFheParameters<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> {
     lwe_params: LweParameters<C, LweModulus>,
     blind_rotation_params: BlindRotationParameters<Q>,
+    blind_rotation_reverse_length: Option<usize>,
     key_switching_params: KeySwitchingParameters,
     steps: Steps,
 }
 
+/// Checks that `reverse_length` is in `1..=log_modulus / log_basis`.
+fn check_reverse_length(
+    reverse_length: Option<usize>,
+    log_modulus: u32,
+    log_basis: u32,
+) -> Result<(), FHECoreError> {
+    let Some(reverse_length) = reverse_length else {
+        return Ok(());
+    };
+    let full_length = log_modulus.checked_div(log_basis).unwrap_or(0) as usize;
+    if reverse_length == 0 || reverse_length > full_length {
+        return Err(FHECoreError::ReverseLengthUnValid {
+            reverse_length,
+            full_length,
+        });
+    }
+    Ok(())
+}
+
 impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Clone
     for BooleanFheParameters<C, LweModulus, Q>
 {
@@ -79,6 +111,11 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
     BooleanFheParameters<C, LweModulus, Q>
 {
     /// Create a new Parameter instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ring dimension is not a power of 2, `2N` does not divide `Q - 1`,
+    /// or an approximate decomposition length is `0` or larger than the full length.
     pub fn new(params: ConstParameters<C, <Q as Field>::ValueT>) -> Result<Self, FHECoreError> {
         let lwe_dimension = params.lwe_dimension;
         let lwe_cipher_modulus = params.lwe_cipher_modulus;
@@ -116,6 +153,14 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         if let Some(&q) = lwe_cipher_modulus.as_power_of2() {
             assert!(t <= q);
         }
+        let ring_log_modulus =
+            <Q as Field>::ValueT::BITS - <Q as Field>::MODULUS_VALUE.leading_zeros();
+        check_reverse_length(
+            params.blind_rotation_reverse_length,
+            ring_log_modulus,
+            params.blind_rotation_basis_bits,
+        )?;
+
         let lwe_cipher_modulus = LweModulus::from_value(lwe_cipher_modulus);
 
         let lwe_params = LweParameters::new(
@@ -133,18 +178,23 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
             basis: NonPowOf2ApproxSignedBasis::new(
                 <Q as Field>::MODULUS_VALUE,
                 params.blind_rotation_basis_bits,
-                None,
+                params.blind_rotation_reverse_length,
             ),
             secret_key_type: ring_secret_key_type,
         };
 
         let log_modulus = match steps {
             Steps::BrMsKs => params.lwe_cipher_modulus.log_modulus(),
-            Steps::BrKsRlevMs | Steps::BrKsLevMs => {
-                <Q as Field>::ValueT::BITS - <Q as Field>::MODULUS_VALUE.leading_zeros()
-            }
+            Steps::BrKsRlevMs | Steps::BrKsLevMs => ring_log_modulus,
             Steps::BrMs => 0,
         };
+        if !matches!(steps, Steps::BrMs) {
+            check_reverse_length(
+                params.key_switching_reverse_length,
+                log_modulus,
+                params.key_switching_basis_bits,
+            )?;
+        }
 
         let key_switching_params = KeySwitchingParameters {
             input_cipher_dimension: ring_dimension,
@@ -152,12 +202,13 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
             log_modulus,
             log_basis: params.key_switching_basis_bits,
             noise_standard_deviation: params.key_switching_standard_deviation,
-            reverse_length: None,
+            reverse_length: params.key_switching_reverse_length,
         };
 
         Ok(Self {
             lwe_params,
             blind_rotation_params,
+            blind_rotation_reverse_length: params.blind_rotation_reverse_length,
             key_switching_params,
             steps,
         })
@@ -236,6 +287,13 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         &self.blind_rotation_params.basis
     }
 
+    /// Returns the blind rotation approximate decomposition length of this [`BooleanFheParameters<C, Q>`],
+    /// `None` for the full length.
+    #[inline]
+    pub fn blind_rotation_reverse_length(&self) -> Option<usize> {
+        self.blind_rotation_reverse_length
+    }
+
     /// Returns the key switching basis' bits of this [`BooleanFheParameters<C, Q>`],
     /// which acts as the decompose basis for `Q` or `q` used for key switching.
     #[inline]
@@ -243,6 +301,13 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         self.key_switching_params.log_basis
     }
 
+    /// Returns the key switching approximate decomposition length of this [`BooleanFheParameters<C, Q>`],
+    /// `None` for the full length.
+    #[inline]
+    pub fn key_switching_reverse_length(&self) -> Option<usize> {
+        self.key_switching_params.reverse_length
+    }
+
     /// Returns the key switching error's standard deviation of this [`BooleanFheParameters<C, Q>`].
     #[inline]
     pub fn key_switching_noise_standard_deviation(&self) -> f64 {
@@ -314,3 +379,130 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         self.key_switching_params
     }
 }
+
+/// The parameters are encoded after their header and descriptor as the fields of
+/// [`ConstParameters`] in their order, without the ring modulus which is the modulus of `Q`.
+/// Dimensions and approximate decomposition lengths are written in `u64`, with `0` for `None`.
+///
+/// Decoding checks every value [`BooleanFheParameters::new`] would panic on.
+impl<C, LweModulus, Q> LeBytes for BooleanFheParameters<C, LweModulus, Q>
+where
+    C: UnsignedInteger + LeBytes,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    <Q as Field>::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"BFHP", buf);
+        write_width::<C>(buf);
+        write_modulus::<Q>(buf);
+
+        (self.lwe_dimension() as u64).write_le(buf);
+        self.lwe_plain_modulus().write_le(buf);
+        self.lwe_cipher_modulus_value().write_le(buf);
+        self.lwe_noise_standard_deviation().write_le(buf);
+        self.lwe_secret_key_type().write_le(buf);
+
+        (self.ring_dimension() as u64).write_le(buf);
+        self.ring_noise_standard_deviation().write_le(buf);
+        self.ring_secret_key_type().write_le(buf);
+
+        self.blind_rotation_basis().log_basis().write_le(buf);
+        (self.blind_rotation_reverse_length().unwrap_or(0) as u64).write_le(buf);
+
+        self.steps().write_le(buf);
+
+        self.key_switching_basis_bits().write_le(buf);
+        (self.key_switching_reverse_length().unwrap_or(0) as u64).write_le(buf);
+        self.key_switching_noise_standard_deviation().write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"BFHP", bytes)?;
+        read_width::<C>(version, bytes)?;
+        read_modulus::<Q>(version, bytes)?;
+
+        let lwe_dimension = read_usize(bytes)?;
+        limits.check_dimension(lwe_dimension)?;
+        let lwe_plain_modulus = C::read_le(bytes)?;
+        let lwe_cipher_modulus = ModulusValue::<C>::read_le(bytes)?;
+        let lwe_noise_standard_deviation = read_standard_deviation(bytes)?;
+        let lwe_secret_key_type = LweSecretKeyType::read_le(bytes)?;
+
+        let ring_dimension = read_usize(bytes)?;
+        limits.check_dimension(ring_dimension)?;
+        let ring_noise_standard_deviation = read_standard_deviation(bytes)?;
+        let ring_secret_key_type = RingSecretKeyType::read_le(bytes)?;
+
+        let blind_rotation_basis_bits = u32::read_le(bytes)?;
+        let blind_rotation_reverse_length = read_reverse_length(bytes)?;
+
+        let steps = Steps::read_le(bytes)?;
+
+        let key_switching_basis_bits = u32::read_le(bytes)?;
+        let key_switching_reverse_length = read_reverse_length(bytes)?;
+        let key_switching_standard_deviation = read_standard_deviation(bytes)?;
+
+        // the values `new` asserts or unwraps
+        let is_valid_modulus = match lwe_cipher_modulus {
+            ModulusValue::Native => true,
+            ModulusValue::PowerOf2(q) => q.is_power_of_two() && lwe_plain_modulus <= q,
+            ModulusValue::Prime(_) | ModulusValue::Others(_) => false,
+        };
+        let is_valid = lwe_plain_modulus.is_power_of_two()
+            && is_valid_modulus
+            && LweModulus::try_from_value(lwe_cipher_modulus).is_some()
+            && ring_dimension <= usize::MAX >> 1
+            && TryInto::<usize>::try_into(<Q as Field>::MODULUS_VALUE).is_ok()
+            && NonPowOf2ApproxSignedBasis::checked_new(
+                <Q as Field>::MODULUS_VALUE,
+                blind_rotation_basis_bits,
+                blind_rotation_reverse_length,
+            )
+            .is_some();
+        if !is_valid {
+            return Err(AlgebraError::DecodeErr);
+        }
+
+        Self::new(ConstParameters {
+            lwe_dimension,
+            lwe_plain_modulus,
+            lwe_cipher_modulus,
+            lwe_noise_standard_deviation,
+            lwe_secret_key_type,
+            ring_dimension,
+            ring_modulus: <Q as Field>::MODULUS_VALUE,
+            ring_noise_standard_deviation,
+            ring_secret_key_type,
+            blind_rotation_basis_bits,
+            blind_rotation_reverse_length,
+            steps,
+            key_switching_basis_bits,
+            key_switching_reverse_length,
+            key_switching_standard_deviation,
+        })
+        .map_err(|_| AlgebraError::DecodeErr)
+    }
+}
+
+#[inline]
+fn read_usize(bytes: &mut &[u8]) -> Result<usize, AlgebraError> {
+    usize::try_from(u64::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)
+}
+
+/// Reads an approximate decomposition length, `0` for `None`.
+#[inline]
+fn read_reverse_length(bytes: &mut &[u8]) -> Result<Option<usize>, AlgebraError> {
+    read_usize(bytes).map(|len| (len != 0).then_some(len))
+}
+
+/// Reads a standard deviation, which is finite and not negative.
+#[inline]
+fn read_standard_deviation(bytes: &mut &[u8]) -> Result<f64, AlgebraError> {
+    let standard_deviation = f64::read_le(bytes)?;
+    if standard_deviation.is_finite() && standard_deviation >= 0. {
+        Ok(standard_deviation)
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
@@ -1,3 +1,8 @@
+use algebra::{
+    utils::{DecodeLimits, LeBytes},
+    AlgebraError,
+};
+
 /// The steps of whole bootstrapping.
 ///
 /// First `Modulus Switch` or `Scale` is decided by following two case:
@@ -25,3 +30,27 @@ pub enum Steps {
     /// (n, q) -> (n, 2N) -> (N, Q) -> (n, q)
     BrMs,
 }
+
+/// The steps are encoded as a byte, in the order of the variants.
+impl LeBytes for Steps {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.push(match self {
+            Steps::BrMsKs => 0,
+            Steps::BrKsRlevMs => 1,
+            Steps::BrKsLevMs => 2,
+            Steps::BrMs => 3,
+        });
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        match u8::read_le(bytes)? {
+            0 => Ok(Steps::BrMsKs),
+            1 => Ok(Steps::BrKsRlevMs),
+            2 => Ok(Steps::BrKsLevMs),
+            3 => Ok(Steps::BrMs),
+            _ => Err(AlgebraError::DecodeErr),
+        }
+    }
+}
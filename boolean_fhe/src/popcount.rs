@@ -0,0 +1,98 @@
+use rayon::prelude::*;
+
+use crate::{
+    integer::{full_add, half_add},
+    BooleanEvaluator,
+};
+
+/// Computes the number of `true` among `bits` with `evaluator`.
+///
+/// # Arguments
+///
+/// * Input: ciphertexts `bits`, with message bits `b_0, b_1, ...`.
+/// * Output: the little-endian bits of `sum(b_i)`, as many as needed to hold `bits.len()`.
+///
+/// The bits are summed by a Wallace tree: at every level, the bits of each column
+/// are compressed in parallel by full adders, three bits into a sum in the same column
+/// and a carry in the next one, until every column has a single bit. A full adder is
+/// two look-up tables on the same linear sum of its inputs,
+/// [`BooleanEvaluator::xor3`] and [`BooleanEvaluator::majority`], so it costs two
+/// bootstraps and the whole count costs about `2 * bits.len()` bootstraps in
+/// `O(log(bits.len()))` levels, instead of the chain of ripple-carry additions of
+/// [`ReduceOp::Add`](crate::ReduceOp::Add).
+///
+/// # Panics
+///
+/// Panics if `bits` is empty.
+pub fn popcount<E: BooleanEvaluator>(evaluator: &E, bits: &[E::Bit]) -> Vec<E::Bit> {
+    assert!(!bits.is_empty());
+    let width = (usize::BITS - bits.len().leading_zeros()) as usize;
+
+    let mut columns: Vec<Vec<E::Bit>> = vec![Vec::new(); width];
+    columns[0] = bits.to_vec();
+
+    while columns.iter().any(|column| column.len() > 1) {
+        // the count fits in `width` bits, the carries out of the top column are `false`
+        let compressed: Vec<_> = columns
+            .par_iter()
+            .enumerate()
+            .map(|(j, column)| {
+                let need_carry = j + 1 < width;
+                column
+                    .par_chunks(3)
+                    .map(|group| match group {
+                        [a, b, c] => full_add(evaluator, a, b, c, need_carry),
+                        [a, b] => half_add(evaluator, a, b, need_carry),
+                        [a] => (a.clone(), None),
+                        _ => unreachable!(),
+                    })
+                    .unzip::<_, _, Vec<_>, Vec<_>>()
+            })
+            .collect();
+
+        let mut carries_in = Vec::new();
+        columns = compressed
+            .into_iter()
+            .map(|(mut sums, carries): (Vec<E::Bit>, Vec<Option<E::Bit>>)| {
+                sums.append(&mut carries_in);
+                carries_in = carries.into_iter().flatten().collect();
+                sums
+            })
+            .collect();
+    }
+
+    columns
+        .into_iter()
+        .map(|mut column| column.pop().unwrap())
+        .collect()
+}
+
+/// Computes the Hamming distance between an encrypted and a plaintext bit string.
+///
+/// # Arguments
+///
+/// * Input: ciphertexts `x`, with message bits `x_0, x_1, ...`.
+/// * Input: plaintext bits `y`, with values `y_0, y_1, ...`.
+/// * Output: the little-endian bits of the number of `i` with `x_i != y_i`,
+///   as many as needed to hold `x.len()`.
+///
+/// The differences `x_i ^ y_i` are free, `x_i` or its negation, so it costs a
+/// single [`popcount`]. The distances to strings of the same length have the same
+/// width, so they can be passed to [`nearest_neighbors`](crate::nearest_neighbors).
+///
+/// # Panics
+///
+/// Panics if `x` is empty or the lengths of `x` and `y` are not equal.
+pub fn hamming_distance<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[bool],
+) -> Vec<E::Bit> {
+    assert_eq!(x.len(), y.len());
+    let differences: Vec<E::Bit> = x
+        .iter()
+        .zip(y)
+        .map(|(a, &b)| if b { evaluator.not(a) } else { a.clone() })
+        .collect();
+    popcount(evaluator, &differences)
+}
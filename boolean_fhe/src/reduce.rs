@@ -0,0 +1,109 @@
+use rayon::prelude::*;
+
+use crate::{integer::ripple_add, BooleanEvaluator};
+
+/// The associative operations supported by [`reduce`].
+///
+/// Every value is a word of little-endian bits, all of the same width.
+/// Single bits are words of width `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReduceOp {
+    /// Bitwise and.
+    And,
+    /// Bitwise or.
+    Or,
+    /// Bitwise xor.
+    Xor,
+    /// Addition modulo `2^width`.
+    Add,
+    /// Unsigned minimum.
+    Min,
+    /// Unsigned maximum.
+    Max,
+}
+
+impl ReduceOp {
+    /// Applies this operation on two words with `evaluator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` have different widths.
+    pub fn apply<E: BooleanEvaluator>(
+        self,
+        evaluator: &E,
+        x: &[E::Bit],
+        y: &[E::Bit],
+    ) -> Vec<E::Bit> {
+        assert_eq!(x.len(), y.len());
+        match self {
+            ReduceOp::And => bitwise(x, y, |a, b| evaluator.and(a, b)),
+            ReduceOp::Or => bitwise(x, y, |a, b| evaluator.or(a, b)),
+            ReduceOp::Xor => bitwise(x, y, |a, b| evaluator.xor(a, b)),
+            ReduceOp::Add => ripple_add(evaluator, x, y, None, false).0,
+            ReduceOp::Min | ReduceOp::Max => {
+                let Some(lt) = less_than(evaluator, x, y) else {
+                    return Vec::new();
+                };
+                let (first, second) = if self == ReduceOp::Min {
+                    (x, y)
+                } else {
+                    (y, x)
+                };
+                bitwise(first, second, |a, b| evaluator.mux(&lt, a, b))
+            }
+        }
+    }
+}
+
+/// Reduces `values` with the associative operation `op`.
+///
+/// The values are combined pairwise in a balanced tree, each level in parallel
+/// on the rayon thread pool. Compared with a sequential fold, it performs the
+/// same number of operations but the depth is `log2(values.len())` instead of
+/// `values.len() - 1`. Returns `None` if `values` is empty.
+///
+/// # Panics
+///
+/// Panics if the values have different widths.
+pub fn reduce<E: BooleanEvaluator>(
+    evaluator: &E,
+    mut values: Vec<Vec<E::Bit>>,
+    op: ReduceOp,
+) -> Option<Vec<E::Bit>> {
+    while values.len() > 1 {
+        values = values
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [x, y] => op.apply(evaluator, x, y),
+                [x] => x.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    values.pop()
+}
+
+fn bitwise<B, F>(x: &[B], y: &[B], f: F) -> Vec<B>
+where
+    B: Send + Sync,
+    F: Fn(&B, &B) -> B + Sync,
+{
+    x.par_iter().zip(y).map(|(a, b)| f(a, b)).collect()
+}
+
+/// Returns `x < y` for two little-endian unsigned words, `None` if they are empty.
+pub(crate) fn less_than<E: BooleanEvaluator>(
+    evaluator: &E,
+    x: &[E::Bit],
+    y: &[E::Bit],
+) -> Option<E::Bit> {
+    let mut bits = x.iter().zip(y);
+    let (a, b) = bits.next()?;
+    let lt = evaluator.and(&evaluator.not(a), b);
+
+    // a higher differing bit decides, `y_i` is set iff `x_i < y_i` there
+    Some(bits.fold(lt, |lt, (a, b)| {
+        let differ = evaluator.xor(a, b);
+        evaluator.mux(&differ, b, &lt)
+    }))
+}
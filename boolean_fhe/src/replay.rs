@@ -0,0 +1,382 @@
+use std::sync::Mutex;
+
+use algebra::{
+    utils::{read_header, write_header, DecodeLimits, LeBytes},
+    AlgebraError,
+};
+
+use crate::BooleanEvaluator;
+
+/// The operation codes of an [`OperationLog`].
+///
+/// The discriminants are the codes in the encoding of an [`OperationLog`],
+/// new operations must be appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum OpCode {
+    /// An input bit of the evaluation.
+    Input,
+    /// The not operation.
+    Not,
+    /// The nand operation.
+    Nand,
+    /// The and operation.
+    And,
+    /// The or operation.
+    Or,
+    /// The nor operation.
+    Nor,
+    /// The xor operation.
+    Xor,
+    /// The xnor operation.
+    Xnor,
+    /// The majority operation.
+    Majority,
+    /// The mux operation.
+    Mux,
+    /// The xor operation of three bits.
+    Xor3,
+}
+
+impl OpCode {
+    /// Every operation code, in the order of their discriminants.
+    const ALL: [OpCode; 11] = [
+        OpCode::Input,
+        OpCode::Not,
+        OpCode::Nand,
+        OpCode::And,
+        OpCode::Or,
+        OpCode::Nor,
+        OpCode::Xor,
+        OpCode::Xnor,
+        OpCode::Majority,
+        OpCode::Mux,
+        OpCode::Xor3,
+    ];
+
+    /// Returns the number of input bits of this operation.
+    #[inline]
+    pub fn arity(self) -> usize {
+        match self {
+            OpCode::Input => 0,
+            OpCode::Not => 1,
+            OpCode::Nand | OpCode::And | OpCode::Or | OpCode::Nor | OpCode::Xor | OpCode::Xnor => 2,
+            OpCode::Majority | OpCode::Mux | OpCode::Xor3 => 3,
+        }
+    }
+
+    /// Returns the number of bootstraps of this operation on the encrypted
+    /// [`Evaluator`](crate::Evaluator).
+    ///
+    /// This is the table behind the counts of [`SimulatedEvaluator`](crate::SimulatedEvaluator)
+    /// and the estimates of [`CostModel`](crate::CostModel).
+    #[inline]
+    pub fn bootstraps(self) -> u32 {
+        match self {
+            OpCode::Input | OpCode::Not => 0,
+            OpCode::Nand
+            | OpCode::And
+            | OpCode::Or
+            | OpCode::Nor
+            | OpCode::Xor
+            | OpCode::Xnor
+            | OpCode::Majority
+            | OpCode::Xor3 => 1,
+            // two ands and one or
+            OpCode::Mux => 3,
+        }
+    }
+}
+
+/// A recorded operation.
+///
+/// The output bit of the operation gets its position in the [`OperationLog`] as id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+    /// The operation code.
+    pub op: OpCode,
+    /// The ids of the input bits, only the first `op.arity()` ones are used.
+    pub inputs: [usize; 3],
+}
+
+/// A log of the operations of an evaluation, recorded by [`RecordingEvaluator`].
+///
+/// Every entry is an input bit or a gate on bits with smaller ids, so the log
+/// can be replayed in order against the same inputs on any [`BooleanEvaluator`],
+/// e.g. a [`SimulatedEvaluator`](crate::SimulatedEvaluator) on the clear inputs.
+///
+/// A log is stored or sent to another environment with [`LeBytes::to_le_vec`],
+/// and loaded back with [`LeBytes::from_le_slice`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OperationLog {
+    operations: Vec<Operation>,
+}
+
+impl OperationLog {
+    /// Creates a new empty [`OperationLog`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded operations, in the order of their ids.
+    #[inline]
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    /// Returns the number of recorded operations, including the inputs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if nothing is recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Returns the number of input bits.
+    #[inline]
+    pub fn input_count(&self) -> usize {
+        self.operations
+            .iter()
+            .filter(|operation| operation.op == OpCode::Input)
+            .count()
+    }
+
+    /// Replays the log on `evaluator`, and returns the values of all bits indexed by id.
+    ///
+    /// `inputs` are taken in the order they were recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `inputs` is not [`input_count`](Self::input_count).
+    pub fn replay<E: BooleanEvaluator>(&self, evaluator: &E, inputs: &[E::Bit]) -> Vec<E::Bit> {
+        assert_eq!(inputs.len(), self.input_count());
+
+        let mut inputs = inputs.iter();
+        let mut values: Vec<E::Bit> = Vec::with_capacity(self.operations.len());
+        for operation in &self.operations {
+            let [a, b, c] = operation.inputs;
+            let bit = match operation.op {
+                OpCode::Input => inputs.next().unwrap().clone(),
+                OpCode::Not => evaluator.not(&values[a]),
+                OpCode::Nand => evaluator.nand(&values[a], &values[b]),
+                OpCode::And => evaluator.and(&values[a], &values[b]),
+                OpCode::Or => evaluator.or(&values[a], &values[b]),
+                OpCode::Nor => evaluator.nor(&values[a], &values[b]),
+                OpCode::Xor => evaluator.xor(&values[a], &values[b]),
+                OpCode::Xnor => evaluator.xnor(&values[a], &values[b]),
+                OpCode::Majority => evaluator.majority(&values[a], &values[b], &values[c]),
+                OpCode::Mux => evaluator.mux(&values[a], &values[b], &values[c]),
+                OpCode::Xor3 => evaluator.xor3(&values[a], &values[b], &values[c]),
+            };
+            values.push(bit);
+        }
+        values
+    }
+}
+
+/// An operation code is encoded as one byte.
+impl LeBytes for OpCode {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        (*self as u8).write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let code = u8::read_le(bytes)?;
+        Self::ALL
+            .get(code as usize)
+            .copied()
+            .ok_or(AlgebraError::DecodeErr)
+    }
+}
+
+/// An operation is encoded as its [`OpCode`], followed by its `op.arity()`
+/// input ids in `u64`. The unused inputs are decoded as `0`.
+impl LeBytes for Operation {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        self.op.write_le(buf);
+        for &id in &self.inputs[..self.op.arity()] {
+            (id as u64).write_le(buf);
+        }
+    }
+
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let op = OpCode::read_le(bytes)?;
+        let mut inputs = [0; 3];
+        for id in &mut inputs[..op.arity()] {
+            *id = usize::try_from(u64::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)?;
+        }
+        Ok(Self { op, inputs })
+    }
+}
+
+/// The log is encoded after its header as the sequence of its operations.
+///
+/// Decoding checks that every operation only reads bits with smaller ids,
+/// so a decoded log can be replayed.
+impl LeBytes for OperationLog {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"OLOG", buf);
+        self.operations.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        read_header(b"OLOG", bytes)?;
+        let operations = Vec::<Operation>::read_le_with(bytes, limits)?;
+        let is_ordered = operations.iter().enumerate().all(|(id, operation)| {
+            operation.inputs[..operation.op.arity()]
+                .iter()
+                .all(|&input| input < id)
+        });
+        if is_ordered {
+            Ok(Self { operations })
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+}
+
+/// A bit of [`RecordingEvaluator`], tagged with its id in the [`OperationLog`].
+#[derive(Debug, Clone)]
+pub struct RecordedBit<B> {
+    id: usize,
+    bit: B,
+}
+
+impl<B> RecordedBit<B> {
+    /// Returns the id of this bit in the [`OperationLog`].
+    #[inline]
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns a reference to the underlying bit.
+    #[inline]
+    pub fn bit(&self) -> &B {
+        &self.bit
+    }
+
+    /// Drops the id, and returns the underlying bit.
+    #[inline]
+    pub fn into_bit(self) -> B {
+        self.bit
+    }
+}
+
+/// An evaluator which runs the gates on another [`BooleanEvaluator`]
+/// and records them to an [`OperationLog`].
+///
+/// Input bits are registered with [`input`](RecordingEvaluator::input).
+/// Gates may run in parallel, every output is logged after its inputs.
+pub struct RecordingEvaluator<'a, E: BooleanEvaluator> {
+    evaluator: &'a E,
+    log: Mutex<OperationLog>,
+}
+
+impl<'a, E: BooleanEvaluator> RecordingEvaluator<'a, E> {
+    /// Creates a new [`RecordingEvaluator`] which runs the gates on `evaluator`.
+    #[inline]
+    pub fn new(evaluator: &'a E) -> Self {
+        Self {
+            evaluator,
+            log: Mutex::new(OperationLog::new()),
+        }
+    }
+
+    /// Registers an input bit.
+    #[inline]
+    pub fn input(&self, bit: E::Bit) -> RecordedBit<E::Bit> {
+        self.record(OpCode::Input, [0; 3], bit)
+    }
+
+    /// Returns a copy of the log recorded so far.
+    #[inline]
+    pub fn log(&self) -> OperationLog {
+        self.log.lock().unwrap().clone()
+    }
+
+    /// Drops self, and returns the recorded log.
+    #[inline]
+    pub fn into_log(self) -> OperationLog {
+        self.log.into_inner().unwrap()
+    }
+
+    fn record(&self, op: OpCode, inputs: [usize; 3], bit: E::Bit) -> RecordedBit<E::Bit> {
+        let mut log = self.log.lock().unwrap();
+        let id = log.operations.len();
+        log.operations.push(Operation { op, inputs });
+        RecordedBit { id, bit }
+    }
+}
+
+impl<E: BooleanEvaluator> BooleanEvaluator for RecordingEvaluator<'_, E> {
+    type Bit = RecordedBit<E::Bit>;
+
+    #[inline]
+    fn not(&self, c: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.not(&c.bit);
+        self.record(OpCode::Not, [c.id, 0, 0], bit)
+    }
+
+    #[inline]
+    fn nand(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.nand(&c0.bit, &c1.bit);
+        self.record(OpCode::Nand, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn and(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.and(&c0.bit, &c1.bit);
+        self.record(OpCode::And, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn or(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.or(&c0.bit, &c1.bit);
+        self.record(OpCode::Or, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn nor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.nor(&c0.bit, &c1.bit);
+        self.record(OpCode::Nor, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn xor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.xor(&c0.bit, &c1.bit);
+        self.record(OpCode::Xor, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn xnor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.xnor(&c0.bit, &c1.bit);
+        self.record(OpCode::Xnor, [c0.id, c1.id, 0], bit)
+    }
+
+    #[inline]
+    fn majority(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.majority(&c0.bit, &c1.bit, &c2.bit);
+        self.record(OpCode::Majority, [c0.id, c1.id, c2.id], bit)
+    }
+
+    #[inline]
+    fn mux(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.mux(&c0.bit, &c1.bit, &c2.bit);
+        self.record(OpCode::Mux, [c0.id, c1.id, c2.id], bit)
+    }
+
+    #[inline]
+    fn xor3(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        let bit = self.evaluator.xor3(&c0.bit, &c1.bit, &c2.bit);
+        self.record(OpCode::Xor3, [c0.id, c1.id, c2.id], bit)
+    }
+}
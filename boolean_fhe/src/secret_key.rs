@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use algebra::{integer::UnsignedInteger, ntt::NttTable, reduce::RingReduce, NttField};
 use fhe_core::{LweSecretKey, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey};
 use rand::{CryptoRng, Rng};
 
@@ -30,6 +30,31 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> SecretKeyPack<C
     where
         R: Rng + CryptoRng,
     {
+        let ntt_table = parameters.generate_ntt_table_for_rlwe();
+        Self::new_with_ntt_table(parameters, ntt_table, rng)
+    }
+
+    /// Creates a new [`SecretKeyPack<C, Q>`] with the given NTT table.
+    ///
+    /// The evaluation key generated from this pack uses `ntt_table` for the
+    /// blind rotation. This allows a table with other settings than the one of
+    /// [`BooleanFheParameters::generate_ntt_table_for_rlwe`], e.g. a
+    /// [`FieldTableWithShoupRoot`](algebra::ntt::FieldTableWithShoupRoot)
+    /// with the radix-4 transforms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `ntt_table` is not the ring dimension.
+    pub fn new_with_ntt_table<R>(
+        parameters: BooleanFheParameters<C, LweModulus, Q>,
+        ntt_table: <Q as NttField>::Table,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        assert_eq!(ntt_table.dimension(), parameters.ring_dimension());
+
         let lwe_secret_key = LweSecretKey::generate(parameters.lwe_params(), rng);
 
         let ring_dimension = parameters.ring_dimension();
@@ -57,8 +82,6 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> SecretKeyPack<C
             }
         };
 
-        let ntt_table = parameters.generate_ntt_table_for_rlwe();
-
         let ntt_rlwe_secret_key =
             NttRlweSecretKey::from_coeff_secret_key(&rlwe_secret_key, &ntt_table);
 
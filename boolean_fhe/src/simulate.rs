@@ -0,0 +1,203 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use fhe_core::LweCiphertext;
+
+use crate::{Evaluator, OpCode};
+
+/// The gate interface shared by [`Evaluator`] and [`SimulatedEvaluator`].
+///
+/// Application logic written against this trait can be debugged on clear
+/// values with [`SimulatedEvaluator`], then run on ciphertexts unchanged.
+pub trait BooleanEvaluator: Sync {
+    /// The type of a (possibly encrypted) bit.
+    type Bit: Clone + Send + Sync;
+
+    /// Performs the not operation.
+    fn not(&self, c: &Self::Bit) -> Self::Bit;
+    /// Performs the nand operation.
+    fn nand(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the and operation.
+    fn and(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the or operation.
+    fn or(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the nor operation.
+    fn nor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the xor operation.
+    fn xor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the xnor operation.
+    fn xnor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit;
+    /// Performs the majority operation.
+    fn majority(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit;
+    /// Performs the mux operation, `if c0 {c1} else {c2}`.
+    fn mux(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit;
+    /// Performs the xor operation of three bits.
+    ///
+    /// The default implementation chains two [`BooleanEvaluator::xor`].
+    #[inline]
+    fn xor3(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        self.xor(&self.xor(c0, c1), c2)
+    }
+}
+
+impl<C, LweModulus, Q> BooleanEvaluator for Evaluator<C, LweModulus, Q>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    type Bit = LweCiphertext<C>;
+
+    #[inline]
+    fn not(&self, c: &Self::Bit) -> Self::Bit {
+        Evaluator::not(self, c)
+    }
+
+    #[inline]
+    fn nand(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::nand(self, c0, c1)
+    }
+
+    #[inline]
+    fn and(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::and(self, c0, c1)
+    }
+
+    #[inline]
+    fn or(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::or(self, c0, c1)
+    }
+
+    #[inline]
+    fn nor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::nor(self, c0, c1)
+    }
+
+    #[inline]
+    fn xor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::xor(self, c0, c1)
+    }
+
+    #[inline]
+    fn xnor(&self, c0: &Self::Bit, c1: &Self::Bit) -> Self::Bit {
+        Evaluator::xnor(self, c0, c1)
+    }
+
+    #[inline]
+    fn majority(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        Evaluator::majority(self, c0, c1, c2)
+    }
+
+    #[inline]
+    fn mux(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        Evaluator::mux(self, c0, c1, c2)
+    }
+
+    #[inline]
+    fn xor3(&self, c0: &Self::Bit, c1: &Self::Bit, c2: &Self::Bit) -> Self::Bit {
+        Evaluator::xor3(self, c0, c1, c2)
+    }
+}
+
+/// An evaluator which runs the gates on clear bits.
+///
+/// It counts the bootstraps the encrypted [`Evaluator`] would perform, so the
+/// cost of a circuit can be measured without any key generation. Since every
+/// encrypted gate refreshes its output, there is no noise budget to track.
+#[derive(Debug, Default)]
+pub struct SimulatedEvaluator {
+    bootstraps: AtomicUsize,
+}
+
+impl SimulatedEvaluator {
+    /// Creates a new [`SimulatedEvaluator`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bootstraps performed so far.
+    #[inline]
+    pub fn bootstrap_count(&self) -> usize {
+        self.bootstraps.load(Ordering::Relaxed)
+    }
+
+    /// Resets the bootstrap counter.
+    #[inline]
+    pub fn reset(&self) {
+        self.bootstraps.store(0, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn count(&self, op: OpCode) {
+        self.bootstraps
+            .fetch_add(op.bootstraps() as usize, Ordering::Relaxed);
+    }
+}
+
+impl BooleanEvaluator for SimulatedEvaluator {
+    type Bit = bool;
+
+    #[inline]
+    fn not(&self, c: &bool) -> bool {
+        !c
+    }
+
+    #[inline]
+    fn nand(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::Nand);
+        !(c0 & c1)
+    }
+
+    #[inline]
+    fn and(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::And);
+        c0 & c1
+    }
+
+    #[inline]
+    fn or(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::Or);
+        c0 | c1
+    }
+
+    #[inline]
+    fn nor(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::Nor);
+        !(c0 | c1)
+    }
+
+    #[inline]
+    fn xor(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::Xor);
+        c0 ^ c1
+    }
+
+    #[inline]
+    fn xnor(&self, c0: &bool, c1: &bool) -> bool {
+        self.count(OpCode::Xnor);
+        !(c0 ^ c1)
+    }
+
+    #[inline]
+    fn majority(&self, c0: &bool, c1: &bool, c2: &bool) -> bool {
+        self.count(OpCode::Majority);
+        (c0 & c1) | (c1 & c2) | (c0 & c2)
+    }
+
+    #[inline]
+    fn mux(&self, c0: &bool, c1: &bool, c2: &bool) -> bool {
+        self.count(OpCode::Mux);
+        if *c0 {
+            *c1
+        } else {
+            *c2
+        }
+    }
+
+    #[inline]
+    fn xor3(&self, c0: &bool, c1: &bool, c2: &bool) -> bool {
+        self.count(OpCode::Xor3);
+        c0 ^ c1 ^ c2
+    }
+}
@@ -0,0 +1,71 @@
+use boolean_fhe::{
+    BloomFilter, BloomFilterParameters, Decryptor, Encryptor, Evaluator, KeyGen,
+    SimulatedEvaluator, TOY_PARAMETERS,
+};
+
+const ITEM_COUNT: usize = 16;
+const FALSE_POSITIVE_RATE: f64 = 0.01;
+
+fn filter() -> (BloomFilter, Vec<String>) {
+    let items: Vec<String> = (0..ITEM_COUNT).map(|i| format!("user-{i}")).collect();
+    let parameters = BloomFilterParameters::optimal(ITEM_COUNT, FALSE_POSITIVE_RATE);
+    (BloomFilter::from_items(parameters, &items), items)
+}
+
+#[test]
+fn test_query_simulated() {
+    let (filter, items) = filter();
+    let parameters = filter.parameters();
+    let sim = SimulatedEvaluator::new();
+    let clear = filter.encrypt(|bit| bit);
+
+    for item in &items {
+        sim.reset();
+        assert!(clear.query(&sim, item), "{item}");
+        assert_eq!(sim.bootstrap_count(), parameters.positions(item).len() - 1);
+    }
+
+    let trials = 10_000;
+    let mut false_positives = 0;
+    for i in 0..trials {
+        let item = format!("guest-{i}");
+        let answer = clear.query(&sim, &item);
+        assert_eq!(answer, filter.contains(&item), "{item}");
+        false_positives += answer as usize;
+    }
+    let rate = false_positives as f64 / trials as f64;
+    assert!(
+        rate < 3.0 * parameters.false_positive_rate(ITEM_COUNT),
+        "{rate}"
+    );
+}
+
+#[test]
+fn test_query_encrypted() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let (filter, items) = filter();
+    let encrypted = filter.encrypt(|bit| enc.encrypt(bit as u8, &mut rand::thread_rng()));
+
+    let non_member = (0..)
+        .map(|i| format!("guest-{i}"))
+        .find(|item| !filter.contains(item))
+        .unwrap();
+    for item in [&items[0], &items[ITEM_COUNT - 1]] {
+        assert_eq!(
+            dec.decrypt::<u8>(&encrypted.query(&eval, item)),
+            1,
+            "{item}"
+        );
+    }
+    assert_eq!(
+        dec.decrypt::<u8>(&encrypted.query(&eval, &non_member)),
+        0,
+        "{non_member}"
+    );
+}
@@ -0,0 +1,58 @@
+use algebra::{modulus::PowOf2Modulus, ntt::NttRadix};
+use boolean_fhe::{
+    BooleanFheParameters, ConstParameters, Decryptor, Encryptor, Evaluator, SecretKeyPack, Steps,
+    ToyField, TOY_CONST_PARAMETERS,
+};
+
+type Parameters = BooleanFheParameters<u16, PowOf2Modulus<u16>, ToyField>;
+
+/// Runs every gate on every input, the pooled key switching buffers are reused across gates.
+fn check_gates(steps: Steps, radix: NttRadix) {
+    let mut rng = rand::thread_rng();
+    let parameters = Parameters::new(ConstParameters {
+        steps,
+        ..TOY_CONST_PARAMETERS
+    })
+    .unwrap();
+    let mut ntt_table = parameters.generate_ntt_table_for_rlwe();
+    ntt_table.set_radix(radix);
+    let sk = SecretKeyPack::new_with_ntt_table(parameters, ntt_table, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    for (a, b, c) in itertools::iproduct!(0..2u8, 0..2, 0..2) {
+        let x = enc.encrypt(a, &mut rng);
+        let y = enc.encrypt(b, &mut rng);
+        let z = enc.encrypt(c, &mut rng);
+
+        let results = [
+            (eval.nand(&x, &y), (a & b) ^ 1),
+            (eval.and(&x, &y), a & b),
+            (eval.or(&x, &y), a | b),
+            (eval.xor(&x, &y), a ^ b),
+            (eval.majority(&x, &y, &z), (a & b) | (b & c) | (a & c)),
+            (eval.mux(&x, &y, &z), if a == 1 { b } else { c }),
+            (eval.xor3(&x, &y, &z), a ^ b ^ c),
+        ];
+        for (ct, expected) in results {
+            assert_eq!(dec.decrypt::<u8>(&ct), expected, "{steps:?} {radix:?}");
+        }
+    }
+}
+
+#[test]
+fn test_bootstrap_br_ks_lev_ms() {
+    check_gates(Steps::BrKsLevMs, NttRadix::Radix2);
+}
+
+#[test]
+fn test_bootstrap_br_ks_rlev_ms() {
+    check_gates(Steps::BrKsRlevMs, NttRadix::Radix2);
+}
+
+#[test]
+fn test_bootstrap_radix4() {
+    check_gates(Steps::BrKsLevMs, NttRadix::Radix4);
+}
@@ -0,0 +1,103 @@
+use algebra::{
+    integer::UnsignedInteger,
+    random::Prg,
+    reduce::RingReduce,
+    utils::{DecodeLimits, LeBytes},
+    AlgebraError, NttField,
+};
+use boolean_fhe::{Checkpoint, Decryptor, Encryptor, Evaluator, KeyGen, TOY_PARAMETERS};
+use fhe_core::LweCiphertext;
+use rand::{Rng, SeedableRng};
+
+const STEPS: u64 = 6;
+
+/// One step of a long evaluation, which draws the gate from `rng`.
+fn step<C, LweModulus, Q>(
+    eval: &Evaluator<C, LweModulus, Q>,
+    state: &mut [LweCiphertext<C>],
+    rng: &mut Prg,
+) where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    state[0] = if rng.gen() {
+        eval.and(&state[0], &state[1])
+    } else {
+        eval.xor(&state[0], &state[1])
+    };
+    state.rotate_left(1);
+}
+
+#[test]
+fn test_checkpoint_resume() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let inputs = vec![
+        enc.encrypt(true, &mut rng),
+        enc.encrypt(false, &mut rng),
+        enc.encrypt(true, &mut rng),
+    ];
+
+    // the uninterrupted evaluation
+    let mut state = inputs.clone();
+    let mut prg = Prg::seed_from_u64(42);
+    (0..STEPS).for_each(|_| step(&eval, &mut state, &mut prg));
+
+    // the evaluation interrupted halfway, saved, and resumed from disk
+    let mut partial = inputs;
+    let mut partial_prg = Prg::seed_from_u64(42);
+    (0..STEPS / 2).for_each(|_| step(&eval, &mut partial, &mut partial_prg));
+    let path = std::env::temp_dir().join(format!("checkpoint-{}", std::process::id()));
+    eval.checkpoint(STEPS / 2, &partial, &partial_prg)
+        .save(&path)
+        .unwrap();
+    drop((partial, partial_prg));
+
+    let checkpoint = Checkpoint::load(&path, &DecodeLimits::default()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let (done, mut resumed, mut resumed_prg) = eval.resume(checkpoint).unwrap();
+    assert_eq!(done, STEPS / 2);
+    (done..STEPS).for_each(|_| step(&eval, &mut resumed, &mut resumed_prg));
+
+    assert_eq!(resumed, state);
+    assert_eq!(
+        resumed
+            .iter()
+            .map(|c| dec.decrypt::<bool>(c))
+            .collect::<Vec<_>>(),
+        state
+            .iter()
+            .map(|c| dec.decrypt::<bool>(c))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_checkpoint_rejects_other_keys() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+    let other = Evaluator::new(&sk, &mut rng);
+
+    let state = [enc.encrypt(true, &mut rng)];
+    let bytes = eval.checkpoint(1, &state, &Prg::new()).to_le_vec();
+    let checkpoint = Checkpoint::<u16>::from_le_slice(&bytes).unwrap();
+    assert_eq!(checkpoint.step(), 1);
+    assert!(matches!(
+        other.resume(checkpoint),
+        Err(AlgebraError::KeyMismatch)
+    ));
+
+    assert!(Checkpoint::<u16>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+    let limits = DecodeLimits {
+        max_dimension: TOY_PARAMETERS.lwe_dimension() - 1,
+        ..DecodeLimits::default()
+    };
+    assert!(Checkpoint::<u16>::from_le_slice_with(&bytes, &limits).is_err());
+}
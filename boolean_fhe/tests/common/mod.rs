@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+/// Splits the low `width` bits of `x` in little-endian order.
+pub fn to_bits(x: u64, width: usize) -> Vec<bool> {
+    (0..width).map(|i| (x >> i) & 1 == 1).collect()
+}
+
+/// Collects little-endian bits into an integer.
+pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> u64 {
+    bits.into_iter()
+        .enumerate()
+        .fold(0, |acc, (i, b)| acc | ((b as u64) << i))
+}
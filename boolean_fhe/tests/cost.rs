@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use algebra::utils::LeBytes;
+use boolean_fhe::{
+    estimate_cost, BooleanEvaluator, CostModel, OpCode, RecordingEvaluator, SimulatedEvaluator,
+};
+
+mod common;
+
+use common::to_bits;
+
+const BOOTSTRAP: Duration = Duration::from_millis(12);
+const NOT: Duration = Duration::from_micros(3);
+
+/// Runs `op` once on clear inputs.
+fn run<E: BooleanEvaluator>(eval: &E, op: OpCode, x: &E::Bit) -> E::Bit {
+    match op {
+        OpCode::Input => x.clone(),
+        OpCode::Not => eval.not(x),
+        OpCode::Nand => eval.nand(x, x),
+        OpCode::And => eval.and(x, x),
+        OpCode::Or => eval.or(x, x),
+        OpCode::Nor => eval.nor(x, x),
+        OpCode::Xor => eval.xor(x, x),
+        OpCode::Xnor => eval.xnor(x, x),
+        OpCode::Majority => eval.majority(x, x, x),
+        OpCode::Mux => eval.mux(x, x, x),
+        OpCode::Xor3 => eval.xor3(x, x, x),
+    }
+}
+
+#[test]
+fn test_estimate_cost() {
+    let model = CostModel::new(BOOTSTRAP, NOT);
+    let sim = SimulatedEvaluator::new();
+
+    for op in [
+        OpCode::Input,
+        OpCode::Not,
+        OpCode::Nand,
+        OpCode::And,
+        OpCode::Or,
+        OpCode::Nor,
+        OpCode::Xor,
+        OpCode::Xnor,
+        OpCode::Majority,
+        OpCode::Mux,
+        OpCode::Xor3,
+    ] {
+        sim.reset();
+        run(&sim, op, &true);
+        let bootstraps = sim.bootstrap_count() as u32;
+        assert_eq!(bootstraps, op.bootstraps(), "{op:?}");
+
+        // `not` and the negated selector of `mux` are the only gates without bootstrap
+        let nots = matches!(op, OpCode::Not | OpCode::Mux) as u32;
+        let expected = BOOTSTRAP * bootstraps + NOT * nots;
+        assert_eq!(model.estimate_cost(op), expected, "{op:?}");
+        assert_eq!(estimate_cost(op, &model), expected, "{op:?}");
+    }
+}
+
+#[test]
+fn test_estimate_log() {
+    let model = CostModel::new(BOOTSTRAP, NOT);
+    let sim = SimulatedEvaluator::new();
+    let recorder = RecordingEvaluator::new(&sim);
+
+    let inputs: Vec<_> = to_bits(0b1011_0110, 8)
+        .into_iter()
+        .map(|b| recorder.input(b))
+        .collect();
+    let (x, y) = inputs.split_at(4);
+    let mut acc = recorder.xnor(&x[0], &y[0]);
+    for (a, b) in x.iter().zip(y).skip(1) {
+        let t = recorder.mux(&acc, a, &recorder.not(b));
+        acc = recorder.majority(&acc, &t, &recorder.nand(a, b));
+    }
+
+    let log = recorder.into_log();
+    let nots = log
+        .operations()
+        .iter()
+        .filter(|operation| matches!(operation.op, OpCode::Not | OpCode::Mux))
+        .count() as u32;
+    assert_eq!(
+        model.estimate_log(&log),
+        BOOTSTRAP * sim.bootstrap_count() as u32 + NOT * nots
+    );
+
+    // replaying the log costs the same bootstraps again
+    let clear = to_bits(0b1011_0110, 8);
+    sim.reset();
+    log.replay(&sim, &clear);
+    assert_eq!(
+        model.estimate_log(&log),
+        BOOTSTRAP * sim.bootstrap_count() as u32 + NOT * nots
+    );
+}
+
+#[test]
+fn test_save_load() {
+    let model = CostModel::new(Duration::new(2, 999_999_999), NOT);
+    let bytes = model.to_le_vec();
+    assert_eq!(bytes.len(), 5 + 2 * 12);
+    assert_eq!(CostModel::from_le_slice(&bytes).unwrap(), model);
+
+    // truncated, trailing bytes, nanoseconds out of range
+    assert!(CostModel::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+    let mut longer = bytes.clone();
+    longer.push(0);
+    assert!(CostModel::from_le_slice(&longer).is_err());
+    let mut bad = bytes.clone();
+    bad[13..17].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+    assert!(CostModel::from_le_slice(&bad).is_err());
+}
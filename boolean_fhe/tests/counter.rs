@@ -0,0 +1,41 @@
+use boolean_fhe::{Decryptor, Encryptor, Evaluator, FheCounter, KeyGen, TOY_PARAMETERS};
+
+mod common;
+
+use common::from_bits;
+
+/// Adds `bits` one by one to a counter of `width` bits, and checks the
+/// decrypted count after every update.
+fn check_counter(width: usize, bits: &[bool]) {
+    let mut rng = rand::thread_rng();
+    let params = *TOY_PARAMETERS;
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let mut counter = FheCounter::new(width, params.lwe_dimension());
+    assert_eq!(counter.width(), width);
+
+    let mut expected = 0u64;
+    for (i, &bit) in bits.iter().enumerate() {
+        counter.add_bit(&enc.encrypt(bit as u8, &mut rng), &eval);
+        expected = (expected + bit as u64) % (1 << width);
+
+        assert_eq!(counter.updates(), i as u64 + 1);
+        assert_eq!(counter.bits().len(), width);
+        let count = from_bits(counter.bits().iter().map(|c| dec.decrypt::<u8>(c) == 1));
+        assert_eq!(count, expected, "after {} updates", i + 1);
+    }
+}
+
+#[test]
+fn test_counter_increment() {
+    check_counter(3, &[true, false, true, true, false, true]);
+}
+
+#[test]
+fn test_counter_wraparound() {
+    check_counter(2, &[true; 6]);
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use algebra::{modulus::PowOf2Modulus, utils::LeBytes};
+use boolean_fhe::{
+    BooleanEvaluator, BooleanFheParameters, ConstParameters, CostModel, Evaluator, KeyGen,
+    OperationLog, RecordingEvaluator, SimulatedEvaluator, Steps, ToyField, TOY_CONST_PARAMETERS,
+};
+use fhe_core::LweSecretKeyType;
+use rand::{thread_rng, Rng, RngCore};
+
+type ToyEvaluator = Evaluator<u16, PowOf2Modulus<u16>, ToyField>;
+
+const MUTATIONS: usize = 64;
+
+/// Decodes random bytes, and truncated and corrupted copies of `bytes`,
+/// none of which may panic.
+fn check_mutations<T: LeBytes>(bytes: &[u8]) {
+    let mut rng = thread_rng();
+    for _ in 0..MUTATIONS {
+        let mut bad = bytes.to_vec();
+        let i = rng.gen_range(0..bad.len());
+        match rng.gen_range(0..4) {
+            0 => bad.truncate(i),
+            1 => bad[i] ^= rng.gen::<u8>() | 1,
+            2 => bad[i..(i + 8).min(bytes.len())].fill(0xff),
+            _ => rng.fill_bytes(&mut bad[i..]),
+        }
+        let _ = T::from_le_slice(&bad);
+    }
+}
+
+#[test]
+fn test_corrupted_evaluators() {
+    let mut rng = thread_rng();
+    for (steps, lwe_secret_key_type) in [
+        (Steps::BrMsKs, LweSecretKeyType::Binary),
+        (Steps::BrMsKs, LweSecretKeyType::Ternary),
+        (Steps::BrKsRlevMs, LweSecretKeyType::Ternary),
+        (Steps::BrKsLevMs, LweSecretKeyType::Binary),
+    ] {
+        let params = BooleanFheParameters::new(ConstParameters {
+            steps,
+            lwe_secret_key_type,
+            ..TOY_CONST_PARAMETERS
+        })
+        .unwrap();
+        let sk = KeyGen::generate_secret_key(params, &mut rng);
+        let bytes = ToyEvaluator::new(&sk, &mut rng).to_le_vec();
+        check_mutations::<ToyEvaluator>(&bytes);
+    }
+}
+
+#[test]
+fn test_corrupted_logs() {
+    let sim = SimulatedEvaluator::new();
+    let recorder = RecordingEvaluator::new(&sim);
+    let (x, y) = (recorder.input(true), recorder.input(false));
+    let z = recorder.xor(&x, &y);
+    recorder.mux(&z, &x, &recorder.not(&y));
+    check_mutations::<OperationLog>(&recorder.into_log().to_le_vec());
+
+    let model = CostModel::new(Duration::from_millis(12), Duration::from_micros(3));
+    check_mutations::<CostModel>(&model.to_le_vec());
+}
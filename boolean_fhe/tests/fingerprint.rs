@@ -0,0 +1,148 @@
+use algebra::{
+    modulus::PowOf2Modulus,
+    random::Prg,
+    utils::{DecodeLimits, Envelope, KeyBound, KeyRecord, LeBytes},
+    AlgebraError,
+};
+use boolean_fhe::{
+    BooleanFheParameters, ConstParameters, Decryptor, Encryptor, Evaluator, KeyGen, Steps,
+    ToyField, TOY_CONST_PARAMETERS, TOY_PARAMETERS,
+};
+use fhe_core::{LweCiphertext, LweSecretKeyType};
+use rand::{thread_rng, SeedableRng};
+
+type ToyEvaluator = Evaluator<u16, PowOf2Modulus<u16>, ToyField>;
+type BoundCiphertext = Envelope<KeyBound<LweCiphertext<u16>>>;
+
+/// Checks that the evaluator survives the round trip with its fingerprint,
+/// and still computes with the decoded keys.
+fn check_round_trip(params: BooleanFheParameters<u16, PowOf2Modulus<u16>, ToyField>) {
+    let mut rng = thread_rng();
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let bytes = eval.to_le_vec();
+    let decoded = ToyEvaluator::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded.to_le_vec(), bytes);
+    assert_eq!(decoded.fingerprint(), eval.fingerprint());
+
+    let (a, b) = (enc.encrypt(true, &mut rng), enc.encrypt(false, &mut rng));
+    assert!(dec.decrypt::<bool>(&decoded.or(&a, &b)));
+    assert!(!dec.decrypt::<bool>(&decoded.and(&a, &b)));
+
+    assert!(ToyEvaluator::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+    let limits = DecodeLimits {
+        max_dimension: params.ring_dimension() - 1,
+        ..DecodeLimits::UNLIMITED
+    };
+    assert!(ToyEvaluator::from_le_slice_with(&bytes, &limits).is_err());
+}
+
+#[test]
+fn test_evaluator_le_bytes() {
+    check_round_trip(*TOY_PARAMETERS);
+
+    for steps in [Steps::BrMsKs, Steps::BrKsRlevMs] {
+        let params = BooleanFheParameters::new(ConstParameters {
+            steps,
+            lwe_secret_key_type: LweSecretKeyType::Ternary,
+            ..TOY_CONST_PARAMETERS
+        })
+        .unwrap();
+        check_round_trip(params);
+    }
+}
+
+#[test]
+fn test_evaluator_generate_to() {
+    let mut rng = thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let mut streamed = Vec::new();
+    ToyEvaluator::generate_to(&mut streamed, &sk, &mut Prg::seed_from_u64(3)).unwrap();
+    let eval = Evaluator::new(&sk, &mut Prg::seed_from_u64(3));
+    assert_eq!(streamed, eval.to_le_vec());
+
+    let decoded = ToyEvaluator::from_le_slice(&streamed).unwrap();
+    assert_eq!(decoded.fingerprint(), eval.fingerprint());
+}
+
+#[test]
+fn test_evaluator_rejects_inconsistent_keys() {
+    let mut rng = thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+    let bytes = Evaluator::new(&sk, &mut rng).to_le_vec();
+
+    // the parameters ask for another key switching key than the encoded one
+    let params = BooleanFheParameters::<u16, PowOf2Modulus<u16>, ToyField>::new(ConstParameters {
+        steps: Steps::BrKsRlevMs,
+        ..TOY_CONST_PARAMETERS
+    })
+    .unwrap();
+    let params_len = TOY_PARAMETERS.to_le_vec().len();
+    let mut bad = b"EVKY\x02".to_vec();
+    bad.extend(params.to_le_vec());
+    bad.extend_from_slice(&bytes[5 + params_len..]);
+    assert!(ToyEvaluator::from_le_slice(&bad).is_err());
+}
+
+#[test]
+fn test_key_binding() {
+    let mut rng = thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+    let other = Evaluator::new(&sk, &mut rng);
+    assert_ne!(eval.fingerprint(), other.fingerprint());
+
+    let c = KeyBound::new(eval.fingerprint(), enc.encrypt(true, &mut rng));
+    let c = KeyBound::<_>::from_le_slice(&c.to_le_vec()).unwrap();
+    assert!(eval.check(&c).is_ok());
+    assert!(matches!(other.check(&c), Err(AlgebraError::KeyMismatch)));
+
+    let record = KeyRecord::new(eval, Some("client 7".to_string()));
+    let decoded = KeyRecord::<ToyEvaluator>::from_le_slice(&record.to_le_vec()).unwrap();
+    assert_eq!(decoded.owner(), Some("client 7"));
+    assert_eq!(decoded.fingerprint(), record.key().fingerprint());
+    assert!(decoded.key().check(&c).is_ok());
+}
+
+#[test]
+fn test_envelope() {
+    const KEY: &[u8] = b"a key shared by client and server";
+
+    let mut rng = thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    // the parameters are the associated data of the evaluator,
+    // the fingerprint of its key is the one of the ciphertexts
+    let params = TOY_PARAMETERS.to_le_vec();
+    let bytes = Envelope::seal(KEY, &params, &eval).to_le_vec();
+    let envelope = Envelope::<ToyEvaluator>::from_le_slice(&bytes).unwrap();
+    assert_eq!(envelope.associated_data(), params);
+    let eval = envelope.open(KEY).unwrap();
+
+    let fingerprint = eval.fingerprint();
+    let c = KeyBound::new(fingerprint, enc.encrypt(true, &mut rng));
+    let bytes = Envelope::seal(KEY, fingerprint.as_bytes(), &c).to_le_vec();
+    let c = BoundCiphertext::from_le_slice(&bytes)
+        .unwrap()
+        .open(KEY)
+        .unwrap();
+    assert!(!dec.decrypt::<bool>(&eval.not(eval.check(&c).unwrap())));
+
+    // a ciphertext tampered with in transport is rejected before it is decoded
+    let mut bad = bytes.clone();
+    let i = bad.len() - 40;
+    bad[i] ^= 1;
+    let envelope = BoundCiphertext::from_le_slice(&bad).unwrap();
+    assert!(matches!(
+        envelope.open(KEY),
+        Err(AlgebraError::AuthenticationErr)
+    ));
+}
@@ -0,0 +1,67 @@
+use boolean_fhe::{borrowing_sub, carrying_add, overflowing_mul, SimulatedEvaluator};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+/// Checks the flags against the native `u8` and `i8` operations, on a spread
+/// of words including the unsigned and signed boundaries.
+#[test]
+fn test_flags_match_u8() {
+    let sim = SimulatedEvaluator::new();
+    let words: Vec<u8> = (0..=u8::MAX)
+        .step_by(7)
+        .chain([1, 127, 128, 254, 255])
+        .collect();
+
+    for (&x, &y) in itertools::iproduct!(&words, &words) {
+        let (bx, by) = (to_bits(x as u64, 8), to_bits(y as u64, 8));
+
+        for c in [false, true] {
+            let wide = x as u16 + y as u16 + c as u16;
+            let signed = x as i8 as i16 + y as i8 as i16 + c as i16;
+            let (sum, flags) = carrying_add(&sim, &bx, &by, Some(&c));
+            assert_eq!(from_bits(sum), wide as u64 & 0xFF, "{x} + {y} + {c}");
+            assert_eq!(flags.carry, wide > 0xFF, "{x} + {y} + {c}");
+            assert_eq!(
+                flags.overflow,
+                signed != signed as i8 as i16,
+                "{x} + {y} + {c}"
+            );
+
+            let wide = x as i16 - y as i16 - c as i16;
+            let signed = x as i8 as i16 - y as i8 as i16 - c as i16;
+            let (difference, flags) = borrowing_sub(&sim, &bx, &by, Some(&c));
+            assert_eq!(from_bits(difference), wide as u64 & 0xFF, "{x} - {y} - {c}");
+            assert_eq!(flags.carry, wide < 0, "{x} - {y} - {c}");
+            assert_eq!(
+                flags.overflow,
+                signed != signed as i8 as i16,
+                "{x} - {y} - {c}"
+            );
+        }
+
+        let (_, flags) = carrying_add(&sim, &bx, &by, None);
+        assert_eq!(flags.carry, x.overflowing_add(y).1, "{x} + {y}");
+        assert_eq!(
+            flags.overflow,
+            (x as i8).overflowing_add(y as i8).1,
+            "{x} + {y}"
+        );
+
+        let (difference, flags) = borrowing_sub(&sim, &bx, &by, None);
+        assert_eq!(flags.carry, x < y, "{x} - {y}");
+        assert_eq!(
+            flags.overflow,
+            (x as i8).overflowing_sub(y as i8).1,
+            "{x} - {y}"
+        );
+        let signed_less = *difference.last().unwrap() ^ flags.overflow;
+        assert_eq!(signed_less, (x as i8) < (y as i8), "{x} < {y}");
+
+        let (product, overflow) = overflowing_mul(&sim, &bx, &by);
+        let (expected, native) = x.overflowing_mul(y);
+        assert_eq!(from_bits(product), expected as u64, "{x} * {y}");
+        assert_eq!(overflow, native, "{x} * {y}");
+    }
+}
@@ -0,0 +1,61 @@
+use boolean_fhe::{OverflowMode, SimulatedEvaluator};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+const BITS: usize = 4;
+const MAX: u64 = (1 << BITS) - 1;
+
+/// The expected result of `op` in `mode`, the checked flag is the bit `BITS`.
+fn expected(op: &str, mode: OverflowMode, x: u64, y: u64) -> u64 {
+    let (value, overflow) = match op {
+        "add" => (x + y, x + y > MAX),
+        "sub" => (x.wrapping_sub(y), x < y),
+        "mul" => (x * y, x * y > MAX),
+        _ => unreachable!(),
+    };
+    match mode {
+        OverflowMode::Wrapping => value & MAX,
+        OverflowMode::Saturating if overflow => {
+            if op == "sub" {
+                0
+            } else {
+                MAX
+            }
+        }
+        OverflowMode::Saturating => value,
+        OverflowMode::Checked => (value & MAX) | ((overflow as u64) << BITS),
+    }
+}
+
+#[test]
+fn test_overflow_modes_exhaustive() {
+    let sim = SimulatedEvaluator::new();
+    let modes = [
+        OverflowMode::Wrapping,
+        OverflowMode::Saturating,
+        OverflowMode::Checked,
+    ];
+
+    for (x, y) in itertools::iproduct!(0..=MAX, 0..=MAX) {
+        let (bx, by) = (to_bits(x, BITS), to_bits(y, BITS));
+        for mode in modes {
+            assert_eq!(
+                from_bits(mode.add(&sim, &bx, &by)),
+                expected("add", mode, x, y),
+                "{mode:?} {x} + {y}"
+            );
+            assert_eq!(
+                from_bits(mode.sub(&sim, &bx, &by)),
+                expected("sub", mode, x, y),
+                "{mode:?} {x} - {y}"
+            );
+            assert_eq!(
+                from_bits(mode.mul(&sim, &bx, &by)),
+                expected("mul", mode, x, y),
+                "{mode:?} {x} * {y}"
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use algebra::random::Block;
+use boolean_fhe::{KeyGen, SecretKeyPack, ToyField, TOY_PARAMETERS};
+use fhe_core::LweCiphertext;
+
+type SecretKey = SecretKeyPack<u16, algebra::modulus::PowOf2Modulus<u16>, ToyField>;
+
+fn secret_key(seed: u8, path: &[u64]) -> SecretKey {
+    KeyGen::derive_secret_key(*TOY_PARAMETERS, Block::new(&[seed; 16]), path)
+}
+
+fn same_secret_key(a: &SecretKey, b: &SecretKey) -> bool {
+    a.lwe_secret_key().as_ref() == b.lwe_secret_key().as_ref()
+        && **a.rlwe_secret_key() == **b.rlwe_secret_key()
+}
+
+/// Bootstraps a fixed ciphertext, which is deterministic for a given evaluation key.
+fn evaluation_fingerprint(sk: &SecretKey, seed: u8, path: &[u64]) -> LweCiphertext<u16> {
+    let eval = KeyGen::derive_evaluator(sk, Block::new(&[seed; 16]), path);
+    // a trivial input would skip the keys, its `a` must be non-zero
+    let c = LweCiphertext::new(vec![1; sk.parameters().lwe_dimension()], 0);
+    eval.and(&c, &c)
+}
+
+#[test]
+fn test_derive_secret_key() {
+    let sk = secret_key(1, &[7, 3]);
+    assert!(same_secret_key(&sk, &secret_key(1, &[7, 3])));
+    assert!(!same_secret_key(&sk, &secret_key(2, &[7, 3])));
+    assert!(!same_secret_key(&sk, &secret_key(1, &[7, 4])));
+}
+
+#[test]
+fn test_derive_evaluator() {
+    let sk = secret_key(1, &[7]);
+    let fingerprint = evaluation_fingerprint(&sk, 1, &[7]);
+    assert_eq!(fingerprint, evaluation_fingerprint(&sk, 1, &[7]));
+    assert_ne!(fingerprint, evaluation_fingerprint(&sk, 2, &[7]));
+}
@@ -0,0 +1,74 @@
+use boolean_fhe::{nearest_neighbors, squared_distances, SimulatedEvaluator};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+const DIMENSION: usize = 2;
+const BITS: usize = 3;
+const COUNT: usize = 6;
+const WIDTH: usize = 8;
+
+fn plain_distance(x: &[u64], y: &[u64]) -> u64 {
+    x.iter().zip(y).map(|(&x, &y)| x.abs_diff(y).pow(2)).sum()
+}
+
+/// The plaintext kNN as `(distance, index)`, ties are broken by the smallest index.
+fn plain_knn(query: &[u64], database: &[Vec<u64>], k: usize) -> Vec<(u64, u64)> {
+    let mut neighbors: Vec<(u64, u64)> = database
+        .iter()
+        .map(|y| plain_distance(query, y))
+        .zip(0..)
+        .collect();
+    neighbors.sort();
+    neighbors.truncate(k);
+    neighbors
+}
+
+#[test]
+fn test_nearest_neighbors_match_plain_knn() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let sim = SimulatedEvaluator::new();
+
+    for _ in 0..20 {
+        let mut random_vector = || -> Vec<u64> {
+            (0..DIMENSION)
+                .map(|_| rng.gen_range(0..1 << BITS))
+                .collect()
+        };
+        let query = random_vector();
+        let mut database: Vec<Vec<u64>> = (0..COUNT).map(|_| random_vector()).collect();
+        // a duplicate vector makes a tie
+        database[COUNT - 1] = database[0].clone();
+
+        let bits: Vec<Vec<bool>> = query.iter().map(|&x| to_bits(x, BITS)).collect();
+        let distances = squared_distances(&sim, &bits, &database, WIDTH);
+        let expected: Vec<u64> = database.iter().map(|y| plain_distance(&query, y)).collect();
+        assert_eq!(
+            distances
+                .iter()
+                .map(|d| from_bits(d.iter().copied()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        for k in 1..=COUNT + 1 {
+            let neighbors: Vec<(u64, u64)> = nearest_neighbors(&sim, distances.clone(), k)
+                .into_iter()
+                .map(|n| (from_bits(n.distance), from_bits(n.index)))
+                .collect();
+            assert_eq!(
+                neighbors,
+                plain_knn(&query, &database, k),
+                "{query:?} {database:?} k = {k}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_nearest_neighbors_of_nothing() {
+    let sim = SimulatedEvaluator::new();
+    assert!(nearest_neighbors(&sim, Vec::new(), 3).is_empty());
+}
@@ -0,0 +1,63 @@
+use algebra::modulus::PowOf2Modulus;
+use boolean_fhe::{
+    BooleanFheParameters, ConstParameters, Evaluator, NoiseReport, SecretKeyPack, Steps, ToyField,
+    TOY_CONST_PARAMETERS,
+};
+
+type Parameters = BooleanFheParameters<u16, PowOf2Modulus<u16>, ToyField>;
+
+/// Measures the toy parameters with `steps`, every sequence stays within its bound.
+fn measure(steps: Steps) -> NoiseReport {
+    let mut rng = rand::thread_rng();
+    let parameters = Parameters::new(ConstParameters {
+        steps,
+        ..TOY_CONST_PARAMETERS
+    })
+    .unwrap();
+    let sk = SecretKeyPack::new(parameters, &mut rng);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let report = NoiseReport::measure(&sk, &eval, 500, &mut rng);
+    for statistics in [
+        report.fresh(),
+        report.gate_input(),
+        report.modulus_switch(),
+        report.bootstrap(),
+    ] {
+        assert_eq!(statistics.samples(), 500);
+        assert!(
+            statistics.max_noise() <= statistics.bound(),
+            "{statistics:?}"
+        );
+    }
+    report
+}
+
+#[test]
+fn test_noise_report_br_ks_lev_ms() {
+    let report = measure(Steps::BrKsLevMs);
+
+    // the fresh noise is a rounded gaussian, its variance is `σ^2 + 1/12`
+    let fresh = report.fresh();
+    assert_eq!(fresh.predicted_variance(), 1.0);
+    assert!((0.8..1.4).contains(&fresh.ratio()), "{fresh:?}");
+
+    let gate_input = report.gate_input();
+    assert_eq!(gate_input.predicted_variance(), 2.0);
+    assert!((0.8..1.4).contains(&gate_input.ratio()), "{gate_input:?}");
+
+    let bootstrap = report.bootstrap();
+    assert!((0.5..2.0).contains(&bootstrap.ratio()), "{bootstrap:?}");
+    assert!(bootstrap.failure_probability() < 1e-6);
+    assert!(bootstrap.subgaussian_bound(1e-6) < bootstrap.bound());
+}
+
+#[test]
+fn test_noise_report_br_ms_ks() {
+    let report = measure(Steps::BrMsKs);
+
+    let bootstrap = report.bootstrap();
+    assert!((0.5..2.0).contains(&bootstrap.ratio()), "{bootstrap:?}");
+    // the toy parameters with this order fail more often
+    assert!(bootstrap.failure_probability() > 1e-6);
+}
@@ -0,0 +1,103 @@
+use boolean_fhe::{
+    BooleanFheParameters, CheckedParameters, ConstParameters, Steps, ToyField, TOY_CONST_PARAMETERS,
+};
+use fhe_core::FHECoreError;
+
+type Parameters = BooleanFheParameters<u16, algebra::modulus::PowOf2Modulus<u16>, ToyField>;
+
+// `Q = 12289` has 14 bits and `q = 2^10` has 10 bits, both decomposed with 2 bits
+const RING_FULL_LENGTH: usize = 7;
+const LWE_FULL_LENGTH: usize = 5;
+
+#[test]
+fn test_reverse_lengths() {
+    let params = Parameters::new(TOY_CONST_PARAMETERS).unwrap();
+    assert_eq!(params.blind_rotation_reverse_length(), None);
+    assert_eq!(params.key_switching_reverse_length(), None);
+    assert_eq!(
+        params.blind_rotation_basis().decompose_length(),
+        RING_FULL_LENGTH
+    );
+
+    let mut constants = TOY_CONST_PARAMETERS;
+    constants.blind_rotation_reverse_length = Some(RING_FULL_LENGTH - 1);
+    constants.key_switching_reverse_length = Some(RING_FULL_LENGTH);
+    let params = Parameters::new(constants).unwrap();
+    assert_eq!(
+        params.blind_rotation_reverse_length(),
+        Some(RING_FULL_LENGTH - 1)
+    );
+    assert_eq!(
+        params.key_switching_reverse_length(),
+        Some(RING_FULL_LENGTH)
+    );
+    assert_eq!(
+        params.blind_rotation_basis().decompose_length(),
+        RING_FULL_LENGTH - 1
+    );
+}
+
+#[test]
+fn test_oversized_reverse_lengths() {
+    let check = |steps, blind_rotation, key_switching, full_length| {
+        let mut constants = ConstParameters {
+            steps,
+            ..TOY_CONST_PARAMETERS
+        };
+        constants.blind_rotation_reverse_length = blind_rotation;
+        constants.key_switching_reverse_length = key_switching;
+        let reverse_length = blind_rotation.or(key_switching).unwrap();
+        assert!(matches!(
+            Parameters::new(constants),
+            Err(FHECoreError::ReverseLengthUnValid { reverse_length: r, full_length: f })
+                if r == reverse_length && f == full_length
+        ));
+    };
+
+    check(
+        Steps::default(),
+        Some(RING_FULL_LENGTH + 1),
+        None,
+        RING_FULL_LENGTH,
+    );
+    check(Steps::default(), Some(0), None, RING_FULL_LENGTH);
+    check(
+        Steps::default(),
+        None,
+        Some(RING_FULL_LENGTH + 1),
+        RING_FULL_LENGTH,
+    );
+    // the key switching of `BrMsKs` is modulo `q`
+    check(
+        Steps::BrMsKs,
+        None,
+        Some(LWE_FULL_LENGTH + 1),
+        LWE_FULL_LENGTH,
+    );
+}
+
+#[test]
+fn test_checked_parameters_mismatch() {
+    type ToyRing = CheckedParameters<256, 12289>;
+    type OtherRing = CheckedParameters<512, 12289>;
+
+    let parameters = ToyRing::parameters::<u16, algebra::modulus::PowOf2Modulus<u16>, ToyField>;
+    assert!(parameters(TOY_CONST_PARAMETERS).is_ok());
+    let constants = ConstParameters {
+        ring_dimension: 128,
+        ..TOY_CONST_PARAMETERS
+    };
+    assert!(matches!(
+        parameters(constants),
+        Err(FHECoreError::CheckedParametersMismatch {
+            ring_dimension: 256,
+            ring_modulus: 12289
+        })
+    ));
+
+    let parameters = OtherRing::parameters::<u16, algebra::modulus::PowOf2Modulus<u16>, ToyField>;
+    assert!(matches!(
+        parameters(TOY_CONST_PARAMETERS),
+        Err(FHECoreError::CheckedParametersMismatch { .. })
+    ));
+}
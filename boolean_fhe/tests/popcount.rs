@@ -0,0 +1,51 @@
+use boolean_fhe::{hamming_distance, popcount, SimulatedEvaluator};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+#[test]
+fn test_popcount_exhaustive() {
+    let sim = SimulatedEvaluator::new();
+
+    for len in 1..=10usize {
+        let width = (usize::BITS - len.leading_zeros()) as usize;
+        for x in 0..1u64 << len {
+            let count = popcount(&sim, &to_bits(x, len));
+            assert_eq!(count.len(), width, "{len} bits");
+            assert_eq!(from_bits(count), x.count_ones() as u64, "{x:#b}");
+        }
+    }
+}
+
+#[test]
+fn test_hamming_distance_exhaustive() {
+    let sim = SimulatedEvaluator::new();
+
+    for len in 1..=6 {
+        for (x, y) in itertools::iproduct!(0..1u64 << len, 0..1u64 << len) {
+            let distance = hamming_distance(&sim, &to_bits(x, len), &to_bits(y, len));
+            assert_eq!(
+                from_bits(distance),
+                (x ^ y).count_ones() as u64,
+                "{x:#b} {y:#b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_popcount_bootstraps() {
+    let sim = SimulatedEvaluator::new();
+
+    // a single full adder, `xor3` and `majority`
+    popcount(&sim, &to_bits(0b101, 3));
+    assert_eq!(sim.bootstrap_count(), 2);
+
+    for len in 1..=64 {
+        sim.reset();
+        popcount(&sim, &vec![true; len]);
+        // the half adders of the tree cost a bit more than two bootstraps per input
+        assert!(sim.bootstrap_count() <= 5 * len / 2, "{len} bits");
+    }
+}
@@ -0,0 +1,65 @@
+use boolean_fhe::{reduce, ReduceOp, SimulatedEvaluator};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+const BITS: usize = 4;
+const MASK: u64 = (1 << BITS) - 1;
+
+/// Checks `reduce` with `op` against `fold` on every count up to 9, and on
+/// every pair of words.
+fn check(op: ReduceOp, fold: impl Fn(u64, u64) -> u64) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let sim = SimulatedEvaluator::new();
+
+    assert!(reduce(&sim, Vec::new(), op).is_none());
+
+    for count in 1..=9 {
+        for _ in 0..10 {
+            let values: Vec<u64> = (0..count).map(|_| rng.gen_range(0..=MASK)).collect();
+            let expected = values.iter().copied().reduce(&fold).unwrap();
+
+            let words = values.iter().map(|&v| to_bits(v, BITS)).collect();
+            let result = reduce(&sim, words, op).unwrap();
+            assert_eq!(result.len(), BITS);
+            assert_eq!(from_bits(result), expected, "{op:?} {values:?}");
+        }
+    }
+
+    for (x, y) in itertools::iproduct!(0..=MASK, 0..=MASK) {
+        let result = op.apply(&sim, &to_bits(x, BITS), &to_bits(y, BITS));
+        assert_eq!(from_bits(result), fold(x, y), "{op:?} {x} {y}");
+    }
+}
+
+#[test]
+fn test_reduce_and() {
+    check(ReduceOp::And, |x, y| x & y);
+}
+
+#[test]
+fn test_reduce_or() {
+    check(ReduceOp::Or, |x, y| x | y);
+}
+
+#[test]
+fn test_reduce_xor() {
+    check(ReduceOp::Xor, |x, y| x ^ y);
+}
+
+#[test]
+fn test_reduce_add() {
+    check(ReduceOp::Add, |x, y| (x + y) & MASK);
+}
+
+#[test]
+fn test_reduce_min() {
+    check(ReduceOp::Min, u64::min);
+}
+
+#[test]
+fn test_reduce_max() {
+    check(ReduceOp::Max, u64::max);
+}
@@ -0,0 +1,120 @@
+use algebra::utils::LeBytes;
+use boolean_fhe::{
+    BooleanEvaluator, Decryptor, Encryptor, Evaluator, KeyGen, OperationLog, OverflowMode,
+    RecordingEvaluator, SimulatedEvaluator, TOY_PARAMETERS,
+};
+
+mod common;
+
+use common::to_bits;
+
+const INPUTS: usize = 6;
+
+/// A circuit going through every gate, followed by a 3-bit addition.
+fn circuit<E: BooleanEvaluator>(eval: &E, bits: &[E::Bit]) -> Vec<E::Bit> {
+    let (x, y) = bits.split_at(INPUTS / 2);
+    let mut outputs = vec![
+        eval.not(&x[0]),
+        eval.nand(&x[0], &y[0]),
+        eval.and(&x[1], &y[1]),
+        eval.or(&x[2], &y[2]),
+        eval.nor(&x[0], &y[1]),
+        eval.xor(&x[1], &y[2]),
+        eval.xnor(&x[2], &y[0]),
+        eval.majority(&x[0], &x[1], &y[2]),
+        eval.mux(&x[2], &y[1], &y[0]),
+        eval.xor3(&x[1], &y[0], &y[2]),
+    ];
+    outputs.extend(OverflowMode::Wrapping.add(eval, x, y));
+    outputs
+}
+
+/// Records the circuit on `inputs`, returns the log and the ids of the outputs.
+fn record(inputs: &[bool]) -> (OperationLog, Vec<usize>) {
+    let sim = SimulatedEvaluator::new();
+    let recorder = RecordingEvaluator::new(&sim);
+    let bits: Vec<_> = inputs.iter().map(|&b| recorder.input(b)).collect();
+    let ids = circuit(&recorder, &bits).iter().map(|b| b.id()).collect();
+    (recorder.into_log(), ids)
+}
+
+#[test]
+fn test_replay_matches_direct_evaluation() {
+    let (log, ids) = record(&[false; INPUTS]);
+    assert_eq!(log.input_count(), INPUTS);
+
+    let sim = SimulatedEvaluator::new();
+    for x in 0..1u64 << INPUTS {
+        let inputs = to_bits(x, INPUTS);
+
+        sim.reset();
+        let direct = circuit(&sim, &inputs);
+        let bootstraps = sim.bootstrap_count();
+
+        sim.reset();
+        let values = log.replay(&sim, &inputs);
+        assert_eq!(values.len(), log.len());
+        let replayed: Vec<bool> = ids.iter().map(|&id| values[id]).collect();
+        assert_eq!(replayed, direct, "{x:#b}");
+        assert_eq!(sim.bootstrap_count(), bootstraps);
+    }
+}
+
+#[test]
+fn test_replay_encrypted() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let (log, ids) = record(&[false; INPUTS]);
+    let inputs = to_bits(0b101_011, INPUTS);
+    let encrypted: Vec<_> = inputs
+        .iter()
+        .map(|&b| enc.encrypt(b as u8, &mut rng))
+        .collect();
+
+    let values = log.replay(&eval, &encrypted);
+    let replayed: Vec<bool> = ids
+        .iter()
+        .map(|&id| dec.decrypt::<u8>(&values[id]) == 1)
+        .collect();
+    assert_eq!(replayed, circuit(&SimulatedEvaluator::new(), &inputs));
+}
+
+#[test]
+fn test_replay_log_le_bytes() {
+    let (log, ids) = record(&[false; INPUTS]);
+
+    let bytes = log.to_le_vec();
+    assert_eq!(bytes[..4], *b"OLOG");
+    let decoded = OperationLog::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded, log);
+
+    // the decoded log replays like the recorded one
+    let sim = SimulatedEvaluator::new();
+    let inputs = to_bits(0b110_001, INPUTS);
+    let values = decoded.replay(&sim, &inputs);
+    let replayed: Vec<bool> = ids.iter().map(|&id| values[id]).collect();
+    assert_eq!(replayed, circuit(&sim, &inputs));
+
+    assert!(OperationLog::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+    let empty = OperationLog::from_le_slice(&OperationLog::new().to_le_vec()).unwrap();
+    assert!(empty.is_empty());
+
+    // an unknown operation code
+    let mut invalid = bytes.clone();
+    let first_op = 5 + 8;
+    invalid[first_op] = 10;
+    assert!(OperationLog::from_le_slice(&invalid).is_err());
+
+    // a gate reading a bit which is not recorded yet
+    let mut invalid = OperationLog::new().to_le_vec();
+    invalid.truncate(5);
+    invalid.extend_from_slice(&1u64.to_le_bytes());
+    invalid.push(1);
+    invalid.extend_from_slice(&0u64.to_le_bytes());
+    assert!(OperationLog::from_le_slice(&invalid).is_err());
+}
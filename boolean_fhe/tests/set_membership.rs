@@ -0,0 +1,30 @@
+use boolean_fhe::{Decryptor, Encryptor, Evaluator, KeyGen, TOY_PARAMETERS};
+
+const BITS: usize = 4;
+
+#[test]
+fn test_contains() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    // `17` and `1 << 40` are wider than the element, `17` shares its low bits with `1`
+    let sets: [&[u64]; 4] = [&[], &[17, 1 << 40], &[1, 6, 15, 17], &[9]];
+
+    for x in 0..1u64 << BITS {
+        let element: Vec<_> = (0..BITS)
+            .map(|i| enc.encrypt(((x >> i) & 1) as u8, &mut rng))
+            .collect();
+        for set in sets {
+            let ct = eval.contains(&element, set);
+            assert_eq!(
+                dec.decrypt::<u8>(&ct) == 1,
+                set.contains(&x),
+                "{x} in {set:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use boolean_fhe::{Decryptor, Encryptor, Evaluator, KeyGen, TOY_PARAMETERS};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn test_threshold_release_unreachable() {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    // a 2 bits count never reaches `8`
+    let count: Vec<_> = (0..2).map(|_| enc.encrypt(1u8, &mut rng)).collect();
+    let aggregate: Vec<_> = (0..8).map(|_| enc.encrypt(1u8, &mut rng)).collect();
+
+    let seed: u64 = rng.gen();
+    let released = eval.threshold_release(&count, 8, &aggregate, &mut StdRng::seed_from_u64(seed));
+
+    let mut mask_rng = StdRng::seed_from_u64(seed);
+    for (i, ct) in released.iter().enumerate() {
+        assert_eq!(dec.decrypt::<u8>(ct), mask_rng.gen::<bool>() as u8);
+        // bootstrapped, not a trivial encryption of the mask
+        assert!(ct.a().iter().any(|&v| v != 0));
+        assert!(released[..i].iter().all(|other| other != ct));
+    }
+}
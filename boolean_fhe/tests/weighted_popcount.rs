@@ -0,0 +1,58 @@
+use boolean_fhe::{Decryptor, Encryptor, Evaluator, KeyGen, TOY_PARAMETERS};
+
+mod common;
+
+use common::{from_bits, to_bits};
+
+/// Checks `weighted_popcount` against the plaintext weighted sum modulo `2^width`
+/// for every selection of `weights`.
+fn check_weighted_popcount(weights: &[u64], width: usize) {
+    let mut rng = rand::thread_rng();
+    let sk = KeyGen::generate_secret_key(*TOY_PARAMETERS, &mut rng);
+
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let mask = u64::MAX >> (64 - width);
+    for selection in 0..1u64 << weights.len() {
+        let bits = to_bits(selection, weights.len());
+        let encrypted: Vec<_> = bits
+            .iter()
+            .map(|&b| enc.encrypt(b as u8, &mut rng))
+            .collect();
+
+        let result = eval.weighted_popcount(&encrypted, weights, width);
+        assert_eq!(result.len(), width);
+
+        let expected = bits
+            .iter()
+            .zip(weights)
+            .filter(|(&b, _)| b)
+            .fold(0u64, |acc, (_, &w)| acc.wrapping_add(w))
+            & mask;
+        let sum = from_bits(result.iter().map(|c| dec.decrypt::<u8>(c) == 1));
+        assert_eq!(sum, expected, "selection {selection:#b}");
+    }
+}
+
+#[test]
+fn test_weighted_popcount() {
+    check_weighted_popcount(&[3, 5, 6], 4);
+}
+
+#[test]
+fn test_weighted_popcount_zero_weights() {
+    check_weighted_popcount(&[0, 7, 0], 3);
+}
+
+#[test]
+fn test_weighted_popcount_wide_weights() {
+    // only the low `width` bits of each weight count
+    check_weighted_popcount(&[u64::MAX, 1 << 40 | 9, 0x1_0000_0003], 4);
+}
+
+#[test]
+fn test_weighted_popcount_empty() {
+    check_weighted_popcount(&[], 5);
+}
@@ -0,0 +1,70 @@
+use algebra::{integer::AsInto, reduce::ModulusValue};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fhe_core::{lwe_modulus_switch, lwe_modulus_switch_inplace, LweCiphertext};
+use rand::{distributions::Uniform, prelude::*};
+
+const Q_IN: u64 = 1125899906826241;
+const Q_OUT: u16 = 1 << 10;
+
+/// The modulus switching before the exact rounding and the borrowed input,
+/// rounding in `f64` and consuming `c_in`.
+fn f64_modulus_switch_inplace(c_in: LweCiphertext<u64>, c_out: &mut LweCiphertext<u16>) {
+    let q_in: f64 = Q_IN.as_into();
+    let q_out: f64 = Q_OUT.as_into();
+
+    let switch = |v: u64| {
+        let v = (AsInto::<f64>::as_into(v) * q_out / q_in).round() as u16;
+        if v < Q_OUT {
+            v
+        } else {
+            v - Q_OUT
+        }
+    };
+
+    c_out
+        .a_mut()
+        .iter_mut()
+        .zip(c_in.a())
+        .for_each(|(des, &inp)| *des = switch(inp));
+    *c_out.b_mut() = switch(c_in.b());
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, Q_IN);
+
+    let mut group = c.benchmark_group("lwe modulus switch");
+    for n in [512usize, 1024, 2048] {
+        let a: Vec<u64> = (&distr).sample_iter(&mut rng).take(n).collect();
+        let c_in = LweCiphertext::new(a, rng.sample(distr));
+        let mut c_out = LweCiphertext::<u16>::zero(n);
+
+        // before: every call takes a fresh input ciphertext
+        group.bench_function(BenchmarkId::new("before f64 by value", n), |b| {
+            b.iter_batched(
+                || c_in.clone(),
+                |c_in| f64_modulus_switch_inplace(c_in, &mut c_out),
+                criterion::BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("after allocating", n), |b| {
+            b.iter(|| lwe_modulus_switch(black_box(&c_in), Q_IN, ModulusValue::PowerOf2(Q_OUT)))
+        });
+
+        group.bench_function(BenchmarkId::new("after inplace", n), |b| {
+            b.iter(|| {
+                lwe_modulus_switch_inplace(
+                    black_box(&c_in),
+                    Q_IN,
+                    ModulusValue::PowerOf2(Q_OUT),
+                    &mut c_out,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
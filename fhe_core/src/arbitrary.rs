@@ -0,0 +1,89 @@
+//! [`Arbitrary`] implementations, which produce valid keys and parameters,
+//! so structured fuzzing reaches the decoders beyond their first checks.
+//!
+//! The blind rotation and key switching keys are only ever generated from
+//! a secret key, so they have no implementations here; their decoders are
+//! fuzzed with the encodings of generated keys instead.
+
+use algebra::{
+    integer::UnsignedInteger, polynomial::FieldPolynomial, utils::arbitrary_field_values, Field,
+    NttField,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    KeySwitchingParameters, LweSecretKey, LweSecretKeyType, RingSecretKeyType, RlweSecretKey,
+};
+
+impl<'a> Arbitrary<'a> for LweSecretKeyType {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if bool::arbitrary(u)? {
+            Self::Binary
+        } else {
+            Self::Ternary
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RingSecretKeyType {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Self::Binary,
+            1 => Self::Ternary,
+            _ => Self::Gaussian,
+        })
+    }
+}
+
+/// The `-1` of a ternary key depends on the cipher modulus,
+/// which the key does not record, so its coefficients are arbitrary.
+impl<'a, C: UnsignedInteger + Arbitrary<'a>> Arbitrary<'a> for LweSecretKey<C> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let distr = LweSecretKeyType::arbitrary(u)?;
+        let key = match distr {
+            LweSecretKeyType::Binary => u
+                .arbitrary_iter::<bool>()?
+                .map(|bit| Ok(if bit? { C::ONE } else { C::ZERO }))
+                .collect::<Result<Vec<C>>>()?,
+            LweSecretKeyType::Ternary => Vec::arbitrary(u)?,
+        };
+        Ok(Self::new(key, distr))
+    }
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for RlweSecretKey<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let distr = RingSecretKeyType::arbitrary(u)?;
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        let key = match distr {
+            RingSecretKeyType::Binary => (0..dimension)
+                .map(|_| Ok(u.choose(&[F::ZERO, F::ONE])?.to_owned()))
+                .collect::<Result<Vec<_>>>()?,
+            RingSecretKeyType::Ternary => (0..dimension)
+                .map(|_| Ok(u.choose(&[F::ZERO, F::ONE, F::MINUS_ONE])?.to_owned()))
+                .collect::<Result<Vec<_>>>()?,
+            RingSecretKeyType::Gaussian => arbitrary_field_values::<F>(u, dimension)?,
+        };
+        Ok(Self::new(FieldPolynomial::new(key), distr))
+    }
+}
+
+/// The reverse length is never `Some(0)`, which is encoded as `None`,
+/// and the standard deviation is finite and not negative.
+impl<'a> Arbitrary<'a> for KeySwitchingParameters {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            input_cipher_dimension: u.arbitrary()?,
+            output_cipher_dimension: u.arbitrary()?,
+            log_modulus: u.arbitrary()?,
+            log_basis: u.arbitrary()?,
+            reverse_length: Option::<usize>::arbitrary(u)?.filter(|&len| len != 0),
+            noise_standard_deviation: u32::arbitrary(u)? as f64 / 1024.,
+        })
+    }
+}
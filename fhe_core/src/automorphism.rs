@@ -2,16 +2,14 @@ use std::sync::Arc;
 
 use algebra::{
     decompose::NonPowOf2ApproxSignedBasis,
-    modulus::PowOf2Modulus,
     ntt::NumberTheoryTransform,
-    polynomial::FieldPolynomial,
+    polynomial::{Automorphism, FieldNttPolynomial, FieldPolynomial},
     random::DiscreteGaussian,
-    reduce::{ReduceAddAssign, ReduceMul, ReduceSubAssign},
     Field, NttField,
 };
 use lattice::{
     utils::{NttRlweSpace, PolyDecomposeSpace},
-    NttGadgetRlwe,
+    NttGadgetRlwe, NttRlwe,
 };
 use num_traits::One;
 use rand::{CryptoRng, Rng};
@@ -20,11 +18,63 @@ use crate::{NttRlweSecretKey, RlweCiphertext, RlweSecretKey};
 
 /// Automorphism key
 pub struct AutoKey<F: NttField> {
-    degree: usize,
+    automorphism: Automorphism<F>,
     key: NttGadgetRlwe<F>,
     ntt_table: Arc<<F as NttField>::Table>,
 }
 
+/// The decomposition of a RLWE ciphertext in NTT domain,
+/// shared by many automorphisms of the same ciphertext.
+///
+/// Automorphisms are signed permutations of the coefficients, so they map
+/// the decomposed polynomials of `a` to the decomposed polynomials of `σ(a)`
+/// with the same bound. In NTT domain they are plain permutations,
+/// which lets all automorphisms reuse one decomposition and its NTTs.
+pub struct HoistedRlwe<F: NttField> {
+    basis: NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+    decomposed: Vec<FieldNttPolynomial<F>>,
+    b: FieldPolynomial<F>,
+}
+
+impl<F: NttField> HoistedRlwe<F> {
+    /// Decomposes `ciphertext` with `basis` for [`AutoKey::automorphism_hoisted`].
+    pub fn new(
+        ciphertext: &RlweCiphertext<F>,
+        basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+    ) -> Self {
+        let dimension = ciphertext.dimension();
+
+        let mut decompose_space = PolyDecomposeSpace::new(dimension);
+        let (adjust_poly, carries, _) = decompose_space.get_mut();
+        ciphertext
+            .a()
+            .init_adjust_poly_carries(basis, carries, adjust_poly);
+
+        let decomposed = basis
+            .decompose_iter()
+            .map(|once_decompose| {
+                let mut poly = FieldNttPolynomial::zero(dimension);
+                adjust_poly.approx_signed_decompose(once_decompose, carries, poly.as_mut_slice());
+                ntt_table.transform_slice(poly.as_mut_slice());
+                poly
+            })
+            .collect();
+
+        Self {
+            basis: *basis,
+            decomposed,
+            b: ciphertext.b().clone(),
+        }
+    }
+
+    /// Returns the dimension of this [`HoistedRlwe<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.b.coeff_count()
+    }
+}
+
 /// Preallocated space for automorphism
 pub struct AutoSpace<F: NttField> {
     decompose_space: PolyDecomposeSpace<F>,
@@ -43,7 +93,12 @@ impl<F: NttField> AutoSpace<F> {
 }
 
 impl<F: NttField> AutoKey<F> {
-    /// Creates a new [`AutoKey<F>`].
+    /// Creates a new [`AutoKey<F>`] for the automorphism `X -> X^degree`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is even or not less than `2N`, where `N` is the ring dimension
+    /// of `ntt_table`. Only odd degrees define automorphisms of `F[X]/(X^N + 1)`.
     #[inline]
     pub fn new<R>(
         secret_key: &RlweSecretKey<F>,
@@ -57,8 +112,7 @@ impl<F: NttField> AutoKey<F> {
     where
         R: Rng + CryptoRng,
     {
-        let rlwe_dimension = secret_key.coeff_count();
-        assert!(degree > 0 && degree < rlwe_dimension << 1);
+        let automorphism = Automorphism::new(degree, &*ntt_table);
         let key = if degree.is_one() {
             NttGadgetRlwe::generate_random_neg_secret_sample(
                 ntt_secret_key,
@@ -68,7 +122,7 @@ impl<F: NttField> AutoKey<F> {
                 rng,
             )
         } else {
-            let p_auto = poly_auto(secret_key, degree, rlwe_dimension);
+            let p_auto = secret_key.apply_automorphism(&automorphism);
             let auto_sk = ntt_table.transform_inplace(-p_auto);
             NttGadgetRlwe::generate_random_poly_sample(
                 ntt_secret_key,
@@ -81,25 +135,59 @@ impl<F: NttField> AutoKey<F> {
         };
 
         Self {
+            automorphism,
             key,
             ntt_table,
-            degree,
         }
     }
 
+    /// Returns the automorphism degree of this [`AutoKey<F>`].
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.automorphism.degree()
+    }
+
+    /// Performs automorphism on a ciphertext decomposed by [`HoistedRlwe::new`].
+    ///
+    /// Compared to [`AutoKey::automorphism`], this skips the decomposition and the
+    /// forward NTTs, which is faster when many automorphisms are applied to one ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hoisted` was decomposed with a different basis from this key.
+    pub fn automorphism_hoisted(&self, hoisted: &HoistedRlwe<F>) -> RlweCiphertext<F> {
+        assert_eq!(&hoisted.basis, self.key.basis());
+
+        let rlwe_dimension = hoisted.dimension();
+
+        let mut ntt_rlwe = <NttRlwe<F>>::zero(rlwe_dimension);
+        let mut permuted = <FieldNttPolynomial<F>>::zero(rlwe_dimension);
+
+        for (gadget, decomposed) in self.key.iter().zip(hoisted.decomposed.iter()) {
+            self.automorphism.apply_ntt(decomposed, &mut permuted);
+            ntt_rlwe.add_ntt_rlwe_mul_ntt_polynomial_assign(gadget, &permuted);
+        }
+
+        let mut result = ntt_rlwe.to_rlwe(&self.ntt_table);
+
+        self.automorphism
+            .apply_add_assign(&hoisted.b, result.b_mut());
+
+        result
+    }
+
     /// Performs automorphism on the given RLWE ciphertext.
     #[inline]
     pub fn automorphism(&self, ciphertext: &RlweCiphertext<F>) -> RlweCiphertext<F> {
-        let rlwe_dimension = ciphertext.dimension();
-
-        let a = poly_auto(ciphertext.a(), self.degree, rlwe_dimension);
+        let a = ciphertext.a().apply_automorphism(&self.automorphism);
 
         let mut result = self
             .key
             .mul_polynomial(&a, &self.ntt_table)
             .to_rlwe(&self.ntt_table);
 
-        poly_auto_inplace(ciphertext.b(), self.degree, rlwe_dimension, result.b_mut());
+        self.automorphism
+            .apply_add_assign(ciphertext.b(), result.b_mut());
 
         result
     }
@@ -111,15 +199,7 @@ impl<F: NttField> AutoKey<F> {
         auto_space: &mut AutoSpace<F>,
         destination: &mut RlweCiphertext<F>,
     ) {
-        let rlwe_dimension = ciphertext.dimension();
-
-        destination.a_mut().set_zero();
-        poly_auto_inplace(
-            ciphertext.a(),
-            self.degree,
-            rlwe_dimension,
-            destination.a_mut(),
-        );
+        self.automorphism.apply(ciphertext.a(), destination.a_mut());
 
         self.key.mul_polynomial_inplace(
             destination.a(),
@@ -132,48 +212,14 @@ impl<F: NttField> AutoKey<F> {
             .ntt_rlwe_space
             .inverse_transform_inplace(&self.ntt_table, destination);
 
-        poly_auto_inplace(
-            ciphertext.b(),
-            self.degree,
-            rlwe_dimension,
-            destination.b_mut(),
-        );
-    }
-}
-
-#[inline]
-fn poly_auto<F: NttField>(
-    poly: &FieldPolynomial<F>,
-    degree: usize,
-    dimension: usize,
-) -> FieldPolynomial<F> {
-    let mut res = FieldPolynomial::zero(dimension);
-    poly_auto_inplace(poly, degree, dimension, &mut res);
-    res
-}
-
-#[inline]
-fn poly_auto_inplace<F: NttField>(
-    poly: &FieldPolynomial<F>,
-    degree: usize,
-    dimension: usize,
-    destination: &mut FieldPolynomial<F>,
-) {
-    let twice_dimension = dimension << 1;
-    let modulus = <PowOf2Modulus<usize>>::new(twice_dimension);
-    for (i, c) in poly.iter().enumerate() {
-        let j = modulus.reduce_mul(i, degree);
-        if j < dimension {
-            F::MODULUS.reduce_add_assign(&mut destination[j], *c);
-        } else {
-            F::MODULUS.reduce_sub_assign(&mut destination[j - dimension], *c);
-        }
+        self.automorphism
+            .apply_add_assign(ciphertext.b(), destination.b_mut());
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use algebra::{reduce::ReduceNeg, Field, U32FieldEval};
+    use algebra::{Field, U32FieldEval};
     use lattice::Rlwe;
     use rand::{distributions::Uniform, prelude::Distribution};
 
@@ -199,28 +245,6 @@ mod tests {
         (c as f64 * PLAIN_MODULUS as f64 / CIPHER_MODULUS as f64).round() as ValT % PLAIN_MODULUS
     }
 
-    #[test]
-    fn test_auto() {
-        let mut rng = rand::thread_rng();
-
-        let poly = PolyT::random_ternary(N, &mut rng);
-        let result = poly_auto(&poly, N + 1, N);
-
-        let flag = result
-            .iter()
-            .zip(poly.iter())
-            .enumerate()
-            .all(|(i, (&r, &p))| {
-                if i % 2 == 1 {
-                    r == Fp::MODULUS.reduce_neg(p)
-                } else {
-                    r == p
-                }
-            });
-
-        assert!(flag);
-    }
-
     #[test]
     fn test_he_auto() {
         let mut rng = rand::thread_rng();
@@ -274,4 +298,73 @@ mod tests {
 
         assert!(flag);
     }
+
+    #[test]
+    #[should_panic]
+    fn test_auto_key_even_degree() {
+        let mut rng = rand::thread_rng();
+
+        let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+        let sk = RlweSecretKey::new(
+            PolyT::random_ternary(N, &mut rng),
+            crate::RingSecretKeyType::Ternary,
+        );
+        let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+        let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+        let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 4, None);
+
+        AutoKey::new(&sk, &ntt_sk, N, &basis, gaussian, ntt_table, &mut rng);
+    }
+
+    #[test]
+    fn test_he_auto_hoisted() {
+        let mut rng = rand::thread_rng();
+
+        let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+        let distr = Uniform::new(0, PLAIN_MODULUS);
+
+        let sk = RlweSecretKey::new(
+            PolyT::random_ternary(N, &mut rng),
+            crate::RingSecretKeyType::Ternary,
+        );
+        let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+        let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+        let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 4, None);
+
+        let values: Vec<ValT> = distr.sample_iter(&mut rng).take(N).collect();
+        let encoded_values = PolyT::new(values.iter().copied().map(encode).collect());
+
+        let mut cipher =
+            <Rlwe<Fp>>::generate_random_zero_sample(&ntt_sk, gaussian, &ntt_table, &mut rng);
+        *cipher.b_mut() += &encoded_values;
+
+        let hoisted = HoistedRlwe::new(&cipher, &basis, &ntt_table);
+
+        for degree in [1, 3, N + 1, 2 * N - 1] {
+            let auto_key = AutoKey::new(
+                &sk,
+                &ntt_sk,
+                degree,
+                &basis,
+                gaussian,
+                Arc::clone(&ntt_table),
+                &mut rng,
+            );
+            let result = auto_key.automorphism_hoisted(&hoisted);
+
+            let decrypted = (result.b()
+                - ntt_table.inverse_transform_inplace(ntt_table.transform(result.a()) * &*ntt_sk))
+            .into_iter()
+            .map(decode)
+            .collect::<Vec<u32>>();
+
+            let expected = encoded_values
+                .apply_automorphism(&Automorphism::new(degree, &*ntt_table))
+                .into_iter()
+                .map(decode)
+                .collect::<Vec<u32>>();
+
+            assert_eq!(decrypted, expected);
+        }
+    }
 }
@@ -64,6 +64,12 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
         }
     }
 
+    /// Returns the RGSW ciphertexts of this [`BinaryBlindRotationKey<F>`].
+    #[inline]
+    pub(super) fn key(&self) -> &[NttRgsw<F>] {
+        &self.key
+    }
+
     /// Returns a reference to the ntt table of this [`BinaryBlindRotationKey<F>`].
     #[inline]
     pub fn ntt_table(&self) -> &<F as NttField>::Table {
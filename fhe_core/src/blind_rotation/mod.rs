@@ -1,13 +1,21 @@
 mod binary;
 mod ternary;
 
-use std::sync::Arc;
+use std::{
+    io::{self, Write},
+    sync::Arc,
+};
 
 use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis, integer::UnsignedInteger, polynomial::FieldPolynomial,
-    random::DiscreteGaussian, Field, NttField,
+    decompose::NonPowOf2ApproxSignedBasis,
+    integer::UnsignedInteger,
+    polynomial::FieldPolynomial,
+    random::DiscreteGaussian,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 pub use binary::BinaryBlindRotationKey;
+use lattice::NttRgsw;
 use rand::{CryptoRng, Rng};
 pub use ternary::TernaryBlindRotationKey;
 
@@ -51,6 +59,16 @@ impl<F: NttField> BlindRotationKey<F> {
         }
     }
 
+    /// Returns the LWE dimension of this [`BlindRotationKey<F>`],
+    /// the number of coefficients of the LWE secret key it encrypts.
+    #[inline]
+    pub fn lwe_dimension(&self) -> usize {
+        match self {
+            BlindRotationKey::Binary(key) => key.key().len(),
+            BlindRotationKey::Ternary(key) => key.key().len(),
+        }
+    }
+
     /// Performs the blind rotation operation.
     pub fn blind_rotate<C: UnsignedInteger>(
         &self,
@@ -99,4 +117,159 @@ impl<F: NttField> BlindRotationKey<F> {
             }
         }
     }
+
+    /// Generates the [`BlindRotationKey<F>`] straight into its encoding in `writer`,
+    /// one RGSW ciphertext at a time, so that at most one of them is held in memory
+    /// and keys larger than the memory are generated to a file.
+    ///
+    /// The bytes are those of the key [`BlindRotationKey::generate`] returns
+    /// for the same arguments and an equally seeded `rng`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `writer`.
+    pub fn generate_to<C, R, W>(
+        writer: &mut W,
+        lwe_secret_key: &LweSecretKey<C>,
+        rlwe_secret_key: &NttRlweSecretKey<F>,
+        blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> io::Result<()>
+    where
+        C: UnsignedInteger,
+        R: Rng + CryptoRng,
+        W: Write,
+        F::ValueT: LeBytes,
+    {
+        let is_ternary = lwe_secret_key.distr() == LweSecretKeyType::Ternary;
+        let count = if is_ternary {
+            2 * lwe_secret_key.as_ref().len()
+        } else {
+            lwe_secret_key.as_ref().len()
+        };
+        let mut buf = Vec::new();
+        write_prefix::<F>(is_ternary, count, &mut buf);
+        writer.write_all(&buf)?;
+
+        // whether each ciphertext encrypts one, in the order of `generate`
+        let ones = lwe_secret_key.as_ref().iter().flat_map(|&s| -> &[bool] {
+            match (is_ternary, s.is_zero(), s.is_one()) {
+                (false, true, _) => &[false],
+                (false, false, _) => &[true],
+                (true, _, true) => &[true, false],
+                (true, true, _) => &[false, false],
+                (true, false, false) => &[false, true],
+            }
+        });
+        for &one in ones {
+            let rgsw = if one {
+                <NttRgsw<F>>::generate_random_one_sample(
+                    rlwe_secret_key,
+                    blind_rotation_basis,
+                    gaussian,
+                    ntt_table,
+                    rng,
+                )
+            } else {
+                <NttRgsw<F>>::generate_random_zero_sample(
+                    rlwe_secret_key,
+                    blind_rotation_basis,
+                    gaussian,
+                    ntt_table,
+                    rng,
+                )
+            };
+            buf.clear();
+            rgsw.write_le(&mut buf);
+            writer.write_all(&buf)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the header, the descriptor, the kind and the number of RGSW ciphertexts
+/// of a blind rotation key.
+fn write_prefix<F: NttField>(is_ternary: bool, count: usize, buf: &mut Vec<u8>)
+where
+    F::ValueT: LeBytes,
+{
+    write_header(b"BRKY", buf);
+    write_modulus::<F>(buf);
+    buf.push(u8::from(is_ternary));
+    (count as u64).write_le(buf);
+}
+
+/// The key is encoded after its header and descriptor as a byte, `0` for binary
+/// and `1` for ternary, followed by its RGSW ciphertexts as one sequence,
+/// with the two ciphertexts of every ternary coefficient next to each other.
+///
+/// The NTT table is not encoded, decoding generates it for the ring dimension.
+impl<F: NttField> LeBytes for BlindRotationKey<F>
+where
+    F::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        match self {
+            BlindRotationKey::Binary(key) => {
+                write_prefix::<F>(false, key.key().len(), buf);
+                key.key().iter().for_each(|rgsw| rgsw.write_le(buf));
+            }
+            BlindRotationKey::Ternary(key) => {
+                write_prefix::<F>(true, 2 * key.key().len(), buf);
+                for (positive, negative) in key.key() {
+                    positive.write_le(buf);
+                    negative.write_le(buf);
+                }
+            }
+        }
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"BRKY", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let is_ternary = match u8::read_le(bytes)? {
+            0 => false,
+            1 => true,
+            _ => return Err(AlgebraError::DecodeErr),
+        };
+        let rgsws = Vec::<NttRgsw<F>>::read_le_with(bytes, limits)?;
+
+        // one ciphertext, or two for ternary, per coefficient of the LWE secret key
+        let lwe_dimension = if is_ternary {
+            rgsws.len() / 2
+        } else {
+            rgsws.len()
+        };
+        limits.check_dimension(lwe_dimension)?;
+        let Some(first) = rgsws.first() else {
+            return Err(AlgebraError::DecodeErr);
+        };
+        let dimension = first.dimension();
+        let basis = *first.basis();
+        let is_consistent = dimension.is_power_of_two()
+            && (!is_ternary || rgsws.len() % 2 == 0)
+            && rgsws
+                .iter()
+                .all(|rgsw| rgsw.dimension() == dimension && *rgsw.basis() == basis);
+        if !is_consistent {
+            return Err(AlgebraError::DecodeErr);
+        }
+
+        let ntt_table = F::generate_ntt_table(dimension.trailing_zeros())
+            .map_err(|_| AlgebraError::DecodeErr)?;
+        let ntt_table = Arc::new(ntt_table);
+        if is_ternary {
+            let mut rgsws = rgsws.into_iter();
+            let key = (0..lwe_dimension)
+                .map(|_| (rgsws.next().unwrap(), rgsws.next().unwrap()))
+                .collect();
+            Ok(Self::Ternary(TernaryBlindRotationKey::new(
+                key, ntt_table, basis,
+            )))
+        } else {
+            Ok(Self::Binary(BinaryBlindRotationKey::new(rgsws, ntt_table)))
+        }
+    }
 }
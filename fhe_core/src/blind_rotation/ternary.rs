@@ -73,6 +73,12 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
         }
     }
 
+    /// Returns the RGSW ciphertexts of this [`TernaryBlindRotationKey<F>`].
+    #[inline]
+    pub(super) fn key(&self) -> &[(NttRgsw<F>, NttRgsw<F>)] {
+        &self.key
+    }
+
     /// Returns a reference to the ntt table of this [`TernaryBlindRotationKey<F>`].
     #[inline]
     pub fn ntt_table(&self) -> &<F as NttField>::Table {
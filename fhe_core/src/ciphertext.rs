@@ -1,11 +1,32 @@
 /// Lwe Ciphertext
 pub type LweCiphertext<C> = lattice::Lwe<C>;
 
+/// Borrowed view of a Lwe Ciphertext
+pub type LweCiphertextView<'a, C> = lattice::LweView<'a, C>;
+
+/// Mutable borrowed view of a Lwe Ciphertext
+pub type LweCiphertextViewMut<'a, C> = lattice::LweViewMut<'a, C>;
+
+/// Contiguous list of Lwe Ciphertexts
+pub type LweCiphertextList<C> = lattice::LweList<C>;
+
 /// CmLwe Ciphertext
 pub type CmLweCiphertext<C> = lattice::CmLwe<C>;
 
 /// Rlwe Ciphertext
 pub type RlweCiphertext<F> = lattice::Rlwe<F>;
 
+/// Borrowed view of a Rlwe Ciphertext
+///
+/// Like the other aliases it is named after its owned type [`RlweCiphertext`].
+/// It borrows both polynomials `a` and `b` of a ciphertext, not a single polynomial.
+pub type RlweCiphertextView<'a, F> = lattice::RlweView<'a, F>;
+
+/// Mutable borrowed view of a Rlwe Ciphertext
+pub type RlweCiphertextViewMut<'a, F> = lattice::RlweViewMut<'a, F>;
+
+/// Contiguous list of Rlwe Ciphertexts
+pub type RlweCiphertextList<F> = lattice::RlweList<F>;
+
 /// Ntt version Rlwe Ciphertext
 pub type NttRlweCiphertext<F> = lattice::NttRlwe<F>;
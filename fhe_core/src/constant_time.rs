@@ -0,0 +1,227 @@
+//! Constant-time arithmetic helpers used on secret dependent data.
+//!
+//! All loops have a trip count depending only on public values
+//! (dimension and modulus), and every selection is done with masks
+//! instead of branches.
+
+use algebra::{integer::UnsignedInteger, reduce::ModulusValue};
+
+/// Returns `if borrow { b } else { a }` without branching.
+#[inline(always)]
+fn select(borrow: bool, a: u128, b: u128) -> u128 {
+    // all ones if `borrow` is `false`.
+    let mask = (borrow as u128).wrapping_sub(1);
+    (a & mask) | (b & !mask)
+}
+
+/// Calculates `x mod q` where `x < q * 2^quotient_bits`.
+///
+/// This is a schoolbook binary long division with `quotient_bits` fixed steps.
+#[inline]
+pub(crate) fn rem(mut x: u128, q: u128, quotient_bits: u32) -> u128 {
+    debug_assert!(quotient_bits == 0 || q.leading_zeros() >= quotient_bits - 1);
+    for k in (0..quotient_bits).rev() {
+        let (r, borrow) = x.overflowing_sub(q << k);
+        x = select(borrow, r, x);
+    }
+    x
+}
+
+/// Calculates `x / d` where `x < d * 2^quotient_bits`.
+#[inline]
+pub(crate) fn div(mut x: u128, d: u128, quotient_bits: u32) -> u128 {
+    debug_assert!(quotient_bits == 0 || d.leading_zeros() >= quotient_bits - 1);
+    let mut quotient = 0u128;
+    for k in (0..quotient_bits).rev() {
+        let (r, borrow) = x.overflowing_sub(d << k);
+        x = select(borrow, r, x);
+        quotient |= ((!borrow) as u128) << k;
+    }
+    quotient
+}
+
+/// Calculates `a - b (mod q)` for `a, b < q`.
+#[inline]
+fn sub_mod(a: u128, b: u128, q: u128) -> u128 {
+    let (r, borrow) = a.overflowing_sub(b);
+    select(borrow, r, r.wrapping_add(q))
+}
+
+/// Calculates `a + b (mod q)` for `a, b < q`.
+#[inline]
+fn add_mod(a: u128, b: u128, q: u128) -> u128 {
+    sub_mod(a, q - b, q)
+}
+
+/// Calculates `a + b (mod q)` in constant time.
+///
+/// # Panics
+///
+/// Panics if `C` has more than 64 bits.
+#[inline]
+pub(crate) fn add<C: UnsignedInteger>(a: C, b: C, q: ModulusValue<C>) -> C {
+    assert!(C::BITS <= 64);
+    match q {
+        ModulusValue::Native => a.wrapping_add(b),
+        ModulusValue::PowerOf2(q) => a.wrapping_add(b) & (q - C::ONE),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            C::as_from(add_mod(a.as_into(), b.as_into(), q.as_into()))
+        }
+    }
+}
+
+/// Calculates `a - b (mod q)` in constant time.
+///
+/// # Panics
+///
+/// Panics if `C` has more than 64 bits.
+#[inline]
+pub(crate) fn sub<C: UnsignedInteger>(a: C, b: C, q: ModulusValue<C>) -> C {
+    assert!(C::BITS <= 64);
+    match q {
+        ModulusValue::Native => a.wrapping_sub(b),
+        ModulusValue::PowerOf2(q) => a.wrapping_sub(b) & (q - C::ONE),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            C::as_from(sub_mod(a.as_into(), b.as_into(), q.as_into()))
+        }
+    }
+}
+
+/// Returns `a` if `mask_bit` is `1`, and `0` if `mask_bit` is `0`.
+#[inline]
+pub(crate) fn mask<C: UnsignedInteger>(a: C, mask_bit: C) -> C {
+    debug_assert!(mask_bit <= C::ONE);
+    a & C::ZERO.wrapping_sub(mask_bit)
+}
+
+/// Calculates `<a, s> (mod q)` in constant time.
+///
+/// # Panics
+///
+/// Panics if `C` has more than 64 bits.
+pub(crate) fn dot_product<C: UnsignedInteger>(a: &[C], s: &[C], q: ModulusValue<C>) -> C {
+    assert!(C::BITS <= 64);
+    debug_assert_eq!(a.len(), s.len());
+
+    match q {
+        ModulusValue::Native => a
+            .iter()
+            .zip(s)
+            .fold(C::ZERO, |acc, (&x, &y)| x.wrapping_mul(y).wrapping_add(acc)),
+        ModulusValue::PowerOf2(q) => {
+            a.iter()
+                .zip(s)
+                .fold(C::ZERO, |acc, (&x, &y)| x.wrapping_mul(y).wrapping_add(acc))
+                & (q - C::ONE)
+        }
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            let q: u128 = q.as_into();
+            let q_bits = u128::BITS - q.leading_zeros();
+            // `acc + x * y < q^2`, so the quotient is less than `2^q_bits`.
+            let result = a.iter().zip(s).fold(0u128, |acc, (&x, &y)| {
+                let x: u128 = x.as_into();
+                let y: u128 = y.as_into();
+                rem(acc + x * y, q, q_bits)
+            });
+            C::as_from(result)
+        }
+    }
+}
+
+/// Calculates `b - <a, s> (mod q)` in constant time.
+///
+/// # Panics
+///
+/// Panics if `C` has more than 64 bits.
+#[inline]
+pub(crate) fn phase<C: UnsignedInteger>(a: &[C], s: &[C], b: C, q: ModulusValue<C>) -> C {
+    sub(b, dot_product(a, s, q), q)
+}
+
+/// Encodes `message` with `round(message * q / t)` in constant time.
+///
+/// `t` must be a power of 2 and `message < t`.
+pub(crate) fn encode_normal<C: UnsignedInteger>(message: C, t: C, q: C) -> C {
+    debug_assert!(t.is_power_of_two() && message < t);
+
+    let q: u128 = q.as_into();
+    let message: u128 = message.as_into();
+    let log_t = t.trailing_zeros();
+
+    // round(message * q / t) = (2 * message * q + t) >> (log_t + 1)
+    C::as_from((((message * q) << 1) + (1 << log_t)) >> (log_t + 1))
+}
+
+/// Decodes `value` with `round(value * t / q) mod t` in constant time.
+///
+/// `t` must be a power of 2.
+pub(crate) fn decode_normal<C: UnsignedInteger>(value: C, t: C, q: C) -> C {
+    debug_assert!(t.is_power_of_two());
+
+    let q: u128 = q.as_into();
+    let t: u128 = t.as_into();
+    let value: u128 = value.as_into();
+
+    // round(value * t / q) = floor((2 * t * value + q) / (2 * q)) <= t
+    let log_t = t.trailing_zeros();
+    let d = q << 1;
+    assert!(d.leading_zeros() > log_t + 1);
+    let x = ((value * t) << 1) + q;
+    let decoded = div(x, d, log_t + 1);
+
+    C::as_from(decoded & (t - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    #[test]
+    fn test_rem_and_div() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let q: u128 = rng.gen_range(2..1u128 << 62);
+            let q_bits = u128::BITS - q.leading_zeros();
+            let x: u128 = rng.gen_range(0..q * q);
+            assert_eq!(rem(x, q, q_bits), x % q);
+            assert_eq!(div(x, q, q_bits), x / q);
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let q: u64 = 132120577;
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = rng.gen_range(0..q);
+            let b = rng.gen_range(0..q);
+            assert_eq!(add(a, b, ModulusValue::Prime(q)), (a + b) % q);
+            assert_eq!(sub(a, b, ModulusValue::Prime(q)), (a + q - b) % q);
+        }
+    }
+
+    #[test]
+    fn test_encode_normal() {
+        let q: u64 = 132120577;
+        let t: u64 = 8;
+        for m in 0..t {
+            let expect = (q as f64 / t as f64 * m as f64).round() as u64;
+            assert_eq!(encode_normal(m, t, q), expect);
+            assert_eq!(decode_normal(encode_normal(m, t, q), t, q), m);
+        }
+    }
+
+    #[test]
+    fn test_decode_normal() {
+        let q: u64 = 132120577;
+        let t: u64 = 4;
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let value = rng.gen_range(0..q);
+            let expect = ((value as f64 * t as f64 / q as f64).round() as u64) % t;
+            assert_eq!(decode_normal(value, t, q), expect);
+        }
+    }
+}
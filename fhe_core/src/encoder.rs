@@ -0,0 +1,309 @@
+//! Plaintext encoders which map messages into `Z_q`.
+//!
+//! The encoders are independent of the ciphertext types. Use
+//! [`LweSecretKey::encrypt_encoded`](crate::LweSecretKey::encrypt_encoded) and
+//! [`LweSecretKey::phase`](crate::LweSecretKey::phase) to combine them with LWE.
+
+use algebra::{
+    integer::UnsignedInteger,
+    reduce::{ModulusValue, RingReduce},
+};
+
+use crate::{decode, encode, LweParameters};
+
+/// An encoder between messages and values of `Z_q`.
+pub trait Encoder<C: UnsignedInteger> {
+    /// The message type.
+    type Message;
+    /// The encoded type, a single value or a sequence of values of `Z_q`.
+    type Encoded;
+
+    /// Encodes `message`.
+    fn encode(&self, message: Self::Message) -> Self::Encoded;
+
+    /// Decodes `encoded`, which may contain small noise.
+    fn decode(&self, encoded: &Self::Encoded) -> Self::Message;
+}
+
+/// The scalar encoder which puts a message of `Z_t`
+/// in the most significant bits, i.e. `m -> round(m * q / t)`.
+#[derive(Debug, Clone, Copy)]
+pub struct MsbEncoder<C: UnsignedInteger> {
+    plain_modulus: C,
+    cipher_modulus: ModulusValue<C>,
+}
+
+impl<C: UnsignedInteger> MsbEncoder<C> {
+    /// Creates a new [`MsbEncoder<C>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `plain_modulus` is not a power of 2.
+    #[inline]
+    pub fn new(plain_modulus: C, cipher_modulus: ModulusValue<C>) -> Self {
+        assert!(plain_modulus.is_power_of_two());
+        Self {
+            plain_modulus,
+            cipher_modulus,
+        }
+    }
+
+    /// Creates a new [`MsbEncoder<C>`] with the moduli of `params`.
+    #[inline]
+    pub fn from_params<Modulus: RingReduce<C>>(params: &LweParameters<C, Modulus>) -> Self {
+        Self::new(params.plain_modulus_value, params.cipher_modulus_value)
+    }
+
+    /// Returns the plain modulus of this [`MsbEncoder<C>`].
+    #[inline]
+    pub fn plain_modulus(&self) -> C {
+        self.plain_modulus
+    }
+
+    /// Returns the cipher modulus of this [`MsbEncoder<C>`].
+    #[inline]
+    pub fn cipher_modulus(&self) -> ModulusValue<C> {
+        self.cipher_modulus
+    }
+}
+
+impl<C: UnsignedInteger> Encoder<C> for MsbEncoder<C> {
+    type Message = C;
+    type Encoded = C;
+
+    #[inline]
+    fn encode(&self, message: C) -> C {
+        encode(message, self.plain_modulus, self.cipher_modulus)
+    }
+
+    #[inline]
+    fn decode(&self, encoded: &C) -> C {
+        decode(*encoded, self.plain_modulus, self.cipher_modulus)
+    }
+}
+
+/// The balanced base-`B` integer encoder.
+///
+/// A signed integer is split into `digits` digits in `[-B/2, B/2)`,
+/// least significant first, and every digit is put in the
+/// most significant bits by a [`MsbEncoder`].
+///
+/// When `B < t`, every digit keeps `t / B` room for carries.
+#[derive(Debug, Clone, Copy)]
+pub struct BalancedBaseEncoder<C: UnsignedInteger> {
+    base: i64,
+    digits: usize,
+    msb: MsbEncoder<C>,
+}
+
+impl<C: UnsignedInteger> BalancedBaseEncoder<C> {
+    /// Creates a new [`BalancedBaseEncoder<C>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if
+    /// - `base` is less than `2` or larger than the plain modulus of `msb`.
+    /// - `digits` is `0` or `base^digits` overflows `i64`.
+    #[inline]
+    pub fn new(base: u32, digits: usize, msb: MsbEncoder<C>) -> Self {
+        let t: u64 = msb.plain_modulus().as_into();
+        assert!(base >= 2 && base as u64 <= t);
+        assert!(digits > 0 && (base as i64).checked_pow(digits as u32).is_some());
+        Self {
+            base: base as i64,
+            digits,
+            msb,
+        }
+    }
+
+    /// Returns the base of this [`BalancedBaseEncoder<C>`].
+    #[inline]
+    pub fn base(&self) -> u32 {
+        self.base as u32
+    }
+
+    /// Returns the digit count of this [`BalancedBaseEncoder<C>`].
+    #[inline]
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Returns the message range `[min, max]` of this [`BalancedBaseEncoder<C>`].
+    #[inline]
+    pub fn message_range(&self) -> (i64, i64) {
+        let half = self.base / 2;
+        let (min, max) = (0..self.digits).fold((0i64, 0i64), |(min, max), i| {
+            let p = self.base.pow(i as u32);
+            (min - half * p, max + (self.base - 1 - half) * p)
+        });
+        (min, max)
+    }
+}
+
+impl<C: UnsignedInteger> Encoder<C> for BalancedBaseEncoder<C> {
+    type Message = i64;
+    type Encoded = Vec<C>;
+
+    /// # Panics
+    ///
+    /// Panics if `message` is out of [`BalancedBaseEncoder::message_range`].
+    fn encode(&self, message: i64) -> Vec<C> {
+        let t: i128 = self.msb.plain_modulus().as_into();
+        let base = self.base as i128;
+        let half = base / 2;
+
+        // `rest - digit` overflows `i64` near `i64::MIN` for an odd base
+        let mut rest = message as i128;
+        let encoded = (0..self.digits)
+            .map(|_| {
+                let mut digit = rest.rem_euclid(base);
+                if digit >= base - half {
+                    digit -= base;
+                }
+                rest = (rest - digit) / base;
+                self.msb.encode(C::as_from(digit.rem_euclid(t) as u64))
+            })
+            .collect();
+
+        assert_eq!(rest, 0, "message {message} is out of the message range");
+        encoded
+    }
+
+    fn decode(&self, encoded: &Vec<C>) -> i64 {
+        debug_assert_eq!(encoded.len(), self.digits);
+        let t: i64 = self.msb.plain_modulus().as_into();
+
+        encoded.iter().rev().fold(0i64, |acc, value| {
+            let digit: i64 = self.msb.decode(value).as_into();
+            let digit = if digit >= t / 2 { digit - t } else { digit };
+            acc.wrapping_mul(self.base).wrapping_add(digit)
+        })
+    }
+}
+
+/// The fractional encoder for real numbers in `[min, max)`.
+///
+/// The interval is split into `2^precision_bits` steps, and the step index
+/// is put in the most significant bits.
+#[derive(Debug, Clone, Copy)]
+pub struct FractionalEncoder<C: UnsignedInteger> {
+    min: f64,
+    max: f64,
+    precision_bits: u32,
+    msb: MsbEncoder<C>,
+}
+
+impl<C: UnsignedInteger> FractionalEncoder<C> {
+    /// Creates a new [`FractionalEncoder<C>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min >= max`, or `precision_bits` is `0` or not less than `C::BITS`.
+    #[inline]
+    pub fn new(min: f64, max: f64, precision_bits: u32, cipher_modulus: ModulusValue<C>) -> Self {
+        assert!(min < max);
+        assert!(precision_bits > 0 && precision_bits < C::BITS);
+        Self {
+            min,
+            max,
+            precision_bits,
+            msb: MsbEncoder::new(C::ONE << precision_bits, cipher_modulus),
+        }
+    }
+
+    /// Returns the distance between two adjacent encodable values.
+    #[inline]
+    pub fn step(&self) -> f64 {
+        (self.max - self.min) / 2f64.powi(self.precision_bits as i32)
+    }
+}
+
+impl<C: UnsignedInteger> Encoder<C> for FractionalEncoder<C> {
+    type Message = f64;
+    type Encoded = C;
+
+    /// Values out of `[min, max)` are clamped.
+    #[inline]
+    fn encode(&self, message: f64) -> C {
+        let steps = self.msb.plain_modulus() - C::ONE;
+        let index = ((message - self.min) / self.step()).round();
+        let index = if index <= 0.0 {
+            C::ZERO
+        } else {
+            C::as_from(index).min(steps)
+        };
+        self.msb.encode(index)
+    }
+
+    #[inline]
+    fn decode(&self, encoded: &C) -> f64 {
+        let index: f64 = self.msb.decode(encoded).as_into();
+        self.min + index * self.step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    #[test]
+    fn test_msb_encoder() {
+        let encoder = MsbEncoder::<u32>::new(8, ModulusValue::Prime(132120577));
+        for m in 0..8 {
+            let mut encoded = encoder.encode(m);
+            encoded += 100;
+            assert_eq!(encoder.decode(&encoded), m);
+        }
+    }
+
+    #[test]
+    fn test_balanced_base_encoder() {
+        let mut rng = thread_rng();
+        let msb = MsbEncoder::<u64>::new(16, ModulusValue::PowerOf2(1 << 32));
+        let encoder = BalancedBaseEncoder::new(4, 8, msb);
+
+        let (min, max) = encoder.message_range();
+        assert_eq!((min, max), (-43690, 21845));
+
+        for _ in 0..100 {
+            let message = rng.gen_range(min..=max);
+            let encoded = encoder.encode(message);
+            assert_eq!(encoded.len(), 8);
+            assert_eq!(encoder.decode(&encoded), message);
+        }
+    }
+
+    #[test]
+    fn test_balanced_base_encoder_extremes() {
+        let msb = MsbEncoder::<u64>::new(16, ModulusValue::PowerOf2(1 << 32));
+        // `3^39` is the largest power of `3` in `i64`
+        let encoder = BalancedBaseEncoder::new(3, 39, msb);
+
+        let (min, max) = encoder.message_range();
+        assert_eq!((min, max), (-(3i64.pow(39) / 2), 3i64.pow(39) / 2));
+        for message in [min, max, 0] {
+            assert_eq!(encoder.decode(&encoder.encode(message)), message);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the message range")]
+    fn test_balanced_base_encoder_min() {
+        let msb = MsbEncoder::<u64>::new(16, ModulusValue::PowerOf2(1 << 32));
+        BalancedBaseEncoder::new(3, 39, msb).encode(i64::MIN);
+    }
+
+    #[test]
+    fn test_fractional_encoder() {
+        let mut rng = thread_rng();
+        let encoder = FractionalEncoder::<u32>::new(-1.0, 1.0, 10, ModulusValue::Native);
+
+        for _ in 0..100 {
+            let message = rng.gen_range(-1.0..1.0);
+            let decoded = encoder.decode(&encoder.encode(message));
+            assert!((decoded - message).abs() <= encoder.step());
+        }
+    }
+}
@@ -31,8 +31,34 @@ pub enum FHECoreError {
         /// Polynomial modulus dimension of ring.
         ring_dimension: Box<dyn Debug>,
     },
+    /// Error that occurs when the given approximate decomposition length
+    /// is `0` or larger than the full decomposition length.
+    #[error("Decomposition length {reverse_length} is not in 1..={full_length}!")]
+    ReverseLengthUnValid {
+        /// The given approximate decomposition length.
+        reverse_length: usize,
+        /// The full decomposition length.
+        full_length: usize,
+    },
     /// Error that occurs when the given steps after blind rotation
     /// is not compatible with other parameters.
     #[error("Steps after blind rotation is not compatible with other parameters!")]
     StepsParametersNotCompatible,
+    /// Error that occurs when the given ring dimension or ring modulus
+    /// is not the one checked at compile time.
+    #[error(
+        "Ring dimension or ring modulus is not the checked {ring_dimension} and {ring_modulus}!"
+    )]
+    CheckedParametersMismatch {
+        /// The checked ring dimension.
+        ring_dimension: usize,
+        /// The checked ring modulus.
+        ring_modulus: u64,
+    },
+    /// Error that occurs when the given message exceeds the message space.
+    #[error("Message exceeds the message space!")]
+    MessageOutOfRange,
+    /// Error that occurs when the decoded message cannot fit in the message type.
+    #[error("Decoded message cannot fit in the message type!")]
+    DecodedMessageOutOfRange,
 }
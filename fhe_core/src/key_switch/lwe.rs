@@ -4,9 +4,13 @@ use algebra::{
     decompose::{NonPowOf2ApproxSignedBasis, PowOf2ApproxSignedBasis},
     integer::UnsignedInteger,
     ntt::NttTable,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
-    reduce::{ReduceNegAssign, RingReduce},
-    Field, NttField,
+    polynomial::FieldPolynomial,
+    reduce::{ReduceNeg, ReduceNegAssign, RingReduce},
+    utils::{
+        read_header, read_modulus, read_width, write_header, write_modulus, write_width,
+        DecodeLimits, LeBytes,
+    },
+    AlgebraError, Field, NttField,
 };
 use lattice::{utils::PolyDecomposeSpace, Lwe, NttGadgetRlwe, NttRlwe};
 use num_traits::ConstOne;
@@ -103,18 +107,43 @@ impl<C: UnsignedInteger> PowOf2LweKeySwitchingKey<C> {
         }
     }
 
+    /// Returns the parameters of this [`PowOf2LweKeySwitchingKey<C>`].
+    #[inline]
+    pub fn key_switching_params(&self) -> KeySwitchingParameters {
+        self.params
+    }
+
     /// Performs key switching operation.
     pub fn key_switch(
         &self,
         ciphertext: &LweCiphertext<C>,
         modulus: impl RingReduce<C>,
     ) -> LweCiphertext<C> {
-        let dimension = self.params.output_cipher_dimension;
+        let mut result = <Lwe<C>>::zero(self.params.output_cipher_dimension);
+        self.key_switch_inplace(ciphertext, modulus, &mut result);
+        result
+    }
+
+    /// Performs key switching operation, store the result into `destination`.
+    ///
+    /// `destination` is overwritten, so it can be reused across calls without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `destination` is not the output dimension of this key.
+    pub fn key_switch_inplace(
+        &self,
+        ciphertext: &LweCiphertext<C>,
+        modulus: impl RingReduce<C>,
+        destination: &mut LweCiphertext<C>,
+    ) {
+        assert_eq!(destination.dimension(), self.params.output_cipher_dimension);
         let minus_one = modulus.modulus_minus_one();
 
         let a = ciphertext.a();
 
-        let mut result = <Lwe<C>>::zero(dimension);
+        let result = destination;
+        result.set_zero();
 
         let (mut decomposed, mut carries) = match self.space.get() {
             Some(sp) => sp,
@@ -129,15 +158,7 @@ impl<C: UnsignedInteger> PowOf2LweKeySwitchingKey<C> {
             .for_each(|(key_i, once_decompose)| {
                 once_decompose.decompose_slice_inplace(a, &mut carries, decomposed.as_mut_slice());
                 decomposed.iter().zip(key_i).for_each(|(&d_i, s_i)| {
-                    if !d_i.is_zero() {
-                        if d_i.is_one() {
-                            result.add_reduce_assign_component_wise(s_i, modulus);
-                        } else if d_i == minus_one {
-                            result.sub_reduce_assign_component_wise(s_i, modulus);
-                        } else {
-                            result.add_assign_rhs_mul_scalar_reduce(s_i, d_i, modulus);
-                        }
-                    }
+                    accumulate_digit(result, s_i, d_i, minus_one, modulus);
                 });
             });
 
@@ -145,8 +166,6 @@ impl<C: UnsignedInteger> PowOf2LweKeySwitchingKey<C> {
 
         result.neg_reduce_assign(modulus);
         modulus.reduce_add_assign(result.b_mut(), ciphertext.b());
-
-        result
     }
 }
 
@@ -231,18 +250,49 @@ impl<C: UnsignedInteger> NonPowOf2LweKeySwitchingKey<C> {
         }
     }
 
+    /// Returns the parameters of this [`NonPowOf2LweKeySwitchingKey<C>`].
+    #[inline]
+    pub fn key_switching_params(&self) -> KeySwitchingParameters {
+        self.params
+    }
+
+    /// Returns the basis of this [`NonPowOf2LweKeySwitchingKey<C>`].
+    #[inline]
+    pub fn basis(&self) -> &NonPowOf2ApproxSignedBasis<C> {
+        &self.basis
+    }
+
     /// Performs key switching operation.
     pub fn key_switch(
         &self,
         ciphertext: &LweCiphertext<C>,
         modulus: impl RingReduce<C>,
     ) -> LweCiphertext<C> {
-        let dimension = self.params.output_cipher_dimension;
+        let mut result = <Lwe<C>>::zero(self.params.output_cipher_dimension);
+        self.key_switch_inplace(ciphertext, modulus, &mut result);
+        result
+    }
+
+    /// Performs key switching operation, store the result into `destination`.
+    ///
+    /// `destination` is overwritten, so it can be reused across calls without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `destination` is not the output dimension of this key.
+    pub fn key_switch_inplace(
+        &self,
+        ciphertext: &LweCiphertext<C>,
+        modulus: impl RingReduce<C>,
+        destination: &mut LweCiphertext<C>,
+    ) {
+        assert_eq!(destination.dimension(), self.params.output_cipher_dimension);
         let minus_one = modulus.modulus_minus_one();
 
         let a = ciphertext.a();
 
-        let mut result = <Lwe<C>>::zero(dimension);
+        let result = destination;
+        result.set_zero();
 
         let (mut adjust_values, mut decomposed, mut carries) = match self.space.get() {
             Some(sp) => sp,
@@ -266,15 +316,7 @@ impl<C: UnsignedInteger> NonPowOf2LweKeySwitchingKey<C> {
                     decomposed.as_mut_slice(),
                 );
                 decomposed.iter().zip(key_i).for_each(|(&d_i, s_i)| {
-                    if !d_i.is_zero() {
-                        if d_i.is_one() {
-                            result.add_reduce_assign_component_wise(s_i, modulus);
-                        } else if d_i == minus_one {
-                            result.sub_reduce_assign_component_wise(s_i, modulus);
-                        } else {
-                            result.add_assign_rhs_mul_scalar_reduce(s_i, d_i, modulus);
-                        }
-                    }
+                    accumulate_digit(result, s_i, d_i, minus_one, modulus);
                 });
             });
 
@@ -282,8 +324,121 @@ impl<C: UnsignedInteger> NonPowOf2LweKeySwitchingKey<C> {
 
         result.neg_reduce_assign(modulus);
         modulus.reduce_add_assign(result.b_mut(), ciphertext.b());
+    }
+}
 
-        result
+/// The key is encoded after its header and descriptor as its parameters,
+/// followed by its LWE ciphertexts for every level.
+///
+/// The basis is not encoded, decoding builds it from the parameters.
+impl<C: UnsignedInteger + LeBytes> LeBytes for PowOf2LweKeySwitchingKey<C> {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"PKSK", buf);
+        write_width::<C>(buf);
+        self.params.write_le(buf);
+        self.key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"PKSK", bytes)?;
+        read_width::<C>(version, bytes)?;
+        let params = KeySwitchingParameters::read_le_with(bytes, limits)?;
+        let basis = PowOf2ApproxSignedBasis::checked_new(
+            params.log_modulus,
+            params.log_basis,
+            params.reverse_length,
+        )
+        .ok_or(AlgebraError::DecodeErr)?;
+        limits.check_levels(basis.decompose_length())?;
+        let key = Vec::read_le_with(bytes, limits)?;
+        check_key_shape(&key, &params, basis.decompose_length())?;
+        Ok(Self {
+            key,
+            params,
+            basis,
+            space: Pool::new(),
+        })
+    }
+}
+
+/// The key is encoded after its header and descriptor as the modulus of its basis,
+/// its parameters, followed by its LWE ciphertexts for every level.
+impl<C: UnsignedInteger + LeBytes> LeBytes for NonPowOf2LweKeySwitchingKey<C> {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"NKSK", buf);
+        write_width::<C>(buf);
+        self.basis.modulus().write_le(buf);
+        self.params.write_le(buf);
+        self.key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"NKSK", bytes)?;
+        read_width::<C>(version, bytes)?;
+        let modulus = C::read_le(bytes)?;
+        let params = KeySwitchingParameters::read_le_with(bytes, limits)?;
+        let basis = NonPowOf2ApproxSignedBasis::checked_new(
+            modulus,
+            params.log_basis,
+            params.reverse_length,
+        )
+        .ok_or(AlgebraError::DecodeErr)?;
+        limits.check_levels(basis.decompose_length())?;
+        let key = Vec::read_le_with(bytes, limits)?;
+        check_key_shape(&key, &params, basis.decompose_length())?;
+        Ok(Self {
+            key,
+            params,
+            basis,
+            space: Pool::new(),
+        })
+    }
+}
+
+/// Checks that `key` has `decompose_length` levels of ciphertexts
+/// with the dimensions of `params`.
+fn check_key_shape<C: UnsignedInteger>(
+    key: &[Vec<Lwe<C>>],
+    params: &KeySwitchingParameters,
+    decompose_length: usize,
+) -> Result<(), AlgebraError> {
+    let is_valid = key.len() == decompose_length
+        && key.iter().all(|level| {
+            level.len() == params.input_cipher_dimension
+                && level
+                    .iter()
+                    .all(|cipher| cipher.dimension() == params.output_cipher_dimension)
+        });
+    if is_valid {
+        Ok(())
+    } else {
+        Err(AlgebraError::DecodeErr)
+    }
+}
+
+/// Performs `result += digit * key` for one digit of the decomposed mask.
+///
+/// Digits `±1` are added or subtracted without multiplication.
+#[inline]
+fn accumulate_digit<C: UnsignedInteger>(
+    result: &mut Lwe<C>,
+    key: &Lwe<C>,
+    digit: C,
+    minus_one: C,
+    modulus: impl RingReduce<C>,
+) {
+    if digit.is_zero() {
+        return;
+    }
+    if digit.is_one() {
+        modulus.add_slice(result.a_mut_slice(), key.a());
+        modulus.reduce_add_assign(result.b_mut(), key.b());
+    } else if digit == minus_one {
+        modulus.sub_slice(result.a_mut_slice(), key.a());
+        modulus.reduce_sub_assign(result.b_mut(), key.b());
+    } else {
+        modulus.fma_slice(result.a_mut_slice(), key.a(), digit);
+        *result.b_mut() = modulus.reduce_mul_add(key.b(), digit, result.b());
     }
 }
 
@@ -297,7 +452,7 @@ pub struct LweKeySwitchingKeyRlweMode<Q: NttField> {
     key: Vec<NttGadgetRlwe<Q>>,
     key_switching_key_params: KeySwitchingParameters,
     ntt_table: Arc<<Q as NttField>::Table>,
-    space: Pool<(PolyDecomposeSpace<Q>, FieldPolynomial<Q>)>,
+    space: Pool<RlweModeSpace<Q>>,
 }
 
 impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
@@ -376,17 +531,38 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
         }
     }
 
+    /// Returns the parameters of this [`LweKeySwitchingKeyRlweMode<Q>`].
+    #[inline]
+    pub fn key_switching_params(&self) -> KeySwitchingParameters {
+        self.key_switching_key_params
+    }
+
     /// Performs key switching operation.
     pub fn key_switch_for_rlwe(
         &self,
-        mut ciphertext: RlweCiphertext<Q>,
+        ciphertext: RlweCiphertext<Q>,
     ) -> LweCiphertext<<Q as Field>::ValueT> {
+        let mut result = <Lwe<<Q as Field>::ValueT>>::zero(
+            self.key_switching_key_params.output_cipher_dimension,
+        );
+        self.key_switch_for_rlwe_inplace(ciphertext, &mut result);
+        result
+    }
+
+    /// Performs key switching operation, store the result into `destination`.
+    ///
+    /// `destination` is overwritten, so it can be reused across calls without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `destination` is not the output dimension of this key.
+    pub fn key_switch_for_rlwe_inplace(
+        &self,
+        mut ciphertext: RlweCiphertext<Q>,
+        destination: &mut LweCiphertext<<Q as Field>::ValueT>,
+    ) {
         let lwe_dimension = self.key_switching_key_params.output_cipher_dimension;
         let b = ciphertext.b()[0];
-        let init = <NttRlwe<Q>>::new(
-            FieldNttPolynomial::zero(lwe_dimension),
-            FieldNttPolynomial::new(vec![b; lwe_dimension]),
-        );
 
         if ciphertext.dimension() != lwe_dimension {
             let a = ciphertext.a_mut_slice();
@@ -400,20 +576,35 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
 
         let iter = ciphertext.a_slice().chunks_exact(lwe_dimension);
 
-        self.key_switch_inner(lwe_dimension, init, iter)
+        self.key_switch_inner(lwe_dimension, b, iter, destination)
     }
 
     /// Performs key switching operation.
     pub fn key_switch_for_lwe(
         &self,
-        mut ciphertext: LweCiphertext<<Q as Field>::ValueT>,
+        ciphertext: LweCiphertext<<Q as Field>::ValueT>,
     ) -> LweCiphertext<<Q as Field>::ValueT> {
+        let mut result = <Lwe<<Q as Field>::ValueT>>::zero(
+            self.key_switching_key_params.output_cipher_dimension,
+        );
+        self.key_switch_for_lwe_inplace(ciphertext, &mut result);
+        result
+    }
+
+    /// Performs key switching operation, store the result into `destination`.
+    ///
+    /// `destination` is overwritten, so it can be reused across calls without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `destination` is not the output dimension of this key.
+    pub fn key_switch_for_lwe_inplace(
+        &self,
+        mut ciphertext: LweCiphertext<<Q as Field>::ValueT>,
+        destination: &mut LweCiphertext<<Q as Field>::ValueT>,
+    ) {
         let lwe_dimension = self.key_switching_key_params.output_cipher_dimension;
         let b = ciphertext.b();
-        let init = <NttRlwe<Q>>::new(
-            FieldNttPolynomial::zero(lwe_dimension),
-            FieldNttPolynomial::new(vec![b; lwe_dimension]),
-        );
 
         let a = ciphertext.a_mut_slice();
         a.chunks_exact_mut(lwe_dimension).for_each(|chunk| {
@@ -425,38 +616,125 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
 
         let iter = ciphertext.a_slice().chunks_exact(lwe_dimension);
 
-        self.key_switch_inner(lwe_dimension, init, iter)
+        self.key_switch_inner(lwe_dimension, b, iter, destination)
     }
 
     fn key_switch_inner(
         &self,
         lwe_dimension: usize,
-        mut init: NttRlwe<Q>,
+        b: <Q as Field>::ValueT,
         iter: ChunksExact<<Q as Field>::ValueT>,
-    ) -> LweCiphertext<<Q as Field>::ValueT> {
+        destination: &mut LweCiphertext<<Q as Field>::ValueT>,
+    ) {
+        assert_eq!(destination.dimension(), lwe_dimension);
+
         let ntt_table = self.ntt_table.as_ref();
-        let (mut decompose_space, mut poly_space) = match self.space.get() {
+        let mut space = match self.space.get() {
             Some(sp) => sp,
-            None => (
-                PolyDecomposeSpace::new(lwe_dimension),
-                FieldPolynomial::zero(lwe_dimension),
-            ),
+            None => RlweModeSpace::new(lwe_dimension),
         };
 
+        // the accumulator starts at the trivial encryption of `b`
+        let acc = &mut space.acc;
+        acc.set_zero();
+        acc.b_mut_slice().fill(b);
+
         self.key.iter().zip(iter).for_each(
             |(z_i, a_i): (&NttGadgetRlwe<Q>, &[<Q as Field>::ValueT])| {
-                poly_space.copy_from(a_i);
-                init.sub_assign_gadget_rlwe_mul_polynomial_fast(
+                space.poly.copy_from(a_i);
+                space.acc.sub_assign_gadget_rlwe_mul_polynomial_fast(
                     z_i,
-                    &poly_space,
+                    &space.poly,
                     ntt_table,
-                    &mut decompose_space,
+                    &mut space.decompose,
                 );
             },
         );
 
-        self.space.store((decompose_space, poly_space));
+        space
+            .acc
+            .inverse_transform_inplace(ntt_table, &mut space.rlwe);
+
+        // extract the constant coefficient, as `Rlwe::extract_lwe_locally`
+        let a = space.rlwe.a_slice();
+        let destination_a = destination.a_mut_slice();
+        destination_a[0] = a[0];
+        destination_a[1..]
+            .iter_mut()
+            .zip(a[1..].iter().rev())
+            .for_each(|(d, &v)| *d = Q::MODULUS.reduce_neg(v));
+        *destination.b_mut() = space.rlwe.b_slice()[0];
+
+        self.space.store(space);
+    }
+}
+
+/// The reusable buffers of [`LweKeySwitchingKeyRlweMode`].
+struct RlweModeSpace<Q: NttField> {
+    decompose: PolyDecomposeSpace<Q>,
+    poly: FieldPolynomial<Q>,
+    acc: NttRlwe<Q>,
+    rlwe: RlweCiphertext<Q>,
+}
 
-        init.to_rlwe(ntt_table).extract_lwe_locally()
+impl<Q: NttField> RlweModeSpace<Q> {
+    fn new(lwe_dimension: usize) -> Self {
+        Self {
+            decompose: PolyDecomposeSpace::new(lwe_dimension),
+            poly: FieldPolynomial::zero(lwe_dimension),
+            acc: NttRlwe::zero(lwe_dimension),
+            rlwe: RlweCiphertext::zero(lwe_dimension),
+        }
+    }
+}
+
+/// The key is encoded after its header and descriptor as its parameters,
+/// followed by its gadget RLWE ciphertexts.
+///
+/// The NTT table is not encoded, decoding generates it for the output dimension.
+impl<Q: NttField> LeBytes for LweKeySwitchingKeyRlweMode<Q>
+where
+    <Q as Field>::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"RKSK", buf);
+        write_modulus::<Q>(buf);
+        self.key_switching_key_params.write_le(buf);
+        self.key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"RKSK", bytes)?;
+        read_modulus::<Q>(version, bytes)?;
+        let params = KeySwitchingParameters::read_le_with(bytes, limits)?;
+        let rlwe_dimension = params.input_cipher_dimension;
+        let lwe_dimension = params.output_cipher_dimension;
+        if !(lwe_dimension.is_power_of_two() && lwe_dimension <= rlwe_dimension) {
+            return Err(AlgebraError::DecodeErr);
+        }
+        let basis = NonPowOf2ApproxSignedBasis::checked_new(
+            Q::MODULUS_VALUE,
+            params.log_basis,
+            params.reverse_length,
+        )
+        .ok_or(AlgebraError::DecodeErr)?;
+
+        let key: Vec<NttGadgetRlwe<Q>> = Vec::read_le_with(bytes, limits)?;
+        let is_valid = key.len() == rlwe_dimension / lwe_dimension
+            && key
+                .iter()
+                .all(|z| z.dimension() == lwe_dimension && *z.basis() == basis);
+        if !is_valid {
+            return Err(AlgebraError::DecodeErr);
+        }
+
+        let ntt_table = Q::generate_ntt_table(lwe_dimension.trailing_zeros())
+            .map_err(|_| AlgebraError::DecodeErr)?;
+        Ok(Self {
+            key,
+            key_switching_key_params: params,
+            ntt_table: Arc::new(ntt_table),
+            space: Pool::new(),
+        })
     }
 }
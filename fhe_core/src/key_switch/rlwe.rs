@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis, ntt::NttTable, random::DiscreteGaussian, Field, NttField,
+    decompose::NonPowOf2ApproxSignedBasis,
+    ntt::NttTable,
+    random::DiscreteGaussian,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use lattice::{utils::PolyDecomposeSpace, NttGadgetRlwe, NttRlwe};
 use rand::{CryptoRng, Rng};
@@ -104,3 +108,31 @@ impl<Q: NttField> RlweKeySwitchingKey<Q> {
         result
     }
 }
+
+/// The key is encoded after its header and descriptor as its gadget RLWE ciphertext.
+///
+/// The NTT table is not encoded, decoding generates it for the ring dimension.
+impl<Q: NttField> LeBytes for RlweKeySwitchingKey<Q>
+where
+    <Q as Field>::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"RLKS", buf);
+        write_modulus::<Q>(buf);
+        self.key.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"RLKS", bytes)?;
+        read_modulus::<Q>(version, bytes)?;
+        let key = NttGadgetRlwe::<Q>::read_le_with(bytes, limits)?;
+        let dimension = key.dimension();
+        if !dimension.is_power_of_two() {
+            return Err(AlgebraError::DecodeErr);
+        }
+        let ntt_table = Q::generate_ntt_table(dimension.trailing_zeros())
+            .map_err(|_| AlgebraError::DecodeErr)?;
+        Ok(Self::new(key, Arc::new(ntt_table)))
+    }
+}
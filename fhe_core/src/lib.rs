@@ -3,6 +3,8 @@
 
 //! This crate defines the core structures and algorithms for fully homomorphic encryption.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod error;
 
 mod parameter;
@@ -11,6 +13,8 @@ mod public_key;
 mod secret_key;
 
 mod ciphertext;
+mod constant_time;
+mod encoder;
 mod plaintext;
 
 mod blind_rotation;
@@ -21,24 +25,36 @@ mod trace;
 
 mod modulus_switch;
 
+pub mod noise;
 pub mod utils;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
 pub use error::FHECoreError;
 
-pub use parameter::{GadgetRlweParameters, KeySwitchingParameters, LweParameters};
+pub use parameter::{GadgetRlweParameters, KeySwitchingParameters, LweParameters, SecurityProfile};
 
 pub use public_key::{LwePublicKey, LwePublicKeyRlweMode, NttRlwePublicKey};
 pub use secret_key::{
     LweSecretKey, LweSecretKeyType, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
 };
 
-pub use ciphertext::{CmLweCiphertext, LweCiphertext, NttRlweCiphertext, RlweCiphertext};
-pub use plaintext::{decode, encode};
+pub use ciphertext::{
+    CmLweCiphertext, LweCiphertext, LweCiphertextList, LweCiphertextView, LweCiphertextViewMut,
+    NttRlweCiphertext, RlweCiphertext, RlweCiphertextList, RlweCiphertextView,
+    RlweCiphertextViewMut,
+};
+pub use encoder::{BalancedBaseEncoder, Encoder, FractionalEncoder, MsbEncoder};
+pub use plaintext::{decode, decode_constant_time, encode, encode_constant_time};
 
 pub use blind_rotation::BlindRotationKey;
 pub use key_switch::*;
 
-pub use automorphism::{AutoKey, AutoSpace};
+pub use automorphism::{AutoKey, AutoSpace, HoistedRlwe};
 pub use trace::TraceKey;
 
 pub use modulus_switch::{
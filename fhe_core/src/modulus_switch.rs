@@ -1,14 +1,25 @@
-use algebra::{
-    integer::{AsInto, UnsignedInteger},
-    reduce::ModulusValue,
-};
+use algebra::{arith::MulDivRound, integer::UnsignedInteger, reduce::ModulusValue};
 
 use crate::LweCiphertext;
 
+/// Returns `2^C::BITS`, the native modulus of `C`.
+#[inline]
+fn native_modulus<C: UnsignedInteger>() -> u128 {
+    1u128
+        .checked_shl(C::BITS)
+        .expect("modulus switching of native `u128` values is not supported")
+}
+
 /// Implementation of modulus switching.
 ///
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// returns a [`LweCiphertext<COut>`] with desired modulus `modulus_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
+/// Also panics if `modulus_out` is neither native nor a power of 2.
 pub fn lwe_modulus_switch<CIn: UnsignedInteger, COut: UnsignedInteger>(
     c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
@@ -27,13 +38,18 @@ pub fn lwe_modulus_switch<CIn: UnsignedInteger, COut: UnsignedInteger>(
 ///
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// returns a [`LweCiphertext<COut>`] with desired modulus `modulus_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedInteger>(
     c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
     modulus_out: COut,
 ) -> LweCiphertext<COut> {
-    let modulus_in_f64: f64 = modulus_in.as_into();
-    let modulus_out_f64: f64 = modulus_out.as_into();
+    let q_in: u128 = modulus_in.as_into();
+    let q_out: u128 = modulus_out.as_into();
 
     let reduce = |v: COut| {
         if v < modulus_out {
@@ -43,11 +59,8 @@ pub fn lwe_modulus_switch_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedIntege
         }
     };
 
-    let switch = |v: CIn| {
-        reduce(COut::as_from(
-            (AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round(),
-        ))
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: CIn| reduce(COut::as_from(scale.apply(v.as_into())));
 
     let a: Vec<COut> = c_in.a().iter().copied().map(&switch).collect();
     let b = switch(c_in.b());
@@ -59,16 +72,20 @@ pub fn lwe_modulus_switch_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedIntege
 ///
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// returns a [`LweCiphertext<COut>`] with desired modulus `modulus_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_to_native<CIn: UnsignedInteger, COut: UnsignedInteger>(
     c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
 ) -> LweCiphertext<COut> {
-    let modulus_in_f64: f64 = modulus_in.as_into();
-    let modulus_out_f64: f64 = 2.0f64.powi(COut::BITS as i32);
+    let q_in: u128 = modulus_in.as_into();
+    let q_out = native_modulus::<COut>();
 
-    let switch = |v: CIn| {
-        COut::as_from((AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round())
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: CIn| COut::as_from(scale.apply(v.as_into()));
 
     let a: Vec<COut> = c_in.a().iter().copied().map(&switch).collect();
     let b = switch(c_in.b());
@@ -81,8 +98,14 @@ pub fn lwe_modulus_switch_to_native<CIn: UnsignedInteger, COut: UnsignedInteger>
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// puts the result [`LweCiphertext<COut>`] with desired modulus `modulus_out`
 /// into `c_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
+/// Also panics if `modulus_out` is neither native nor a power of 2.
 pub fn lwe_modulus_switch_inplace<CIn: UnsignedInteger, COut: UnsignedInteger>(
-    c_in: LweCiphertext<CIn>,
+    c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
     modulus_out: ModulusValue<COut>,
     c_out: &mut LweCiphertext<COut>,
@@ -101,14 +124,19 @@ pub fn lwe_modulus_switch_inplace<CIn: UnsignedInteger, COut: UnsignedInteger>(
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// puts the result [`LweCiphertext<COut>`] with desired modulus `modulus_out`
 /// into `c_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_inplace_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedInteger>(
-    c_in: LweCiphertext<CIn>,
+    c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
     modulus_out: COut,
     c_out: &mut LweCiphertext<COut>,
 ) {
-    let modulus_in_f64: f64 = modulus_in.as_into();
-    let modulus_out_f64: f64 = modulus_out.as_into();
+    let q_in: u128 = modulus_in.as_into();
+    let q_out: u128 = modulus_out.as_into();
 
     let reduce = |v: COut| {
         if v < modulus_out {
@@ -118,11 +146,8 @@ pub fn lwe_modulus_switch_inplace_to_pow_of_2<CIn: UnsignedInteger, COut: Unsign
         }
     };
 
-    let switch = |v: CIn| {
-        reduce(COut::as_from(
-            (AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round(),
-        ))
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: CIn| reduce(COut::as_from(scale.apply(v.as_into())));
 
     c_out
         .a_mut()
@@ -137,17 +162,21 @@ pub fn lwe_modulus_switch_inplace_to_pow_of_2<CIn: UnsignedInteger, COut: Unsign
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// puts the result [`LweCiphertext<COut>`] with desired modulus `modulus_out`
 /// into `c_out`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_inplace_to_native<CIn: UnsignedInteger, COut: UnsignedInteger>(
-    c_in: LweCiphertext<CIn>,
+    c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
     c_out: &mut LweCiphertext<COut>,
 ) {
-    let modulus_in_f64: f64 = modulus_in.as_into();
-    let modulus_out_f64: f64 = 2.0f64.powi(COut::BITS as i32);
+    let q_in: u128 = modulus_in.as_into();
+    let q_out = native_modulus::<COut>();
 
-    let switch = |v: CIn| {
-        COut::as_from((AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round())
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: CIn| COut::as_from(scale.apply(v.as_into()));
 
     c_out
         .a_mut()
@@ -162,6 +191,11 @@ pub fn lwe_modulus_switch_inplace_to_native<CIn: UnsignedInteger, COut: Unsigned
 /// This function performs on a [`LweCiphertext<C>`] with modulus `modulus_in`,
 /// puts the result [`LweCiphertext<C>`] with desired modulus `modulus_out`
 /// back to `c`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_assign<C: UnsignedInteger>(
     c: &mut LweCiphertext<C>,
     modulus_in: ModulusValue<C>,
@@ -182,13 +216,18 @@ pub fn lwe_modulus_switch_assign<C: UnsignedInteger>(
 /// This function performs on a [`LweCiphertext<C>`] with modulus `modulus_in`,
 /// puts the result [`LweCiphertext<C>`] with desired modulus `modulus_out`
 /// back to `c`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_assign_normal<C: UnsignedInteger>(
     c: &mut LweCiphertext<C>,
     modulus_in: C,
     modulus_out: C,
 ) {
-    let modulus_in_f64: f64 = modulus_in.as_into();
-    let modulus_out_f64: f64 = modulus_out.as_into();
+    let q_in: u128 = modulus_in.as_into();
+    let q_out: u128 = modulus_out.as_into();
 
     let reduce = |v: C| {
         if v < modulus_out {
@@ -198,11 +237,8 @@ pub fn lwe_modulus_switch_assign_normal<C: UnsignedInteger>(
         }
     };
 
-    let switch = |v: C| {
-        reduce(C::as_from(
-            (AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round(),
-        ))
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: C| reduce(C::as_from(scale.apply(v.as_into())));
 
     c.a_mut().iter_mut().for_each(|v| *v = switch(*v));
     *c.b_mut() = switch(c.b());
@@ -213,12 +249,17 @@ pub fn lwe_modulus_switch_assign_normal<C: UnsignedInteger>(
 /// This function performs on a [`LweCiphertext<C>`] with modulus `modulus_in`,
 /// puts the result [`LweCiphertext<C>`] with desired modulus `modulus_out`
 /// back to `c`.
+///
+/// # Panics
+///
+/// Panics if a modulus is the native modulus of `u128`, or a coefficient times
+/// the output modulus overflows `u128`, which only happens for `u128` ciphertexts.
 pub fn lwe_modulus_switch_assign_native<C: UnsignedInteger>(
     c: &mut LweCiphertext<C>,
     modulus_out: C,
 ) {
-    let modulus_in_f64: f64 = 2.0f64.powi(C::BITS as i32);
-    let modulus_out_f64: f64 = modulus_out.as_into();
+    let q_in = native_modulus::<C>();
+    let q_out: u128 = modulus_out.as_into();
 
     let reduce = |v: C| {
         if v < modulus_out {
@@ -228,11 +269,8 @@ pub fn lwe_modulus_switch_assign_native<C: UnsignedInteger>(
         }
     };
 
-    let switch = |v: C| {
-        reduce(C::as_from(
-            (AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round(),
-        ))
-    };
+    let scale = MulDivRound::new(q_out, q_in);
+    let switch = |v: C| reduce(C::as_from(scale.apply(v.as_into())));
 
     c.a_mut().iter_mut().for_each(|v| *v = switch(*v));
     *c.b_mut() = switch(c.b());
@@ -0,0 +1,198 @@
+//! Floating-point helpers for noise analysis.
+//!
+//! Noise terms are tracked by their variance. They are assumed to be independent
+//! with zero mean, so variances add, and scale with the square of a constant factor.
+//! Products with secret key coefficients use the second moment `E[s^2]` of the key.
+//! The failure probability is estimated from the final variance by a Gaussian tail.
+
+use std::f64::consts::{LN_2, SQRT_2};
+
+use crate::{LweSecretKeyType, RingSecretKeyType};
+
+/// Returns the variance `w^2 / 12` of the continuous uniform distribution
+/// over an interval of width `w`.
+///
+/// A rounding error to the nearest integer has variance `uniform_variance(1.0)`.
+#[inline]
+pub fn uniform_variance(width: f64) -> f64 {
+    width * width / 12.0
+}
+
+/// Returns the second moment `(B^2 + 2) / 12` of a digit of the balanced
+/// decomposition with basis `B = 2^log_basis`, uniform in `[-B/2, B/2)`.
+#[inline]
+pub fn balanced_digit_second_moment(log_basis: u32) -> f64 {
+    let basis = 2.0f64.powi(log_basis as i32);
+    (basis * basis + 2.0) / 12.0
+}
+
+/// Returns the second moment `E[s^2]` of a coefficient of a LWE secret key.
+#[inline]
+pub fn lwe_secret_second_moment(key_type: LweSecretKeyType) -> f64 {
+    match key_type {
+        LweSecretKeyType::Binary => 1.0 / 2.0,
+        LweSecretKeyType::Ternary => 2.0 / 3.0,
+    }
+}
+
+/// Returns the second moment `E[s^2]` of a coefficient of a ring secret key.
+///
+/// `standard_deviation` is only used by [`RingSecretKeyType::Gaussian`].
+#[inline]
+pub fn ring_secret_second_moment(key_type: RingSecretKeyType, standard_deviation: f64) -> f64 {
+    match key_type {
+        RingSecretKeyType::Binary => 1.0 / 2.0,
+        RingSecretKeyType::Ternary => 2.0 / 3.0,
+        RingSecretKeyType::Gaussian => standard_deviation * standard_deviation,
+    }
+}
+
+/// Returns the variance of `c·e` for a noise `e` with variance `variance`.
+#[inline]
+pub fn scale_variance(variance: f64, factor: f64) -> f64 {
+    factor * factor * variance
+}
+
+/// Returns the variance of a sum of independent noise terms.
+#[inline]
+pub fn sum_variance(variances: impl IntoIterator<Item = f64>) -> f64 {
+    variances.into_iter().sum()
+}
+
+/// Returns the variance of `∑ e_i·s_i` over `dimension` terms, for noises `e_i`
+/// with variance `variance` and independent `s_i` with second moment `second_moment`.
+#[inline]
+pub fn inner_product_variance(dimension: usize, variance: f64, second_moment: f64) -> f64 {
+    dimension as f64 * variance * second_moment
+}
+
+/// Returns the variance added by a modulus switching of a LWE ciphertext,
+/// measured in units of the output modulus.
+///
+/// Every coefficient is rounded once, so it is `(1 + n·E[s^2]) / 12`.
+#[inline]
+pub fn modulus_switch_variance(dimension: usize, key_second_moment: f64) -> f64 {
+    uniform_variance(1.0) * (1.0 + dimension as f64 * key_second_moment)
+}
+
+/// Returns a bound `B` with `Pr[|e| >= B] <= failure_probability` for a
+/// sub-Gaussian noise `e` with parameter `standard_deviation`.
+///
+/// It is `σ·sqrt(2·ln(2/p))`, from the tail `Pr[|e| >= B] <= 2·exp(-B^2 / (2σ^2))`.
+#[inline]
+pub fn subgaussian_bound(standard_deviation: f64, failure_probability: f64) -> f64 {
+    standard_deviation * (2.0 * (2.0 / failure_probability).ln()).sqrt()
+}
+
+/// Returns `Pr[|e| >= bound]` for a Gaussian noise `e`, which is `erfc(B / (σ·√2))`.
+#[inline]
+pub fn failure_probability(standard_deviation: f64, bound: f64) -> f64 {
+    erfc(bound / (standard_deviation * SQRT_2))
+}
+
+/// Returns `log2(Pr[|e| >= bound])` for a Gaussian noise `e`.
+///
+/// Unlike [`failure_probability`], it does not underflow for tiny probabilities.
+#[inline]
+pub fn log2_failure_probability(standard_deviation: f64, bound: f64) -> f64 {
+    ln_erfc(bound / (standard_deviation * SQRT_2)) / LN_2
+}
+
+/// Returns the complementary error function `erfc(x)`.
+///
+/// It uses a Chebyshev approximation with a relative error below `1.2e-7` everywhere.
+#[inline]
+pub fn erfc(x: f64) -> f64 {
+    let value = ln_erfc_positive(x.abs()).exp();
+    if x >= 0.0 {
+        value
+    } else {
+        2.0 - value
+    }
+}
+
+/// Returns `ln(erfc(x))`, without underflow for large `x`.
+#[inline]
+pub fn ln_erfc(x: f64) -> f64 {
+    if x >= 0.0 {
+        ln_erfc_positive(x)
+    } else {
+        erfc(x).ln()
+    }
+}
+
+/// `ln(erfc(z))` for `z >= 0`, by the approximation of Numerical Recipes.
+fn ln_erfc_positive(z: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = t
+        * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    t.ln() - z * z - 1.26551223 + poly
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64, relative: f64) {
+        assert!((a - b).abs() <= relative * b.abs(), "{a} != {b}");
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert_close(erfc(0.0), 1.0, 1e-6);
+        assert_close(erfc(1.0), 0.157_299_207_050_285_13, 1e-6);
+        assert_close(erfc(3.0), 2.209_049_699_858_544e-5, 1e-6);
+        assert_close(erfc(-1.0), 1.842_700_792_949_715, 1e-6);
+
+        // `erfc(x) ~ exp(-x^2) / (x·√π)` for large `x`
+        let x: f64 = 30.0;
+        let asymptotic = -x * x - (x * std::f64::consts::PI.sqrt()).ln();
+        assert_close(ln_erfc(x), asymptotic, 1e-5);
+        assert_eq!(erfc(x), 0.0);
+    }
+
+    #[test]
+    fn test_failure_probability() {
+        assert_close(
+            failure_probability(1.0, 3.0),
+            2.699_796_063_260_207e-3,
+            1e-6,
+        );
+        assert_close(
+            log2_failure_probability(2.0, 6.0),
+            failure_probability(2.0, 6.0).log2(),
+            1e-9,
+        );
+
+        let p = 2.0f64.powi(-40);
+        let bound = subgaussian_bound(3.2, p);
+        assert!(failure_probability(3.2, bound) <= p);
+        assert!(log2_failure_probability(3.2, bound) <= -40.0);
+    }
+
+    #[test]
+    fn test_variances() {
+        assert_eq!(uniform_variance(1.0), 1.0 / 12.0);
+        // digits uniform in `[-2, 2)`: `(4 + 1 + 0 + 1) / 4`
+        assert_eq!(balanced_digit_second_moment(2), 1.5);
+        assert_eq!(scale_variance(2.0, 3.0), 18.0);
+        assert_eq!(sum_variance([1.0, 2.0, 3.5]), 6.5);
+        assert_eq!(
+            inner_product_variance(512, 1.0, lwe_secret_second_moment(LweSecretKeyType::Binary)),
+            256.0
+        );
+        assert_eq!(
+            modulus_switch_variance(
+                3,
+                ring_secret_second_moment(RingSecretKeyType::Ternary, 0.0)
+            ),
+            3.0 / 12.0
+        );
+    }
+}
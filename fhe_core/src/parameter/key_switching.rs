@@ -1,7 +1,12 @@
-use algebra::{integer::UnsignedInteger, random::DiscreteGaussian, Field};
+use algebra::{
+    integer::UnsignedInteger,
+    random::DiscreteGaussian,
+    utils::{DecodeLimits, LeBytes},
+    AlgebraError, Field,
+};
 
 /// Represents the parameters used for key switching in cryptographic schemes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct KeySwitchingParameters {
     /// The dimension of the input ciphertext.
     pub input_cipher_dimension: usize,
@@ -34,3 +39,45 @@ impl KeySwitchingParameters {
         DiscreteGaussian::new(0.0, self.noise_standard_deviation, Q::MINUS_ONE).unwrap()
     }
 }
+
+/// The parameters are encoded as the two dimensions in `u64`, `log_modulus` and `log_basis`
+/// in `u32`, the reverse length in `u64` with `0` for `None`, and the standard deviation.
+impl LeBytes for KeySwitchingParameters {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        (self.input_cipher_dimension as u64).write_le(buf);
+        (self.output_cipher_dimension as u64).write_le(buf);
+        self.log_modulus.write_le(buf);
+        self.log_basis.write_le(buf);
+        (self.reverse_length.unwrap_or(0) as u64).write_le(buf);
+        self.noise_standard_deviation.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let input_cipher_dimension = read_usize(bytes)?;
+        let output_cipher_dimension = read_usize(bytes)?;
+        limits.check_dimension(input_cipher_dimension)?;
+        limits.check_dimension(output_cipher_dimension)?;
+
+        let log_modulus = u32::read_le(bytes)?;
+        let log_basis = u32::read_le(bytes)?;
+        let reverse_length = read_usize(bytes)?;
+        let noise_standard_deviation = f64::read_le(bytes)?;
+        if !(noise_standard_deviation.is_finite() && noise_standard_deviation >= 0.) {
+            return Err(AlgebraError::DecodeErr);
+        }
+
+        Ok(Self {
+            input_cipher_dimension,
+            output_cipher_dimension,
+            log_modulus,
+            log_basis,
+            reverse_length: (reverse_length != 0).then_some(reverse_length),
+            noise_standard_deviation,
+        })
+    }
+}
+
+#[inline]
+fn read_usize(bytes: &mut &[u8]) -> Result<usize, AlgebraError> {
+    usize::try_from(u64::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)
+}
@@ -1,7 +1,9 @@
 mod key_switching;
 mod lwe;
 mod rlwe;
+mod security;
 
 pub use key_switching::KeySwitchingParameters;
 pub use lwe::LweParameters;
 pub use rlwe::GadgetRlweParameters;
+pub use security::SecurityProfile;
@@ -0,0 +1,21 @@
+/// The side-channel hardening level of encryption and decryption.
+///
+/// The noise sampler is shared by both profiles, only the arithmetic
+/// on the secret key, the message and the encryption randomness differs.
+/// The discrete Gaussian noise is sampled by rejection from a floating point
+/// normal distribution, so its running time and branches depend on the sampled
+/// noise under both profiles. [`SecurityProfile::Hardened`] does not protect the noise.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityProfile {
+    /// The fastest code path.
+    ///
+    /// It may branch on the secret key, the message or the
+    /// encryption randomness, e.g. skipping zero entries.
+    #[default]
+    Performance,
+    /// No branches or memory accesses depending on the secret key,
+    /// the message or the uniform and binary encryption randomness.
+    ///
+    /// Intended for clients running next to untrusted co-tenants.
+    Hardened,
+}
@@ -3,6 +3,8 @@ use algebra::{
     reduce::ModulusValue,
 };
 
+use crate::{constant_time, FHECoreError};
+
 /// Encodes a message.
 ///
 /// # Parameters
@@ -22,6 +24,43 @@ where
     }
 }
 
+/// Encodes a message in constant time.
+///
+/// Unlike [`encode`], the normal modulus case uses integer
+/// arithmetic instead of floating point rounding.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+/// - This function needs `t` be power of 2.
+///
+/// # Errors
+///
+/// Returns [`FHECoreError::MessageOutOfRange`] if the message exceeds the message space.
+#[inline]
+pub fn encode_constant_time<M, C>(message: M, t: C, q: ModulusValue<C>) -> Result<C, FHECoreError>
+where
+    C: UnsignedInteger,
+    M: TryInto<C>,
+{
+    let message: C = message
+        .try_into()
+        .map_err(|_| FHECoreError::MessageOutOfRange)?;
+    if message >= t {
+        return Err(FHECoreError::MessageOutOfRange);
+    }
+
+    Ok(match q {
+        // These two are already shifts only.
+        ModulusValue::Native => encode_native(message, t),
+        ModulusValue::PowerOf2(q) => encode_pow_of_2(message, t, q),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            constant_time::encode_normal(message, t, q)
+        }
+    })
+}
+
 /// Encodes a message.
 ///
 /// # Parameters
@@ -116,6 +155,38 @@ where
     }
 }
 
+/// Decodes an encode value in constant time.
+///
+/// Unlike [`decode`], the normal modulus case uses fixed-step integer
+/// arithmetic instead of floating point rounding and branches.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+/// - This function needs `t` be power of 2.
+///
+/// # Errors
+///
+/// Returns [`FHECoreError::DecodedMessageOutOfRange`] if the decoded message cannot fit in `M`.
+#[inline]
+pub fn decode_constant_time<M, C>(cipher: C, t: C, q: ModulusValue<C>) -> Result<M, FHECoreError>
+where
+    M: TryFrom<C>,
+    C: UnsignedInteger,
+{
+    let decoded: C = match q {
+        // These two are already shifts and masks only.
+        ModulusValue::Native => decode_native(cipher, t),
+        ModulusValue::PowerOf2(q) => decode_pow_of_2(cipher, t, q),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            constant_time::decode_normal(cipher, t, q)
+        }
+    };
+
+    M::try_from(decoded).map_err(|_| FHECoreError::DecodedMessageOutOfRange)
+}
+
 pub fn decode_normal<M, C>(cipher: C, t: C, q: C) -> M
 where
     M: TryFrom<C>,
@@ -9,7 +9,8 @@ use lattice::{Lwe, NttRlwe, NumRlwe};
 use rand::{prelude::Distribution, CryptoRng, Rng};
 
 use crate::{
-    encode, CmLweCiphertext, LweCiphertext, LweParameters, LweSecretKey, NttRlweSecretKey,
+    constant_time, encode, encode_constant_time, CmLweCiphertext, FHECoreError, LweCiphertext,
+    LweParameters, LweSecretKey, NttRlweSecretKey, SecurityProfile,
 };
 
 /// Represents a public key for the Learning with Errors (LWE) cryptographic scheme.
@@ -122,6 +123,75 @@ impl<C: UnsignedInteger> LwePublicKey<C> {
 
         result
     }
+
+    /// Encrypts a message using the LWE public key with the given [`SecurityProfile`].
+    ///
+    /// [`LwePublicKey::encrypt`] only adds the public key samples selected by
+    /// the binary randomness `r`, which leaks `r` through timing and memory accesses.
+    /// With [`SecurityProfile::Hardened`], every sample is masked and added, and all
+    /// modular additions are computed in constant time. The noise is still sampled
+    /// by [`DiscreteGaussian`], which is not constant time, see [`SecurityProfile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::MessageOutOfRange`] if the message exceeds the message space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `profile` is [`SecurityProfile::Hardened`] and `C` has more than 64 bits.
+    pub fn encrypt_with_profile<Msg, R, Modulus>(
+        &self,
+        message: Msg,
+        params: &LweParameters<C, Modulus>,
+        profile: SecurityProfile,
+        rng: &mut R,
+    ) -> Result<LweCiphertext<C>, FHECoreError>
+    where
+        Msg: TryInto<C>,
+        R: Rng + CryptoRng,
+        Modulus: RingReduce<C>,
+    {
+        let q = params.cipher_modulus_value;
+        let message: C = message
+            .try_into()
+            .map_err(|_| FHECoreError::MessageOutOfRange)?;
+
+        if profile == SecurityProfile::Performance {
+            if message >= params.plain_modulus_value {
+                return Err(FHECoreError::MessageOutOfRange);
+            }
+            return Ok(self.encrypt(message, params, rng));
+        }
+
+        let dimension = params.dimension;
+        let gaussian = params.noise_distribution();
+
+        let r: Vec<C> = sample_binary_values(dimension, rng);
+
+        let mut result = LweCiphertext::zero(dimension);
+
+        *result.b_mut() = encode_constant_time::<C, C>(message, params.plain_modulus_value, q)?;
+
+        for (zero, &ri) in self.public_key.iter().zip(r.iter()) {
+            for (x, &y) in result.a_mut().iter_mut().zip(zero.a()) {
+                *x = constant_time::add(*x, constant_time::mask(y, ri), q);
+            }
+            let b = constant_time::add(result.b(), constant_time::mask(zero.b(), ri), q);
+            *result.b_mut() = b;
+        }
+
+        for (ai, ei) in result
+            .a_mut()
+            .iter_mut()
+            .zip(gaussian.sample_iter(&mut *rng))
+        {
+            *ai = constant_time::add(*ai, ei, q);
+        }
+        let b = constant_time::add(result.b(), gaussian.sample(rng), q);
+        *result.b_mut() = b;
+
+        Ok(result)
+    }
 }
 
 /// Represents a public key for the Learning with Errors (LWE) cryptographic scheme in RLWE mode.
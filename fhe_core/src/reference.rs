@@ -0,0 +1,159 @@
+//! Slow and obviously correct reference implementations of the core operations.
+//!
+//! These functions work on plain `u64` values with an explicit modulus and use
+//! `u128`/`i128` arithmetic everywhere, so they are easy to check by reading.
+//! They are meant as oracles in randomized tests of the optimized kernels,
+//! never for production use.
+//!
+//! Signed secret keys are given as `i64` values, e.g. `-1`, `0` or `1`.
+
+/// Reduces a signed value into `[0, modulus)`.
+#[inline]
+fn reduce_signed(value: i128, modulus: u64) -> u64 {
+    value.rem_euclid(modulus as i128) as u64
+}
+
+/// Schoolbook multiplication of `a` and `b` in `Z_q[X]/(X^N + 1)`.
+///
+/// The products are computed in `u128`, so any `u64` modulus works.
+///
+/// # Panics
+///
+/// Panics if the lengths of `a` and `b` are not equal.
+pub fn negacyclic_mul(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    assert_eq!(a.len(), b.len());
+    let n = a.len();
+    let modulus = modulus as u128;
+
+    let mut result = vec![0u128; n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = (x as u128 * y as u128) % modulus;
+            // X^n = -1
+            let (k, product) = if i + j < n {
+                (i + j, product)
+            } else {
+                (i + j - n, modulus - product)
+            };
+            result[k] = (result[k] + product) % modulus;
+        }
+    }
+
+    result.into_iter().map(|v| v as u64).collect()
+}
+
+/// Multiplies `a` by the monomial `X^exponent` in `Z_q[X]/(X^N + 1)`.
+///
+/// The product of an empty `a` is empty.
+pub fn monomial_mul(a: &[u64], exponent: usize, modulus: u64) -> Vec<u64> {
+    let n = a.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let exponent = exponent % (2 * n);
+
+    let mut result = vec![0u64; n];
+    for (i, &x) in a.iter().enumerate() {
+        let j = i + exponent;
+        // X^n = -1, X^{2n} = 1
+        let negate = (j / n) % 2 == 1;
+        result[j % n] = if negate {
+            reduce_signed(-(x as i128), modulus)
+        } else {
+            x % modulus
+        };
+    }
+    result
+}
+
+/// Returns the count of low bits which are dropped by the approximate decomposition.
+#[inline]
+fn drop_bits(modulus: u64, log_basis: u32, length: usize) -> u32 {
+    let modulus_bits = u64::BITS - modulus.leading_zeros();
+    let kept_bits = log_basis * length as u32;
+    assert!(log_basis > 0 && length > 0 && kept_bits <= modulus_bits);
+    modulus_bits - kept_bits
+}
+
+/// Textbook approximate signed decomposition of `value` in `[0, modulus)`.
+///
+/// The value is lifted to its centered representative, rounded to a multiple
+/// of `2^drop_bits` where `drop_bits = bits(modulus) - length * log_basis`,
+/// and split into `length` balanced digits of base `B = 2^log_basis`,
+/// least significant first.
+///
+/// Every digit lies in `[-B/2, B/2)`, except the most significant one
+/// which absorbs the final carry and lies in `[-B/2, B/2]`.
+///
+/// # Panics
+///
+/// Panics if `log_basis` or `length` is `0`,
+/// or `length * log_basis` is larger than the bit count of `modulus`.
+pub fn signed_decompose(value: u64, modulus: u64, log_basis: u32, length: usize) -> Vec<i64> {
+    let drop_bits = drop_bits(modulus, log_basis, length);
+    let basis = 1i128 << log_basis;
+
+    let value = (value % modulus) as i128;
+    let centered = if 2 * value >= modulus as i128 {
+        value - modulus as i128
+    } else {
+        value
+    };
+
+    // round half up to a multiple of `2^drop_bits`
+    let mut rest = if drop_bits == 0 {
+        centered
+    } else {
+        (centered + (1i128 << (drop_bits - 1))).div_euclid(1i128 << drop_bits)
+    };
+
+    let mut digits: Vec<i64> = (0..length)
+        .map(|_| {
+            let mut digit = rest.rem_euclid(basis);
+            if digit >= basis / 2 {
+                digit -= basis;
+            }
+            rest = (rest - digit) / basis;
+            digit as i64
+        })
+        .collect();
+
+    *digits.last_mut().unwrap() += (rest * basis) as i64;
+    digits
+}
+
+/// Recomposes digits of [`signed_decompose`], i.e. `sum(d_i * 2^drop_bits * B^i) mod modulus`.
+pub fn signed_recompose(digits: &[i64], modulus: u64, log_basis: u32) -> u64 {
+    let drop_bits = drop_bits(modulus, log_basis, digits.len());
+
+    let value = digits.iter().enumerate().fold(0i128, |acc, (i, &d)| {
+        let scalar = reduce_signed(1i128 << (drop_bits + i as u32 * log_basis), modulus);
+        (acc + d as i128 * scalar as i128) % modulus as i128
+    });
+    reduce_signed(value, modulus)
+}
+
+/// Computes the LWE phase `b - <a, s> mod modulus`.
+///
+/// # Panics
+///
+/// Panics if the lengths of `a` and `secret` are not equal.
+pub fn lwe_phase(a: &[u64], b: u64, secret: &[i64], modulus: u64) -> u64 {
+    assert_eq!(a.len(), secret.len());
+
+    let inner_product = a.iter().zip(secret).fold(0i128, |acc, (&a_i, &s_i)| {
+        (acc + a_i as i128 * s_i as i128) % modulus as i128
+    });
+    reduce_signed(b as i128 - inner_product, modulus)
+}
+
+/// Naive blind rotation in the clear.
+///
+/// The input LWE ciphertext `(a, b)` lives modulo `2N`, where `N` is the length of `lut`.
+/// The result is `lut * X^{-(b - <a, s>)}` in `Z_q[X]/(X^N + 1)`, which is what an
+/// encrypted blind rotation of the same ciphertext decrypts to, up to noise.
+pub fn blind_rotate(lut: &[u64], a: &[u64], b: u64, secret: &[i64], modulus: u64) -> Vec<u64> {
+    let twice_n = 2 * lut.len() as u64;
+    let phase = lwe_phase(a, b, secret, twice_n);
+    monomial_mul(lut, (twice_n - phase) as usize, modulus)
+}
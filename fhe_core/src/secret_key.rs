@@ -5,13 +5,23 @@ use algebra::{
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     random::{sample_binary_values, sample_ternary_values, DiscreteGaussian},
-    reduce::RingReduce,
-    Field, NttField,
+    reduce::{ModulusValue, RingReduce},
+    utils::{
+        read_coeffs, read_header, read_modulus, read_width, write_header, write_modulus,
+        write_width, DecodeLimits, LeBytes,
+    },
+    AlgebraError, Field, NttField,
 };
 use num_traits::{ConstOne, ConstZero, One, Zero};
-use rand::{CryptoRng, Rng};
+use rand::{
+    distributions::{Distribution, Uniform},
+    CryptoRng, Rng,
+};
 
-use crate::{decode, encode, LweCiphertext, LweParameters};
+use crate::{
+    constant_time, decode, decode_constant_time, encode, encode_constant_time, FHECoreError,
+    LweCiphertext, LweParameters, RlweCiphertext, SecurityProfile,
+};
 
 /// The distribution type of the LWE Secret Key.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -35,6 +45,49 @@ pub enum RingSecretKeyType {
     Gaussian,
 }
 
+/// The distribution type is encoded as one byte: `0` for binary, `1` for ternary.
+impl LeBytes for LweSecretKeyType {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.push(match self {
+            Self::Binary => 0,
+            Self::Ternary => 1,
+        });
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        match u8::read_le(bytes)? {
+            0 => Ok(Self::Binary),
+            1 => Ok(Self::Ternary),
+            _ => Err(AlgebraError::DecodeErr),
+        }
+    }
+}
+
+/// The distribution type is encoded as one byte:
+/// `0` for binary, `1` for ternary, `2` for gaussian.
+impl LeBytes for RingSecretKeyType {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.push(match self {
+            Self::Binary => 0,
+            Self::Ternary => 1,
+            Self::Gaussian => 2,
+        });
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], _: &DecodeLimits) -> Result<Self, AlgebraError> {
+        match u8::read_le(bytes)? {
+            0 => Ok(Self::Binary),
+            1 => Ok(Self::Ternary),
+            2 => Ok(Self::Gaussian),
+            _ => Err(AlgebraError::DecodeErr),
+        }
+    }
+}
+
 /// Represents a secret key for the Learning with Errors (LWE) cryptographic scheme.
 ///
 /// # Type Parameters
@@ -177,6 +230,144 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         ciphertext
     }
 
+    /// Encrypts an already encoded value of `Z_q` into [`LweCiphertext<C>`].
+    ///
+    /// Use it together with an [`Encoder`](crate::Encoder).
+    #[inline]
+    pub fn encrypt_encoded<R, Modulus>(
+        &self,
+        encoded: C,
+        params: &LweParameters<C, Modulus>,
+        rng: &mut R,
+    ) -> LweCiphertext<C>
+    where
+        R: Rng + CryptoRng,
+        Modulus: RingReduce<C>,
+    {
+        let gaussian = params.noise_distribution();
+        let modulus = params.cipher_modulus;
+
+        let mut ciphertext =
+            LweCiphertext::generate_random_zero_sample(self.as_ref(), modulus, gaussian, rng);
+        modulus.reduce_add_assign(ciphertext.b_mut(), encoded);
+
+        ciphertext
+    }
+
+    /// Encrypts message into [`LweCiphertext<C>`] with the given [`SecurityProfile`].
+    ///
+    /// With [`SecurityProfile::Hardened`], the inner product with the secret key and
+    /// the message encoding are computed in constant time. The noise is still sampled
+    /// by [`DiscreteGaussian`], which is not constant time, see [`SecurityProfile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::MessageOutOfRange`] if the message exceeds the message space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `profile` is [`SecurityProfile::Hardened`] and `C` has more than 64 bits.
+    pub fn encrypt_with_profile<Msg, R, Modulus>(
+        &self,
+        message: Msg,
+        params: &LweParameters<C, Modulus>,
+        profile: SecurityProfile,
+        rng: &mut R,
+    ) -> Result<LweCiphertext<C>, FHECoreError>
+    where
+        Msg: TryInto<C>,
+        R: Rng + CryptoRng,
+        Modulus: RingReduce<C>,
+    {
+        let q = params.cipher_modulus_value;
+        let message: C = message
+            .try_into()
+            .map_err(|_| FHECoreError::MessageOutOfRange)?;
+
+        match profile {
+            SecurityProfile::Performance => {
+                if message >= params.plain_modulus_value {
+                    return Err(FHECoreError::MessageOutOfRange);
+                }
+                Ok(self.encrypt(message, params, rng))
+            }
+            SecurityProfile::Hardened => {
+                let m = encode_constant_time::<C, C>(message, params.plain_modulus_value, q)?;
+                let gaussian = params.noise_distribution();
+                let uniform = Uniform::new_inclusive(C::ZERO, params.cipher_modulus_minus_one);
+
+                let a: Vec<C> = uniform
+                    .sample_iter(&mut *rng)
+                    .take(self.dimension())
+                    .collect();
+                let e = gaussian.sample(rng);
+
+                let b = constant_time::add(constant_time::dot_product(&a, self.as_ref(), q), e, q);
+
+                Ok(LweCiphertext::new(a, constant_time::add(b, m, q)))
+            }
+        }
+    }
+
+    /// Decrypts the [`LweCiphertext`] back to message with the given [`SecurityProfile`].
+    ///
+    /// With [`SecurityProfile::Hardened`], the inner product with the secret key,
+    /// the modular reduction and the decoding have no branches or memory accesses
+    /// depending on the secret key or the message, at the cost of being slower than
+    /// [`LweSecretKey::decrypt`] for non power of 2 modulus.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::DecodedMessageOutOfRange`] if the decoded message cannot fit in `Msg`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `profile` is [`SecurityProfile::Hardened`] and `C` has more than 64 bits.
+    #[inline]
+    pub fn decrypt_with_profile<Msg, Modulus>(
+        &self,
+        cipher_text: &LweCiphertext<C>,
+        params: &LweParameters<C, Modulus>,
+        profile: SecurityProfile,
+    ) -> Result<Msg, FHECoreError>
+    where
+        Msg: TryFrom<C>,
+        Modulus: RingReduce<C>,
+    {
+        let t = params.plain_modulus_value;
+        let q = params.cipher_modulus_value;
+
+        let decoded: C = match profile {
+            SecurityProfile::Performance => self.decrypt(cipher_text, params),
+            SecurityProfile::Hardened => {
+                let plaintext =
+                    constant_time::phase(cipher_text.a(), self.as_ref(), cipher_text.b(), q);
+                decode_constant_time(plaintext, t, q)?
+            }
+        };
+
+        Msg::try_from(decoded).map_err(|_| FHECoreError::DecodedMessageOutOfRange)
+    }
+
+    /// Returns the phase `b - <a, s>` of the [`LweCiphertext`],
+    /// i.e. the encoded value with noise.
+    ///
+    /// Use it together with an [`Encoder`](crate::Encoder).
+    #[inline]
+    pub fn phase<Modulus>(
+        &self,
+        cipher_text: &LweCiphertext<C>,
+        params: &LweParameters<C, Modulus>,
+    ) -> C
+    where
+        Modulus: RingReduce<C>,
+    {
+        let modulus = params.cipher_modulus;
+
+        let a_mul_s = modulus.reduce_dot_product(cipher_text.a(), self);
+        modulus.reduce_sub(cipher_text.b(), a_mul_s)
+    }
+
     /// Decrypts the [`LweCiphertext`] back to message.
     #[inline]
     pub fn decrypt<Msg, Modulus>(
@@ -229,6 +420,27 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
     }
 }
 
+/// The key is encoded after its header and descriptor as its distribution type,
+/// followed by its coefficients.
+impl<C: UnsignedInteger + LeBytes> LeBytes for LweSecretKey<C> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"LWSK", buf);
+        write_width::<C>(buf);
+        self.distr.write_le(buf);
+        self.key.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"LWSK", bytes)?;
+        read_width::<C>(version, bytes)?;
+        let distr = LweSecretKeyType::read_le(bytes)?;
+        let key = read_coeffs(bytes, limits)?;
+        Ok(Self { key, distr })
+    }
+}
+
 /// Represents a secret key for the Ring Learning with Errors (RLWE) cryptographic scheme.
 ///
 /// # Type Parameters
@@ -336,6 +548,83 @@ impl<F: NttField> RlweSecretKey<F> {
     pub fn distr(&self) -> RingSecretKeyType {
         self.distr
     }
+
+    /// Computes the phase `b_i - (a * s)_i` of the coefficient `index` of `ciphertext`.
+    ///
+    /// It's the inner product of the LWE sample extracted at `index` with the
+    /// secret key, which costs `O(N)` without transforming the whole ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the ring dimension.
+    pub fn phase_at(&self, ciphertext: &RlweCiphertext<F>, index: usize) -> <F as Field>::ValueT {
+        let a = ciphertext.a_slice();
+        let (low, high) = self.key.as_slice().split_at(index + 1);
+
+        // `(a * s)_i = Σ_{j <= i} a_{i-j} s_j - Σ_{j > i} a_{N+i-j} s_j`
+        let positive = low
+            .iter()
+            .zip(a[..=index].iter().rev())
+            .fold(<F as Field>::ValueT::ZERO, |acc, (&s, &a)| {
+                F::mul_add(s, a, acc)
+            });
+        let negative = high
+            .iter()
+            .zip(a[index + 1..].iter().rev())
+            .fold(<F as Field>::ValueT::ZERO, |acc, (&s, &a)| {
+                F::mul_add(s, a, acc)
+            });
+
+        F::sub(F::add(ciphertext.b()[index], negative), positive)
+    }
+
+    /// Decrypts only the coefficients at `indices` of a packed [`RlweCiphertext`].
+    ///
+    /// Every coefficient costs `O(N)`, which is cheaper than decrypting the
+    /// whole ciphertext when only a few results are needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of the ring dimension.
+    pub fn decrypt_coefficients<Msg>(
+        &self,
+        ciphertext: &RlweCiphertext<F>,
+        indices: &[usize],
+        plain_modulus: <F as Field>::ValueT,
+    ) -> Vec<Msg>
+    where
+        Msg: TryFrom<<F as Field>::ValueT>,
+    {
+        let q = ModulusValue::Prime(F::MODULUS_VALUE);
+        indices
+            .iter()
+            .map(|&index| decode(self.phase_at(ciphertext, index), plain_modulus, q))
+            .collect()
+    }
+}
+
+/// The key is encoded after its header and descriptor as its distribution type,
+/// followed by its polynomial.
+impl<F: NttField> LeBytes for RlweSecretKey<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"RLSK", buf);
+        write_modulus::<F>(buf);
+        self.distr.write_le(buf);
+        self.key.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"RLSK", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let distr = RingSecretKeyType::read_le(bytes)?;
+        let key = FieldPolynomial::read_le_with(bytes, limits)?;
+        Ok(Self { key, distr })
+    }
 }
 
 /// Represents a secret key for the Number Theoretic Transform (NTT) Ring Learning with Errors (RLWE) cryptographic scheme.
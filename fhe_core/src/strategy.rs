@@ -0,0 +1,148 @@
+//! [proptest] strategies for parameters, plaintexts and ciphertexts.
+//!
+//! Applications can use them to property-test their own encrypted pipelines:
+//!
+//! ```ignore
+//! use fhe_core::strategy::{lwe_sample, pow_of_2_lwe_parameters};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn decrypts(sample in lwe_sample(pow_of_2_lwe_parameters())) {
+//!         let m: u32 = sample.secret_key.decrypt(&sample.ciphertext, &sample.params);
+//!         prop_assert_eq!(m, sample.message);
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use algebra::{
+    integer::UnsignedInteger,
+    modulus::{BarrettModulus, PowOf2Modulus},
+    reduce::RingReduce,
+};
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{LweCiphertext, LweParameters, LweSecretKey, LweSecretKeyType};
+
+/// The noise standard deviation used by the generated parameters.
+const NOISE_STANDARD_DEVIATION: f64 = 3.2;
+
+/// Some NTT friendly primes used as prime cipher moduli.
+const PRIMES: [u32; 3] = [132120577, 134215681, 1073479681];
+
+/// A strategy for LWE secret key distributions.
+pub fn secret_key_type() -> impl Strategy<Value = LweSecretKeyType> {
+    prop_oneof![
+        Just(LweSecretKeyType::Binary),
+        Just(LweSecretKeyType::Ternary),
+    ]
+}
+
+/// A strategy for valid [`LweParameters`] with a power of 2 cipher modulus.
+///
+/// The cipher modulus is `2^12` to `2^31`, the plain modulus is `2` to `16`, and the
+/// dimension is `16` to `1024`, so that a fresh ciphertext always decrypts correctly.
+pub fn pow_of_2_lwe_parameters() -> impl Strategy<Value = LweParameters<u32, PowOf2Modulus<u32>>> {
+    (16usize..=1024, 12u32..=31, 1u32..=4, secret_key_type()).prop_map(
+        |(dimension, log_q, log_t, secret_key_type)| {
+            LweParameters::new(
+                dimension,
+                1 << log_t,
+                <PowOf2Modulus<u32>>::new(1 << log_q),
+                secret_key_type,
+                NOISE_STANDARD_DEVIATION,
+            )
+        },
+    )
+}
+
+/// A strategy for valid [`LweParameters`] with a prime cipher modulus.
+///
+/// The plain modulus is `2` to `16` and the dimension is `16` to `1024`,
+/// so that a fresh ciphertext always decrypts correctly.
+pub fn prime_lwe_parameters() -> impl Strategy<Value = LweParameters<u32, BarrettModulus<u32>>> {
+    (
+        16usize..=1024,
+        proptest::sample::select(&PRIMES[..]),
+        1u32..=4,
+        secret_key_type(),
+    )
+        .prop_map(|(dimension, q, log_t, secret_key_type)| {
+            LweParameters::new(
+                dimension,
+                1 << log_t,
+                <BarrettModulus<u32>>::new(q),
+                secret_key_type,
+                NOISE_STANDARD_DEVIATION,
+            )
+        })
+}
+
+/// A strategy for plaintexts in `[0, plain_modulus)`.
+pub fn plaintext<C: UnsignedInteger>(plain_modulus: C) -> impl Strategy<Value = C> {
+    let t: u64 = plain_modulus.as_into();
+    (0..t).prop_map(C::as_from)
+}
+
+/// An LWE ciphertext together with everything needed to check it.
+#[derive(Clone)]
+pub struct LweSample<C: UnsignedInteger, Modulus: RingReduce<C>> {
+    /// The parameters.
+    pub params: LweParameters<C, Modulus>,
+    /// The secret key.
+    pub secret_key: LweSecretKey<C>,
+    /// The encrypted message.
+    pub message: C,
+    /// The fresh ciphertext of `message`.
+    pub ciphertext: LweCiphertext<C>,
+}
+
+impl<C, Modulus> fmt::Debug for LweSample<C, Modulus>
+where
+    C: UnsignedInteger,
+    Modulus: RingReduce<C> + fmt::Debug,
+{
+    /// The secret key is not printed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LweSample")
+            .field("params", &self.params)
+            .field("message", &self.message)
+            .field("ciphertext", &self.ciphertext)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A strategy for [`LweSample`]s under the parameters generated by `params`.
+///
+/// The secret key and the ciphertext come from a [`StdRng`] seeded by proptest,
+/// so failing cases shrink and replay deterministically.
+pub fn lwe_sample<C, Modulus>(
+    params: impl Strategy<Value = LweParameters<C, Modulus>>,
+) -> impl Strategy<Value = LweSample<C, Modulus>>
+where
+    C: UnsignedInteger,
+    Modulus: RingReduce<C> + fmt::Debug,
+{
+    params
+        .prop_flat_map(|params| {
+            (
+                Just(params),
+                plaintext(params.plain_modulus_value),
+                any::<[u8; 32]>(),
+            )
+        })
+        .prop_map(|(params, message, seed)| {
+            let mut rng = StdRng::from_seed(seed);
+            let secret_key = LweSecretKey::generate(&params, &mut rng);
+            let ciphertext = secret_key.encrypt(message, &params, &mut rng);
+            LweSample {
+                params,
+                secret_key,
+                message,
+                ciphertext,
+            }
+        })
+}
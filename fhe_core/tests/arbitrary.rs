@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    modulus::{BarrettModulus, PowOf2Modulus},
+    random::DiscreteGaussian,
+    utils::LeBytes,
+    Field, NttField, U32FieldEval,
+};
+use arbitrary::{Arbitrary, Unstructured};
+use fhe_core::{
+    BlindRotationKey, KeySwitchingParameters, LweKeySwitchingKeyRlweMode, LweSecretKey,
+    LweSecretKeyType, NonPowOf2LweKeySwitchingKey, NttRlweSecretKey, PowOf2LweKeySwitchingKey,
+    RingSecretKeyType, RlweKeySwitchingKey, RlweSecretKey,
+};
+use rand::{thread_rng, Rng, RngCore};
+
+type Fp = U32FieldEval<132120577>;
+
+const ROUNDS: usize = 64;
+const MUTATIONS: usize = 64;
+
+/// Decodes truncated and corrupted copies of `bytes`, none of which may panic.
+fn check_mutations<T: LeBytes>(bytes: &[u8], rng: &mut impl Rng) {
+    for _ in 0..MUTATIONS {
+        let mut bad = bytes.to_vec();
+        let i = rng.gen_range(0..bad.len());
+        match rng.gen_range(0..3) {
+            0 => bad.truncate(i),
+            1 => bad[i] ^= rng.gen::<u8>() | 1,
+            _ => bad[i..(i + 8).min(bytes.len())].fill(0xff),
+        }
+        let _ = T::from_le_slice(&bad);
+    }
+}
+
+/// Checks that arbitrary values survive the round trip,
+/// and that random and corrupted encodings are rejected without panics.
+fn check<T: for<'a> Arbitrary<'a> + LeBytes>() {
+    let mut rng = thread_rng();
+    for _ in 0..ROUNDS {
+        let mut data = vec![0u8; rng.gen_range(0..1024)];
+        rng.fill_bytes(&mut data);
+        let _ = T::from_le_slice(&data);
+
+        let Ok(value) = T::arbitrary(&mut Unstructured::new(&data)) else {
+            continue;
+        };
+        let bytes = value.to_le_vec();
+        assert_eq!(T::from_le_slice(&bytes).unwrap().to_le_vec(), bytes);
+        check_mutations::<T>(&bytes, &mut rng);
+    }
+}
+
+/// Checks that a generated key survives the round trip,
+/// and that corrupted encodings are rejected without panics.
+fn check_key<T: LeBytes>(key: &T) {
+    let bytes = key.to_le_vec();
+    assert_eq!(T::from_le_slice(&bytes).unwrap().to_le_vec(), bytes);
+    check_mutations::<T>(&bytes, &mut thread_rng());
+}
+
+#[test]
+fn test_arbitrary_secret_keys() {
+    check::<LweSecretKey<u16>>();
+    check::<LweSecretKey<u64>>();
+    check::<RlweSecretKey<Fp>>();
+    check::<KeySwitchingParameters>();
+}
+
+#[test]
+fn test_corrupted_keys() {
+    let mut rng = thread_rng();
+
+    let lwe_sk = LweSecretKey::new(
+        (0..64).map(|_| rng.gen_range(0..2u16)).collect(),
+        LweSecretKeyType::Binary,
+    );
+    let sk_out = LweSecretKey::new(
+        (0..32).map(|_| rng.gen_range(0..2u16)).collect(),
+        LweSecretKeyType::Binary,
+    );
+    let params = KeySwitchingParameters {
+        input_cipher_dimension: 64,
+        output_cipher_dimension: 32,
+        log_modulus: 14,
+        log_basis: 3,
+        reverse_length: None,
+        noise_standard_deviation: 3.2,
+    };
+    check_key(&PowOf2LweKeySwitchingKey::generate(
+        &lwe_sk,
+        &sk_out,
+        params,
+        PowOf2Modulus::<u16>::new(1 << 14),
+        &mut rng,
+    ));
+
+    let lwe_sk = LweSecretKey::new(
+        lwe_sk.as_ref().iter().map(|&v| v as u32).collect(),
+        LweSecretKeyType::Binary,
+    );
+    let params = KeySwitchingParameters {
+        log_modulus: 27,
+        ..params
+    };
+    check_key(&NonPowOf2LweKeySwitchingKey::generate(
+        &lwe_sk,
+        &sk_out,
+        params,
+        BarrettModulus::<u32>::new(134215681),
+        &mut rng,
+    ));
+
+    let ntt_table = Arc::new(Fp::generate_ntt_table(6).unwrap());
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, 64, None, &mut rng);
+    check_key(&LweKeySwitchingKeyRlweMode::generate(
+        &rlwe_sk,
+        &sk_out,
+        KeySwitchingParameters {
+            log_basis: 7,
+            ..params
+        },
+        Arc::clone(&ntt_table),
+        &mut rng,
+    ));
+
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+    let other = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Binary, 64, None, &mut rng);
+    let other = NttRlweSecretKey::from_coeff_secret_key(&other, &ntt_table);
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    check_key(&RlweKeySwitchingKey::generate(
+        &ntt_rlwe_sk,
+        &other,
+        &basis,
+        gaussian,
+        Arc::clone(&ntt_table),
+        &mut rng,
+    ));
+    check_key(&BlindRotationKey::generate(
+        &lwe_sk,
+        &ntt_rlwe_sk,
+        &basis,
+        gaussian,
+        ntt_table,
+        &mut rng,
+    ));
+}
@@ -1,13 +1,22 @@
+use std::{io::Cursor, sync::Arc};
+
 use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
     modulus::{BarrettModulus, PowOf2Modulus},
+    polynomial::FieldPolynomial,
+    random::{DiscreteGaussian, Prg},
     reduce::ModulusValue,
+    utils::{DecodeLimits, Fingerprint, KeyPack, LeBytes, Manifest},
+    Field, NttField, U32FieldEval,
 };
 use fhe_core::{
-    lwe_modulus_switch, KeySwitchingParameters, LweParameters, LwePublicKey, LwePublicKeyRlweMode,
-    LweSecretKey, LweSecretKeyType, NonPowOf2LweKeySwitchingKey,
+    lwe_modulus_switch, BalancedBaseEncoder, BlindRotationKey, Encoder, FHECoreError,
+    KeySwitchingParameters, LweParameters, LwePublicKey, LwePublicKeyRlweMode, LweSecretKey,
+    LweSecretKeyType, MsbEncoder, NonPowOf2LweKeySwitchingKey, NttRlweSecretKey, RingSecretKeyType,
+    RlweSecretKey, SecurityProfile,
 };
 use lattice::Lwe;
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, thread_rng, Rng, SeedableRng};
 
 #[test]
 fn test_lwe_pk() {
@@ -129,6 +138,8 @@ fn test_key_switch() {
         &mut rng,
     );
 
+    let mut reused = <Lwe<u32>>::zero(params_out.dimension);
+
     for i in 0..20 {
         // encrypt message with secret key
         let message: MsgT = rng.sample(msg_distr);
@@ -139,6 +150,8 @@ fn test_key_switch() {
 
         // key switch
         let c2 = key_switch_key.key_switch(&c, modulus_in);
+        key_switch_key.key_switch_inplace(&c, modulus_in, &mut reused);
+        assert_eq!(reused, c2);
         println!("key switch done");
 
         // modulus switch
@@ -155,3 +168,337 @@ fn test_key_switch() {
         println!("round {} done", i);
     }
 }
+
+#[test]
+fn test_lwe_hardened_decrypt() {
+    type MsgT = u8;
+    type CipherT = u32;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus = 4;
+    let distr = Uniform::new(0, plain_modulus);
+
+    let cipher_modulus = 1 << 20;
+    let params = LweParameters {
+        dimension: 512,
+        plain_modulus_value: plain_modulus as CipherT,
+        cipher_modulus_value: ModulusValue::PowerOf2(cipher_modulus),
+        cipher_modulus_minus_one: cipher_modulus - 1,
+        cipher_modulus: PowOf2Modulus::<CipherT>::new(cipher_modulus),
+        secret_key_type: LweSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+    };
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    for _ in 0..16 {
+        let message: MsgT = rng.sample(distr);
+        let c = sk.encrypt(message, &params, &mut rng);
+        let m: MsgT = sk
+            .decrypt_with_profile(&c, &params, SecurityProfile::Hardened)
+            .unwrap();
+        assert_eq!(m, message);
+        assert_eq!(m, sk.decrypt::<MsgT, _>(&c, &params));
+    }
+
+    let cipher_modulus = 132120577;
+    let params = LweParameters {
+        dimension: 512,
+        plain_modulus_value: plain_modulus as CipherT,
+        cipher_modulus_value: ModulusValue::Prime(cipher_modulus),
+        cipher_modulus_minus_one: cipher_modulus - 1,
+        cipher_modulus: BarrettModulus::<CipherT>::new(cipher_modulus),
+        secret_key_type: LweSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+    };
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    for _ in 0..16 {
+        let message: MsgT = rng.sample(distr);
+        let c = sk.encrypt(message, &params, &mut rng);
+        let m: MsgT = sk
+            .decrypt_with_profile(&c, &params, SecurityProfile::Hardened)
+            .unwrap();
+        assert_eq!(m, message);
+        assert_eq!(m, sk.decrypt::<MsgT, _>(&c, &params));
+    }
+}
+
+/// A message type holding a single bit.
+struct Bit;
+
+impl TryFrom<u32> for Bit {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value <= 1 {
+            Ok(Bit)
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[test]
+fn test_lwe_hardened_encrypt() {
+    type MsgT = u8;
+    type CipherT = u32;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus = 4;
+    let distr = Uniform::new(0, plain_modulus);
+
+    let cipher_modulus = 132120577;
+    let params = LweParameters {
+        dimension: 512,
+        plain_modulus_value: plain_modulus as CipherT,
+        cipher_modulus_value: ModulusValue::Prime(cipher_modulus),
+        cipher_modulus_minus_one: cipher_modulus - 1,
+        cipher_modulus: BarrettModulus::<CipherT>::new(cipher_modulus),
+        secret_key_type: LweSecretKeyType::Binary,
+        noise_standard_deviation: 3.20,
+    };
+    let profile = SecurityProfile::Hardened;
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    let pk = LwePublicKey::new(&sk, &params, &mut rng);
+
+    for _ in 0..16 {
+        let message: MsgT = rng.sample(distr);
+        let c = sk
+            .encrypt_with_profile(message, &params, profile, &mut rng)
+            .unwrap();
+        let m: MsgT = sk.decrypt_with_profile(&c, &params, profile).unwrap();
+        assert_eq!(m, message);
+
+        let message: MsgT = rng.sample(distr);
+        let c = pk
+            .encrypt_with_profile(message, &params, profile, &mut rng)
+            .unwrap();
+        let m: MsgT = sk.decrypt_with_profile(&c, &params, profile).unwrap();
+        assert_eq!(m, message);
+    }
+
+    for profile in [SecurityProfile::Performance, SecurityProfile::Hardened] {
+        let message = plain_modulus;
+        assert!(matches!(
+            sk.encrypt_with_profile(message, &params, profile, &mut rng),
+            Err(FHECoreError::MessageOutOfRange)
+        ));
+        assert!(matches!(
+            pk.encrypt_with_profile(message, &params, profile, &mut rng),
+            Err(FHECoreError::MessageOutOfRange)
+        ));
+
+        let c = sk
+            .encrypt_with_profile(plain_modulus - 1, &params, profile, &mut rng)
+            .unwrap();
+        assert!(matches!(
+            sk.decrypt_with_profile::<Bit, _>(&c, &params, profile),
+            Err(FHECoreError::DecodedMessageOutOfRange)
+        ));
+    }
+}
+
+#[test]
+fn test_lwe_with_encoder() {
+    type CipherT = u32;
+
+    let mut rng = thread_rng();
+
+    let cipher_modulus = 1 << 24;
+    let params = LweParameters {
+        dimension: 512,
+        plain_modulus_value: 16,
+        cipher_modulus_value: ModulusValue::PowerOf2(cipher_modulus),
+        cipher_modulus_minus_one: cipher_modulus - 1,
+        cipher_modulus: PowOf2Modulus::<CipherT>::new(cipher_modulus),
+        secret_key_type: LweSecretKeyType::Binary,
+        noise_standard_deviation: 3.20,
+    };
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    let encoder = BalancedBaseEncoder::new(4, 4, MsbEncoder::from_params(&params));
+    let (min, max) = encoder.message_range();
+    let message = rng.gen_range(min..=max);
+
+    let phases: Vec<CipherT> = encoder
+        .encode(message)
+        .into_iter()
+        .map(|v| sk.encrypt_encoded(v, &params, &mut rng))
+        .map(|c| sk.phase(&c, &params))
+        .collect();
+    assert_eq!(encoder.decode(&phases), message);
+}
+
+#[test]
+fn test_rlwe_decrypt_coefficients() {
+    use algebra::{polynomial::FieldPolynomial, random::DiscreteGaussian, Field, NttField};
+    use fhe_core::{NttRlweSecretKey, RingSecretKeyType, RlweCiphertext, RlweSecretKey};
+
+    type Fp = algebra::U32FieldEval<132120577>;
+    const LOG_N: u32 = 10;
+    const N: usize = 1 << LOG_N;
+    const T: u32 = 8;
+
+    let mut rng = thread_rng();
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+
+    let sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+
+    let messages: Vec<u32> = (0..N).map(|_| rng.gen_range(0..T)).collect();
+    let q = ModulusValue::Prime(Fp::MODULUS_VALUE);
+    let encoded = messages
+        .iter()
+        .map(|&m| fhe_core::encode(m, T, q))
+        .collect();
+
+    let mut cipher =
+        RlweCiphertext::generate_random_zero_sample(&ntt_sk, gaussian, &ntt_table, &mut rng);
+    *cipher.b_mut() += &FieldPolynomial::new(encoded);
+
+    let indices = [0, 1, 7, N / 2, N - 1];
+    let decrypted: Vec<u32> = sk.decrypt_coefficients(&cipher, &indices, T);
+    let expected: Vec<u32> = indices.iter().map(|&i| messages[i]).collect();
+    assert_eq!(decrypted, expected);
+
+    for &i in &indices {
+        let lwe = cipher.extract_lwe_with_index(i);
+        let dot = lwe
+            .a()
+            .iter()
+            .zip(sk.iter())
+            .fold(0, |acc, (&a, &s)| Fp::mul_add(a, s, acc));
+        assert_eq!(sk.phase_at(&cipher, i), Fp::sub(lwe.b(), dot));
+    }
+}
+
+#[test]
+fn test_key_le_bytes() {
+    type Fp = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+
+    let cipher_modulus_value = 134215681;
+    let modulus = BarrettModulus::<u32>::new(cipher_modulus_value);
+
+    // secret keys
+    let lwe_sk = LweSecretKey::new(
+        (0..64).map(|_| rng.gen_range(0..2u32)).collect(),
+        LweSecretKeyType::Binary,
+    );
+    let bytes = lwe_sk.to_le_vec();
+    let decoded = LweSecretKey::<u32>::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded.as_ref(), lwe_sk.as_ref());
+    assert_eq!(Fingerprint::of(&decoded), Fingerprint::of(&lwe_sk));
+    assert!(LweSecretKey::<u16>::from_le_slice(&bytes).is_err());
+
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, 256, None, &mut rng);
+    let bytes = rlwe_sk.to_le_vec();
+    let decoded = RlweSecretKey::<Fp>::from_le_slice(&bytes).unwrap();
+    assert_eq!(*decoded, *rlwe_sk);
+    assert_eq!(decoded.distr(), RingSecretKeyType::Ternary);
+
+    // key switching key
+    let params = KeySwitchingParameters {
+        input_cipher_dimension: 64,
+        output_cipher_dimension: 32,
+        log_modulus: 27,
+        log_basis: 4,
+        reverse_length: None,
+        noise_standard_deviation: 3.2,
+    };
+    let sk_out = LweSecretKey::new(
+        (0..32).map(|_| rng.gen_range(0..2u32)).collect(),
+        LweSecretKeyType::Binary,
+    );
+    let ksk = NonPowOf2LweKeySwitchingKey::generate(&lwe_sk, &sk_out, params, modulus, &mut rng);
+    let bytes = ksk.to_le_vec();
+    let decoded = NonPowOf2LweKeySwitchingKey::<u32>::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded.key_switching_params(), params);
+    assert_eq!(decoded.to_le_vec(), bytes);
+    let c = Lwe::new(
+        (0..64)
+            .map(|_| rng.gen_range(0..cipher_modulus_value))
+            .collect(),
+        5,
+    );
+    assert_eq!(decoded.key_switch(&c, modulus), ksk.key_switch(&c, modulus));
+    // a zero `log_basis`, after the header, descriptor, modulus, dimensions and `log_modulus`
+    let mut bad = bytes.clone();
+    bad[30..34].fill(0);
+    assert!(NonPowOf2LweKeySwitchingKey::<u32>::from_le_slice(&bad).is_err());
+    assert!(NonPowOf2LweKeySwitchingKey::<u32>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+
+    // blind rotation key
+    let ntt_table = Arc::new(Fp::generate_ntt_table(8).unwrap());
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let brk =
+        BlindRotationKey::generate(&lwe_sk, &ntt_rlwe_sk, &basis, gaussian, ntt_table, &mut rng);
+    let bytes = brk.to_le_vec();
+    let decoded = BlindRotationKey::<Fp>::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded.lwe_dimension(), 64);
+    assert_eq!(decoded.to_le_vec(), bytes);
+    let lut = FieldPolynomial::<Fp>::random(256, &mut rng);
+    let c = Lwe::new((0..64).map(|_| rng.gen_range(0..512u32)).collect(), 7);
+    assert!(decoded.blind_rotate(lut.clone(), &c) == brk.blind_rotate(lut, &c));
+    assert!(BlindRotationKey::<Fp>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+
+    // streamed generation writes the same key as the generation in memory
+    let ternary_sk = LweSecretKey::new(
+        (0..64).map(|_| rng.gen_range(0..3u32)).collect(),
+        LweSecretKeyType::Ternary,
+    );
+    for sk in [&lwe_sk, &ternary_sk] {
+        let mut streamed = Vec::new();
+        BlindRotationKey::generate_to(
+            &mut streamed,
+            sk,
+            &ntt_rlwe_sk,
+            &basis,
+            gaussian,
+            brk.ntt_table(),
+            &mut Prg::seed_from_u64(7),
+        )
+        .unwrap();
+        let generated = BlindRotationKey::generate(
+            sk,
+            &ntt_rlwe_sk,
+            &basis,
+            gaussian,
+            Arc::new(Fp::generate_ntt_table(8).unwrap()),
+            &mut Prg::seed_from_u64(7),
+        );
+        assert_eq!(streamed, generated.to_le_vec());
+        assert!(BlindRotationKey::<Fp>::from_le_slice(&streamed).is_ok());
+    }
+
+    // a key pack, of which only the key switching key is loaded
+    let mut pack = KeyPack::new();
+    pack.push("blind_rotation", &brk);
+    pack.push("key_switching", &ksk);
+    let mut file = Vec::new();
+    pack.write_to(&mut file).unwrap();
+
+    let limits = DecodeLimits::default();
+    let mut reader = Cursor::new(file);
+    let manifest = Manifest::read_from(&mut reader, &limits).unwrap();
+    let entry = manifest.get("key_switching").unwrap();
+    assert_eq!(*entry.fingerprint(), Fingerprint::of(&ksk));
+    let loaded: NonPowOf2LweKeySwitchingKey<u32> = manifest
+        .load(&mut reader, "key_switching", &limits)
+        .unwrap();
+    let c = Lwe::new(
+        (0..64)
+            .map(|_| rng.gen_range(0..cipher_modulus_value))
+            .collect(),
+        5,
+    );
+    assert_eq!(loaded.key_switch(&c, modulus), ksk.key_switch(&c, modulus));
+    assert!(manifest
+        .load::<BlindRotationKey<Fp>, _>(&mut reader, "key_switching", &limits)
+        .is_err());
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, ntt::NumberTheoryTransform, polynomial::FieldPolynomial,
+    random::DiscreteGaussian, Field, NttField, U32FieldEval,
+};
+use fhe_core::{
+    reference, BlindRotationKey, LweSecretKey, LweSecretKeyType, NttRlweSecretKey,
+    RingSecretKeyType, RlweSecretKey,
+};
+use lattice::Lwe;
+use rand::{distributions::Uniform, thread_rng, Rng};
+
+type Fp = U32FieldEval<132120577>;
+type PolyT = FieldPolynomial<Fp>;
+
+const Q: u32 = <Fp as Field>::MODULUS_VALUE;
+const LOG_N: u32 = 10;
+const N: usize = 1 << LOG_N;
+
+fn to_u64(values: &[u32]) -> Vec<u64> {
+    values.iter().map(|&v| v as u64).collect()
+}
+
+#[test]
+fn test_reference_negacyclic_mul() {
+    let mut rng = thread_rng();
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+
+    let a = PolyT::random(N, &mut rng);
+    let b = PolyT::random(N, &mut rng);
+
+    let expected =
+        reference::negacyclic_mul(&to_u64(a.as_slice()), &to_u64(b.as_slice()), Q as u64);
+    let result =
+        ntt_table.inverse_transform_inplace(ntt_table.transform(&a) * ntt_table.transform(&b));
+    assert_eq!(to_u64(result.as_slice()), expected);
+
+    let exponent = rng.gen_range(0..2 * N);
+    let mut monomial = PolyT::zero(N);
+    monomial[exponent % N] = if exponent < N { 1 } else { Q - 1 };
+    assert_eq!(
+        reference::monomial_mul(&to_u64(a.as_slice()), exponent, Q as u64),
+        reference::negacyclic_mul(
+            &to_u64(a.as_slice()),
+            &to_u64(monomial.as_slice()),
+            Q as u64
+        )
+    );
+}
+
+#[test]
+fn test_reference_negacyclic_mul_wide_modulus() {
+    let mut rng = thread_rng();
+    // a prime above `2^63`
+    let modulus = u64::MAX - 58;
+
+    // (-1) * (-1) = 1 and (-X) * (-X) = X^2 = -1 in `Z_q[X]/(X^2 + 1)`
+    let minus_one = modulus - 1;
+    assert_eq!(
+        reference::negacyclic_mul(&[minus_one, 0], &[minus_one, 0], modulus),
+        [1, 0]
+    );
+    assert_eq!(
+        reference::negacyclic_mul(&[0, minus_one], &[0, minus_one], modulus),
+        [minus_one, 0]
+    );
+
+    let n = 16;
+    let a: Vec<u64> = (0..n).map(|_| rng.gen_range(0..modulus)).collect();
+    for exponent in [0, 1, n - 1, n, n + 1, 2 * n - 1] {
+        let mut monomial = vec![0; n];
+        monomial[exponent % n] = if exponent < n { 1 } else { minus_one };
+        assert_eq!(
+            reference::monomial_mul(&a, exponent, modulus),
+            reference::negacyclic_mul(&a, &monomial, modulus)
+        );
+    }
+
+    assert!(reference::monomial_mul(&[], 3, modulus).is_empty());
+    assert!(reference::negacyclic_mul(&[], &[], modulus).is_empty());
+}
+
+#[test]
+fn test_reference_decompose() {
+    let mut rng = thread_rng();
+
+    for log_basis in [1, 3, 4, 7] {
+        let basis = NonPowOf2ApproxSignedBasis::new(Q, log_basis, None);
+        let length = basis.decompose_length();
+        let drop_bits = basis.drop_bits();
+        let bound = 1u64 << drop_bits.saturating_sub(1);
+
+        let centered_distance = |x: u64, y: u64| {
+            let d = (x + Q as u64 - y) % Q as u64;
+            d.min(Q as u64 - d)
+        };
+
+        for _ in 0..1000 {
+            let value = rng.gen_range(0..Q);
+
+            let digits = reference::signed_decompose(value as u64, Q as u64, log_basis, length);
+            assert_eq!(digits.len(), length);
+            let half = 1i64 << (log_basis - 1);
+            assert!(digits.iter().all(|&d| -half <= d && d <= half));
+            let recomposed = reference::signed_recompose(&digits, Q as u64, log_basis);
+            assert!(centered_distance(recomposed, value as u64) <= bound);
+
+            // the optimized decomposition recomposes to the same approximation quality
+            let optimized: Vec<i64> = basis.signed_digits(value).collect();
+            let recomposed = reference::signed_recompose(&optimized, Q as u64, log_basis);
+            assert!(centered_distance(recomposed, value as u64) <= bound);
+        }
+    }
+}
+
+#[test]
+fn test_reference_blind_rotate() {
+    const LWE_DIMENSION: usize = 64;
+
+    let mut rng = thread_rng();
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(Q, 7, None);
+
+    let lwe_key: Vec<u16> = (0..LWE_DIMENSION).map(|_| rng.gen_range(0..2)).collect();
+    let lwe_sk = LweSecretKey::new(lwe_key.clone(), LweSecretKeyType::Binary);
+    let rlwe_sk = RlweSecretKey::new(
+        PolyT::random_ternary(N, &mut rng),
+        RingSecretKeyType::Ternary,
+    );
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+
+    let key = BlindRotationKey::generate(
+        &lwe_sk,
+        &ntt_sk,
+        &basis,
+        gaussian,
+        Arc::clone(&ntt_table),
+        &mut rng,
+    );
+
+    let twice_n = Uniform::new(0, 2 * N as u16);
+    let lut = PolyT::new((0..N).map(|_| rng.gen_range(0..Q / 4)).collect());
+    let a: Vec<u16> = (&mut rng)
+        .sample_iter(twice_n)
+        .take(LWE_DIMENSION)
+        .collect();
+    let b: u16 = rng.sample(twice_n);
+
+    let result = key.blind_rotate(lut.clone(), &Lwe::new(a.clone(), b));
+    let decrypted = result.b()
+        - ntt_table.inverse_transform_inplace(ntt_table.transform(result.a()) * &*ntt_sk);
+
+    let secret: Vec<i64> = lwe_key.iter().map(|&s| s as i64).collect();
+    let a: Vec<u64> = a.iter().map(|&v| v as u64).collect();
+    let expected =
+        reference::blind_rotate(&to_u64(lut.as_slice()), &a, b as u64, &secret, Q as u64);
+
+    for (&x, &y) in decrypted.iter().zip(expected.iter()) {
+        let d = (x as u64 + Q as u64 - y) % Q as u64;
+        assert!(d.min(Q as u64 - d) < Q as u64 / 64);
+    }
+}
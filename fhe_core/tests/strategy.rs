@@ -0,0 +1,37 @@
+use fhe_core::strategy::{lwe_sample, pow_of_2_lwe_parameters, prime_lwe_parameters};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_pow_of_2_lwe_sample_decrypts(sample in lwe_sample(pow_of_2_lwe_parameters())) {
+        let m: u32 = sample.secret_key.decrypt(&sample.ciphertext, &sample.params);
+        prop_assert_eq!(m, sample.message);
+    }
+
+    #[test]
+    fn test_prime_lwe_sample_decrypts(sample in lwe_sample(prime_lwe_parameters())) {
+        let m: u32 = sample.secret_key.decrypt(&sample.ciphertext, &sample.params);
+        prop_assert_eq!(m, sample.message);
+    }
+
+    #[test]
+    fn test_lwe_sample_is_homomorphic(
+        x in lwe_sample(pow_of_2_lwe_parameters()),
+        y in any::<u32>(),
+    ) {
+        let params = x.params;
+        let t = params.plain_modulus_value;
+        let y = y % t;
+
+        let mut c = x.ciphertext.clone();
+        let mut cy = c.clone();
+        cy.a_mut().fill(0);
+        *cy.b_mut() = fhe_core::encode(y, t, params.cipher_modulus_value);
+        c.add_reduce_assign_component_wise(&cy, params.cipher_modulus);
+
+        let m: u32 = x.secret_key.decrypt(&c, &params);
+        prop_assert_eq!(m, (x.message + y) % t);
+    }
+}
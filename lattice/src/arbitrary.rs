@@ -0,0 +1,142 @@
+//! [`Arbitrary`] implementations, which produce valid ciphertexts,
+//! so structured fuzzing reaches the decoders beyond their first checks.
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, integer::Bits, polynomial::FieldNttPolynomial,
+    polynomial::FieldPolynomial, utils::arbitrary_field_values, Field, NttField,
+};
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{GadgetRlwe, Lwe, LweList, NttGadgetRlwe, NttRgsw, NttRlwe, Rlwe, RlweList};
+
+/// Generates a basis of the modulus of `F`,
+/// or fails if the modulus has no [`NonPowOf2ApproxSignedBasis`].
+fn arbitrary_basis<F: Field>(
+    u: &mut Unstructured<'_>,
+) -> Result<NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>> {
+    let bits = <<F as Field>::ValueT as Bits>::BITS;
+    let modulus_bits = bits - F::MODULUS_VALUE.leading_zeros();
+    let log_basis = u.int_in_range(1..=modulus_bits.clamp(1, bits - 1))?;
+    let levels = u.int_in_range(1..=((modulus_bits / log_basis) as usize).max(1))?;
+    NonPowOf2ApproxSignedBasis::checked_new(F::MODULUS_VALUE, log_basis, Some(levels))
+        .ok_or(Error::IncorrectFormat)
+}
+
+impl<'a, T: Copy + Arbitrary<'a>> Arbitrary<'a> for Lwe<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let a = Vec::arbitrary(u)?;
+        Ok(Lwe::new(a, T::arbitrary(u)?))
+    }
+}
+
+impl<'a, T: Copy + Arbitrary<'a>> Arbitrary<'a> for LweList<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = u.arbitrary_len::<T>()?;
+        let mut data = Vec::<T>::arbitrary(u)?;
+        data.truncate(data.len() - data.len() % (dimension + 1));
+        Ok(LweList::from_raw(data, dimension))
+    }
+}
+
+impl<'a, F: Field> Arbitrary<'a> for Rlwe<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        Ok(Rlwe::new(
+            FieldPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+            FieldPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+        ))
+    }
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for NttRlwe<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        Ok(NttRlwe::new(
+            FieldNttPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+            FieldNttPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+        ))
+    }
+}
+
+impl<'a, F: Field> Arbitrary<'a> for RlweList<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?.max(1);
+        let len = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        let data = arbitrary_field_values::<F>(u, len - len % (2 * dimension))?;
+        Ok(RlweList::from_raw(data, dimension))
+    }
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for GadgetRlwe<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let basis = arbitrary_basis::<F>(u)?;
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        let data = (0..basis.decompose_length())
+            .map(|_| {
+                Ok(Rlwe::new(
+                    FieldPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+                    FieldPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GadgetRlwe::new(data, basis))
+    }
+}
+
+/// Generates a [`NttGadgetRlwe<F>`] with `basis` and `dimension`.
+fn arbitrary_ntt_gadget<'a, F: NttField>(
+    u: &mut Unstructured<'a>,
+    basis: NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+    dimension: usize,
+) -> Result<NttGadgetRlwe<F>>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    let data = (0..basis.decompose_length())
+        .map(|_| {
+            Ok(NttRlwe::new(
+                FieldNttPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+                FieldNttPolynomial::new(arbitrary_field_values::<F>(u, dimension)?),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(NttGadgetRlwe::new(data, basis))
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for NttGadgetRlwe<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let basis = arbitrary_basis::<F>(u)?;
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        arbitrary_ntt_gadget(u, basis, dimension)
+    }
+}
+
+impl<'a, F: NttField> Arbitrary<'a> for NttRgsw<F>
+where
+    <F as Field>::ValueT: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let basis = arbitrary_basis::<F>(u)?;
+        let dimension = u.arbitrary_len::<<F as Field>::ValueT>()?;
+        let minus_s_m = arbitrary_ntt_gadget(u, basis, dimension)?;
+        let m = arbitrary_ntt_gadget(u, basis, dimension)?;
+        Ok(NttRgsw::new(minus_s_m, m))
+    }
+}
@@ -1,5 +1,38 @@
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    utils::{DecodeLimits, LeBytes},
+    AlgebraError, Field,
+};
+
 mod gadget_rlwe;
 mod ntt_gadget_rlwe;
 
 pub use gadget_rlwe::GadgetRlwe;
 pub use ntt_gadget_rlwe::NttGadgetRlwe;
+
+/// Writes a basis of the modulus of `F` as its `log_basis` in `u32`,
+/// followed by its decompose length in `u64`.
+#[inline]
+fn write_basis<F: Field>(
+    basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+    buf: &mut Vec<u8>,
+) {
+    basis.log_basis().write_le(buf);
+    (basis.decompose_length() as u64).write_le(buf);
+}
+
+/// Reads a basis written by [`write_basis`], with at most `max_levels` levels.
+///
+/// The parameters are checked before the basis is built, so an invalid basis
+/// is an error instead of a panic.
+fn read_basis<F: Field>(
+    bytes: &mut &[u8],
+    limits: &DecodeLimits,
+) -> Result<NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>, AlgebraError> {
+    let log_basis = u32::read_le(bytes)?;
+    let levels = usize::try_from(u64::read_le(bytes)?).map_err(|_| AlgebraError::DecodeErr)?;
+    limits.check_levels(levels)?;
+
+    NonPowOf2ApproxSignedBasis::checked_new(F::MODULUS_VALUE, log_basis, Some(levels))
+        .ok_or(AlgebraError::DecodeErr)
+}
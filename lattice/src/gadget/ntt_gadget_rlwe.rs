@@ -4,13 +4,14 @@ use algebra::{
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     random::DiscreteGaussian,
     reduce::ReduceAddAssign,
-    Field, NttField,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use rand::{CryptoRng, Rng};
 
 use crate::{utils::PolyDecomposeSpace, NttRlwe};
 
-use super::GadgetRlwe;
+use super::{read_basis, write_basis, GadgetRlwe};
 
 /// A representation of Ring Learning with Errors (RLWE) ciphertexts with respect to different powers
 /// of a base, used to control noise growth in polynomial multiplications.
@@ -91,6 +92,12 @@ impl<F: NttField> NttGadgetRlwe<F> {
         self.data.as_ref()
     }
 
+    /// Returns the dimension of the ciphertexts of this [`NttGadgetRlwe<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.data[0].dimension()
+    }
+
     /// Returns the basis of this [`NttGadgetRlwe<F>`].
     #[inline]
     pub fn basis(&self) -> &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT> {
@@ -359,3 +366,46 @@ impl<F: NttField> NttGadgetRlwe<F> {
         }
     }
 }
+
+/// The gadget ciphertext is encoded after its header and descriptor as its basis,
+/// its `log_basis` in `u32` and its decompose length in `u64`, followed by
+/// the `a` and `b` of every level.
+impl<F: NttField> LeBytes for NttGadgetRlwe<F>
+where
+    F::ValueT: LeBytes,
+{
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"NGRL", buf);
+        write_modulus::<F>(buf);
+        write_basis::<F>(&self.basis, buf);
+        for rlwe in &self.data {
+            rlwe.a().write_le(buf);
+            rlwe.b().write_le(buf);
+        }
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"NGRL", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let basis = read_basis::<F>(bytes, limits)?;
+        let data = (0..basis.decompose_length())
+            .map(|_| {
+                let a = FieldNttPolynomial::read_le_with(bytes, limits)?;
+                let b = FieldNttPolynomial::read_le_with(bytes, limits)?;
+                // `NttRlwe::new` panics on polynomials of different dimensions
+                if a.coeff_count() == b.coeff_count() {
+                    Ok(NttRlwe::new(a, b))
+                } else {
+                    Err(AlgebraError::DecodeErr)
+                }
+            })
+            .collect::<Result<Vec<_>, AlgebraError>>()?;
+
+        let dimension = data[0].dimension();
+        if data.iter().all(|rlwe| rlwe.dimension() == dimension) {
+            Ok(Self { data, basis })
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+}
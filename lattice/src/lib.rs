@@ -3,6 +3,8 @@
 
 //! Defines some lattice cryptographic structure.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod gadget;
 mod lwe;
 mod rgsw;
@@ -11,6 +13,6 @@ mod rlwe;
 pub mod utils;
 
 pub use gadget::{GadgetRlwe, NttGadgetRlwe};
-pub use lwe::{CmLwe, Lwe};
+pub use lwe::{CmLwe, Lwe, LweList, LweView, LweViewMut};
 pub use rgsw::{NttRgsw, Rgsw};
-pub use rlwe::{NttRlwe, NumRlwe, Rlwe};
+pub use rlwe::{ArrayRlwe, NttRlwe, NumRlwe, Rlwe, RlweList, RlweView, RlweViewMut};
@@ -0,0 +1,232 @@
+use algebra::{
+    integer::UnsignedInteger,
+    utils::{
+        read_chunks, read_header, read_width, write_header, write_width, DecodeLimits, LeBytes,
+    },
+    AlgebraError,
+};
+use rayon::prelude::*;
+
+use super::{Lwe, LweView, LweViewMut};
+
+/// A list of [`Lwe<T>`] with the same dimension,
+/// stored in one contiguous allocation.
+///
+/// Every ciphertext is laid out as `a || b`, so the whole list can be moved
+/// as a single buffer with [`LweList::as_slice`] and [`LweList::from_raw`].
+/// The raw buffer is in the byte order of the host, use [`LeBytes`] to send
+/// the list to another machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweList<T: Copy> {
+    data: Vec<T>,
+    dimension: usize,
+}
+
+impl<T: Copy> LweList<T> {
+    /// Creates a new empty [`LweList<T>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `usize::MAX`.
+    #[inline]
+    pub fn new(dimension: usize) -> Self {
+        assert!(dimension < usize::MAX);
+        Self {
+            data: Vec::new(),
+            dimension,
+        }
+    }
+
+    /// Creates a new empty [`LweList<T>`] with room for `capacity` ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `usize::MAX` or the buffer length overflows.
+    #[inline]
+    pub fn with_capacity(dimension: usize, capacity: usize) -> Self {
+        Self {
+            data: Vec::with_capacity(buffer_len(dimension, capacity)),
+            dimension,
+        }
+    }
+
+    /// Creates a [`LweList<T>`] from a raw buffer of consecutive `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `usize::MAX`,
+    /// or the length of `data` is not a multiple of `dimension + 1`.
+    #[inline]
+    pub fn from_raw(data: Vec<T>, dimension: usize) -> Self {
+        assert!(dimension < usize::MAX);
+        assert_eq!(data.len() % (dimension + 1), 0);
+        Self { data, dimension }
+    }
+
+    /// Drops self, and returns the raw buffer.
+    #[inline]
+    pub fn into_raw(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns the raw buffer of this [`LweList<T>`].
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the mutable raw buffer of this [`LweList<T>`].
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the dimension of the ciphertexts.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the count of the ciphertexts.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len() / (self.dimension + 1)
+    }
+
+    /// Returns `true` if the list contains no ciphertexts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Appends a ciphertext to the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `lwe` doesn't match.
+    #[inline]
+    pub fn push(&mut self, lwe: LweView<'_, T>) {
+        assert_eq!(lwe.dimension(), self.dimension);
+        self.data.extend_from_slice(lwe.a());
+        self.data.push(lwe.b());
+    }
+
+    /// Returns the range of the `index`-th ciphertext in the raw buffer,
+    /// `None` if it overflows.
+    #[inline]
+    fn range(&self, index: usize) -> Option<core::ops::Range<usize>> {
+        let size = self.dimension + 1;
+        let start = index.checked_mul(size)?;
+        Some(start..start.checked_add(size)?)
+    }
+
+    /// Returns a view of the `index`-th ciphertext.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<LweView<'_, T>> {
+        let range = self.range(index)?;
+        self.data.get(range).map(LweView::from_slice)
+    }
+
+    /// Returns a mutable view of the `index`-th ciphertext.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<LweViewMut<'_, T>> {
+        let range = self.range(index)?;
+        self.data.get_mut(range).map(LweViewMut::from_slice)
+    }
+
+    /// Returns an iterator over the views of the ciphertexts.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = LweView<'_, T>> {
+        self.data
+            .chunks_exact(self.dimension + 1)
+            .map(LweView::from_slice)
+    }
+
+    /// Returns an iterator over the mutable views of the ciphertexts.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = LweViewMut<'_, T>> {
+        self.data
+            .chunks_exact_mut(self.dimension + 1)
+            .map(LweViewMut::from_slice)
+    }
+}
+
+impl<T: Copy + Send + Sync> LweList<T> {
+    /// Returns a parallel iterator over the views of the ciphertexts.
+    #[inline]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = LweView<'_, T>> {
+        self.data
+            .par_chunks_exact(self.dimension + 1)
+            .map(LweView::from_slice)
+    }
+
+    /// Returns a parallel iterator over the mutable views of the ciphertexts.
+    #[inline]
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = LweViewMut<'_, T>> {
+        self.data
+            .par_chunks_exact_mut(self.dimension + 1)
+            .map(LweViewMut::from_slice)
+    }
+}
+
+impl<T: UnsignedInteger> LweList<T> {
+    /// Creates a [`LweList<T>`] with `count` ciphertexts whose values are all `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `usize::MAX` or the buffer length overflows.
+    #[inline]
+    pub fn zero(dimension: usize, count: usize) -> Self {
+        Self {
+            data: vec![T::ZERO; buffer_len(dimension, count)],
+            dimension,
+        }
+    }
+}
+
+/// Returns the length of the buffer of `count` ciphertexts of `dimension`.
+#[inline]
+fn buffer_len(dimension: usize, count: usize) -> usize {
+    dimension
+        .checked_add(1)
+        .and_then(|size| size.checked_mul(count))
+        .expect("capacity overflow")
+}
+
+/// The list is encoded after its header and descriptor as its dimension in `u64`,
+/// followed by the raw buffer as one sequence.
+impl<T: Copy + LeBytes> LeBytes for LweList<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"LWEL", buf);
+        write_width::<T>(buf);
+        (self.dimension as u64).write_le(buf);
+        self.data.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"LWEL", bytes)?;
+        read_width::<T>(version, bytes)?;
+        let dimension = usize::try_from(u64::read_le(bytes)?)
+            .ok()
+            .filter(|&d| d < usize::MAX)
+            .ok_or(AlgebraError::DecodeErr)?;
+        limits.check_dimension(dimension)?;
+        let data = read_chunks(bytes, dimension + 1, limits)?;
+        Ok(Self { data, dimension })
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<LweView<'a, T>> for LweList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = LweView<'a, T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|lwe| self.push(lwe));
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a Lwe<T>> for LweList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a Lwe<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|lwe| self.push(lwe.as_view()));
+    }
+}
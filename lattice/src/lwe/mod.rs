@@ -1,5 +1,9 @@
 mod compress;
+mod list;
 mod normal;
+mod view;
 
 pub use compress::CmLwe;
+pub use list::LweList;
 pub use normal::Lwe;
+pub use view::{LweView, LweViewMut};
@@ -5,9 +5,15 @@ use algebra::{
         Modulus, ReduceAdd, ReduceAddAssign, ReduceDotProduct, ReduceMulAdd, ReduceMulAssign,
         ReduceNeg, ReduceNegAssign, ReduceSub, ReduceSubAssign,
     },
+    utils::{
+        read_coeffs, read_header, read_width, write_header, write_width, DecodeLimits, LeBytes,
+    },
+    AlgebraError,
 };
 use rand::{distributions::Uniform, prelude::Distribution};
 
+use super::{LweView, LweViewMut};
+
 /// Represents a cryptographic structure based on the Learning with Errors (LWE) problem.
 /// The LWE problem is a fundamental component in modern cryptography, often used to build
 /// secure cryptographic systems that are considered hard to crack by quantum computers.
@@ -74,6 +80,18 @@ impl<T: Copy> Lwe<T> {
     pub fn a_mut_slice(&mut self) -> &mut [T] {
         self.a.as_mut_slice()
     }
+
+    /// Returns a [`LweView<T>`] of this [`Lwe<T>`].
+    #[inline]
+    pub fn as_view(&self) -> LweView<'_, T> {
+        LweView::new(&self.a, &self.b)
+    }
+
+    /// Returns a [`LweViewMut<T>`] of this [`Lwe<T>`].
+    #[inline]
+    pub fn as_view_mut(&mut self) -> LweViewMut<'_, T> {
+        LweViewMut::new(&mut self.a, &mut self.b)
+    }
 }
 
 impl<T: UnsignedInteger> Lwe<T> {
@@ -269,3 +287,22 @@ impl<T: UnsignedInteger> Lwe<T> {
         Lwe { a, b }
     }
 }
+
+impl<T: Copy + LeBytes> LeBytes for Lwe<T> {
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"LWE ", buf);
+        write_width::<T>(buf);
+        self.a.write_le(buf);
+        self.b.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"LWE ", bytes)?;
+        read_width::<T>(version, bytes)?;
+        let a = read_coeffs(bytes, limits)?;
+        let b = T::read_le(bytes)?;
+        Ok(Self { a, b })
+    }
+}
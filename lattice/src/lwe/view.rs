@@ -0,0 +1,178 @@
+use algebra::{
+    integer::UnsignedInteger,
+    reduce::{ReduceAddAssign, ReduceSubAssign},
+};
+
+use super::Lwe;
+
+/// A borrowed view of a [`Lwe<T>`].
+///
+/// It can point into an owned [`Lwe<T>`], or into a contiguous buffer
+/// laid out as `a || b`, so batch containers can expose single
+/// ciphertexts without copying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LweView<'a, T: Copy> {
+    a: &'a [T],
+    b: &'a T,
+}
+
+impl<'a, T: Copy> LweView<'a, T> {
+    /// Creates a new [`LweView<T>`].
+    #[inline]
+    pub fn new(a: &'a [T], b: &'a T) -> Self {
+        Self { a, b }
+    }
+
+    /// Creates a new [`LweView<T>`] from a buffer laid out as `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is empty.
+    #[inline]
+    pub fn from_slice(data: &'a [T]) -> Self {
+        let (b, a) = data.split_last().expect("empty lwe buffer");
+        Self { a, b }
+    }
+
+    /// Returns a reference to the `a` of this [`LweView<T>`].
+    #[inline]
+    pub fn a(&self) -> &'a [T] {
+        self.a
+    }
+
+    /// Returns the `b` of this [`LweView<T>`].
+    #[inline]
+    pub fn b(&self) -> T {
+        *self.b
+    }
+
+    /// Returns the dimension of this [`LweView<T>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Copies the viewed values into a new [`Lwe<T>`].
+    #[inline]
+    pub fn to_lwe(&self) -> Lwe<T> {
+        Lwe::new(self.a.to_vec(), *self.b)
+    }
+}
+
+/// A mutable borrowed view of a [`Lwe<T>`].
+///
+/// See [`LweView<T>`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweViewMut<'a, T: Copy> {
+    a: &'a mut [T],
+    b: &'a mut T,
+}
+
+impl<'a, T: Copy> LweViewMut<'a, T> {
+    /// Creates a new [`LweViewMut<T>`].
+    #[inline]
+    pub fn new(a: &'a mut [T], b: &'a mut T) -> Self {
+        Self { a, b }
+    }
+
+    /// Creates a new [`LweViewMut<T>`] from a buffer laid out as `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is empty.
+    #[inline]
+    pub fn from_slice(data: &'a mut [T]) -> Self {
+        let (b, a) = data.split_last_mut().expect("empty lwe buffer");
+        Self { a, b }
+    }
+
+    /// Reborrows this [`LweViewMut<T>`] as a [`LweView<T>`].
+    #[inline]
+    pub fn as_view(&self) -> LweView<'_, T> {
+        LweView {
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Returns a reference to the `a` of this [`LweViewMut<T>`].
+    #[inline]
+    pub fn a(&self) -> &[T] {
+        self.a
+    }
+
+    /// Returns a mutable reference to the `a` of this [`LweViewMut<T>`].
+    #[inline]
+    pub fn a_mut(&mut self) -> &mut [T] {
+        self.a
+    }
+
+    /// Returns the `b` of this [`LweViewMut<T>`].
+    #[inline]
+    pub fn b(&self) -> T {
+        *self.b
+    }
+
+    /// Returns a mutable reference to the `b` of this [`LweViewMut<T>`].
+    #[inline]
+    pub fn b_mut(&mut self) -> &mut T {
+        self.b
+    }
+
+    /// Returns the dimension of this [`LweViewMut<T>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Copies the values of `src` into this [`LweViewMut<T>`].
+    #[inline]
+    pub fn copy_from(&mut self, src: LweView<'_, T>) {
+        self.a.copy_from_slice(src.a);
+        *self.b = *src.b;
+    }
+}
+
+impl<T: UnsignedInteger> LweViewMut<'_, T> {
+    /// Sets all values to `0`.
+    #[inline]
+    pub fn set_zero(&mut self) {
+        self.a.fill(T::ZERO);
+        *self.b = T::ZERO;
+    }
+
+    /// Performs an in-place component-wise reduce addition with `rhs`.
+    #[inline]
+    pub fn add_reduce_assign_component_wise<M>(&mut self, rhs: LweView<'_, T>, modulus: M)
+    where
+        M: Copy + ReduceAddAssign<T>,
+    {
+        debug_assert_eq!(self.a.len(), rhs.a.len());
+        self.a
+            .iter_mut()
+            .zip(rhs.a)
+            .for_each(|(v0, &v1)| modulus.reduce_add_assign(v0, v1));
+        modulus.reduce_add_assign(self.b, *rhs.b);
+    }
+
+    /// Performs an in-place component-wise reduce subtraction with `rhs`.
+    #[inline]
+    pub fn sub_reduce_assign_component_wise<M>(&mut self, rhs: LweView<'_, T>, modulus: M)
+    where
+        M: Copy + ReduceSubAssign<T>,
+    {
+        debug_assert_eq!(self.a.len(), rhs.a.len());
+        self.a
+            .iter_mut()
+            .zip(rhs.a)
+            .for_each(|(v0, &v1)| modulus.reduce_sub_assign(v0, v1));
+        modulus.reduce_sub_assign(self.b, *rhs.b);
+    }
+}
+
+impl<'a, T: Copy> From<&'a Lwe<T>> for LweView<'a, T> {
+    #[inline]
+    fn from(value: &'a Lwe<T>) -> Self {
+        value.as_view()
+    }
+}
@@ -1,6 +1,9 @@
 use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis, polynomial::FieldNttPolynomial,
-    random::DiscreteGaussian, Field, NttField,
+    decompose::NonPowOf2ApproxSignedBasis,
+    polynomial::FieldNttPolynomial,
+    random::DiscreteGaussian,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use rand::{CryptoRng, Rng};
 
@@ -76,6 +79,12 @@ impl<F: NttField> NttRgsw<F> {
         self.minus_s_m.set_zero();
     }
 
+    /// Returns the dimension of the ciphertexts of this [`NttRgsw<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.m.dimension()
+    }
+
     /// Returns a reference to the `-s*m` of this [`NttRgsw<F>`].
     #[inline]
     pub fn minus_s_m(&self) -> &NttGadgetRlwe<F> {
@@ -192,3 +201,30 @@ impl<F: NttField> NttRgsw<F> {
         }
     }
 }
+
+/// The ciphertext is encoded after its header and descriptor as
+/// its `minus_s_m` followed by its `m`, which have the same basis and dimension.
+impl<F: NttField> LeBytes for NttRgsw<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"NRGS", buf);
+        write_modulus::<F>(buf);
+        self.minus_s_m.write_le(buf);
+        self.m.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"NRGS", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let minus_s_m = NttGadgetRlwe::read_le_with(bytes, limits)?;
+        let m = NttGadgetRlwe::read_le_with(bytes, limits)?;
+        if minus_s_m.basis() == m.basis() && minus_s_m.dimension() == m.dimension() {
+            Ok(Self { minus_s_m, m })
+        } else {
+            Err(AlgebraError::DecodeErr)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use algebra::{
+    integer::UnsignedInteger,
+    polynomial::ArrayPolynomial,
+    reduce::{ReduceAddAssign, ReduceNeg, ReduceNegAssign, ReduceSub, ReduceSubAssign},
+};
+
+use crate::{Lwe, NumRlwe};
+
+/// A RLWE ciphertext with a ring dimension `N` known at compile time.
+///
+/// It mirrors the coefficient domain operations of the dynamic [`NumRlwe<T>`]
+/// that a blind rotation accumulator needs: additions, monomial multiplications
+/// and sample extraction. With `N` a constant, the loops over the coefficients
+/// have a known trip count and their bounds checks are removed.
+///
+/// The blind rotation in `fhe_core` does not use it. It multiplies by the
+/// key in the NTT domain, and there is no NTT for [`ArrayPolynomial<T, N>`].
+///
+/// Like [`ArrayPolynomial<T, N>`], it is [`Clone`] but not [`Copy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayRlwe<T, const N: usize> {
+    a: ArrayPolynomial<T, N>,
+    b: ArrayPolynomial<T, N>,
+}
+
+impl<T: UnsignedInteger, const N: usize> ArrayRlwe<T, N> {
+    /// Creates a new [`ArrayRlwe<T, N>`].
+    #[inline]
+    pub fn new(a: ArrayPolynomial<T, N>, b: ArrayPolynomial<T, N>) -> Self {
+        Self { a, b }
+    }
+
+    /// Creates a new [`ArrayRlwe<T, N>`] that is initialized to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            a: ArrayPolynomial::zero(),
+            b: ArrayPolynomial::zero(),
+        }
+    }
+
+    /// Returns a reference to the a of this [`ArrayRlwe<T, N>`].
+    #[inline]
+    pub fn a(&self) -> &ArrayPolynomial<T, N> {
+        &self.a
+    }
+
+    /// Returns a reference to the b of this [`ArrayRlwe<T, N>`].
+    #[inline]
+    pub fn b(&self) -> &ArrayPolynomial<T, N> {
+        &self.b
+    }
+
+    /// Returns a mutable reference to the a of this [`ArrayRlwe<T, N>`].
+    #[inline]
+    pub fn a_mut(&mut self) -> &mut ArrayPolynomial<T, N> {
+        &mut self.a
+    }
+
+    /// Returns a mutable reference to the b of this [`ArrayRlwe<T, N>`].
+    #[inline]
+    pub fn b_mut(&mut self) -> &mut ArrayPolynomial<T, N> {
+        &mut self.b
+    }
+
+    /// Converts `self` into a heap allocated [`NumRlwe<T>`].
+    #[inline]
+    pub fn to_num_rlwe(&self) -> NumRlwe<T> {
+        NumRlwe::new(self.a.to_polynomial(), self.b.to_polynomial())
+    }
+
+    /// Performs `self += rhs` according to `modulus`.
+    #[inline]
+    pub fn add_assign_element_wise<M>(&mut self, rhs: &Self, modulus: M)
+    where
+        M: Copy + ReduceAddAssign<T>,
+    {
+        self.a.add_assign(&rhs.a, modulus);
+        self.b.add_assign(&rhs.b, modulus);
+    }
+
+    /// Performs `self -= rhs` according to `modulus`.
+    #[inline]
+    pub fn sub_assign_element_wise<M>(&mut self, rhs: &Self, modulus: M)
+    where
+        M: Copy + ReduceSubAssign<T>,
+    {
+        self.a.sub_assign(&rhs.a, modulus);
+        self.b.sub_assign(&rhs.b, modulus);
+    }
+
+    /// Performs `destination = self * (X^r - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r >= 2N`.
+    #[inline]
+    pub fn mul_monic_monomial_sub_one_inplace<M>(
+        &self,
+        r: usize,
+        modulus: M,
+        destination: &mut Self,
+    ) where
+        M: Copy + ReduceNeg<T, Output = T> + ReduceSub<T, Output = T>,
+    {
+        self.a
+            .mul_monic_monomial_sub_one_inplace(r, modulus, &mut destination.a);
+        self.b
+            .mul_monic_monomial_sub_one_inplace(r, modulus, &mut destination.b);
+    }
+
+    /// Performs `self = self + rhs * X^r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r >= 2N`.
+    #[inline]
+    pub fn add_assign_rhs_mul_monic_monomial<M>(&mut self, rhs: &Self, r: usize, modulus: M)
+    where
+        M: Copy + ReduceAddAssign<T> + ReduceSubAssign<T>,
+    {
+        self.a.add_assign_rhs_mul_monic_monomial(&rhs.a, r, modulus);
+        self.b.add_assign_rhs_mul_monic_monomial(&rhs.b, r, modulus);
+    }
+
+    /// Extract an LWE sample from RLWE.
+    #[inline]
+    pub fn extract_lwe<M>(&self, modulus: M) -> Lwe<T>
+    where
+        M: Copy + ReduceNegAssign<T>,
+    {
+        let mut a = self.a.as_slice().to_vec();
+        a[1..].reverse();
+        a[1..].iter_mut().for_each(|v| modulus.reduce_neg_assign(v));
+
+        Lwe::new(a, self.b[0])
+    }
+}
+
+impl<T: UnsignedInteger, const N: usize> TryFrom<NumRlwe<T>> for ArrayRlwe<T, N> {
+    type Error = NumRlwe<T>;
+
+    /// Fails and returns the ciphertext back if its dimension is not `N`.
+    #[inline]
+    fn try_from(rlwe: NumRlwe<T>) -> Result<Self, Self::Error> {
+        if rlwe.a.coeff_count() != N || rlwe.b.coeff_count() != N {
+            return Err(rlwe);
+        }
+        Ok(Self {
+            a: ArrayPolynomial::from_slice(rlwe.a.as_slice()),
+            b: ArrayPolynomial::from_slice(rlwe.b.as_slice()),
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use algebra::{
+    utils::{
+        read_chunks, read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes,
+    },
+    AlgebraError, Field,
+};
+use num_traits::ConstZero;
+use rayon::prelude::*;
+
+use super::{Rlwe, RlweView, RlweViewMut};
+
+/// A list of [`Rlwe<F>`] with the same dimension,
+/// stored in one contiguous allocation.
+///
+/// Every ciphertext is laid out as `a || b`, so the whole list can be moved
+/// as a single buffer with [`RlweList::as_slice`] and [`RlweList::from_raw`].
+/// The raw buffer is in the byte order of the host, use [`LeBytes`] to send
+/// the list to another machine.
+pub struct RlweList<F: Field> {
+    data: Vec<<F as Field>::ValueT>,
+    dimension: usize,
+}
+
+impl<F: Field> Clone for RlweList<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            dimension: self.dimension,
+        }
+    }
+}
+
+impl<F: Field> RlweList<F> {
+    /// Creates a new empty [`RlweList<F>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `0` or larger than `usize::MAX / 2`.
+    #[inline]
+    pub fn new(dimension: usize) -> Self {
+        check_dimension(dimension);
+        Self {
+            data: Vec::new(),
+            dimension,
+        }
+    }
+
+    /// Creates a new empty [`RlweList<F>`] with room for `capacity` ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `0` or larger than `usize::MAX / 2`,
+    /// or the buffer length overflows.
+    #[inline]
+    pub fn with_capacity(dimension: usize, capacity: usize) -> Self {
+        Self {
+            data: Vec::with_capacity(buffer_len(dimension, capacity)),
+            dimension,
+        }
+    }
+
+    /// Creates a [`RlweList<F>`] with `count` ciphertexts whose values are all `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `0` or larger than `usize::MAX / 2`,
+    /// or the buffer length overflows.
+    #[inline]
+    pub fn zero(dimension: usize, count: usize) -> Self {
+        Self {
+            data: vec![<F as Field>::ValueT::ZERO; buffer_len(dimension, count)],
+            dimension,
+        }
+    }
+
+    /// Creates a [`RlweList<F>`] from a raw buffer of consecutive `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is `0` or larger than `usize::MAX / 2`,
+    /// or the length of `data` is not a multiple of `2 * dimension`.
+    #[inline]
+    pub fn from_raw(data: Vec<<F as Field>::ValueT>, dimension: usize) -> Self {
+        check_dimension(dimension);
+        assert_eq!(data.len() % (2 * dimension), 0);
+        Self { data, dimension }
+    }
+
+    /// Drops self, and returns the raw buffer.
+    #[inline]
+    pub fn into_raw(self) -> Vec<<F as Field>::ValueT> {
+        self.data
+    }
+
+    /// Returns the raw buffer of this [`RlweList<F>`].
+    #[inline]
+    pub fn as_slice(&self) -> &[<F as Field>::ValueT] {
+        &self.data
+    }
+
+    /// Returns the mutable raw buffer of this [`RlweList<F>`].
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [<F as Field>::ValueT] {
+        &mut self.data
+    }
+
+    /// Returns the dimension of the ciphertexts.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the count of the ciphertexts.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len() / (2 * self.dimension)
+    }
+
+    /// Returns `true` if the list contains no ciphertexts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Appends a ciphertext to the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `rlwe` doesn't match.
+    #[inline]
+    pub fn push(&mut self, rlwe: RlweView<'_, F>) {
+        assert_eq!(rlwe.dimension(), self.dimension);
+        self.data.extend_from_slice(rlwe.a());
+        self.data.extend_from_slice(rlwe.b());
+    }
+
+    /// Returns the range of the `index`-th ciphertext in the raw buffer,
+    /// `None` if it overflows.
+    #[inline]
+    fn range(&self, index: usize) -> Option<core::ops::Range<usize>> {
+        let size = 2 * self.dimension;
+        let start = index.checked_mul(size)?;
+        Some(start..start.checked_add(size)?)
+    }
+
+    /// Returns a view of the `index`-th ciphertext.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<RlweView<'_, F>> {
+        let range = self.range(index)?;
+        self.data.get(range).map(RlweView::from_slice)
+    }
+
+    /// Returns a mutable view of the `index`-th ciphertext.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<RlweViewMut<'_, F>> {
+        let range = self.range(index)?;
+        self.data.get_mut(range).map(RlweViewMut::from_slice)
+    }
+
+    /// Returns an iterator over the views of the ciphertexts.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = RlweView<'_, F>> {
+        self.data
+            .chunks_exact(2 * self.dimension)
+            .map(RlweView::from_slice)
+    }
+
+    /// Returns an iterator over the mutable views of the ciphertexts.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = RlweViewMut<'_, F>> {
+        self.data
+            .chunks_exact_mut(2 * self.dimension)
+            .map(RlweViewMut::from_slice)
+    }
+
+    /// Returns a parallel iterator over the views of the ciphertexts.
+    #[inline]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = RlweView<'_, F>> {
+        self.data
+            .par_chunks_exact(2 * self.dimension)
+            .map(RlweView::from_slice)
+    }
+
+    /// Returns a parallel iterator over the mutable views of the ciphertexts.
+    #[inline]
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = RlweViewMut<'_, F>> {
+        self.data
+            .par_chunks_exact_mut(2 * self.dimension)
+            .map(RlweViewMut::from_slice)
+    }
+}
+
+/// Checks that a ciphertext of `dimension` has a size of `2 * dimension` in `usize`.
+#[inline]
+fn check_dimension(dimension: usize) {
+    assert!(dimension > 0 && dimension <= usize::MAX / 2);
+}
+
+/// Returns the length of the buffer of `count` ciphertexts of `dimension`.
+#[inline]
+fn buffer_len(dimension: usize, count: usize) -> usize {
+    check_dimension(dimension);
+    (2 * dimension)
+        .checked_mul(count)
+        .expect("capacity overflow")
+}
+
+/// The list is encoded after its header and descriptor as its dimension in `u64`,
+/// followed by the raw buffer as one sequence.
+impl<F: Field> LeBytes for RlweList<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"RLWL", buf);
+        write_modulus::<F>(buf);
+        (self.dimension as u64).write_le(buf);
+        self.data.write_le(buf);
+    }
+
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"RLWL", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let dimension = usize::try_from(u64::read_le(bytes)?)
+            .ok()
+            .filter(|&d| d > 0 && d <= usize::MAX / 2)
+            .ok_or(AlgebraError::DecodeErr)?;
+        limits.check_dimension(dimension)?;
+        let data = read_chunks::<F::ValueT>(bytes, 2 * dimension, limits)?;
+        if data.iter().any(|&v| v >= F::MODULUS_VALUE) {
+            return Err(AlgebraError::DecodeErr);
+        }
+        Ok(Self { data, dimension })
+    }
+}
+
+impl<'a, F: Field + 'a> Extend<RlweView<'a, F>> for RlweList<F> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = RlweView<'a, F>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|rlwe| self.push(rlwe));
+    }
+}
+
+impl<'a, F: Field + 'a> Extend<&'a Rlwe<F>> for RlweList<F> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a Rlwe<F>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|rlwe| self.push(rlwe.as_view()));
+    }
+}
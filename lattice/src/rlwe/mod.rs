@@ -1,7 +1,13 @@
+mod array;
+mod list;
 mod normal;
 mod ntt;
 mod num;
+mod view;
 
+pub use array::ArrayRlwe;
+pub use list::RlweList;
 pub use normal::Rlwe;
 pub use ntt::NttRlwe;
 pub use num::NumRlwe;
+pub use view::{RlweView, RlweViewMut};
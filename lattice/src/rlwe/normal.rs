@@ -1,9 +1,10 @@
 use algebra::{
     ntt::NumberTheoryTransform,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    polynomial::{FieldNttPolynomial, FieldPolynomial, SparsePolynomial},
     random::DiscreteGaussian,
     reduce::{ReduceAddAssign, ReduceNeg, ReduceNegAssign, ReduceSubAssign},
-    Field, NttField,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use rand::{CryptoRng, Rng};
 
@@ -536,4 +537,50 @@ impl<F: NttField> Rlwe<F> {
 
         Self { a, b: e }
     }
+
+    /// Generate a `Rlwe<F>` sample which encrypts `0` under a sparse secret key.
+    ///
+    /// `a * s` is computed from the nonzero coefficients of `s`, without NTT,
+    /// which is faster when the Hamming weight of `s` is small.
+    pub fn generate_random_zero_sample_sparse<R>(
+        secret_key: &SparsePolynomial<F>,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        let rlwe_dimension = secret_key.dimension();
+        let a = <FieldPolynomial<F>>::random(rlwe_dimension, rng);
+
+        let mut e = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+        secret_key.mul_dense_add_assign(&a, &mut e);
+
+        Self { a, b: e }
+    }
+}
+
+impl<F: Field> LeBytes for Rlwe<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"RLWE", buf);
+        write_modulus::<F>(buf);
+        self.a.write_le(buf);
+        self.b.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"RLWE", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let a = FieldPolynomial::read_le_with(bytes, limits)?;
+        let b = FieldPolynomial::read_le_with(bytes, limits)?;
+        if a.coeff_count() != b.coeff_count() {
+            return Err(AlgebraError::DecodeErr);
+        }
+        Ok(Self { a, b })
+    }
 }
@@ -5,7 +5,8 @@ use algebra::{
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     random::DiscreteGaussian,
     reduce::ReduceAddAssign,
-    Field, NttField,
+    utils::{read_header, read_modulus, write_header, write_modulus, DecodeLimits, LeBytes},
+    AlgebraError, Field, NttField,
 };
 use rand::{CryptoRng, Rng};
 
@@ -444,3 +445,28 @@ impl<F: NttField> NttRlwe<F> {
         Self { a, b }
     }
 }
+
+impl<F: NttField> LeBytes for NttRlwe<F>
+where
+    F::ValueT: LeBytes,
+{
+    #[inline]
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        write_header(b"NRLW", buf);
+        write_modulus::<F>(buf);
+        self.a.write_le(buf);
+        self.b.write_le(buf);
+    }
+
+    #[inline]
+    fn read_le_with(bytes: &mut &[u8], limits: &DecodeLimits) -> Result<Self, AlgebraError> {
+        let version = read_header(b"NRLW", bytes)?;
+        read_modulus::<F>(version, bytes)?;
+        let a = FieldNttPolynomial::read_le_with(bytes, limits)?;
+        let b = FieldNttPolynomial::read_le_with(bytes, limits)?;
+        if a.coeff_count() != b.coeff_count() {
+            return Err(AlgebraError::DecodeErr);
+        }
+        Ok(Self { a, b })
+    }
+}
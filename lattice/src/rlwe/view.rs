@@ -0,0 +1,194 @@
+use algebra::{polynomial::FieldPolynomial, Field};
+
+use super::Rlwe;
+
+/// A borrowed view of a [`Rlwe<F>`] in coefficient form.
+///
+/// It can point into an owned [`Rlwe<F>`], or into a contiguous buffer
+/// laid out as `a || b`, so batch containers can expose single
+/// ciphertexts without copying.
+pub struct RlweView<'a, F: Field> {
+    a: &'a [<F as Field>::ValueT],
+    b: &'a [<F as Field>::ValueT],
+}
+
+impl<F: Field> Clone for RlweView<'_, F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Field> Copy for RlweView<'_, F> {}
+
+impl<'a, F: Field> RlweView<'a, F> {
+    /// Creates a new [`RlweView<F>`].
+    #[inline]
+    pub fn new(a: &'a [<F as Field>::ValueT], b: &'a [<F as Field>::ValueT]) -> Self {
+        assert_eq!(a.len(), b.len());
+        Self { a, b }
+    }
+
+    /// Creates a new [`RlweView<F>`] from a buffer laid out as `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is odd.
+    #[inline]
+    pub fn from_slice(data: &'a [<F as Field>::ValueT]) -> Self {
+        assert!(data.len() % 2 == 0);
+        let (a, b) = data.split_at(data.len() / 2);
+        Self { a, b }
+    }
+
+    /// Returns the coefficients of `a` of this [`RlweView<F>`].
+    #[inline]
+    pub fn a(&self) -> &'a [<F as Field>::ValueT] {
+        self.a
+    }
+
+    /// Returns the coefficients of `b` of this [`RlweView<F>`].
+    #[inline]
+    pub fn b(&self) -> &'a [<F as Field>::ValueT] {
+        self.b
+    }
+
+    /// Returns the dimension of this [`RlweView<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Copies the viewed values into a new [`Rlwe<F>`].
+    #[inline]
+    pub fn to_rlwe(&self) -> Rlwe<F> {
+        Rlwe {
+            a: FieldPolynomial::from_slice(self.a),
+            b: FieldPolynomial::from_slice(self.b),
+        }
+    }
+}
+
+/// A mutable borrowed view of a [`Rlwe<F>`] in coefficient form.
+///
+/// See [`RlweView<F>`].
+pub struct RlweViewMut<'a, F: Field> {
+    a: &'a mut [<F as Field>::ValueT],
+    b: &'a mut [<F as Field>::ValueT],
+}
+
+impl<'a, F: Field> RlweViewMut<'a, F> {
+    /// Creates a new [`RlweViewMut<F>`].
+    #[inline]
+    pub fn new(a: &'a mut [<F as Field>::ValueT], b: &'a mut [<F as Field>::ValueT]) -> Self {
+        assert_eq!(a.len(), b.len());
+        Self { a, b }
+    }
+
+    /// Creates a new [`RlweViewMut<F>`] from a buffer laid out as `a || b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is odd.
+    #[inline]
+    pub fn from_slice(data: &'a mut [<F as Field>::ValueT]) -> Self {
+        assert!(data.len() % 2 == 0);
+        let (a, b) = data.split_at_mut(data.len() / 2);
+        Self { a, b }
+    }
+
+    /// Reborrows this [`RlweViewMut<F>`] as a [`RlweView<F>`].
+    #[inline]
+    pub fn as_view(&self) -> RlweView<'_, F> {
+        RlweView {
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Returns the coefficients of `a` of this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn a(&self) -> &[<F as Field>::ValueT] {
+        self.a
+    }
+
+    /// Returns the mutable coefficients of `a` of this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn a_mut(&mut self) -> &mut [<F as Field>::ValueT] {
+        self.a
+    }
+
+    /// Returns the coefficients of `b` of this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn b(&self) -> &[<F as Field>::ValueT] {
+        self.b
+    }
+
+    /// Returns the mutable coefficients of `b` of this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn b_mut(&mut self) -> &mut [<F as Field>::ValueT] {
+        self.b
+    }
+
+    /// Returns the dimension of this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Copies the values of `src` into this [`RlweViewMut<F>`].
+    #[inline]
+    pub fn copy_from(&mut self, src: RlweView<'_, F>) {
+        self.a.copy_from_slice(src.a);
+        self.b.copy_from_slice(src.b);
+    }
+
+    /// Performs element-wise addition with `rhs` in place.
+    #[inline]
+    pub fn add_assign_element_wise(&mut self, rhs: RlweView<'_, F>) {
+        debug_assert_eq!(self.a.len(), rhs.a.len());
+        self.a
+            .iter_mut()
+            .zip(rhs.a)
+            .chain(self.b.iter_mut().zip(rhs.b))
+            .for_each(|(x, &y)| F::add_assign(x, y));
+    }
+
+    /// Performs element-wise subtraction with `rhs` in place.
+    #[inline]
+    pub fn sub_assign_element_wise(&mut self, rhs: RlweView<'_, F>) {
+        debug_assert_eq!(self.a.len(), rhs.a.len());
+        self.a
+            .iter_mut()
+            .zip(rhs.a)
+            .chain(self.b.iter_mut().zip(rhs.b))
+            .for_each(|(x, &y)| F::sub_assign(x, y));
+    }
+}
+
+impl<F: Field> Rlwe<F> {
+    /// Returns a [`RlweView<F>`] of this [`Rlwe<F>`].
+    #[inline]
+    pub fn as_view(&self) -> RlweView<'_, F> {
+        RlweView {
+            a: self.a.as_slice(),
+            b: self.b.as_slice(),
+        }
+    }
+
+    /// Returns a [`RlweViewMut<F>`] of this [`Rlwe<F>`].
+    #[inline]
+    pub fn as_view_mut(&mut self) -> RlweViewMut<'_, F> {
+        RlweViewMut {
+            a: self.a.as_mut_slice(),
+            b: self.b.as_mut_slice(),
+        }
+    }
+}
+
+impl<'a, F: Field> From<&'a Rlwe<F>> for RlweView<'a, F> {
+    #[inline]
+    fn from(value: &'a Rlwe<F>) -> Self {
+        value.as_view()
+    }
+}
@@ -3,14 +3,19 @@ use std::sync::LazyLock;
 use algebra::decompose::NonPowOf2ApproxSignedBasis;
 use algebra::modulus::PowOf2Modulus;
 use algebra::ntt::NumberTheoryTransform;
-use algebra::polynomial::FieldPolynomial;
+use algebra::polynomial::{ArrayPolynomial, FieldPolynomial, SparsePolynomial};
 use algebra::random::DiscreteGaussian;
 use algebra::reduce::{ReduceAdd, ReduceMulAdd, ReduceSub};
+use algebra::utils::{DecodeLimits, LeBytes, FORMAT_VERSION};
 use algebra::{Field, NttField, U32FieldEval};
-use lattice::{GadgetRlwe, Lwe, NttRlwe, Rlwe};
+use lattice::{
+    ArrayRlwe, GadgetRlwe, Lwe, LweList, LweView, LweViewMut, NttGadgetRlwe, NttRgsw, NttRlwe,
+    Rlwe, RlweList, RlweView, RlweViewMut,
+};
 use rand::distributions::Uniform;
 use rand::prelude::Distribution;
 use rand::{thread_rng, Rng};
+use rayon::prelude::*;
 
 type Inner = u32; // inner type
 type FF = U32FieldEval<132120577>; // field type
@@ -54,6 +59,150 @@ fn test_lwe() {
     assert_eq!(lwe3.sub_reduce_component_wise(&lwe2, modulus), lwe1);
 }
 
+#[test]
+fn test_lwe_view() {
+    let rng = &mut rand::thread_rng();
+
+    let dis = Uniform::new(0u32, RR);
+    let modulus = <PowOf2Modulus<u32>>::new(RR);
+
+    // two ciphertexts laid out as `a || b` in one buffer
+    let mut buffer = rng
+        .sample_iter(dis)
+        .take(2 * (N + 1))
+        .collect::<Vec<Inner>>();
+    let lwe1 = LweView::from_slice(&buffer[..N + 1]).to_lwe();
+    let lwe2 = LweView::from_slice(&buffer[N + 1..]).to_lwe();
+    assert_eq!(lwe1.a(), &buffer[..N]);
+    assert_eq!(lwe1.b(), buffer[N]);
+
+    let (first, second) = buffer.split_at_mut(N + 1);
+    let mut view = LweViewMut::from_slice(first);
+    view.add_reduce_assign_component_wise(LweView::from_slice(second), modulus);
+    assert_eq!(
+        view.as_view().to_lwe(),
+        lwe1.clone().add_reduce_component_wise(&lwe2, modulus)
+    );
+
+    view.sub_reduce_assign_component_wise(lwe2.as_view(), modulus);
+    assert_eq!(view.as_view(), lwe1.as_view());
+}
+
+#[test]
+fn test_lwe_list() {
+    let rng = &mut rand::thread_rng();
+
+    let dis = Uniform::new(0u32, RR);
+    let modulus = <PowOf2Modulus<u32>>::new(RR);
+
+    let lwes: Vec<Lwe<Inner>> = (0..8)
+        .map(|_| Lwe::new(rng.sample_iter(dis).take(N).collect(), rng.sample(dis)))
+        .collect();
+
+    let mut list = LweList::with_capacity(N, lwes.len());
+    list.extend(lwes.iter());
+    assert_eq!(list.len(), lwes.len());
+    assert_eq!(list.as_slice().len(), lwes.len() * (N + 1));
+    assert!(list.get(lwes.len()).is_none());
+    // the offset of these indices overflows
+    assert!(list.get(usize::MAX).is_none());
+    assert!(list.get_mut(usize::MAX / N).is_none());
+    assert!(list.iter().zip(lwes.iter()).all(|(x, y)| x == y.as_view()));
+
+    let mut list = LweList::from_raw(list.into_raw(), N);
+    let other = list.clone();
+    list.par_iter_mut()
+        .zip(other.par_iter())
+        .for_each(|(mut x, y)| x.add_reduce_assign_component_wise(y, modulus));
+    for (x, y) in list.iter().zip(lwes.iter()) {
+        assert_eq!(x.to_lwe(), y.clone().add_reduce_component_wise(y, modulus));
+    }
+
+    list.get_mut(0).unwrap().set_zero();
+    assert_eq!(list.get(0).unwrap(), LweList::zero(N, 1).get(0).unwrap());
+
+    let bytes = list.to_le_vec();
+    assert_eq!(bytes.len(), 5 + 1 + 8 + 8 + 4 * lwes.len() * (N + 1));
+    assert_eq!(LweList::<Inner>::from_le_slice(&bytes).unwrap(), list);
+}
+
+#[test]
+fn test_list_le_bytes() {
+    let mut list = LweList::new(1);
+    list.extend([&Lwe::new(vec![0x0102u16], 3), &Lwe::new(vec![4], 0x0506)]);
+    let bytes = list.to_le_vec();
+    assert_eq!(bytes[..5], [b'L', b'W', b'E', b'L', FORMAT_VERSION]);
+    assert_eq!(
+        bytes[5..],
+        [
+            2, // width
+            1, 0, 0, 0, 0, 0, 0, 0, // dimension
+            4, 0, 0, 0, 0, 0, 0, 0, 2, 1, 3, 0, 4, 0, 6, 5, // data
+        ]
+    );
+    assert_eq!(LweList::<u16>::from_le_slice(&bytes).unwrap(), list);
+    assert!(LweList::<u32>::from_le_slice(&bytes).is_err());
+
+    // version 1 has no width
+    let mut old = bytes[..5].to_vec();
+    old[4] = 1;
+    old.extend_from_slice(&bytes[6..]);
+    assert_eq!(LweList::<u16>::from_le_slice(&old).unwrap(), list);
+
+    // a buffer which is not a whole number of ciphertexts
+    let mut bad = bytes[..14].to_vec();
+    bad.extend_from_slice(&Vec::<u16>::from([1, 2, 3]).to_le_vec());
+    assert!(LweList::<u16>::from_le_slice(&bad).is_err());
+    assert!(LweList::<u16>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+
+    // more ciphertexts than the limits allow
+    let limits = DecodeLimits {
+        max_dimension: 1,
+        ..DecodeLimits::UNLIMITED
+    };
+    assert!(LweList::<u16>::from_le_slice_with(&bytes, &limits).is_err());
+
+    let mut list = RlweList::<FF>::new(1);
+    let rlwe = Rlwe::new(PolyFF::new(vec![1]), PolyFF::new(vec![0x07df0000]));
+    list.push(rlwe.as_view());
+    let bytes = list.to_le_vec();
+    assert_eq!(bytes[..5], [b'R', b'L', b'W', b'L', FORMAT_VERSION]);
+    assert_eq!(
+        bytes[5..],
+        [
+            1, 0, 0xe0, 7, // modulus
+            1, 0, 0, 0, 0, 0, 0, 0, // dimension
+            2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0xdf, 7, // data
+        ]
+    );
+    assert!(RlweList::<FF>::from_le_slice(&bytes).unwrap().as_slice() == list.as_slice());
+    let limits = DecodeLimits {
+        max_dimension: 1,
+        ..DecodeLimits::UNLIMITED
+    };
+    assert!(RlweList::<FF>::from_le_slice_with(&bytes, &limits).is_ok());
+    let mut longer = list.clone();
+    longer.push(rlwe.as_view());
+    assert!(RlweList::<FF>::from_le_slice_with(&longer.to_le_vec(), &limits).is_err());
+
+    // version 1 has no modulus
+    let mut old = bytes[..5].to_vec();
+    old[4] = 1;
+    old.extend_from_slice(&bytes[9..]);
+    assert!(RlweList::<FF>::from_le_slice(&old).unwrap().as_slice() == list.as_slice());
+
+    // a zero dimension, another field, a value out of range, the header of a LWE list
+    let mut bad = bytes.clone();
+    bad[9] = 0;
+    assert!(RlweList::<FF>::from_le_slice(&bad).is_err());
+    assert!(RlweList::<U32FieldEval<65537>>::from_le_slice(&bytes).is_err());
+    let mut bad = bytes.clone();
+    bad[25..29].copy_from_slice(&FF::MODULUS_VALUE.to_le_bytes());
+    assert!(RlweList::<FF>::from_le_slice(&bad).is_err());
+    bad[..5].copy_from_slice(&[b'L', b'W', b'E', b'L', FORMAT_VERSION]);
+    assert!(RlweList::<FF>::from_le_slice(&bad).is_err());
+}
+
 #[test]
 fn test_lwe_he() {
     const RP: Inner = RR;
@@ -141,6 +290,161 @@ fn test_rlwe() {
     assert!(rlwe2 == rlwe3);
 }
 
+#[test]
+fn test_array_rlwe() {
+    let mut rng = thread_rng();
+    let modulus = FF::MODULUS;
+    let array = |poly: &PolyFF| ArrayPolynomial::<Inner, N>::from_slice(poly.as_slice());
+
+    let rlwe = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let rhs = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let array_rlwe = ArrayRlwe::new(array(rlwe.a()), array(rlwe.b()));
+    let array_rhs = ArrayRlwe::new(array(rhs.a()), array(rhs.b()));
+
+    assert!(ArrayRlwe::try_from(array_rlwe.to_num_rlwe()).is_ok_and(|r| r == array_rlwe));
+    assert_eq!(array_rlwe.extract_lwe(modulus), rlwe.extract_lwe());
+
+    for r in [0, 1, N - 1, N, N + 1, 2 * N - 1] {
+        let mut expected = Rlwe::zero(N);
+        rlwe.mul_monic_monomial_sub_one_inplace(N, r, &mut expected);
+        let mut result = ArrayRlwe::zero();
+        array_rlwe.mul_monic_monomial_sub_one_inplace(r, modulus, &mut result);
+        assert_eq!(result.a().as_slice(), expected.a_slice());
+        assert_eq!(result.b().as_slice(), expected.b_slice());
+
+        let mut expected = rlwe.clone();
+        expected.add_assign_rhs_mul_monic_monomial(&rhs, N, r);
+        let mut result = array_rlwe.clone();
+        result.add_assign_rhs_mul_monic_monomial(&array_rhs, r, modulus);
+        assert_eq!(result.a().as_slice(), expected.a_slice());
+        assert_eq!(result.b().as_slice(), expected.b_slice());
+    }
+}
+
+#[test]
+fn test_ciphertext_le_bytes() {
+    let mut rng = thread_rng();
+
+    let lwe = Lwe::new(vec![1u32, 0x01020304], 0x0a0b0c0d);
+    let bytes = lwe.to_le_vec();
+    assert_eq!(bytes[..5], [b'L', b'W', b'E', b' ', FORMAT_VERSION]);
+    assert_eq!(
+        bytes[5..],
+        [4, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 3, 2, 1, 0x0d, 0x0c, 0x0b, 0x0a]
+    );
+    assert_eq!(Lwe::<u32>::from_le_slice(&bytes).unwrap(), lwe);
+    assert!(Lwe::<u64>::from_le_slice(&bytes).is_err());
+
+    // version 1 has no width
+    let mut old = vec![b'L', b'W', b'E', b' ', 1];
+    old.extend_from_slice(&bytes[6..]);
+    assert_eq!(Lwe::<u32>::from_le_slice(&old).unwrap(), lwe);
+
+    let rlwe = Rlwe::new(PolyFF::new(vec![1]), PolyFF::new(vec![0x07df0000]));
+    let body = [
+        1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, // a
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xdf, 7, // b
+    ];
+    let mut bytes = vec![b'R', b'L', b'W', b'E', FORMAT_VERSION, 1, 0, 0xe0, 7];
+    bytes.extend_from_slice(&body);
+    assert_eq!(rlwe.to_le_vec(), bytes);
+    assert!(Rlwe::<FF>::from_le_slice(&bytes).unwrap() == rlwe);
+    assert!(Rlwe::<U32FieldEval<65537>>::from_le_slice(&bytes).is_err());
+
+    // version 1 has no modulus
+    let mut old = vec![b'R', b'L', b'W', b'E', 1];
+    old.extend_from_slice(&body);
+    assert!(Rlwe::<FF>::from_le_slice(&old).unwrap() == rlwe);
+
+    let rlwe = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let bytes = rlwe.to_le_vec();
+    assert_eq!(bytes.len(), 5 + 4 + 2 * (8 + 4 * N));
+    assert!(Rlwe::<FF>::from_le_slice(&bytes).unwrap() == rlwe);
+
+    let ntt_rlwe = NttRlwe::new(NTT_TABLE.transform(rlwe.a()), NTT_TABLE.transform(rlwe.b()));
+    let ntt_bytes = ntt_rlwe.to_le_vec();
+    assert_eq!(ntt_bytes[..5], [b'N', b'R', b'L', b'W', FORMAT_VERSION]);
+    assert!(NttRlwe::<FF>::from_le_slice(&ntt_bytes).unwrap() == ntt_rlwe);
+
+    // the tag of another type, or another version
+    assert!(NttRlwe::<FF>::from_le_slice(&bytes).is_err());
+    assert!(Rlwe::<FF>::from_le_slice(&ntt_bytes).is_err());
+    let mut other_version = bytes.clone();
+    other_version[4] = FORMAT_VERSION + 1;
+    assert!(Rlwe::<FF>::from_le_slice(&other_version).is_err());
+
+    // truncated, or `a` and `b` of different lengths
+    let rlwe_bytes = rlwe.to_le_vec();
+    assert!(Rlwe::<FF>::from_le_slice(&rlwe_bytes[..rlwe_bytes.len() - 1]).is_err());
+    let mut bytes = rlwe_bytes[..9].to_vec();
+    bytes.extend_from_slice(&rlwe.a().to_le_vec());
+    bytes.extend_from_slice(&PolyFF::random(N / 2, &mut rng).to_le_vec());
+    assert!(Rlwe::<FF>::from_le_slice(&bytes).is_err());
+}
+
+#[test]
+fn test_rlwe_view() {
+    let mut rng = thread_rng();
+
+    let rlwe1 = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let rlwe2 = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+
+    let mut buffer: Vec<Inner> = rlwe1.a().iter().chain(rlwe1.b().iter()).copied().collect();
+    assert!(RlweView::<FF>::from_slice(&buffer).to_rlwe() == rlwe1);
+
+    let mut view = RlweViewMut::<FF>::from_slice(&mut buffer);
+    view.add_assign_element_wise(rlwe2.as_view());
+    assert!(view.as_view().to_rlwe() == rlwe1.clone().add_element_wise(&rlwe2));
+
+    view.sub_assign_element_wise(rlwe2.as_view());
+    assert!(view.as_view().to_rlwe() == rlwe1);
+}
+
+#[test]
+fn test_rlwe_list() {
+    let mut rng = thread_rng();
+
+    let rlwes: Vec<Rlwe<FF>> = (0..4)
+        .map(|_| Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng)))
+        .collect();
+
+    let mut list = RlweList::new(N);
+    list.extend(rlwes.iter());
+    assert_eq!(list.len(), rlwes.len());
+    assert!(list.get(rlwes.len()).is_none());
+    assert!(list.get(usize::MAX).is_none());
+    assert!(list.get_mut(usize::MAX / N).is_none());
+    assert!(list
+        .iter()
+        .zip(rlwes.iter())
+        .all(|(x, y)| x.to_rlwe() == *y));
+
+    let other = RlweList::<FF>::from_raw(list.as_slice().to_vec(), N);
+    list.par_iter_mut()
+        .zip(other.par_iter())
+        .for_each(|(mut x, y)| x.sub_assign_element_wise(y));
+    assert!(list
+        .iter()
+        .all(|x| x.a().iter().chain(x.b()).all(|&v| v == 0)));
+    assert!(list.as_slice() == RlweList::<FF>::zero(N, rlwes.len()).as_slice());
+
+    let list = RlweList::<FF>::from_le_slice(&other.to_le_vec()).unwrap();
+    assert_eq!(list.dimension(), N);
+    assert!(list.as_slice() == other.as_slice());
+}
+
+#[test]
+#[should_panic]
+fn test_lwe_list_max_dimension() {
+    LweList::<Inner>::new(usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_rlwe_list_max_dimension() {
+    RlweList::<FF>::new(usize::MAX / 2 + 1);
+}
+
 #[inline]
 fn encode(m: Inner) -> Inner {
     (m as f64 * FP as f64 / FT as f64).round() as Inner
@@ -204,6 +508,21 @@ fn test_rlwe_he() {
     assert_eq!(decrypted_add, v_add);
 }
 
+#[test]
+fn test_rlwe_sparse_zero_sample() {
+    let mut rng = rand::thread_rng();
+    let chi = DiscreteGaussian::new(0., 3.2, FF::MINUS_ONE).unwrap();
+
+    let s = SparsePolynomial::<FF>::random_ternary(N, N / 4, &mut rng);
+    let ntt_s = NTT_TABLE.transform(&s.to_dense());
+
+    let rlwe = Rlwe::generate_random_zero_sample_sparse(&s, chi, &mut rng);
+
+    let a_mul_s = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(rlwe.a()) * &ntt_s);
+    let e = rlwe.b() - a_mul_s;
+    assert!(e.iter().all(|&v| min_to_zero(v) <= 3.2 as Inner * 6));
+}
+
 #[test]
 fn extract_lwe_test() {
     let mut rng = thread_rng();
@@ -294,3 +613,141 @@ fn test_gadget_rlwe() {
     let decoded: Vec<Inner> = poly_mul_m.into_iter().map(decode).collect();
     assert_eq!(decrypted, decoded);
 }
+
+#[test]
+fn test_gadget_le_bytes() {
+    let mut rng = rand::thread_rng();
+
+    let ntt_s = NTT_TABLE.transform(&PolyFF::random(N, &mut rng));
+    let gaussian = DiscreteGaussian::new(0., 1.0, FF::MINUS_ONE).unwrap();
+    let basis = <NonPowOf2ApproxSignedBasis<Inner>>::new(FF::MODULUS_VALUE, BASE_BITS, None);
+    let m = PolyFF::random_binary(N, &mut rng);
+
+    let gadget_rlwe =
+        GadgetRlwe::generate_random_poly_sample(&ntt_s, &m, &basis, gaussian, &NTT_TABLE, &mut rng);
+    let bytes = gadget_rlwe.to_le_vec();
+    assert_eq!(bytes[..5], [b'G', b'R', b'L', b'W', FORMAT_VERSION]);
+    // modulus, `log_basis` and decompose length
+    assert_eq!(
+        bytes[5..21],
+        [1, 0, 0xe0, 7, 3, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0]
+    );
+    let decoded = GadgetRlwe::<FF>::from_le_slice(&bytes).unwrap();
+    assert!(*decoded.basis() == basis);
+    assert_eq!(decoded.to_le_vec(), bytes);
+
+    let ntt_gadget_rlwe = gadget_rlwe.clone().to_ntt_gadget_rlwe(&NTT_TABLE);
+    let ntt_bytes = ntt_gadget_rlwe.to_le_vec();
+    assert_eq!(ntt_bytes[..5], [b'N', b'G', b'R', b'L', FORMAT_VERSION]);
+    let decoded = NttGadgetRlwe::<FF>::from_le_slice(&ntt_bytes).unwrap();
+    assert_eq!(decoded.to_le_vec(), ntt_bytes);
+    assert!(GadgetRlwe::<FF>::from_le_slice(&ntt_bytes).is_err());
+
+    // within and beyond the limits
+    let limits = DecodeLimits {
+        max_bytes: bytes.len(),
+        max_dimension: N,
+        max_levels: basis.decompose_length(),
+    };
+    assert!(GadgetRlwe::<FF>::from_le_slice_with(&bytes, &limits).is_ok());
+    for limits in [
+        DecodeLimits {
+            max_bytes: bytes.len() - 1,
+            ..limits
+        },
+        DecodeLimits {
+            max_dimension: N - 1,
+            ..limits
+        },
+        DecodeLimits {
+            max_levels: basis.decompose_length() - 1,
+            ..limits
+        },
+    ] {
+        assert!(GadgetRlwe::<FF>::from_le_slice_with(&bytes, &limits).is_err());
+    }
+
+    // a basis which can't be built: no levels, too many levels, a zero `log_basis`
+    for (offset, value) in [(13, 0u8), (13, 10), (9, 0)] {
+        let mut bad = bytes.clone();
+        bad[offset] = value;
+        assert!(GadgetRlwe::<FF>::from_le_slice(&bad).is_err());
+    }
+
+    // levels of different dimensions, truncated
+    let mut bad = bytes[..21].to_vec();
+    for (i, rlwe) in gadget_rlwe.iter().enumerate() {
+        let n = if i == 0 { N / 2 } else { N };
+        bad.extend_from_slice(&PolyFF::new(rlwe.a().as_slice()[..n].to_vec()).to_le_vec());
+        bad.extend_from_slice(&PolyFF::new(rlwe.b().as_slice()[..n].to_vec()).to_le_vec());
+    }
+    assert!(GadgetRlwe::<FF>::from_le_slice(&bad).is_err());
+    assert!(GadgetRlwe::<FF>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_rgsw_le_bytes() {
+    let mut rng = rand::thread_rng();
+
+    let ntt_s = NTT_TABLE.transform(&PolyFF::random(N, &mut rng));
+    let gaussian = DiscreteGaussian::new(0., 1.0, FF::MINUS_ONE).unwrap();
+    let basis = <NonPowOf2ApproxSignedBasis<Inner>>::new(FF::MODULUS_VALUE, BASE_BITS, None);
+
+    let rgsw = NttRgsw::generate_random_one_sample(&ntt_s, &basis, gaussian, &NTT_TABLE, &mut rng);
+    let bytes = rgsw.to_le_vec();
+    assert_eq!(bytes[..5], [b'N', b'R', b'G', b'S', FORMAT_VERSION]);
+    let decoded = NttRgsw::<FF>::from_le_slice(&bytes).unwrap();
+    assert_eq!(decoded.dimension(), N);
+    assert!(*decoded.basis() == basis);
+    assert_eq!(decoded.to_le_vec(), bytes);
+    assert!(NttRgsw::<FF>::from_le_slice(&bytes[..bytes.len() - 1]).is_err());
+
+    // the two gadget ciphertexts with different bases
+    let other_basis =
+        <NonPowOf2ApproxSignedBasis<Inner>>::new(FF::MODULUS_VALUE, BASE_BITS + 1, None);
+    let other = NttGadgetRlwe::generate_random_zero_sample(
+        &ntt_s,
+        &other_basis,
+        gaussian,
+        &NTT_TABLE,
+        &mut rng,
+    );
+    let mut bad = bytes[..9].to_vec();
+    bad.extend(rgsw.minus_s_m().to_le_vec());
+    bad.extend(other.to_le_vec());
+    assert!(NttRgsw::<FF>::from_le_slice(&bad).is_err());
+}
+
+#[test]
+fn test_ciphertext_decode_limits() {
+    let mut rng = thread_rng();
+
+    let lwe = Lwe::new(vec![1u32; N], 2);
+    let rlwe = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let mut lwe_list = LweList::new(N);
+    lwe_list.push(lwe.as_view());
+    let mut rlwe_list = RlweList::new(N);
+    rlwe_list.push(rlwe.as_view());
+
+    let limits = DecodeLimits {
+        max_dimension: N,
+        ..DecodeLimits::UNLIMITED
+    };
+    let lower = DecodeLimits {
+        max_dimension: N - 1,
+        ..DecodeLimits::UNLIMITED
+    };
+
+    let bytes = lwe.to_le_vec();
+    assert!(Lwe::<u32>::from_le_slice_with(&bytes, &limits).is_ok());
+    assert!(Lwe::<u32>::from_le_slice_with(&bytes, &lower).is_err());
+    let bytes = rlwe.to_le_vec();
+    assert!(Rlwe::<FF>::from_le_slice_with(&bytes, &limits).is_ok());
+    assert!(Rlwe::<FF>::from_le_slice_with(&bytes, &lower).is_err());
+    let bytes = lwe_list.to_le_vec();
+    assert!(LweList::<u32>::from_le_slice_with(&bytes, &limits).is_ok());
+    assert!(LweList::<u32>::from_le_slice_with(&bytes, &lower).is_err());
+    let bytes = rlwe_list.to_le_vec();
+    assert!(RlweList::<FF>::from_le_slice_with(&bytes, &limits).is_ok());
+    assert!(RlweList::<FF>::from_le_slice_with(&bytes, &lower).is_err());
+}